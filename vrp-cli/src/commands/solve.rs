@@ -43,6 +43,7 @@ const PARALLELISM_ARG_NAME: &str = "parallelism";
 const HEURISTIC_ARG_NAME: &str = "heuristic";
 const EXPERIMENTAL_ARG_NAME: &str = "experimental";
 const ROUNDED_ARG_NAME: &str = "round";
+const REPLAN_ARG_NAME: &str = "replan";
 
 #[allow(clippy::type_complexity)]
 struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, GenericError>>);
@@ -119,21 +120,24 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
     }
 }
 
-fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>) {
+fn add_pragmatic(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn Random + Send + Sync>) {
     use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
     use vrp_pragmatic::format::solution::read_init_solution as read_init_pragmatic;
 
+    let replan_files = get_replan_files(matches);
+
     formats.insert(
         "pragmatic",
         (
-            ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                if let Some(matrices) = matrices {
+            ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| {
+                if let Some((solution, state)) = replan_files.as_ref() {
+                    read_replan_problem(problem, matrices, solution, state)
+                } else if let Some(matrices) = matrices {
                     let matrices = matrices.into_iter().map(BufReader::new).collect();
-                    (BufReader::new(problem), matrices).read_pragmatic()
+                    (BufReader::new(problem), matrices).read_pragmatic().map_err(|errs| errs.into())
                 } else {
-                    BufReader::new(problem).read_pragmatic()
+                    BufReader::new(problem).read_pragmatic().map_err(|errs| errs.into())
                 }
-                .map_err(|errs| errs.into())
             })),
             InitSolutionReader(Box::new(move |file, problem| {
                 read_init_pragmatic(BufReader::new(file), problem, random.clone())
@@ -160,7 +164,7 @@ fn get_formats<'a>(matches: &ArgMatches, random: Arc<dyn Random + Send + Sync>)
     let mut formats = FormatMap::default();
 
     add_scientific(&mut formats, matches, random.clone());
-    add_pragmatic(&mut formats, random);
+    add_pragmatic(&mut formats, matches, random);
 
     formats
 }
@@ -294,6 +298,15 @@ pub fn get_solve_app() -> Command {
                 .required(false)
                 .action(ArgAction::SetTrue)
         )
+        .arg(
+            Arg::new(REPLAN_ARG_NAME)
+                .help("Specifies paths to already executing solution and its execution state to re-plan. Applicable only for pragmatic format.")
+                .long(REPLAN_ARG_NAME)
+                .num_args(2)
+                .value_names(["SOLUTION", "STATE"])
+                .required(false)
+                .conflicts_with_all([INIT_SOLUTION_ARG_NAME, CHECK_ARG_NAME])
+        )
         .arg(
             Arg::new(ROUNDED_ARG_NAME)
                 .help("Specifies whether costs are rounded. Applicable only for scientific formats.")
@@ -324,6 +337,10 @@ pub fn run_solve(
     let is_get_locations_set = matches.get_one::<bool>(GET_LOCATIONS_ARG_NAME).copied().unwrap_or(false);
     let is_check_requested = matches.get_one::<bool>(CHECK_ARG_NAME).copied().unwrap_or(false);
 
+    if matches.contains_id(REPLAN_ARG_NAME) && problem_format != "pragmatic" {
+        return Err(format!("replan is not supported for '{problem_format}' format").into());
+    }
+
     match formats.get(problem_format.as_str()) {
         Some((
             ProblemReader(problem_reader),
@@ -499,6 +516,40 @@ fn get_environment(matches: &ArgMatches) -> Result<Arc<Environment>, GenericErro
         .unwrap_or_else(|| Ok(Arc::new(Environment { quota, is_experimental, ..Environment::default() })))
 }
 
+fn get_replan_files(matches: &ArgMatches) -> Option<(String, String)> {
+    matches.get_many::<String>(REPLAN_ARG_NAME).and_then(|paths| match paths.collect::<Vec<_>>().as_slice() {
+        [solution, state] => Some((solution.to_string(), state.to_string())),
+        _ => None,
+    })
+}
+
+fn read_replan_problem(
+    problem: File,
+    matrices: Option<Vec<File>>,
+    solution: &str,
+    state: &str,
+) -> Result<Problem, GenericError> {
+    use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem};
+    use vrp_pragmatic::format::replan::{create_replan_problem, deserialize_execution_state};
+    use vrp_pragmatic::format::solution::deserialize_solution;
+
+    let problem = deserialize_problem(BufReader::new(problem)).map_err(|errs| errs.to_string())?;
+    let solution = deserialize_solution(BufReader::new(open_file(solution, "replan solution")))
+        .map_err(|err| format!("cannot read solution to replan: '{err}'"))?;
+    let state = deserialize_execution_state(BufReader::new(open_file(state, "execution state")))?;
+
+    let problem = create_replan_problem(&problem, &solution, &state)?;
+
+    let matrices = matrices
+        .map(|matrices| {
+            matrices.into_iter().map(|matrix| deserialize_matrix(BufReader::new(matrix))).collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .map_err(|errs| errs.to_string())?;
+
+    (problem, matrices).read_pragmatic().map_err(|errs| errs.into())
+}
+
 fn get_matrix_files(matches: &ArgMatches) -> Option<Vec<File>> {
     matches
         .get_many::<String>(MATRIX_ARG_NAME)
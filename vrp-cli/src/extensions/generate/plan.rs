@@ -5,7 +5,7 @@ mod plan_test;
 use super::get_random_item;
 use vrp_core::prelude::GenericError;
 use vrp_core::utils::{DefaultRandom, Random};
-use vrp_pragmatic::format::problem::{Job, JobPlace, JobTask, Plan, Problem};
+use vrp_pragmatic::format::problem::{Job, JobPlace, JobTask, JobVariant, Plan, Problem};
 use vrp_pragmatic::format::Location;
 
 /// Generates a new plan for given problem with amount of jobs specified by`jobs_size` and
@@ -36,8 +36,12 @@ pub(crate) fn generate_plan(
                         .map(|place| JobPlace {
                             location: get_location_fn(&rnd),
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
+                            setup: None,
+                            duration_rules: None,
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
                             tag: place.tag.clone(),
+                            tolerance: None,
+                            dock: None,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -46,6 +50,9 @@ pub(crate) fn generate_plan(
                         get_random_item(demands.as_slice(), &rnd).cloned()
                     },
                     order: task.order,
+                    splittable: task.splittable,
+                    min_split: task.min_split.clone(),
+                    max_time_in_vehicle: task.max_time_in_vehicle,
                 })
                 .collect::<Vec<_>>()
         })
@@ -69,11 +76,30 @@ pub(crate) fn generate_plan(
                 value: job_proto.value,
                 group: job_proto.group.clone(),
                 compatibility: job_proto.compatibility.clone(),
+                max_ride_duration: job_proto.max_ride_duration,
+                max_ride_factor: job_proto.max_ride_factor,
+                product: job_proto.product.clone(),
+                periodic: job_proto.periodic.clone(),
+                truck_only: job_proto.truck_only,
+                transfers: job_proto.transfers.clone(),
+                satellite_id: job_proto.satellite_id.clone(),
+                alternatives: job_proto.alternatives.as_ref().map(|variants| {
+                    variants
+                        .iter()
+                        .map(|variant| JobVariant {
+                            pickups: generate_tasks(&variant.pickups, keep_original_demand),
+                            deliveries: generate_tasks(&variant.deliveries, keep_original_demand),
+                            replacements: generate_tasks(&variant.replacements, false),
+                            services: generate_tasks(&variant.services, true),
+                            value: variant.value,
+                        })
+                        .collect()
+                }),
             }
         })
         .collect();
 
-    Ok(Plan { jobs, relations: None, clustering: None })
+    Ok(Plan { jobs, relations: None, clustering: None, zones: None })
 }
 
 type LocationFn = Box<dyn Fn(&DefaultRandom) -> Location>;
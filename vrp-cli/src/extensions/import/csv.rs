@@ -68,11 +68,18 @@ mod actual {
             places: vec![JobPlace {
                 location: Location::Coordinate { lat: job.lat, lng: job.lng },
                 duration: job.duration as f64,
+                setup: None,
+                duration_rules: None,
                 times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
                 tag: None,
+                tolerance: None,
+                dock: None,
             }],
             demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
             order: None,
+            splittable: None,
+            min_split: None,
+            max_time_in_vehicle: None,
         };
 
         let get_tasks = |jobs: &Vec<&CsvJob>, filter: Box<dyn Fn(&CsvJob) -> bool>| {
@@ -101,6 +108,14 @@ mod actual {
                 value: None,
                 group: None,
                 compatibility: None,
+                max_ride_duration: None,
+                max_ride_factor: None,
+                product: None,
+                periodic: None,
+                alternatives: None,
+                truck_only: None,
+                transfers: None,
+                satellite_id: None,
             })
             .collect();
 
@@ -117,7 +132,14 @@ mod actual {
                     type_id: vehicle.id.clone(),
                     vehicle_ids: (1..=vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
                     profile: VehicleProfile { matrix: vehicle.profile, scale: None },
-                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+                    costs: VehicleCosts {
+                        fixed: Some(25.),
+                        distance: 0.0002,
+                        time: 0.005,
+                        distance_loaded: None,
+                        time_tiers: None,
+                        distance_tiers: None,
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: vehicle.tw_start,
@@ -132,6 +154,8 @@ mod actual {
                     capacity: vec![vehicle.capacity],
                     skills: None,
                     limits: None,
+                    compartments: None,
+                    trailer: None,
                 }
             })
             .collect();
@@ -158,11 +182,12 @@ mod actual {
         let matrix_profile_names = vehicles.iter().map(|v| v.profile.matrix.clone()).collect::<HashSet<_>>();
 
         Ok(Problem {
-            plan: Plan { jobs, relations: None, clustering: None },
+            plan: Plan { jobs, relations: None, clustering: None, zones: None },
             fleet: Fleet {
                 vehicles,
                 profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
                 resources: None,
+                drivers: None,
             },
             objectives: None,
         })
@@ -12,19 +12,36 @@ pub fn create_empty_job() -> Job {
         value: None,
         group: None,
         compatibility: None,
+        max_ride_duration: None,
+        max_ride_factor: None,
+        product: None,
+        periodic: None,
+        alternatives: None,
+        truck_only: None,
+        transfers: None,
+        satellite_id: None,
     }
 }
 
 pub fn create_empty_job_task() -> JobTask {
-    JobTask { places: vec![], demand: None, order: None }
+    JobTask { places: vec![], demand: None, order: None, splittable: None, min_split: None, max_time_in_vehicle: None }
 }
 
 pub fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None, tag: None }
+    JobPlace {
+        location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+        duration: 0.0,
+        setup: None,
+        duration_rules: None,
+        times: None,
+        tag: None,
+        tolerance: None,
+        dock: None,
+    }
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, clustering: None, zones: None }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
@@ -32,7 +49,14 @@ pub fn create_test_vehicle_type() -> VehicleType {
         type_id: "vehicle".to_string(),
         vehicle_ids: vec!["vehicle_1".to_string()],
         profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-        costs: VehicleCosts { fixed: None, distance: 1., time: 0. },
+        costs: VehicleCosts {
+            fixed: None,
+            distance: 1.,
+            time: 0.,
+            distance_loaded: None,
+            time_tiers: None,
+            distance_tiers: None,
+        },
         shifts: vec![VehicleShift {
             start: ShiftStart {
                 earliest: "2020-05-01T09:00:00.00Z".to_string(),
@@ -47,6 +71,8 @@ pub fn create_test_vehicle_type() -> VehicleType {
         capacity: vec![10],
         skills: None,
         limits: None,
+        compartments: None,
+        trailer: None,
     }
 }
 
@@ -150,3 +150,28 @@ fn can_specify_cv() {
         assert_eq!(min_cv, result);
     }
 }
+
+#[test]
+fn can_solve_pragmatic_problem_with_replan() {
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--replan",
+        "../examples/data/pragmatic/simple.basic.solution.json",
+        "../examples/data/pragmatic/simple.basic.state.json",
+        "--max-generations",
+        "1",
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+fn can_reject_replan_for_non_pragmatic_format() {
+    let args = vec!["solve", "solomon", SOLOMON_PROBLEM_PATH, "--replan", "solution.json", "state.json"];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    assert!(run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).is_err());
+}
@@ -117,6 +117,23 @@ pub(crate) fn accept_route_state_with_states(
     }
 }
 
+/// Updates state of routes which were changed as a side effect of changes in other routes.
+/// NOTE route state is not cleared as it keeps solution wide data shared by features.
+pub(crate) fn accept_changed_routes_with_states(
+    states: &[Arc<dyn FeatureState + Send + Sync>],
+    solution_ctx: &mut SolutionContext,
+) {
+    solution_ctx.take_changed_routes().into_iter().for_each(|route_idx| {
+        if let Some(route_ctx) = solution_ctx.routes.get_mut(route_idx) {
+            let activities = route_ctx.route().tour.job_activity_count();
+            states.iter().for_each(|state| state.accept_route_state(route_ctx));
+            assert_eq!(activities, route_ctx.route().tour.job_activity_count());
+
+            route_ctx.mark_stale(false);
+        }
+    });
+}
+
 pub(crate) fn accept_solution_state_with_states(
     states: &[Arc<dyn FeatureState + Send + Sync>],
     solution_ctx: &mut SolutionContext,
@@ -22,5 +22,8 @@ pub use self::reserved_time::*;
 mod schedule_update;
 pub use self::schedule_update::*;
 
+mod time_window_bounds;
+pub use self::time_window_bounds::*;
+
 mod travel_info;
 pub use self::travel_info::*;
@@ -3,7 +3,7 @@
 mod reserved_time_test;
 
 use crate::models::common::*;
-use crate::models::problem::{ActivityCost, Actor, TransportCost, TravelTime};
+use crate::models::problem::{get_service_duration, ActivityCost, Actor, TransportCost, TravelTime};
 use crate::models::solution::{Activity, Route};
 use hashbrown::HashMap;
 use rosomaxa::prelude::{compare_floats, GenericError};
@@ -57,7 +57,7 @@ impl DynamicActivityCost {
 impl ActivityCost for DynamicActivityCost {
     fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
         let activity_start = arrival.max(activity.place.time.start);
-        let departure = activity_start + activity.place.duration;
+        let departure = activity_start + get_service_duration(route, activity);
         let schedule = TimeWindow::new(arrival, departure);
 
         (self.reserved_times_fn)(route, &schedule).map_or(departure, |reserved_time| {
@@ -90,7 +90,7 @@ impl ActivityCost for DynamicActivityCost {
     }
 
     fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
-        let arrival = activity.place.time.end.min(departure - activity.place.duration);
+        let arrival = activity.place.time.end.min(departure - get_service_duration(route, activity));
         let schedule = TimeWindow::new(arrival, departure);
 
         (self.reserved_times_fn)(route, &schedule)
@@ -1,6 +1,6 @@
 use crate::construction::heuristics::{RouteContext, StateKey};
-use crate::models::common::{Distance, Schedule, Timestamp};
-use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::common::{Cost, Distance, Schedule, Timestamp};
+use crate::models::problem::{get_setup_duration, get_time_window_penalty, ActivityCost, TransportCost, TravelTime};
 use crate::models::OP_START_MSG;
 
 /// Contains state keys ids used by route schedule updating logic.
@@ -13,6 +13,8 @@ pub struct ScheduleStateKeys {
     pub total_distance: StateKey,
     /// Total duration state key.
     pub total_duration: StateKey,
+    /// Total time window penalty state key.
+    pub time_window_penalty: StateKey,
 }
 
 /// Updates route schedule data.
@@ -56,7 +58,7 @@ fn update_schedules(
             let a = route_ctx.route().tour.get(activity_idx).unwrap();
             let location = a.place.location;
             let arrival = dep + transport.duration(route_ctx.route(), loc, location, TravelTime::Departure(dep));
-            let departure = activity.estimate_departure(route_ctx.route(), a, arrival);
+            let departure = activity.estimate_departure(route_ctx.route(), a, arrival + get_setup_duration(a, loc));
 
             (location, arrival, departure)
         };
@@ -94,14 +96,16 @@ fn update_states(
         }
 
         let (end_time, prev_loc, waiting) = acc;
+        // NOTE latest arrival keeps time needed for setup which depends on the previous activity
+        let setup = route.tour.get(activity_idx - 1).map_or(0., |prev| get_setup_duration(act, prev.place.location));
         let latest_arrival_time = if end_time == f64::MAX {
-            act.place.time.end
+            act.place.time.end - setup
         } else {
             let latest_departure =
                 end_time - transport.duration(route, act.place.location, prev_loc, TravelTime::Arrival(end_time));
-            activity.estimate_arrival(route, act, latest_departure)
+            activity.estimate_arrival(route, act, latest_departure) - setup
         };
-        let future_waiting = waiting + (act.place.time.start - act.schedule.arrival).max(0.);
+        let future_waiting = waiting + (act.place.time.start - act.schedule.arrival - setup).max(0.);
 
         state.put_activity_state(state_keys.latest_arrival, activity_idx, latest_arrival_time);
         state.put_activity_state(state_keys.waiting_time, activity_idx, future_waiting);
@@ -121,18 +125,23 @@ fn update_statistics(
     let end = route.tour.end().unwrap();
     let total_dur = end.schedule.departure - start.schedule.departure;
 
-    let init = (start.place.location, start.schedule.departure, Distance::default());
-    let (_, _, total_dist) =
-        route.tour.all_activities().enumerate().skip(1).fold(init, |(loc, dep, total_dist), (a_idx, a)| {
+    let init = (start.place.location, start.schedule.departure, Distance::default(), Cost::default());
+    let (_, _, total_dist, total_penalty) = route.tour.all_activities().enumerate().skip(1).fold(
+        init,
+        |(loc, dep, total_dist, total_penalty), (a_idx, a)| {
             let total_dist = total_dist + transport.distance(route, loc, a.place.location, TravelTime::Departure(dep));
             let total_dur = a.schedule.departure - start.schedule.departure;
+            let service_start = a.schedule.arrival + get_setup_duration(a, loc);
+            let total_penalty = total_penalty + get_time_window_penalty(route, a, service_start);
 
             state.put_activity_state(state_keys.total_distance, a_idx, total_dist);
             state.put_activity_state(state_keys.total_duration, a_idx, total_dur);
 
-            (a.place.location, a.schedule.departure, total_dist)
-        });
+            (a.place.location, a.schedule.departure, total_dist, total_penalty)
+        },
+    );
 
     state.put_route_state(state_keys.total_distance, total_dist);
     state.put_route_state(state_keys.total_duration, total_dur);
+    state.put_route_state(state_keys.time_window_penalty, total_penalty);
 }
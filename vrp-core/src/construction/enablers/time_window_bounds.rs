@@ -0,0 +1,171 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/enablers/time_window_bounds_test.rs"]
+mod time_window_bounds_test;
+
+use crate::construction::enablers::{update_route_schedule, ScheduleStateKeys};
+use crate::construction::features::{LATEST_ARRIVAL_KEY, TIME_WINDOW_BOUNDS_KEY};
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext, StateKey};
+use crate::models::common::{TimeWindow, Timestamp};
+use crate::models::problem::{get_original_time_window, ActivityCost, Job, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use hashbrown::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Specifies max amount of schedule propagation passes between dependent routes.
+const MAX_PROPAGATION_PASSES: usize = 8;
+
+/// Keeps time window bounds of job activities set by different features, e.g. by features which
+/// synchronize schedules across routes. Activity's time window is an intersection of its original
+/// time window and bounds of all features.
+#[derive(Clone, Default)]
+struct TimeWindowBounds {
+    features: HashMap<StateKey, HashMap<Job, TimeWindow>>,
+}
+
+impl TimeWindowBounds {
+    /// Returns original time window of the activity narrowed by bounds of all features except given one.
+    fn get_time_window(&self, route: &Route, activity: &Activity, excluded: Option<StateKey>) -> TimeWindow {
+        let original = get_original_time_window(route, activity);
+
+        let Some(job) = activity.job.as_ref().map(|single| Job::Single(single.clone())) else {
+            return original;
+        };
+
+        let (start, end) = self
+            .features
+            .iter()
+            .filter(|(key, _)| Some(**key) != excluded)
+            .filter_map(|(_, bounds)| bounds.get(&job))
+            .fold((original.start, original.end), |(start, end), bound| (start.max(bound.start), end.min(bound.end)));
+
+        // NOTE keep original time window when bounds cannot be satisfied, it is handled later by features
+        if start > end {
+            original
+        } else {
+            TimeWindow::new(start, end)
+        }
+    }
+}
+
+/// Returns original time window of the activity narrowed by bounds of all features except the given one.
+pub fn get_bounded_time_window(
+    solution_ctx: &SolutionContext,
+    feature_key: StateKey,
+    route: &Route,
+    activity: &Activity,
+) -> TimeWindow {
+    get_time_window_bounds(solution_ctx).map_or_else(
+        || get_original_time_window(route, activity),
+        |bounds| bounds.get_time_window(route, activity, Some(feature_key)),
+    )
+}
+
+/// Propagates schedule changes between routes by narrowing time windows of activities to intersection
+/// of their original time windows and bounds set by all features. Bounds of the given feature are
+/// returned by `bounds_fn` which is called again till schedules are stable or max amount of passes
+/// is reached. Changed routes are marked, so their state is updated by all features.
+pub fn propagate_time_window_bounds<F>(
+    solution_ctx: &mut SolutionContext,
+    feature_key: StateKey,
+    transport: &(dyn TransportCost + Send + Sync),
+    activity: &(dyn ActivityCost + Send + Sync),
+    bounds_fn: F,
+) where
+    F: Fn(&SolutionContext) -> HashMap<Job, TimeWindow>,
+{
+    let _ = (0..MAX_PROPAGATION_PASSES).try_for_each(|_| {
+        let bounds = bounds_fn(solution_ctx);
+        let route_indices = apply_time_window_bounds(solution_ctx, feature_key, bounds);
+
+        if route_indices.is_empty() {
+            return Err(());
+        }
+
+        route_indices.iter().for_each(|&route_idx| {
+            update_route_schedule(
+                &mut solution_ctx.routes[route_idx],
+                activity,
+                transport,
+                &ScheduleStateKeys::default(),
+            );
+        });
+        solution_ctx.mark_routes_changed(route_indices);
+
+        Ok(())
+    });
+}
+
+/// Checks whether the next activity can be reached in time when the target activity is served after
+/// the given arrival, e.g. when it has to wait for a partner in another route. Waiting time caused by
+/// bounds is not considered by other constraints, so delay at the next activity is checked here.
+pub fn can_reach_next_activity(
+    route_ctx: &RouteContext,
+    activity_ctx: &ActivityContext,
+    arrival: Timestamp,
+    transport: &(dyn TransportCost + Send + Sync),
+    activity: &(dyn ActivityCost + Send + Sync),
+) -> bool {
+    let Some(next) = activity_ctx.next else {
+        return true;
+    };
+
+    let route = route_ctx.route();
+    let target = activity_ctx.target;
+
+    let departure = activity.estimate_departure(route, target, arrival);
+    let next_arrival = departure
+        + transport.duration(route, target.place.location, next.place.location, TravelTime::Departure(departure));
+    let latest_arrival = route_ctx
+        .state()
+        .get_activity_state::<Timestamp>(LATEST_ARRIVAL_KEY, activity_ctx.index + 1)
+        .copied()
+        .unwrap_or(next.place.time.end);
+
+    next_arrival <= latest_arrival
+}
+
+/// Replaces bounds of the feature and updates time windows of affected activities.
+/// Returns indices of routes with changed activities.
+fn apply_time_window_bounds(
+    solution_ctx: &mut SolutionContext,
+    feature_key: StateKey,
+    bounds: HashMap<Job, TimeWindow>,
+) -> HashSet<usize> {
+    let mut time_window_bounds = get_time_window_bounds(solution_ctx).cloned().unwrap_or_default();
+
+    // NOTE activities which are not bounded by the feature anymore should get their time windows back
+    let mut jobs = bounds.keys().cloned().collect::<HashSet<_>>();
+    jobs.extend(time_window_bounds.features.insert(feature_key, bounds).into_iter().flat_map(|old| old.into_keys()));
+
+    let changes = solution_ctx
+        .routes
+        .iter()
+        .enumerate()
+        .flat_map(|(route_idx, route_ctx)| {
+            let route = route_ctx.route();
+            let (jobs, time_window_bounds) = (&jobs, &time_window_bounds);
+
+            route.tour.all_activities().enumerate().filter_map(move |(activity_idx, activity)| {
+                activity.job.as_ref().filter(|single| jobs.contains(&Job::Single((*single).clone())))?;
+
+                let time = time_window_bounds.get_time_window(route, activity, None);
+                (time != activity.place.time).then_some((route_idx, activity_idx, time))
+            })
+        })
+        .collect::<Vec<_>>();
+
+    solution_ctx.state.insert(TIME_WINDOW_BOUNDS_KEY, Arc::new(time_window_bounds));
+
+    changes
+        .into_iter()
+        .map(|(route_idx, activity_idx, time)| {
+            let activity = solution_ctx.routes[route_idx].route_mut().tour.get_mut(activity_idx).unwrap();
+            activity.place.time = time;
+            route_idx
+        })
+        .collect()
+}
+
+fn get_time_window_bounds(solution_ctx: &SolutionContext) -> Option<&TimeWindowBounds> {
+    solution_ctx.state.get(&TIME_WINDOW_BOUNDS_KEY).and_then(|s| s.downcast_ref::<TimeWindowBounds>())
+}
@@ -1,6 +1,6 @@
 use crate::construction::heuristics::{ActivityContext, RouteContext};
 use crate::models::common::{Distance, Duration, Timestamp};
-use crate::models::problem::{TransportCost, TravelTime};
+use crate::models::problem::{get_service_duration, TransportCost, TravelTime};
 use crate::models::solution::{Activity, Route};
 
 /// Calculates a travel info from prev to next directly.
@@ -74,7 +74,7 @@ fn calculate_travel_leg(
 
     let second_arr = departure + first_to_second_dur;
     let second_wait = (second.place.time.start - second_arr).max(0.);
-    let second_dep = second_arr + second_wait + second.place.duration;
+    let second_dep = second_arr + second_wait + get_service_duration(route, second);
 
     (first_to_second_dis, second_dep - departure)
 }
@@ -10,6 +10,9 @@ use crate::models::solution::Activity;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
+const CAPACITY_STATE_KEYS: [StateKey; 6] =
+    [CURRENT_CAPACITY_KEY, MAX_FUTURE_CAPACITY_KEY, MAX_PAST_CAPACITY_KEY, MAX_LOAD_KEY, LOAD_RATIO_KEY, LOAD_COST_KEY];
+
 /// Creates capacity feature as a hard constraint with multi trip functionality as a soft constraint.
 pub fn create_capacity_limit_with_multi_trip_feature<T: LoadOps>(
     name: &str,
@@ -19,7 +22,7 @@ pub fn create_capacity_limit_with_multi_trip_feature<T: LoadOps>(
     create_multi_trip_feature(
         name,
         code,
-        &[CURRENT_CAPACITY_KEY, MAX_FUTURE_CAPACITY_KEY, MAX_PAST_CAPACITY_KEY, MAX_LOAD_KEY],
+        &CAPACITY_STATE_KEYS,
         MarkerInsertionPolicy::Last,
         Arc::new(CapacitatedMultiTrip::<T> { route_intervals, code, phantom: Default::default() }),
     )
@@ -31,7 +34,7 @@ pub fn create_capacity_limit_feature<T: LoadOps>(name: &str, code: ViolationCode
     create_multi_trip_feature(
         name,
         code,
-        &[CURRENT_CAPACITY_KEY, MAX_FUTURE_CAPACITY_KEY, MAX_PAST_CAPACITY_KEY, MAX_LOAD_KEY],
+        &CAPACITY_STATE_KEYS,
         MarkerInsertionPolicy::Last,
         Arc::new(CapacitatedMultiTrip::<T> {
             route_intervals: Arc::new(NoRouteIntervals::default()),
@@ -144,6 +147,8 @@ impl<T: LoadOps> MultiTrip for CapacitatedMultiTrip<T> {
         if let Some(capacity) = route_ctx.route().actor.clone().vehicle.dimens.get_capacity() {
             route_ctx.state_mut().put_route_state(MAX_LOAD_KEY, max_load.ratio(capacity));
         }
+
+        self.update_load_costs(route_ctx);
     }
 
     fn try_recover(&self, _: &mut SolutionContext, _: &[usize], _: &[Job]) -> bool {
@@ -195,6 +200,41 @@ impl<T: LoadOps> CapacitatedMultiTrip<T> {
         violation.map(|stopped| ConstraintViolation { code: self.code, stopped })
     }
 
+    /// Calculates vehicle load ratio at each activity and extra distance costs caused by vehicle load
+    /// when vehicle has load dependent distance costs.
+    /// NOTE relies on distance states calculated by schedule update.
+    fn update_load_costs(&self, route_ctx: &mut RouteContext) {
+        let actor = route_ctx.route().actor.clone();
+        let capacity: Option<&T> = actor.vehicle.dimens.get_capacity();
+        let capacity = match capacity {
+            Some(capacity) => *capacity,
+            None => return,
+        };
+        let rate = actor.vehicle.costs.per_distance_loaded.map(|loaded| loaded - actor.vehicle.costs.per_distance);
+
+        let (route, state) = route_ctx.as_mut();
+        let (_, _, load_cost) = (0..route.tour.total()).fold(
+            (0., Distance::default(), Cost::default()),
+            |(prev_ratio, prev_distance, load_cost), activity_idx| {
+                let current: T =
+                    state.get_activity_state(CURRENT_CAPACITY_KEY, activity_idx).copied().unwrap_or_default();
+                let distance = state
+                    .get_activity_state::<Distance>(TOTAL_DISTANCE_KEY, activity_idx)
+                    .copied()
+                    .unwrap_or(prev_distance);
+                let ratio = get_load_ratio(&current, &capacity);
+
+                state.put_activity_state(LOAD_RATIO_KEY, activity_idx, ratio);
+
+                (ratio, distance, load_cost + (distance - prev_distance) * rate.unwrap_or_default() * prev_ratio)
+            },
+        );
+
+        if rate.is_some() {
+            state.put_route_state(LOAD_COST_KEY, load_cost);
+        }
+    }
+
     fn has_markers(&self, route_ctx: &RouteContext) -> bool {
         self.route_intervals.get_marker_intervals(route_ctx).map_or(false, |intervals| intervals.len() > 1)
     }
@@ -288,6 +328,17 @@ fn has_demand_violation<T: LoadOps>(
     None
 }
 
+fn get_load_ratio<T: LoadOps>(load: &T, capacity: &T) -> f64 {
+    let ratio = load.ratio(capacity);
+
+    // NOTE ratio is not defined for zero capacity
+    if ratio.is_nan() {
+        0.
+    } else {
+        ratio.clamp(0., 1.)
+    }
+}
+
 fn get_demand<T: LoadOps>(activity: &Activity) -> Option<&Demand<T>> {
     activity.job.as_ref().and_then(|job| job.dimens.get_demand())
 }
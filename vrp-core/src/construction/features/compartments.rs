@@ -0,0 +1,521 @@
+//! A feature to model vehicles with multiple compartments where each compartment can hold only one
+//! product type at a time, e.g. fuel or grocery distribution.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/compartments_test.rs"]
+mod compartments_test;
+
+use super::*;
+use crate::models::common::{Demand, Duration, SingleDimLoad, Timestamp};
+use crate::models::problem::{get_service_duration, Single, TransportCost, TravelTime, Vehicle};
+use crate::models::solution::{Activity, Route};
+use hashbrown::HashMap;
+use std::iter::once;
+use std::ops::Range;
+
+/// Specifies a vehicle compartment which can hold only one product type at a time.
+#[derive(Clone, Debug)]
+pub struct Compartment {
+    /// Compartment capacity.
+    pub capacity: i32,
+    /// A time needed to clean the compartment when it changes product type between trips.
+    pub cleaning_duration: Duration,
+}
+
+/// Specifies job demand of a specific product type.
+#[derive(Clone)]
+pub struct ProductDemand {
+    /// Product type index.
+    pub product: usize,
+    /// Product amount.
+    pub demand: Demand<SingleDimLoad>,
+}
+
+/// Specifies compartment usage: assigned product type and its current load.
+pub type CompartmentUsage = (Option<usize>, i32);
+
+/// A function which returns vehicle compartments.
+pub type CompartmentsFn = Arc<dyn Fn(&Vehicle) -> Option<&Vec<Compartment>> + Send + Sync>;
+/// A function which returns product demand of the job.
+pub type ProductDemandFn = Arc<dyn Fn(&Single) -> Option<&ProductDemand> + Send + Sync>;
+/// A function which checks whether activity starts a new trip, e.g. reload.
+pub type TripMarkerFn = Arc<dyn Fn(&Activity) -> bool + Send + Sync>;
+
+/// Provides access to compartment related data.
+#[derive(Clone)]
+pub struct CompartmentAccessor {
+    /// Returns vehicle compartments.
+    pub compartments_fn: CompartmentsFn,
+    /// Returns product demand of the job.
+    pub product_fn: ProductDemandFn,
+    /// Checks whether activity starts a new trip.
+    pub marker_fn: TripMarkerFn,
+}
+
+/// Creates a feature which assigns product types to vehicle compartments. Assignment is done per
+/// trip: a compartment keeps the same product type till the next trip marker (e.g. reload) where
+/// it can be switched to another product type after cleaning. Cleaning time is added to the service
+/// duration of the trip marker activity. This is a hard constraint.
+pub fn create_compartment_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    accessor: CompartmentAccessor,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(CompartmentConstraint { code, transport, accessor: accessor.clone() })
+        .with_state(CompartmentState { accessor, state_keys: vec![] })
+        .build()
+}
+
+/// Returns compartments usage after departure from each activity of the route. Returns `None` if
+/// vehicle has no compartments or products cannot be assigned to them.
+pub fn get_compartment_usage(route: &Route, accessor: &CompartmentAccessor) -> Option<Vec<Vec<CompartmentUsage>>> {
+    let compartments = (accessor.compartments_fn)(route.actor.vehicle.as_ref())?;
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+    let trips = get_trips(&activities, accessor);
+    let assignments = get_assignments(&activities, &trips, compartments, accessor)?;
+
+    let usage = trips.iter().zip(assignments.iter()).flat_map(|(&(start, end), (products, _))| {
+        let (_, timeline) = get_load_timeline(&activities[start..end], accessor);
+
+        timeline.into_iter().map(move |loads| {
+            let mut loads = loads;
+            products
+                .iter()
+                .zip(compartments.iter())
+                .map(|(product, compartment)| {
+                    let load = product
+                        .and_then(|product| loads.get_mut(&product))
+                        .map(|left| {
+                            let load = (*left).min(compartment.capacity).max(0);
+                            *left -= load;
+                            load
+                        })
+                        .unwrap_or(0);
+
+                    (*product, load)
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    Some(usage.collect())
+}
+
+/// Checks whether all vehicle compartments are occupied on the last trip of the route, so other
+/// product types can be served only after a new trip marker.
+pub fn is_last_trip_full(route: &Route, accessor: &CompartmentAccessor) -> bool {
+    let compartments = match (accessor.compartments_fn)(route.actor.vehicle.as_ref()) {
+        Some(compartments) if !compartments.is_empty() => compartments,
+        _ => return false,
+    };
+
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+    let trips = get_trips(&activities, accessor);
+
+    get_assignments(&activities, &trips, compartments, accessor)
+        .and_then(|assignments| assignments.last().map(|(products, _)| products.iter().all(|p| p.is_some())))
+        .unwrap_or(true)
+}
+
+/// Checks whether products of two consecutive trips, specified by inclusive activity index ranges,
+/// can be assigned to vehicle compartments as a single trip. A trip marker is expected at the start
+/// of the right range.
+pub fn can_merge_trips(route: &Route, accessor: &CompartmentAccessor, left: Range<usize>, right: Range<usize>) -> bool {
+    let compartments = match (accessor.compartments_fn)(route.actor.vehicle.as_ref()) {
+        Some(compartments) => compartments,
+        None => return true,
+    };
+
+    let activities = route
+        .tour
+        .activities_slice(left.start, right.end)
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| left.start + idx != right.start)
+        .map(|(_, activity)| activity)
+        .collect::<Vec<_>>();
+    let (max_loads, _) = get_load_timeline(&activities, accessor);
+
+    assign_products(max_loads, compartments, &vec![None; compartments.len()]).is_some()
+}
+
+struct CompartmentConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    accessor: CompartmentAccessor,
+}
+
+impl CompartmentConstraint {
+    fn evaluate_job(&self, route_ctx: &RouteContext, job: &Job) -> Option<ConstraintViolation> {
+        let compartments = (self.accessor.compartments_fn)(route_ctx.route().actor.vehicle.as_ref())?;
+        let total_capacity = compartments.iter().map(|compartment| compartment.capacity).sum::<i32>();
+
+        let singles = match job {
+            Job::Single(single) => vec![single],
+            Job::Multi(multi) => multi.jobs.iter().collect(),
+        };
+
+        let exceeds_capacity =
+            singles.into_iter().filter_map(|single| (self.accessor.product_fn)(single.as_ref())).any(|product| {
+                let demand = &product.demand;
+                let amount =
+                    (demand.pickup.0 + demand.pickup.1).value.max((demand.delivery.0 + demand.delivery.1).value);
+                amount > total_capacity
+            });
+
+        if exceeds_capacity {
+            ConstraintViolation::fail(self.code)
+        } else {
+            None
+        }
+    }
+
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let compartments = (self.accessor.compartments_fn)(route.actor.vehicle.as_ref())?;
+        let target = activity_ctx.target;
+
+        let has_product = target.job.as_ref().and_then(|single| (self.accessor.product_fn)(single)).is_some();
+        if !has_product && !(self.accessor.marker_fn)(target) {
+            return None;
+        }
+
+        let target_idx = activity_ctx.index + 1;
+        let activities = route
+            .tour
+            .all_activities()
+            .take(target_idx)
+            .chain(once(target))
+            .chain(route.tour.all_activities().skip(target_idx))
+            .collect::<Vec<_>>();
+
+        let trips = get_trips(&activities, &self.accessor);
+        let assignments = match get_assignments(&activities, &trips, compartments, &self.accessor) {
+            Some(assignments) => assignments,
+            None => return ConstraintViolation::skip(self.code),
+        };
+
+        // NOTE cleaning time is added to the trip marker, so its increase delays the rest of the tour
+        let (delay, first_marker_idx) = trips
+            .iter()
+            .zip(assignments.iter())
+            .skip(1)
+            .filter_map(|(&(start, _), (_, cleaning))| {
+                let current = if start == target_idx { 0. } else { get_cleaning_duration(activities[start]) };
+                Some((start, cleaning - current)).filter(|(_, delay)| *delay > 0.)
+            })
+            .fold((0., None), |(total, first), (start, delay)| (total + delay, first.or(Some(start))));
+
+        match first_marker_idx {
+            Some(marker_idx) if !self.can_be_delayed(route_ctx, activity_ctx, marker_idx, delay) => {
+                ConstraintViolation::skip(self.code)
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks whether the tour stays feasible when activities after the marker are delayed.
+    fn can_be_delayed(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        marker_idx: usize,
+        delay: Duration,
+    ) -> bool {
+        let route = route_ctx.route();
+        let get_latest_arrival = |idx: usize| {
+            route_ctx
+                .state()
+                .get_activity_state::<Timestamp>(LATEST_ARRIVAL_KEY, idx)
+                .copied()
+                .or_else(|| route.tour.get(idx).map(|activity| activity.place.time.end))
+                .unwrap_or(Timestamp::MAX)
+        };
+
+        let (target_arrival, next_delay) = self.get_target_schedule(route, activity_ctx);
+
+        // NOTE marker is before the target: activities between them and the target itself are delayed
+        let is_before_target_ok = marker_idx > activity_ctx.index || {
+            let after_marker_idx = marker_idx + 1;
+            let is_tour_ok = after_marker_idx > activity_ctx.index
+                || route
+                    .tour
+                    .get(after_marker_idx)
+                    .map_or(true, |activity| activity.schedule.arrival + delay <= get_latest_arrival(after_marker_idx));
+
+            is_tour_ok && target_arrival + delay <= activity_ctx.target.place.time.end
+        };
+
+        let is_next_ok = activity_ctx.next.map_or(true, |next| {
+            next.schedule.arrival + next_delay + delay <= get_latest_arrival(activity_ctx.index + 1)
+        });
+
+        is_before_target_ok && is_next_ok
+    }
+
+    /// Returns arrival time at the target activity and arrival delay at the next one.
+    fn get_target_schedule(&self, route: &Route, activity_ctx: &ActivityContext) -> (Timestamp, Duration) {
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let arrival = prev.schedule.departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+        let departure = arrival.max(target.place.time.start) + get_service_duration(route, target);
+
+        let next_delay = activity_ctx.next.map_or(0., |next| {
+            let next_arrival = departure
+                + self.transport.duration(
+                    route,
+                    target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(departure),
+                );
+
+            (next_arrival - next.schedule.arrival).max(0.)
+        });
+
+        (arrival, next_delay)
+    }
+}
+
+impl FeatureConstraint for CompartmentConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => self.evaluate_job(route_ctx, job),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let has_product =
+            |job: &Job| job.as_single().map_or(false, |single| (self.accessor.product_fn)(single).is_some());
+
+        if has_product(&source) || has_product(&candidate) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+struct CompartmentState {
+    accessor: CompartmentAccessor,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for CompartmentState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route();
+        let compartments = if let Some(compartments) = (self.accessor.compartments_fn)(route.actor.vehicle.as_ref()) {
+            compartments
+        } else {
+            return;
+        };
+
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+        let trips = get_trips(&activities, &self.accessor);
+        let cleanings = get_assignments(&activities, &trips, compartments, &self.accessor)
+            .map(|assignments| assignments.into_iter().map(|(_, cleaning)| cleaning).collect::<Vec<_>>())
+            .unwrap_or_else(|| vec![0.; trips.len()]);
+
+        let durations = trips
+            .iter()
+            .zip(cleanings)
+            .skip(1)
+            .filter_map(|(&(start, _), cleaning)| {
+                let activity = activities[start];
+                get_base_duration(activity)
+                    .map(|duration| duration + cleaning)
+                    .filter(|&duration| duration != activity.place.duration)
+                    .map(|duration| (start, duration))
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE this state has to be accepted before schedule is updated
+        durations.into_iter().for_each(|(idx, duration)| {
+            route_ctx.route_mut().tour.get_mut(idx).unwrap().place.duration = duration;
+        });
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+/// Splits activities into trips, returns start (inclusive) and end (exclusive) indices.
+fn get_trips(activities: &[&Activity], accessor: &CompartmentAccessor) -> Vec<(usize, usize)> {
+    let starts = once(0)
+        .chain(
+            activities
+                .iter()
+                .enumerate()
+                .skip(1)
+                .filter(|(_, activity)| (accessor.marker_fn)(activity))
+                .map(|(idx, _)| idx),
+        )
+        .collect::<Vec<_>>();
+
+    starts.iter().zip(starts.iter().skip(1).chain(once(&activities.len()))).map(|(&start, &end)| (start, end)).collect()
+}
+
+/// Returns product assignment of each compartment and cleaning duration for each trip.
+fn get_assignments(
+    activities: &[&Activity],
+    trips: &[(usize, usize)],
+    compartments: &[Compartment],
+    accessor: &CompartmentAccessor,
+) -> Option<Vec<(Vec<Option<usize>>, Duration)>> {
+    let mut last_products = vec![None; compartments.len()];
+    let mut assignments = Vec::with_capacity(trips.len());
+
+    for &(start, end) in trips {
+        let (max_loads, _) = get_load_timeline(&activities[start..end], accessor);
+        let products = assign_products(max_loads, compartments, &last_products)?;
+
+        let cleaning = compartments
+            .iter()
+            .zip(products.iter().zip(last_products.iter()))
+            .filter(|(_, (new, old))| matches!((new, old), (Some(new), Some(old)) if new != old))
+            .map(|(compartment, _)| compartment.cleaning_duration)
+            .sum::<Duration>();
+
+        products.iter().zip(last_products.iter_mut()).filter(|(new, _)| new.is_some()).for_each(|(new, old)| {
+            *old = *new;
+        });
+
+        assignments.push((products, cleaning));
+    }
+
+    Some(assignments)
+}
+
+/// Specifies loads of products by product index.
+type ProductLoads = HashMap<usize, i32>;
+
+/// Returns max load of each product type sorted in descending order and current product loads
+/// after departure from each activity of the trip.
+fn get_load_timeline(
+    activities: &[&Activity],
+    accessor: &CompartmentAccessor,
+) -> (Vec<(usize, i32)>, Vec<ProductLoads>) {
+    // NOTE static deliveries are loaded at the trip start
+    let start = activities.iter().filter_map(|activity| get_product(activity, accessor)).fold(
+        HashMap::new(),
+        |mut acc, product| {
+            *acc.entry(product.product).or_insert(0) += product.demand.delivery.0.value;
+            acc
+        },
+    );
+
+    let (_, max_loads, timeline) = activities.iter().fold(
+        (start.clone(), start, Vec::with_capacity(activities.len())),
+        |(mut current, mut max_loads, mut timeline), activity| {
+            if let Some(product) = get_product(activity, accessor) {
+                let load = current.entry(product.product).or_insert(0);
+                *load += product.demand.change().value;
+
+                let max_load = max_loads.entry(product.product).or_insert(0);
+                *max_load = (*max_load).max(*load);
+            }
+
+            timeline.push(current.clone());
+
+            (current, max_loads, timeline)
+        },
+    );
+
+    let mut max_loads = max_loads.into_iter().filter(|(_, load)| *load > 0).collect::<Vec<_>>();
+    max_loads.sort_by(|(a_product, a_load), (b_product, b_load)| b_load.cmp(a_load).then(a_product.cmp(b_product)));
+
+    (max_loads, timeline)
+}
+
+/// Assigns products to compartments preferring the product which compartment held previously.
+fn assign_products(
+    max_loads: Vec<(usize, i32)>,
+    compartments: &[Compartment],
+    last_products: &[Option<usize>],
+) -> Option<Vec<Option<usize>>> {
+    fn search(
+        idx: usize,
+        compartments: &[Compartment],
+        last_products: &[Option<usize>],
+        needs: &mut [(usize, i32)],
+        assignment: &mut [Option<usize>],
+    ) -> bool {
+        let left_need = needs.iter().map(|(_, need)| (*need).max(0)).sum::<i32>();
+        if left_need == 0 {
+            return true;
+        }
+
+        let left_capacity = compartments[idx..].iter().map(|compartment| compartment.capacity).sum::<i32>();
+        if left_capacity < left_need {
+            return false;
+        }
+
+        let preferred = last_products[idx].and_then(|product| needs.iter().position(|(p, _)| *p == product));
+        let candidates = preferred.into_iter().chain((0..needs.len()).filter(|&need_idx| Some(need_idx) != preferred));
+
+        for need_idx in candidates.collect::<Vec<_>>() {
+            if needs[need_idx].1 <= 0 {
+                continue;
+            }
+
+            needs[need_idx].1 -= compartments[idx].capacity;
+            assignment[idx] = Some(needs[need_idx].0);
+
+            if search(idx + 1, compartments, last_products, needs, assignment) {
+                return true;
+            }
+
+            needs[need_idx].1 += compartments[idx].capacity;
+            assignment[idx] = None;
+        }
+
+        search(idx + 1, compartments, last_products, needs, assignment)
+    }
+
+    let mut needs = max_loads;
+    let mut assignment = vec![None; compartments.len()];
+
+    if search(0, compartments, last_products, needs.as_mut_slice(), assignment.as_mut_slice()) {
+        Some(assignment)
+    } else {
+        None
+    }
+}
+
+fn get_product<'a>(activity: &'a Activity, accessor: &'a CompartmentAccessor) -> Option<&'a ProductDemand> {
+    activity.job.as_ref().and_then(|single| (accessor.product_fn)(single))
+}
+
+fn get_base_duration(activity: &Activity) -> Option<Duration> {
+    activity.job.as_ref().and_then(|single| single.places.get(activity.place.idx)).map(|place| place.duration)
+}
+
+fn get_cleaning_duration(activity: &Activity) -> Duration {
+    get_base_duration(activity).map_or(0., |duration| activity.place.duration - duration)
+}
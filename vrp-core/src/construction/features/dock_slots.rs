@@ -0,0 +1,376 @@
+//! A feature to limit amount of vehicles which can be served simultaneously at the same dock,
+//! e.g. warehouse loading bays or customer docks with a limited number of parallel slots.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/dock_slots_test.rs"]
+mod dock_slots_test;
+
+use super::*;
+use crate::construction::enablers::{
+    can_reach_next_activity, get_bounded_time_window, propagate_time_window_bounds, update_route_schedule,
+    ScheduleStateKeys,
+};
+use crate::models::common::{Duration, TimeWindow, Timestamp};
+use crate::models::problem::{get_service_duration, ActivityCost, Actor, Single, TransportCost, TravelTime};
+use crate::models::solution::Activity;
+use hashbrown::{HashMap, HashSet};
+use std::cmp::Ordering;
+
+/// Represents a dock id.
+pub type DockId = usize;
+/// Specifies a type for a dock function which returns dock of the job's place with given index.
+pub type DockFn = Arc<dyn Fn(&Single, usize) -> Option<DockId> + Send + Sync>;
+
+/// Creates a feature which limits amount of activities served simultaneously at the same dock.
+/// When all slots are busy, the vehicle has to wait in queue: it is modeled by narrowing time window
+/// of the activity, so the route schedule is recalculated in routes which share the same dock.
+/// This is a hard constraint.
+pub fn create_dock_slots_feature(
+    name: &str,
+    code: ViolationCode,
+    slots: HashMap<DockId, usize>,
+    dock_fn: DockFn,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Result<Feature, GenericError> {
+    if slots.values().any(|slots| *slots == 0) {
+        return Err("dock should have at least one slot".into());
+    }
+
+    let slots = Arc::new(slots);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(DockSlotsConstraint {
+            code,
+            slots: slots.clone(),
+            dock_fn: dock_fn.clone(),
+            transport: transport.clone(),
+            activity: activity.clone(),
+        })
+        .with_state(DockSlotsState { code, slots, dock_fn, transport, activity, state_keys: vec![DOCK_SLOTS_KEY] })
+        .build()
+}
+
+/// Keeps service interval of the activity at the dock.
+#[derive(Clone, Debug)]
+struct DockInterval {
+    actor: Arc<Actor>,
+    start: Timestamp,
+    end: Timestamp,
+}
+
+/// Keeps dock occupancy in the whole solution.
+type DockOccupancy = HashMap<DockId, Vec<DockInterval>>;
+
+/// Keeps actual schedule of the activity served at the dock.
+struct DockVisit {
+    job: Job,
+    dock: DockId,
+    route_idx: usize,
+    activity_idx: usize,
+    arrival: Timestamp,
+    duration: Duration,
+    time: TimeWindow,
+}
+
+impl DockVisit {
+    /// Returns service start without waiting for a dock slot.
+    fn get_natural_start(&self) -> Timestamp {
+        self.arrival.max(self.time.start)
+    }
+}
+
+struct DockSlotsConstraint {
+    code: ViolationCode,
+    slots: Arc<HashMap<DockId, usize>>,
+    dock_fn: DockFn,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl DockSlotsConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let target = activity_ctx.target;
+        let dock = get_dock(self.dock_fn.as_ref(), target)?;
+        let slots = self.slots.get(&dock).copied().unwrap_or(1);
+        let route = route_ctx.route();
+
+        // NOTE occupancy is not available in the route which state was updated without solution context
+        let occupancy = match route_ctx.state().get_route_state::<DockOccupancy>(DOCK_SLOTS_KEY) {
+            Some(occupancy) => occupancy,
+            None => return ConstraintViolation::skip(self.code),
+        };
+
+        let departure = activity_ctx.prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                activity_ctx.prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        let intervals = occupancy
+            .get(&dock)
+            .iter()
+            .flat_map(|intervals| intervals.iter())
+            .filter(|interval| interval.actor != route.actor)
+            .collect::<Vec<_>>();
+
+        let duration = get_service_duration(route, target);
+        let start = get_earliest_start(&intervals, slots, arrival.max(target.place.time.start), duration);
+
+        if start > target.place.time.end {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        if can_reach_next_activity(route_ctx, activity_ctx, start, self.transport.as_ref(), self.activity.as_ref()) {
+            None
+        } else {
+            ConstraintViolation::skip(self.code)
+        }
+    }
+
+    fn has_dock(&self, job: &Job) -> bool {
+        let has_dock = |single: &Arc<Single>| (0..single.places.len()).any(|idx| (self.dock_fn)(single, idx).is_some());
+
+        match job {
+            Job::Single(single) => has_dock(single),
+            Job::Multi(multi) => multi.jobs.iter().any(has_dock),
+        }
+    }
+}
+
+impl FeatureConstraint for DockSlotsConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if self.has_dock(&source) || self.has_dock(&candidate) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+struct DockSlotsState {
+    code: ViolationCode,
+    slots: Arc<HashMap<DockId, usize>>,
+    dock_fn: DockFn,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    state_keys: Vec<StateKey>,
+}
+
+impl DockSlotsState {
+    /// Returns all activities served at docks grouped by dock and ordered by their natural service start.
+    fn get_visits(&self, solution_ctx: &SolutionContext) -> HashMap<DockId, Vec<DockVisit>> {
+        let mut visits = solution_ctx
+            .routes
+            .iter()
+            .enumerate()
+            .flat_map(|(route_idx, route_ctx)| {
+                let route = route_ctx.route();
+                route.tour.all_activities().enumerate().filter_map(move |(activity_idx, activity)| {
+                    let dock = get_dock(self.dock_fn.as_ref(), activity)?;
+                    let single = activity.job.as_ref()?;
+
+                    Some(DockVisit {
+                        job: Job::Single(single.clone()),
+                        dock,
+                        route_idx,
+                        activity_idx,
+                        arrival: activity.schedule.arrival,
+                        duration: get_service_duration(route, activity),
+                        time: get_bounded_time_window(solution_ctx, DOCK_SLOTS_KEY, route, activity),
+                    })
+                })
+            })
+            .fold(HashMap::<DockId, Vec<_>>::new(), |mut acc, visit| {
+                acc.entry(visit.dock).or_default().push(visit);
+                acc
+            });
+
+        visits.values_mut().for_each(|visits| {
+            visits.sort_by(|a, b| {
+                compare_floats(a.get_natural_start(), b.get_natural_start()).then(a.route_idx.cmp(&b.route_idx))
+            })
+        });
+
+        visits
+    }
+
+    /// Assigns dock slots in order of arrival and makes activities to wait in queue by narrowing
+    /// their time windows. Stores dock occupancy in each route.
+    fn update_schedules(&self, solution_ctx: &mut SolutionContext) {
+        propagate_time_window_bounds(
+            solution_ctx,
+            DOCK_SLOTS_KEY,
+            self.transport.as_ref(),
+            self.activity.as_ref(),
+            |solution_ctx| {
+                self.get_visits(solution_ctx)
+                    .into_iter()
+                    .flat_map(|(dock, visits)| {
+                        let slots = self.slots.get(&dock).copied().unwrap_or(1);
+                        let mut releases = vec![f64::MIN; slots];
+
+                        visits.into_iter().filter_map(move |visit| {
+                            let (slot_idx, release) = releases
+                                .iter()
+                                .copied()
+                                .enumerate()
+                                .min_by(|(_, a), (_, b)| compare_floats(*a, *b))
+                                .expect("dock should have at least one slot");
+
+                            let start = visit.get_natural_start().max(release);
+                            releases[slot_idx] = start + visit.duration;
+
+                            // NOTE queueing does not narrow time window when it is not possible, it is handled later
+                            (start <= visit.time.end).then(|| (visit.job, TimeWindow::new(start, visit.time.end)))
+                        })
+                    })
+                    .collect()
+            },
+        );
+
+        let occupancy: Arc<DockOccupancy> = Arc::new(
+            self.get_visits(solution_ctx)
+                .into_iter()
+                .map(|(dock, visits)| {
+                    let intervals = visits
+                        .into_iter()
+                        .map(|visit| {
+                            let route = solution_ctx.routes[visit.route_idx].route();
+                            let activity = route.tour.get(visit.activity_idx).unwrap();
+                            let start = activity.schedule.arrival.max(activity.place.time.start);
+
+                            DockInterval { actor: route.actor.clone(), start, end: start + visit.duration }
+                        })
+                        .collect();
+
+                    (dock, intervals)
+                })
+                .collect(),
+        );
+
+        solution_ctx.routes.iter_mut().chain(solution_ctx.registry.all_routes_mut()).for_each(|route_ctx| {
+            route_ctx.state_mut().put_route_state_raw(DOCK_SLOTS_KEY, occupancy.clone());
+        });
+    }
+
+    /// Removes jobs which cannot get a free dock slot within their time windows.
+    /// Returns indices of modified routes.
+    fn remove_violated_visits(&self, solution_ctx: &mut SolutionContext) -> HashSet<usize> {
+        let jobs = self
+            .get_visits(solution_ctx)
+            .into_iter()
+            .flat_map(|(dock, visits)| {
+                let slots = self.slots.get(&dock).copied().unwrap_or(1);
+                let routes = &solution_ctx.routes;
+
+                visits
+                    .into_iter()
+                    .fold((Vec::<(Timestamp, Timestamp)>::new(), Vec::new()), |(mut busy, mut violated), visit| {
+                        let activity = routes[visit.route_idx].route().tour.get(visit.activity_idx).unwrap();
+                        let start = activity.schedule.arrival.max(activity.place.time.start);
+                        let end = start + visit.duration;
+
+                        let is_greater =
+                            |left: Timestamp, right: Timestamp| compare_floats(left, right) == Ordering::Greater;
+                        let overlaps =
+                            busy.iter().filter(|(s, e)| is_greater(end, *s) && is_greater(*e, start)).count();
+
+                        if overlaps >= slots {
+                            violated.push((visit.route_idx, activity.retrieve_job()));
+                        } else {
+                            busy.push((start, end));
+                        }
+
+                        (busy, violated)
+                    })
+                    .1
+            })
+            .filter_map(|(route_idx, job)| job.map(|job| (route_idx, job)))
+            .filter(|(_, job)| !solution_ctx.locked.contains(job))
+            .collect::<Vec<_>>();
+
+        jobs.into_iter()
+            .map(|(route_idx, job)| {
+                solution_ctx.routes[route_idx].route_mut().tour.remove(&job);
+                solution_ctx.unassigned.insert(job, UnassignmentInfo::Simple(self.code));
+
+                route_idx
+            })
+            .collect()
+    }
+
+    fn update_route_schedule(&self, solution_ctx: &mut SolutionContext, route_idx: usize) {
+        update_route_schedule(
+            &mut solution_ctx.routes[route_idx],
+            self.activity.as_ref(),
+            self.transport.as_ref(),
+            &ScheduleStateKeys::default(),
+        );
+    }
+}
+
+impl FeatureState for DockSlotsState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        self.update_schedules(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.update_schedules(solution_ctx);
+
+        let route_indices = self.remove_violated_visits(solution_ctx);
+        if !route_indices.is_empty() {
+            route_indices.iter().for_each(|&route_idx| self.update_route_schedule(solution_ctx, route_idx));
+            solution_ctx.mark_routes_changed(route_indices);
+            self.update_schedules(solution_ctx);
+        }
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_dock(dock_fn: &(dyn Fn(&Single, usize) -> Option<DockId> + Send + Sync), activity: &Activity) -> Option<DockId> {
+    activity.job.as_ref().and_then(|single| dock_fn(single, activity.place.idx))
+}
+
+/// Returns the earliest service start not earlier than given one when dock has a free slot
+/// for the whole service duration.
+fn get_earliest_start(intervals: &[&DockInterval], slots: usize, start: Timestamp, duration: Duration) -> Timestamp {
+    let is_greater = |left: Timestamp, right: Timestamp| compare_floats(left, right) == Ordering::Greater;
+
+    let mut candidates = std::iter::once(start)
+        .chain(intervals.iter().map(|interval| interval.end).filter(|end| *end > start))
+        .collect::<Vec<_>>();
+    candidates.sort_by(|a, b| compare_floats(*a, *b));
+
+    candidates
+        .into_iter()
+        .find(|&candidate| {
+            let end = candidate + duration;
+            intervals
+                .iter()
+                .filter(|interval| is_greater(end, interval.start) && is_greater(interval.end, candidate))
+                .count()
+                < slots
+        })
+        .unwrap_or(f64::MAX)
+}
@@ -0,0 +1,170 @@
+//! A feature to serve exactly one of alternative job variants.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/job_alternatives_test.rs"]
+mod job_alternatives_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use hashbrown::HashMap;
+
+/// Creates a feature which allows to serve only one variant from a group of job alternatives.
+/// Variants of the same job are identified by [`JobAlternative`] dimension: once one of them is
+/// assigned, the rest are moved to ignored jobs and promoted back when it gets unassigned.
+/// A variant served by several jobs is kept in the solution only when all of them are assigned.
+pub fn create_job_alternatives_feature(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(JobAlternativesConstraint { code, state_key: JOB_ALTERNATIVES_KEY })
+        .with_state(JobAlternativesState {
+            context_transition: Box::new(ConcreteJobContextTransition {
+                remove_required: |solution_ctx, _, job| is_sibling_assigned(solution_ctx, job),
+                promote_required: |solution_ctx, _, job| {
+                    get_alternative_id(job).is_some() && !is_sibling_assigned(solution_ctx, job)
+                },
+                remove_locked: |_, _, _| false,
+                promote_locked: |_, _, _| false,
+            }),
+            code,
+            state_key: JOB_ALTERNATIVES_KEY,
+            state_keys: vec![JOB_ALTERNATIVES_KEY],
+        })
+        .build()
+}
+
+struct JobAlternativesConstraint {
+    code: ViolationCode,
+    state_key: StateKey,
+}
+
+impl FeatureConstraint for JobAlternativesConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, job, .. } => job
+                .dimens()
+                .get_job_alternative()
+                .zip(get_assigned_alternatives(solution_ctx, self.state_key))
+                .and_then(|(alternative, assigned)| {
+                    assigned.get(&alternative.id).filter(|&&idx| idx != alternative.index)
+                })
+                .and_then(|_| ConstraintViolation::fail(self.code)),
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if get_alternative_id(&source).is_some() || get_alternative_id(&candidate).is_some() {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+struct JobAlternativesState {
+    context_transition: Box<dyn JobContextTransition + Send + Sync>,
+    code: ViolationCode,
+    state_key: StateKey,
+    state_keys: Vec<StateKey>,
+}
+
+impl JobAlternativesState {
+    fn update_state(&self, solution_ctx: &mut SolutionContext) {
+        let assigned = solution_ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.jobs())
+            .filter_map(|job| job.dimens().get_job_alternative())
+            .map(|alternative| (alternative.id.clone(), alternative.index))
+            .collect::<HashMap<_, _>>();
+
+        solution_ctx.state.insert(self.state_key, Arc::new(assigned));
+        process_conditional_jobs(solution_ctx, None, self.context_transition.as_ref());
+
+        // NOTE unassigned variants are not necessary in required list (e.g. initial solution)
+        let ignored = solution_ctx
+            .unassigned
+            .keys()
+            .filter(|job| is_sibling_assigned(solution_ctx, job))
+            .cloned()
+            .collect::<Vec<_>>();
+        ignored.into_iter().for_each(|job| {
+            solution_ctx.unassigned.remove(&job);
+            solution_ctx.ignored.push(job);
+        });
+    }
+
+    /// Removes jobs of the variants which are not served by all their jobs.
+    fn remove_partial_variants(&self, solution_ctx: &mut SolutionContext) {
+        get_partial_variant_jobs(solution_ctx).into_iter().for_each(|(route_idx, job)| {
+            solution_ctx.routes[route_idx].route_mut().tour.remove(&job);
+            solution_ctx.unassigned.insert(job, UnassignmentInfo::Simple(self.code));
+        });
+    }
+}
+
+impl FeatureState for JobAlternativesState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, job: &Job) {
+        if get_alternative_id(job).is_some() {
+            self.update_state(solution_ctx);
+        }
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.remove_partial_variants(solution_ctx);
+        self.update_state(solution_ctx);
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_alternative_id(job: &Job) -> Option<&String> {
+    job.dimens().get_job_alternative().map(|alternative| &alternative.id)
+}
+
+/// Returns assigned jobs (with their route indices) of the variants which are served partially.
+fn get_partial_variant_jobs(solution_ctx: &SolutionContext) -> Vec<(usize, Job)> {
+    let served = solution_ctx
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route().tour.jobs())
+        .filter_map(|job| job.dimens().get_job_alternative())
+        .filter(|alternative| alternative.parts > 1)
+        .fold(HashMap::<(&String, usize), usize>::new(), |mut acc, alternative| {
+            *acc.entry((&alternative.id, alternative.index)).or_insert(0) += 1;
+            acc
+        });
+
+    let is_partial = |job: &Job| {
+        job.dimens().get_job_alternative().map_or(false, |alternative| {
+            served.get(&(&alternative.id, alternative.index)).map_or(false, |&count| count < alternative.parts)
+        })
+    };
+
+    solution_ctx
+        .routes
+        .iter()
+        .enumerate()
+        .flat_map(|(route_idx, route_ctx)| route_ctx.route().tour.jobs().map(move |job| (route_idx, job)))
+        .filter(|(_, job)| is_partial(job) && !solution_ctx.locked.contains(*job))
+        .map(|(route_idx, job)| (route_idx, job.clone()))
+        .collect()
+}
+
+/// Returns index of the assigned variant by job alternative id.
+fn get_assigned_alternatives(solution_ctx: &SolutionContext, state_key: StateKey) -> Option<&HashMap<String, usize>> {
+    solution_ctx.state.get(&state_key).and_then(|s| s.downcast_ref::<HashMap<String, usize>>())
+}
+
+/// Checks whether another variant of the same job is already assigned.
+fn is_sibling_assigned(solution_ctx: &SolutionContext, job: &Job) -> bool {
+    job.dimens()
+        .get_job_alternative()
+        .zip(get_assigned_alternatives(solution_ctx, JOB_ALTERNATIVES_KEY))
+        .and_then(|(alternative, assigned)| assigned.get(&alternative.id).map(|&idx| idx != alternative.index))
+        .unwrap_or(false)
+}
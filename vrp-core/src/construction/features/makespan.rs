@@ -0,0 +1,129 @@
+//! Provides a feature to minimize makespan: the longest tour duration across the fleet.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/makespan_test.rs"]
+mod makespan_test;
+
+use super::*;
+
+/// Creates a feature which minimizes the longest tour duration.
+pub fn create_minimize_makespan_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MakespanObjective { transport, activity, state_key: MAKESPAN_KEY })
+        .with_state(MakespanState { state_key: MAKESPAN_KEY, state_keys: vec![MAKESPAN_KEY] })
+        .build()
+}
+
+struct MakespanObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    state_key: StateKey,
+}
+
+impl MakespanObjective {
+    /// Estimates how much route end time is shifted by inserting the target activity.
+    fn estimate_end_shift(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let route = route_ctx.route();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let arrival = prev.schedule.departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+        let departure = self.activity.estimate_departure(route, target, arrival);
+
+        let Some(next) = activity_ctx.next else {
+            // NOTE open route ends at the target activity
+            return (departure - prev.schedule.departure).max(0.);
+        };
+
+        let next_arrival = departure
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        // NOTE waiting time at the next and further activities absorbs the delay
+        let waiting = route_ctx.state().get_activity_state::<f64>(WAITING_KEY, activity_ctx.index + 1).copied();
+
+        (next_arrival - next.schedule.arrival - waiting.unwrap_or(0.)).max(0.)
+    }
+}
+
+impl Objective for MakespanObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .state
+            .get(&self.state_key)
+            .and_then(|s| s.downcast_ref::<f64>())
+            .cloned()
+            .unwrap_or_else(|| get_makespan(&solution.solution))
+    }
+}
+
+impl FeatureObjective for MakespanObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            // NOTE change of route end time depends on insertion position, so it is estimated on activity level
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let makespan = route_ctx.state().get_route_state::<f64>(self.state_key).copied().unwrap_or(0.);
+                let end_shift = self.estimate_end_shift(route_ctx, activity_ctx);
+
+                (get_route_duration(route_ctx) + end_shift - makespan).max(0.)
+            }
+        }
+    }
+}
+
+struct MakespanState {
+    state_key: StateKey,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for MakespanState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        self.accept_solution_state(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let makespan = get_makespan(solution_ctx);
+        solution_ctx.state.insert(self.state_key, Arc::new(makespan));
+
+        // NOTE makespan is kept in route state as it is not accessible on activity level
+        // NOTE mutable access marks route as stale, so update only routes with different value
+        solution_ctx
+            .routes
+            .iter_mut()
+            .chain(solution_ctx.registry.all_routes_mut())
+            .filter(|route_ctx| route_ctx.state().get_route_state::<f64>(self.state_key) != Some(&makespan))
+            .for_each(|route_ctx| route_ctx.state_mut().put_route_state(self.state_key, makespan));
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_makespan(solution_ctx: &SolutionContext) -> f64 {
+    solution_ctx.routes.iter().map(get_route_duration).fold(0., f64::max)
+}
+
+fn get_route_duration(route_ctx: &RouteContext) -> f64 {
+    route_ctx.state().get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.)
+}
@@ -5,6 +5,7 @@
 mod minimize_unassigned_test;
 
 use super::*;
+use hashbrown::HashMap;
 use std::cmp::Ordering;
 
 /// A type which allows to control how job is estimated in objective fitness.
@@ -41,12 +42,23 @@ impl Objective for MinimizeUnassignedObjective {
     }
 
     fn fitness(&self, solution: &Self::Solution) -> f64 {
-        solution
-            .solution
-            .unassigned
-            .iter()
-            .map(|(job, _)| (self.unassigned_job_estimator)(&solution.solution, job))
-            .sum::<f64>()
+        // NOTE variants of the same job alternative are counted once using the highest estimate
+        let (alternatives, total) = solution.solution.unassigned.iter().fold(
+            (HashMap::<&String, f64>::default(), 0.),
+            |(mut alternatives, total), (job, _)| {
+                let estimate = (self.unassigned_job_estimator)(&solution.solution, job);
+
+                if let Some(alternative) = job.dimens().get_job_alternative() {
+                    let value = alternatives.entry(&alternative.id).or_insert(estimate);
+                    *value = value.max(estimate);
+                    (alternatives, total)
+                } else {
+                    (alternatives, total + estimate)
+                }
+            },
+        );
+
+        total + alternatives.values().sum::<f64>()
     }
 }
 
@@ -11,21 +11,48 @@ use std::sync::Arc;
 mod capacity;
 pub use self::capacity::*;
 
+mod compartments;
+pub use self::compartments::*;
+
+mod dock_slots;
+pub use self::dock_slots::*;
+
 mod fast_service;
 pub use self::fast_service::*;
 
 mod fleet_usage;
 pub use self::fleet_usage::*;
 
+mod job_alternatives;
+pub use self::job_alternatives::*;
+
 mod locked_jobs;
 pub use self::locked_jobs::*;
 
+mod makespan;
+pub use self::makespan::*;
+
 mod minimize_unassigned;
 pub use self::minimize_unassigned::*;
 
+mod partial_jobs;
+pub use self::partial_jobs::*;
+
+mod plan_stability;
+pub use self::plan_stability::*;
+
+mod ride_duration;
+pub use self::ride_duration::*;
+
+mod schedule_relations;
+pub use self::schedule_relations::*;
+
 mod shared_resource;
 pub use self::shared_resource::*;
 
+mod time_in_vehicle;
+pub use self::time_in_vehicle::*;
+
 mod total_value;
 pub use self::total_value::*;
 
@@ -41,6 +68,9 @@ pub use self::tour_order::*;
 mod transport;
 pub use self::transport::*;
 
+mod weighted_sum;
+pub use self::weighted_sum::*;
+
 mod work_balance;
 pub use self::work_balance::*;
 
@@ -54,6 +84,16 @@ pub const TOTAL_DISTANCE_KEY: StateKey = StateKey(3);
 pub const TOTAL_DURATION_KEY: StateKey = StateKey(4);
 /// A key which tracks global duration limit.
 pub const LIMIT_DURATION_KEY: StateKey = StateKey(5);
+/// A key which tracks total time window penalty.
+pub const TIME_WINDOW_PENALTY_KEY: StateKey = StateKey(6);
+/// A key which tracks ride duration slack of jobs with multiple activities.
+pub const RIDE_DURATION_SLACK_KEY: StateKey = StateKey(7);
+/// A key which tracks service time bounds of jobs in schedule relations.
+pub const SCHEDULE_RELATION_KEY: StateKey = StateKey(8);
+/// A key which tracks dock occupancy in the whole solution.
+pub const DOCK_SLOTS_KEY: StateKey = StateKey(9);
+/// A key which tracks time window bounds of activities set by features which synchronize routes.
+pub const TIME_WINDOW_BOUNDS_KEY: StateKey = StateKey(10);
 
 /// A key which tracks current vehicle capacity.
 pub const CURRENT_CAPACITY_KEY: StateKey = StateKey(11);
@@ -69,6 +109,10 @@ pub const MAX_LOAD_KEY: StateKey = StateKey(15);
 pub const RECHARGE_INTERVALS_KEY: StateKey = StateKey(16);
 /// A key to track recharge distance.
 pub const RECHARGE_DISTANCE_KEY: StateKey = StateKey(17);
+/// A key which tracks vehicle load ratio at activity, used by load dependent distance costs and consumption.
+pub const LOAD_RATIO_KEY: StateKey = StateKey(18);
+/// A key which tracks extra distance cost caused by vehicle load in tour.
+pub const LOAD_COST_KEY: StateKey = StateKey(19);
 
 /// A key for balancing max load.
 pub const BALANCE_MAX_LOAD_KEY: StateKey = StateKey(20);
@@ -78,3 +122,11 @@ pub const BALANCE_ACTIVITY_KEY: StateKey = StateKey(21);
 pub const BALANCE_DISTANCE_KEY: StateKey = StateKey(22);
 /// A key for balancing duration.
 pub const BALANCE_DURATION_KEY: StateKey = StateKey(23);
+/// A key which tracks makespan: the longest tour duration.
+pub const MAKESPAN_KEY: StateKey = StateKey(24);
+/// A key which tracks ids of job alternatives assigned in the solution.
+pub const JOB_ALTERNATIVES_KEY: StateKey = StateKey(25);
+/// A key which tracks time in vehicle slack of the following activities within the same trip.
+pub const TIME_IN_VEHICLE_SLACK_KEY: StateKey = StateKey(26);
+/// A key which tracks routes changed as a side effect of changes in other routes.
+pub const CHANGED_ROUTES_KEY: StateKey = StateKey(27);
@@ -0,0 +1,107 @@
+//! A feature to serve all parts of the split job or none of them.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/partial_jobs_test.rs"]
+mod partial_jobs_test;
+
+use super::*;
+use hashbrown::{HashMap, HashSet};
+
+/// Creates a feature which links partial jobs created from the same job, see [`PartialJob`]:
+/// once insertion is finished, assigned parts of the job which is not served completely are
+/// moved to unassigned jobs. Parts which are still required are not considered as missing.
+pub fn create_partial_jobs_feature(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(PartialJobsConstraint { code })
+        .with_state(PartialJobsState { code, state_keys: vec![] })
+        .build()
+}
+
+struct PartialJobsConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for PartialJobsConstraint {
+    fn evaluate(&self, _: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        None
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if get_partial_id(&source).is_some() || get_partial_id(&candidate).is_some() {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+struct PartialJobsState {
+    code: ViolationCode,
+    state_keys: Vec<StateKey>,
+}
+
+impl PartialJobsState {
+    /// Removes assigned parts of the jobs which are not served completely.
+    fn remove_incomplete_jobs(&self, solution_ctx: &mut SolutionContext) {
+        let jobs = get_incomplete_job_parts(solution_ctx);
+        if jobs.is_empty() {
+            return;
+        }
+
+        jobs.into_iter().for_each(|(route_idx, job)| {
+            solution_ctx.routes[route_idx].route_mut().tour.remove(&job);
+            solution_ctx.unassigned.insert(job, UnassignmentInfo::Simple(self.code));
+        });
+        solution_ctx.keep_routes(&|route_ctx| route_ctx.route().tour.has_jobs());
+    }
+}
+
+impl FeatureState for PartialJobsState {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.remove_incomplete_jobs(solution_ctx);
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_partial_id(job: &Job) -> Option<&String> {
+    job.dimens().get_partial_job().map(|partial| &partial.id)
+}
+
+/// Returns assigned parts (with their route indices) of the jobs which are served partially.
+fn get_incomplete_job_parts(solution_ctx: &SolutionContext) -> Vec<(usize, Job)> {
+    let assigned =
+        solution_ctx.routes.iter().flat_map(|route_ctx| route_ctx.route().tour.jobs()).filter_map(get_partial_id).fold(
+            HashMap::<&String, usize>::new(),
+            |mut acc, id| {
+                *acc.entry(id).or_insert(0) += 1;
+                acc
+            },
+        );
+    // NOTE required parts still can be inserted, so such jobs are not considered as incomplete
+    let pending = solution_ctx.required.iter().filter_map(get_partial_id).collect::<HashSet<_>>();
+
+    let is_incomplete = |job: &Job| {
+        job.dimens().get_partial_job().map_or(false, |partial| {
+            !pending.contains(&partial.id) && assigned.get(&partial.id).map_or(false, |&count| count < partial.total)
+        })
+    };
+
+    let locked_ids = solution_ctx.locked.iter().filter_map(get_partial_id).collect::<HashSet<_>>();
+
+    solution_ctx
+        .routes
+        .iter()
+        .enumerate()
+        .flat_map(|(route_idx, route_ctx)| route_ctx.route().tour.jobs().map(move |job| (route_idx, job)))
+        .filter(|(_, job)| is_incomplete(job) && get_partial_id(job).map_or(true, |id| !locked_ids.contains(id)))
+        .map(|(route_idx, job)| (route_idx, job.clone()))
+        .collect()
+}
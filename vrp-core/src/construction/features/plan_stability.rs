@@ -0,0 +1,167 @@
+//! Provides a feature to keep a new plan close to the reference one, e.g. when the plan is
+//! re-optimized, but drivers already received the previous one.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/plan_stability_test.rs"]
+mod plan_stability_test;
+
+use super::*;
+use crate::models::problem::Actor;
+use crate::models::solution::Activity;
+
+/// Specifies a job position in the reference plan relatively to some actor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReferencePosition {
+    /// The job is served by the same actor in the reference plan with given order in its tour.
+    SameActor(usize),
+    /// The job is served by another actor in the reference plan.
+    OtherActor,
+}
+
+/// Specifies a function which returns a position of the job in the reference plan.
+/// Returns `None` if the job is not present in the reference plan.
+pub type ReferencePositionFn = Arc<dyn Fn(&Actor, &Job) -> Option<ReferencePosition> + Send + Sync>;
+
+/// Specifies weights of plan changes.
+#[derive(Clone, Copy, Debug)]
+pub struct PlanChangeWeights {
+    /// A weight of each job activity served by another actor.
+    pub reassignment: Cost,
+    /// A weight of each pair of neighbour job activities which order is changed.
+    pub sequence: Cost,
+}
+
+impl Default for PlanChangeWeights {
+    fn default() -> Self {
+        Self { reassignment: 1., sequence: 1. }
+    }
+}
+
+/// Creates a feature to minimize changes of the plan comparing to the reference one: job reassignments
+/// to another actor and changes of job activity sequence within the same actor.
+pub fn create_plan_stability_feature(
+    name: &str,
+    state_key: StateKey,
+    weights: PlanChangeWeights,
+    position_fn: ReferencePositionFn,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(PlanStabilityObjective { state_key, weights, position_fn: position_fn.clone() })
+        .with_state(PlanStabilityState { state_key, state_keys: vec![state_key], weights, position_fn })
+        .build()
+}
+
+struct PlanStabilityObjective {
+    state_key: StateKey,
+    weights: PlanChangeWeights,
+    position_fn: ReferencePositionFn,
+}
+
+impl Objective for PlanStabilityObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let solution = &solution.solution;
+
+        solution
+            .state
+            .get(&self.state_key)
+            .and_then(|s| s.downcast_ref::<Cost>())
+            .cloned()
+            .unwrap_or_else(|| get_plan_changes(solution.routes.as_slice(), &self.weights, &self.position_fn))
+    }
+}
+
+impl FeatureObjective for PlanStabilityObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let actor = route_ctx.route().actor.as_ref();
+                let get_position = |activity: &Activity| get_position(actor, activity, &self.position_fn);
+
+                match get_position(activity_ctx.target) {
+                    Some(ReferencePosition::OtherActor) => self.weights.reassignment,
+                    Some(ReferencePosition::SameActor(order)) => {
+                        let get_order = |activity: &Activity| match get_position(activity) {
+                            Some(ReferencePosition::SameActor(order)) => Some(order),
+                            _ => None,
+                        };
+
+                        let tour = &route_ctx.route().tour;
+                        let prev = (0..=activity_ctx.index).rev().filter_map(|idx| tour.get(idx)).find_map(get_order);
+                        let next =
+                            (activity_ctx.index + 1..tour.total()).filter_map(|idx| tour.get(idx)).find_map(get_order);
+
+                        let is_inversion = |left: Option<usize>, right: Option<usize>| match (left, right) {
+                            (Some(left), Some(right)) if left > right => 1.,
+                            _ => 0.,
+                        };
+
+                        let delta = is_inversion(prev, Some(order)) + is_inversion(Some(order), next)
+                            - is_inversion(prev, next);
+
+                        delta * self.weights.sequence
+                    }
+                    None => Cost::default(),
+                }
+            }
+            MoveContext::Route { .. } => Cost::default(),
+        }
+    }
+}
+
+struct PlanStabilityState {
+    state_key: StateKey,
+    state_keys: Vec<StateKey>,
+    weights: PlanChangeWeights,
+    position_fn: ReferencePositionFn,
+}
+
+impl FeatureState for PlanStabilityState {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let changes = get_plan_changes(solution_ctx.routes.as_slice(), &self.weights, &self.position_fn);
+        solution_ctx.state.insert(self.state_key, Arc::new(changes));
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_plan_changes(routes: &[RouteContext], weights: &PlanChangeWeights, position_fn: &ReferencePositionFn) -> Cost {
+    routes
+        .iter()
+        .map(|route_ctx| {
+            let actor = route_ctx.route().actor.as_ref();
+            let positions = route_ctx
+                .route()
+                .tour
+                .all_activities()
+                .filter_map(|activity| get_position(actor, activity, position_fn))
+                .collect::<Vec<_>>();
+
+            let reassignments =
+                positions.iter().filter(|position| matches!(position, ReferencePosition::OtherActor)).count();
+
+            let orders = positions
+                .iter()
+                .filter_map(|position| match position {
+                    ReferencePosition::SameActor(order) => Some(*order),
+                    ReferencePosition::OtherActor => None,
+                })
+                .collect::<Vec<_>>();
+            let inversions = orders.windows(2).filter(|pair| pair[0] > pair[1]).count();
+
+            reassignments as Cost * weights.reassignment + inversions as Cost * weights.sequence
+        })
+        .sum()
+}
+
+fn get_position(actor: &Actor, activity: &Activity, position_fn: &ReferencePositionFn) -> Option<ReferencePosition> {
+    activity.retrieve_job().and_then(|job| (position_fn)(actor, &job))
+}
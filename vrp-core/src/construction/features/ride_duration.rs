@@ -0,0 +1,270 @@
+//! A feature to limit ride duration of jobs with multiple activities, e.g. pickup and delivery
+//! in dial-a-ride problem.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/ride_duration_test.rs"]
+mod ride_duration_test;
+
+use super::*;
+use crate::models::common::{Duration, Location, Timestamp};
+use crate::models::problem::{get_service_duration, Multi, TransportCost, TravelTime};
+use crate::models::solution::Route;
+use hashbrown::HashMap;
+
+/// Specifies ride duration limit of a job with multiple activities. Ride duration is measured
+/// from departure at the first job activity till arrival at the last one.
+#[derive(Clone, Debug, Default)]
+pub struct RideDurationLimit {
+    /// Max ride duration.
+    pub max_duration: Option<Duration>,
+    /// Max ratio between actual ride duration and duration of the direct trip from the first
+    /// job activity to the last one.
+    pub max_factor: Option<f64>,
+}
+
+impl RideDurationLimit {
+    /// Returns ride duration limit using given duration of the direct trip.
+    pub fn get_limit(&self, direct_duration: Duration) -> Option<Duration> {
+        let factor_limit = self.max_factor.map(|factor| factor * direct_duration);
+
+        match (self.max_duration, factor_limit) {
+            (Some(max_duration), Some(factor_limit)) => Some(max_duration.min(factor_limit)),
+            (max_duration, factor_limit) => max_duration.or(factor_limit),
+        }
+    }
+}
+
+/// A function which returns ride duration limit for given multi job.
+pub type RideDurationLimitFn = Arc<dyn Fn(&Multi) -> Option<&RideDurationLimit> + Send + Sync>;
+
+/// Creates a feature which limits ride duration of jobs with multiple activities.
+/// This is a hard constraint.
+pub fn create_max_ride_duration_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    limit_fn: RideDurationLimitFn,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(RideDurationConstraint { code, transport: transport.clone(), limit_fn: limit_fn.clone() })
+        .with_state(RideDurationState { transport, limit_fn, state_keys: vec![RIDE_DURATION_SLACK_KEY] })
+        .build()
+}
+
+/// Returns actual ride duration of the multi job in the route if it is fully inserted there.
+pub fn get_ride_duration(route: &Route, multi: &Arc<Multi>) -> Option<Duration> {
+    let job = Job::Multi(multi.clone());
+    let mut activities = route.tour.job_activities(&job);
+
+    let first = activities.next()?;
+    let (count, last) = activities.fold((1, first), |(count, _), activity| (count + 1, activity));
+
+    if count == multi.jobs.len() {
+        Some(last.schedule.arrival - first.schedule.departure)
+    } else {
+        None
+    }
+}
+
+struct RideDurationConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    limit_fn: RideDurationLimitFn,
+}
+
+impl RideDurationConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let target = activity_ctx.target;
+
+        let slack = route_ctx.state().get_activity_state::<Duration>(RIDE_DURATION_SLACK_KEY, activity_ctx.index);
+        let multi = target
+            .job
+            .as_ref()
+            .and_then(|single| Multi::roots(single))
+            .filter(|multi| (self.limit_fn)(multi.as_ref()).is_some());
+
+        if slack.is_none() && multi.is_none() {
+            return None;
+        }
+
+        let delay = self.get_next_delay(route, activity_ctx);
+        if slack.map_or(false, |&slack| delay > slack) {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let multi = multi?;
+        let limit = (self.limit_fn)(multi.as_ref())?;
+
+        // NOTE evaluate ride duration only when all other job activities are already in the route
+        let job = Job::Multi(multi.clone());
+        let indices = route.tour.all_activities().enumerate().filter(|(_, activity)| activity.has_same_job(&job));
+        let (count, first, last) = indices.fold((0, None, None), |(count, first, _), (idx, activity)| {
+            (count + 1, first.or(Some((idx, activity))), Some((idx, activity)))
+        });
+
+        let ((first_idx, first), (last_idx, last)) = match (first, last) {
+            (Some(first), Some(last)) if count + 1 == multi.jobs.len() => (first, last),
+            _ => return None,
+        };
+
+        let (target_arrival, target_departure) = self.get_target_schedule(route, activity_ctx);
+
+        let (start, end, ride_duration) = if activity_ctx.index >= last_idx {
+            (
+                (first.place.location, first.schedule.departure),
+                target.place.location,
+                target_arrival - first.schedule.departure,
+            )
+        } else if activity_ctx.index < first_idx {
+            let ride_duration = last.schedule.arrival + delay - target_departure;
+            ((target.place.location, target_departure), last.place.location, ride_duration)
+        } else {
+            let ride_duration = last.schedule.arrival + delay - first.schedule.departure;
+            ((first.place.location, first.schedule.departure), last.place.location, ride_duration)
+        };
+
+        let ride_limit = get_ride_limit(self.transport.as_ref(), route, limit, start, end);
+
+        if ride_limit.map_or(false, |ride_limit| ride_duration > ride_limit) {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
+
+    /// Returns arrival and departure time at the target activity.
+    fn get_target_schedule(&self, route: &Route, activity_ctx: &ActivityContext) -> (Timestamp, Timestamp) {
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        (arrival, arrival.max(target.place.time.start) + get_service_duration(route, target))
+    }
+
+    /// Returns a delay of arrival at the next activity caused by inserting the target activity.
+    fn get_next_delay(&self, route: &Route, activity_ctx: &ActivityContext) -> Duration {
+        activity_ctx.next.map_or(0., |next| {
+            let (_, target_departure) = self.get_target_schedule(route, activity_ctx);
+            let next_arrival = target_departure
+                + self.transport.duration(
+                    route,
+                    activity_ctx.target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(target_departure),
+                );
+
+            (next_arrival - next.schedule.arrival).max(0.)
+        })
+    }
+}
+
+impl FeatureConstraint for RideDurationConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct RideDurationState {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    limit_fn: RideDurationLimitFn,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for RideDurationState {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route();
+
+        // NOTE collect first, last activity indices and activity count of the limited multi jobs
+        let rides = route.tour.all_activities().enumerate().fold(
+            HashMap::<Job, (usize, usize, usize)>::new(),
+            |mut rides, (idx, activity)| {
+                let multi = activity.job.as_ref().and_then(|single| Multi::roots(single));
+                if let Some(multi) = multi.filter(|multi| (self.limit_fn)(multi.as_ref()).is_some()) {
+                    rides
+                        .entry(Job::Multi(multi))
+                        .and_modify(|(_, last, count)| {
+                            *last = idx;
+                            *count += 1;
+                        })
+                        .or_insert((idx, idx, 1));
+                }
+
+                rides
+            },
+        );
+
+        let slacks = rides
+            .into_iter()
+            .filter_map(|(job, (first_idx, last_idx, count))| {
+                let multi = job.to_multi();
+                if count != multi.jobs.len() {
+                    return None;
+                }
+
+                let limit = (self.limit_fn)(multi.as_ref())?;
+                let (first, last) = (route.tour.get(first_idx)?, route.tour.get(last_idx)?);
+                let start = (first.place.location, first.schedule.departure);
+                let ride_limit = get_ride_limit(self.transport.as_ref(), route, limit, start, last.place.location)?;
+                let ride_duration = last.schedule.arrival - first.schedule.departure;
+
+                Some((first_idx, last_idx, ride_limit - ride_duration))
+            })
+            .fold(HashMap::<usize, Duration>::new(), |mut slacks, (first_idx, last_idx, slack)| {
+                (first_idx..last_idx).for_each(|idx| {
+                    let value = slacks.entry(idx).or_insert(slack);
+                    *value = value.min(slack);
+                });
+
+                slacks
+            });
+
+        slacks.into_iter().for_each(|(idx, slack)| {
+            route_ctx.state_mut().put_activity_state(RIDE_DURATION_SLACK_KEY, idx, slack);
+        });
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_ride_limit(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &Route,
+    limit: &RideDurationLimit,
+    start: (Location, Timestamp),
+    end: Location,
+) -> Option<Duration> {
+    let (start_location, departure) = start;
+    let direct_duration = if limit.max_factor.is_some() {
+        transport.duration(route, start_location, end, TravelTime::Departure(departure))
+    } else {
+        0.
+    };
+
+    limit.get_limit(direct_duration)
+}
@@ -0,0 +1,455 @@
+//! A feature to synchronize or order service start of jobs which can be served by different routes,
+//! e.g. two technicians should arrive at the same job site at the same time or delivery should
+//! happen only after installation is done.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/schedule_relations_test.rs"]
+mod schedule_relations_test;
+
+use super::*;
+use crate::construction::enablers::{
+    can_reach_next_activity, get_bounded_time_window, propagate_time_window_bounds, update_route_schedule,
+    ScheduleStateKeys,
+};
+use crate::models::common::{Duration, TimeWindow, Timestamp};
+use crate::models::problem::{ActivityCost, Multi, TransportCost, TravelTime};
+use crate::models::solution::Route;
+use hashbrown::{HashMap, HashSet};
+use std::cmp::Ordering;
+
+/// Specifies a type of schedule relation between jobs.
+#[derive(Clone, Debug)]
+pub enum ScheduleRelationType {
+    /// Jobs should start at the same time within a given tolerance.
+    Sync {
+        /// Max difference between service start of any two jobs.
+        tolerance: Duration,
+    },
+    /// Jobs should be served in the given order: each job starts not earlier than the previous
+    /// one is finished plus a given offset.
+    Precedence {
+        /// Min time between service end of the previous job and service start of the next one.
+        offset: Duration,
+    },
+}
+
+/// Specifies a schedule relation between jobs which can be served by any routes.
+/// Only jobs which are assigned are constrained: if one of the jobs is unassigned, the
+/// rest of them can be served at any time. A related job can be a part of the multi job,
+/// then the whole multi job is unassigned when the relation is violated.
+#[derive(Clone, Debug)]
+pub struct ScheduleRelation {
+    /// A relation type.
+    pub relation_type: ScheduleRelationType,
+    /// Related jobs. For precedence relation, the order of jobs defines the order of service.
+    pub jobs: Vec<Job>,
+}
+
+/// Creates a feature which synchronizes or orders service start of related jobs across routes.
+/// Service start of related jobs is controlled by narrowing their time windows, so the route
+/// schedule is recalculated in dependent routes when one of the related jobs is moved.
+/// This is a hard constraint.
+pub fn create_schedule_relation_feature(
+    name: &str,
+    code: ViolationCode,
+    relations: Vec<ScheduleRelation>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Result<Feature, GenericError> {
+    let index = Arc::new(RelationIndex::new(relations)?);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(ScheduleRelationConstraint {
+            code,
+            index: index.clone(),
+            transport: transport.clone(),
+            activity: activity.clone(),
+        })
+        .with_state(ScheduleRelationState { code, index, transport, activity, state_keys: vec![SCHEDULE_RELATION_KEY] })
+        .build()
+}
+
+/// Keeps service time bounds of the related job derived from schedules of its assigned partners.
+#[derive(Clone, Debug)]
+struct ScheduleBounds {
+    earliest_start: Timestamp,
+    latest_start: Timestamp,
+    latest_departure: Timestamp,
+}
+
+impl Default for ScheduleBounds {
+    fn default() -> Self {
+        Self { earliest_start: f64::MIN, latest_start: f64::MAX, latest_departure: f64::MAX }
+    }
+}
+
+impl ScheduleBounds {
+    /// Returns the latest service start of the activity with given duration.
+    fn get_latest_start(&self, duration: Duration) -> Timestamp {
+        self.latest_start.min(self.latest_departure - duration)
+    }
+}
+
+/// Keeps actual schedule of the assigned related job.
+#[derive(Clone, Debug)]
+struct JobSchedule {
+    route_idx: usize,
+    activity_idx: usize,
+    start: Timestamp,
+    departure: Timestamp,
+}
+
+struct RelationIndex {
+    relations: Vec<ScheduleRelation>,
+    jobs: HashMap<Job, Vec<(usize, usize)>>,
+}
+
+impl RelationIndex {
+    fn new(relations: Vec<ScheduleRelation>) -> Result<Self, GenericError> {
+        if relations.iter().any(|relation| relation.jobs.len() < 2) {
+            return Err("schedule relation should have at least two jobs".into());
+        }
+
+        if relations.iter().flat_map(|relation| relation.jobs.iter()).any(|job| job.as_single().is_none()) {
+            return Err("schedule relation supports only single jobs".into());
+        }
+
+        let jobs =
+            relations.iter().enumerate().fold(HashMap::<Job, Vec<_>>::new(), |mut acc, (relation_idx, relation)| {
+                relation.jobs.iter().enumerate().for_each(|(position, job)| {
+                    acc.entry(job.clone()).or_default().push((relation_idx, position));
+                });
+                acc
+            });
+
+        Ok(Self { relations, jobs })
+    }
+
+    fn is_related(&self, job: &Job) -> bool {
+        self.jobs.contains_key(job)
+    }
+
+    /// Returns schedules of all assigned related jobs.
+    fn get_schedules(&self, routes: &[RouteContext]) -> HashMap<Job, JobSchedule> {
+        routes
+            .iter()
+            .enumerate()
+            .flat_map(|(route_idx, route_ctx)| {
+                route_ctx.route().tour.all_activities().enumerate().filter_map(move |(activity_idx, activity)| {
+                    activity
+                        .job
+                        .as_ref()
+                        .map(|single| Job::Single(single.clone()))
+                        .filter(|job| self.is_related(job))
+                        .map(|job| {
+                            let start = activity.schedule.arrival.max(activity.place.time.start);
+                            (
+                                job,
+                                JobSchedule { route_idx, activity_idx, start, departure: activity.schedule.departure },
+                            )
+                        })
+                })
+            })
+            .collect()
+    }
+
+    /// Returns service time bounds of the job imposed by its assigned partners.
+    fn get_bounds(&self, job: &Job, schedules: &HashMap<Job, JobSchedule>) -> ScheduleBounds {
+        self.jobs.get(job).iter().flat_map(|positions| positions.iter()).fold(
+            ScheduleBounds::default(),
+            |bounds, &(relation_idx, position)| {
+                let relation = &self.relations[relation_idx];
+
+                match relation.relation_type {
+                    ScheduleRelationType::Sync { tolerance } => relation
+                        .jobs
+                        .iter()
+                        .enumerate()
+                        .filter(|(idx, _)| *idx != position)
+                        .filter_map(|(_, job)| schedules.get(job))
+                        .fold(bounds, |bounds, partner| ScheduleBounds {
+                            earliest_start: bounds.earliest_start.max(partner.start - tolerance),
+                            latest_start: bounds.latest_start.min(partner.start + tolerance),
+                            ..bounds
+                        }),
+                    ScheduleRelationType::Precedence { offset } => {
+                        let prev = position.checked_sub(1).and_then(|idx| relation.jobs.get(idx));
+                        let next = relation.jobs.get(position + 1);
+
+                        ScheduleBounds {
+                            earliest_start: prev
+                                .and_then(|job| schedules.get(job))
+                                .map_or(bounds.earliest_start, |prev| {
+                                    bounds.earliest_start.max(prev.departure + offset)
+                                }),
+                            latest_departure: next
+                                .and_then(|job| schedules.get(job))
+                                .map_or(bounds.latest_departure, |next| {
+                                    bounds.latest_departure.min(next.start - offset)
+                                }),
+                            ..bounds
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Returns indices of relations which are violated by the current schedules.
+    fn get_violated_relations(&self, schedules: &HashMap<Job, JobSchedule>) -> Vec<usize> {
+        let is_greater = |left: Timestamp, right: Timestamp| compare_floats(left, right) == Ordering::Greater;
+
+        self.relations
+            .iter()
+            .enumerate()
+            .filter(|(_, relation)| match relation.relation_type {
+                ScheduleRelationType::Sync { tolerance } => {
+                    let (min_start, max_start) = relation
+                        .jobs
+                        .iter()
+                        .filter_map(|job| schedules.get(job))
+                        .fold((f64::MAX, f64::MIN), |(min, max), schedule| {
+                            (min.min(schedule.start), max.max(schedule.start))
+                        });
+
+                    is_greater(max_start - min_start, tolerance)
+                }
+                ScheduleRelationType::Precedence { offset } => {
+                    relation.jobs.windows(2).any(|pair| match (schedules.get(&pair[0]), schedules.get(&pair[1])) {
+                        (Some(prev), Some(next)) => is_greater(prev.departure + offset, next.start),
+                        _ => false,
+                    })
+                }
+            })
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+}
+
+struct ScheduleRelationConstraint {
+    code: ViolationCode,
+    index: Arc<RelationIndex>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl ScheduleRelationConstraint {
+    fn evaluate_job(&self, route_ctx: &RouteContext, job: &Job) -> Option<ConstraintViolation> {
+        let positions = self.index.jobs.get(job)?;
+
+        let has_sync_partner_in_route =
+            positions.iter().any(|&(relation_idx, position)| {
+                let relation = &self.index.relations[relation_idx];
+                matches!(relation.relation_type, ScheduleRelationType::Sync { .. })
+                    && relation.jobs.iter().enumerate().any(|(idx, partner)| {
+                        idx != position && get_activity_index(route_ctx.route(), partner).is_some()
+                    })
+            });
+
+        if has_sync_partner_in_route {
+            return ConstraintViolation::fail(self.code);
+        }
+
+        // NOTE bounds are not available in the route which state was updated without solution context
+        match get_bounds(route_ctx, job) {
+            Some(bounds) if bounds.earliest_start <= bounds.get_latest_start(0.) => None,
+            _ => ConstraintViolation::fail(self.code),
+        }
+    }
+
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let target = activity_ctx.target;
+        let job =
+            target.job.as_ref().map(|single| Job::Single(single.clone())).filter(|job| self.index.is_related(job))?;
+        let route = route_ctx.route();
+
+        if !self.is_proper_order(route, &job, activity_ctx.index) {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        // NOTE bounds are not available in the route modified while inserting a multi job, so its
+        // next activities are not restricted by partners: violated relations are removed later
+        let default_bounds = ScheduleBounds::default();
+        let bounds = get_bounds(route_ctx, &job).unwrap_or(&default_bounds);
+
+        let departure = activity_ctx.prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                activity_ctx.prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        // NOTE target activity has to wait for the earliest start imposed by its partners
+        let arrival = arrival.max(bounds.earliest_start);
+        let start = arrival.max(target.place.time.start);
+        let latest_start = target.place.time.end.min(bounds.get_latest_start(target.place.duration));
+
+        if start > latest_start {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        if can_reach_next_activity(route_ctx, activity_ctx, arrival, self.transport.as_ref(), self.activity.as_ref()) {
+            None
+        } else {
+            ConstraintViolation::skip(self.code)
+        }
+    }
+
+    /// Checks that target activity doesn't break order of precedence jobs in the same route.
+    fn is_proper_order(&self, route: &Route, job: &Job, index: usize) -> bool {
+        self.index.jobs.get(job).iter().flat_map(|positions| positions.iter()).all(|&(relation_idx, position)| {
+            let relation = &self.index.relations[relation_idx];
+            if !matches!(relation.relation_type, ScheduleRelationType::Precedence { .. }) {
+                return true;
+            }
+
+            let prev = position.checked_sub(1).and_then(|idx| relation.jobs.get(idx));
+            let next = relation.jobs.get(position + 1);
+
+            prev.and_then(|prev| get_activity_index(route, prev)).map_or(true, |prev_idx| prev_idx <= index)
+                && next.and_then(|next| get_activity_index(route, next)).map_or(true, |next_idx| next_idx > index)
+        })
+    }
+}
+
+impl FeatureConstraint for ScheduleRelationConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => self.evaluate_job(route_ctx, job),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if self.index.is_related(&source) || self.index.is_related(&candidate) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+struct ScheduleRelationState {
+    code: ViolationCode,
+    index: Arc<RelationIndex>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    state_keys: Vec<StateKey>,
+}
+
+impl ScheduleRelationState {
+    /// Propagates schedule changes between dependent routes by narrowing time windows of the
+    /// related activities and stores bounds of all related jobs in each route.
+    fn update_schedules(&self, solution_ctx: &mut SolutionContext) {
+        propagate_time_window_bounds(
+            solution_ctx,
+            SCHEDULE_RELATION_KEY,
+            self.transport.as_ref(),
+            self.activity.as_ref(),
+            |solution_ctx| {
+                let schedules = self.index.get_schedules(&solution_ctx.routes);
+
+                schedules
+                    .iter()
+                    .filter_map(|(job, schedule)| {
+                        let route = solution_ctx.routes[schedule.route_idx].route();
+                        let activity = route.tour.get(schedule.activity_idx)?;
+                        let bounds = self.index.get_bounds(job, &schedules);
+
+                        let time = get_bounded_time_window(solution_ctx, SCHEDULE_RELATION_KEY, route, activity);
+                        let start = time.start.max(bounds.earliest_start);
+                        let end = time.end.min(bounds.get_latest_start(activity.place.duration));
+
+                        // NOTE violated relation does not narrow time window, it is handled later
+                        (start <= end).then(|| (job.clone(), TimeWindow::new(start, end)))
+                    })
+                    .collect()
+            },
+        );
+
+        let schedules = self.index.get_schedules(&solution_ctx.routes);
+        let bounds: Arc<HashMap<Job, ScheduleBounds>> =
+            Arc::new(self.index.jobs.keys().map(|job| (job.clone(), self.index.get_bounds(job, &schedules))).collect());
+
+        solution_ctx.routes.iter_mut().chain(solution_ctx.registry.all_routes_mut()).for_each(|route_ctx| {
+            route_ctx.state_mut().put_route_state_raw(SCHEDULE_RELATION_KEY, bounds.clone());
+        });
+    }
+
+    /// Removes jobs of relations which cannot be satisfied anymore. Returns indices of modified routes.
+    fn remove_violated_relations(&self, solution_ctx: &mut SolutionContext) -> HashSet<usize> {
+        let schedules = self.index.get_schedules(&solution_ctx.routes);
+        let jobs = self
+            .index
+            .get_violated_relations(&schedules)
+            .into_iter()
+            .flat_map(|relation_idx| self.index.relations[relation_idx].jobs.iter())
+            .filter_map(|job| schedules.get(job).map(|schedule| (get_root_job(job), schedule.route_idx)))
+            .filter(|(job, _)| !solution_ctx.locked.contains(job))
+            .collect::<HashMap<_, _>>();
+
+        jobs.into_iter()
+            .map(|(job, route_idx)| {
+                solution_ctx.routes[route_idx].route_mut().tour.remove(&job);
+                solution_ctx.unassigned.insert(job, UnassignmentInfo::Simple(self.code));
+
+                route_idx
+            })
+            .collect()
+    }
+}
+
+impl FeatureState for ScheduleRelationState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        self.update_schedules(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.update_schedules(solution_ctx);
+
+        let route_indices = self.remove_violated_relations(solution_ctx);
+        if !route_indices.is_empty() {
+            route_indices.iter().for_each(|&route_idx| {
+                update_route_schedule(
+                    &mut solution_ctx.routes[route_idx],
+                    self.activity.as_ref(),
+                    self.transport.as_ref(),
+                    &ScheduleStateKeys::default(),
+                )
+            });
+            solution_ctx.mark_routes_changed(route_indices);
+            self.update_schedules(solution_ctx);
+        }
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+/// Returns index of the related job activity in the route.
+fn get_activity_index(route: &Route, job: &Job) -> Option<usize> {
+    let single = job.as_single()?;
+    route.tour.all_activities().position(|activity| activity.job.as_ref().map_or(false, |s| Arc::ptr_eq(s, single)))
+}
+
+/// Returns the multi job for its part or the job itself.
+fn get_root_job(job: &Job) -> Job {
+    job.as_single().and_then(|single| Multi::roots(single)).map(Job::Multi).unwrap_or_else(|| job.clone())
+}
+
+fn get_bounds<'a>(route_ctx: &'a RouteContext, job: &Job) -> Option<&'a ScheduleBounds> {
+    route_ctx
+        .state()
+        .get_route_state::<HashMap<Job, ScheduleBounds>>(SCHEDULE_RELATION_KEY)
+        .and_then(|bounds| bounds.get(job))
+}
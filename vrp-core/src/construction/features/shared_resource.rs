@@ -8,6 +8,7 @@ use super::*;
 use crate::models::common::{MultiDimLoad, SingleDimLoad};
 use crate::models::problem::Single;
 use crate::models::solution::{Activity, Route};
+use crate::utils::Either;
 use hashbrown::HashMap;
 use std::cmp::Ordering;
 use std::ops::{Add, RangeInclusive, Sub};
@@ -22,6 +23,8 @@ pub type SharedResourceIntervalFn = Arc<dyn Fn(&RouteContext) -> Option<&Vec<(us
 pub type SharedResourceCapacityFn<T> = Arc<dyn Fn(&Activity) -> Option<(T, SharedResourceId)> + Send + Sync>;
 /// Specifies a type for a shared resource demand function.
 pub type SharedResourceDemandFn<T> = Arc<dyn Fn(&Single) -> Option<T> + Send + Sync>;
+/// Specifies a type for a shared resource supply function.
+pub type SharedResourceSupplyFn<T> = Arc<dyn Fn(&Single) -> Option<(T, SharedResourceId)> + Send + Sync>;
 
 /// Creates a feature which provides a way to define and use time independent, shared across multiple
 /// routes resource. It is a hard constraint.
@@ -34,6 +37,35 @@ pub fn create_shared_resource_feature<T>(
     resource_capacity_fn: SharedResourceCapacityFn<T>,
     resource_demand_fn: SharedResourceDemandFn<T>,
 ) -> Result<Feature, GenericError>
+where
+    T: SharedResource + Add<Output = T> + Sub<Output = T>,
+{
+    create_shared_resource_feature_with_supply(
+        name,
+        total_jobs,
+        code,
+        resource_key,
+        interval_fn,
+        resource_capacity_fn,
+        resource_demand_fn,
+        Arc::new(|_| None),
+    )
+}
+
+/// Creates a shared resource feature where resource capacity is increased by a supply of assigned
+/// jobs, e.g. deliveries to a satellite depot. When supply is decreased, the jobs which consume more
+/// resource than available are removed from the solution.
+#[allow(clippy::too_many_arguments)]
+pub fn create_shared_resource_feature_with_supply<T>(
+    name: &str,
+    total_jobs: usize,
+    code: ViolationCode,
+    resource_key: StateKey,
+    interval_fn: SharedResourceIntervalFn,
+    resource_capacity_fn: SharedResourceCapacityFn<T>,
+    resource_demand_fn: SharedResourceDemandFn<T>,
+    resource_supply_fn: SharedResourceSupplyFn<T>,
+) -> Result<Feature, GenericError>
 where
     T: SharedResource + Add<Output = T> + Sub<Output = T>,
 {
@@ -51,7 +83,9 @@ where
             interval_fn,
             resource_capacity_fn,
             resource_demand_fn,
+            resource_supply_fn,
             total_jobs,
+            code,
             resource_key,
         })
         .build()
@@ -92,11 +126,9 @@ impl<T: SharedResource> SharedResourceConstraint<T> {
         route_ctx: &RouteContext,
         activity_ctx: &ActivityContext,
     ) -> Option<ConstraintViolation> {
-        (self.interval_fn)(route_ctx)
-            .iter()
-            .flat_map(|intervals| intervals.iter())
+        get_resource_intervals(&self.interval_fn, route_ctx)
             .find(|(_, end_idx)| activity_ctx.index <= *end_idx)
-            .and_then(|&(start_idx, _)| {
+            .and_then(|(start_idx, _)| {
                 route_ctx.state().get_activity_state::<T>(self.resource_key, start_idx).and_then(|resource_available| {
                     let resource_demand = activity_ctx
                         .target
@@ -139,7 +171,9 @@ where
     interval_fn: SharedResourceIntervalFn,
     resource_capacity_fn: SharedResourceCapacityFn<T>,
     resource_demand_fn: SharedResourceDemandFn<T>,
+    resource_supply_fn: SharedResourceSupplyFn<T>,
     total_jobs: usize,
+    code: ViolationCode,
     resource_key: StateKey,
 }
 
@@ -151,33 +185,21 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> SharedResourceState<
             return;
         }
 
-        // first pass: get total demand for each shared resource
-        let total_demand = solution_ctx.routes.iter().fold(HashMap::<usize, T>::default(), |acc, route_ctx| {
-            (self.interval_fn)(route_ctx).iter().flat_map(|intervals| intervals.iter()).fold(
-                acc,
-                |mut acc, &(start_idx, end_idx)| {
-                    // get total resource demand for given interval
-                    let activity = get_activity_by_idx(route_ctx.route(), start_idx);
-                    let resource_demand_with_id = (self.resource_capacity_fn)(activity)
-                        .map(|(_, resource_id)| (self.get_total_demand(route_ctx, start_idx..=end_idx), resource_id));
-
-                    if let Some((resource_demand, id)) = resource_demand_with_id {
-                        let entry = acc.entry(id).or_insert_with(T::default);
-                        *entry = *entry + resource_demand;
-                    }
-
-                    acc
-                },
-            )
-        });
+        // first pass: get total demand and supply for each shared resource
+        let total_demand = self.get_total_resource_demand(solution_ctx);
+        let total_supply = self.get_total_resource_supply(solution_ctx);
 
         // second pass: store amount of available resources inside activity state
-        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
-            #[allow(clippy::unnecessary_to_owned)]
-            (self.interval_fn)(route_ctx).cloned().unwrap_or_default().into_iter().for_each(|(start_idx, _)| {
+        // NOTE empty routes are included as their first interval can start at the resource
+        solution_ctx.routes.iter_mut().chain(solution_ctx.registry.all_routes_mut()).for_each(|route_ctx| {
+            let intervals = get_resource_intervals(&self.interval_fn, route_ctx).collect::<Vec<_>>();
+            intervals.into_iter().for_each(|(start_idx, _)| {
                 let resource_available = (self.resource_capacity_fn)(get_activity_by_idx(route_ctx.route(), start_idx))
-                    .and_then(|(total_capacity, resource_id)| {
-                        total_demand.get(&resource_id).map(|total_demand| total_capacity - *total_demand)
+                    .map(|(total_capacity, resource_id)| {
+                        let total_supply = total_supply.get(&resource_id).copied().unwrap_or_default();
+                        let total_demand = total_demand.get(&resource_id).copied().unwrap_or_default();
+
+                        total_capacity + total_supply - total_demand
                     });
 
                 if let Some(resource_available) = resource_available {
@@ -187,9 +209,101 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> SharedResourceState<
         });
     }
 
+    /// Removes jobs which consume more resource than available, e.g. when supply job is unassigned.
+    /// Returns true if any job is removed.
+    fn remove_exceeded_consumption(&self, solution_ctx: &mut SolutionContext) -> bool {
+        if solution_ctx.get_jobs_amount() != self.total_jobs {
+            return false;
+        }
+
+        let total_demand = self.get_total_resource_demand(solution_ctx);
+        let total_supply = self.get_total_resource_supply(solution_ctx);
+
+        // NOTE consumers from the last routes are removed first till resource is not exceeded
+        let mut exceeded = HashMap::<SharedResourceId, T>::default();
+        let jobs = solution_ctx
+            .routes
+            .iter()
+            .enumerate()
+            .rev()
+            .flat_map(|(route_idx, route_ctx)| {
+                get_resource_intervals(&self.interval_fn, route_ctx)
+                    .filter_map(|(start_idx, end_idx)| {
+                        (self.resource_capacity_fn)(get_activity_by_idx(route_ctx.route(), start_idx))
+                            .map(|(capacity, resource_id)| (start_idx, end_idx, capacity, resource_id))
+                    })
+                    .flat_map(move |(start_idx, end_idx, capacity, resource_id)| {
+                        (start_idx..=end_idx).filter_map(move |idx| route_ctx.route().tour.get(idx)).filter_map(
+                            move |activity| {
+                                let demand = activity.job.as_ref().and_then(|job| (self.resource_demand_fn)(job))?;
+                                activity.retrieve_job().map(|job| (route_idx, job, demand, capacity, resource_id))
+                            },
+                        )
+                    })
+            })
+            .filter(|(_, job, ..)| !solution_ctx.locked.contains(job))
+            .filter(|&(_, _, demand, capacity, resource_id)| {
+                let available = *exceeded.entry(resource_id).or_insert_with(|| {
+                    let supply = total_supply.get(&resource_id).copied().unwrap_or_default();
+                    capacity + supply - total_demand.get(&resource_id).copied().unwrap_or_default()
+                });
+
+                if available.partial_cmp(&T::default()) == Some(Ordering::Less) {
+                    exceeded.insert(resource_id, available + demand);
+                    true
+                } else {
+                    false
+                }
+            })
+            .map(|(route_idx, job, ..)| (route_idx, job))
+            .collect::<Vec<_>>();
+
+        let is_removed = !jobs.is_empty();
+
+        jobs.into_iter().for_each(|(route_idx, job)| {
+            solution_ctx.routes[route_idx].route_mut().tour.remove(&job);
+            solution_ctx.unassigned.insert(job, UnassignmentInfo::Simple(self.code));
+        });
+
+        is_removed
+    }
+
+    fn get_total_resource_demand(&self, solution_ctx: &SolutionContext) -> HashMap<SharedResourceId, T> {
+        solution_ctx.routes.iter().fold(HashMap::<usize, T>::default(), |acc, route_ctx| {
+            get_resource_intervals(&self.interval_fn, route_ctx).fold(acc, |mut acc, (start_idx, end_idx)| {
+                // get total resource demand for given interval
+                let activity = get_activity_by_idx(route_ctx.route(), start_idx);
+                let resource_demand_with_id = (self.resource_capacity_fn)(activity)
+                    .map(|(_, resource_id)| (self.get_total_demand(route_ctx, start_idx..=end_idx), resource_id));
+
+                if let Some((resource_demand, id)) = resource_demand_with_id {
+                    let entry = acc.entry(id).or_insert_with(T::default);
+                    *entry = *entry + resource_demand;
+                }
+
+                acc
+            })
+        })
+    }
+
+    fn get_total_resource_supply(&self, solution_ctx: &SolutionContext) -> HashMap<SharedResourceId, T> {
+        solution_ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+            .filter_map(|activity| activity.job.as_ref().and_then(|job| (self.resource_supply_fn)(job)))
+            .fold(HashMap::default(), |mut acc, (supply, resource_id)| {
+                let entry = acc.entry(resource_id).or_insert_with(T::default);
+                *entry = *entry + supply;
+
+                acc
+            })
+    }
+
     /// Prevents resource consumption in given route by setting available to zero (default).
     fn prevent_resource_consumption(&self, route_ctx: &mut RouteContext) {
-        (self.interval_fn)(route_ctx).cloned().unwrap_or_default().into_iter().for_each(|(start_idx, end_idx)| {
+        let intervals = get_resource_intervals(&self.interval_fn, route_ctx).collect::<Vec<_>>();
+        intervals.into_iter().for_each(|(start_idx, end_idx)| {
             let activity = get_activity_by_idx(route_ctx.route(), start_idx);
             let has_resource_demand = (self.resource_capacity_fn)(activity).map_or(false, |(_, _)| {
                 (start_idx..=end_idx)
@@ -228,7 +342,10 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> FeatureState for Sha
     }
 
     fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
-        self.update_resource_consumption(solution_ctx);
+        // NOTE route intervals are stale after removal, consumption is updated when states are recalculated
+        if !self.remove_exceeded_consumption(solution_ctx) {
+            self.update_resource_consumption(solution_ctx);
+        }
     }
 
     fn state_keys(&self) -> Iter<StateKey> {
@@ -236,6 +353,19 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> FeatureState for Sha
     }
 }
 
+/// Returns resource intervals or a default interval for a route without them, e.g. an empty one.
+fn get_resource_intervals<'a>(
+    interval_fn: &SharedResourceIntervalFn,
+    route_ctx: &'a RouteContext,
+) -> impl Iterator<Item = (usize, usize)> + 'a {
+    let last_idx = route_ctx.route().tour.total() - 1;
+
+    match interval_fn(route_ctx) {
+        Some(intervals) => Either::Left(intervals.iter().copied()),
+        None => Either::Right(std::iter::once((0, last_idx))),
+    }
+}
+
 fn get_activity_by_idx(route: &Route, idx: usize) -> &Activity {
     route.tour.get(idx).expect("cannot get activity by idx")
 }
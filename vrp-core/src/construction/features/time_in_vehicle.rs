@@ -0,0 +1,205 @@
+//! A feature to limit time in vehicle of goods which are loaded at the trip start, e.g. at vehicle
+//! depot or at reload, and have to be delivered within some time after loading.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/time_in_vehicle_test.rs"]
+mod time_in_vehicle_test;
+
+use super::*;
+use crate::construction::enablers::RouteIntervals;
+use crate::models::common::{Duration, Timestamp};
+use crate::models::problem::{get_service_duration, get_setup_duration, Single, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+
+/// A function which returns max time in vehicle for given job.
+pub type TimeInVehicleLimitFn = Arc<dyn Fn(&Single) -> Option<Duration> + Send + Sync>;
+
+/// Creates a feature which limits time between departure from the trip start and service start of
+/// the job activity. Trips are defined by route intervals: a trip starts at vehicle start or at
+/// a marker job activity (e.g. reload). This is a hard constraint.
+pub fn create_max_time_in_vehicle_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    route_intervals: Arc<dyn RouteIntervals + Send + Sync>,
+    limit_fn: TimeInVehicleLimitFn,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(TimeInVehicleConstraint {
+            code,
+            transport,
+            route_intervals: route_intervals.clone(),
+            limit_fn: limit_fn.clone(),
+        })
+        .with_state(TimeInVehicleState { route_intervals, limit_fn, state_keys: vec![TIME_IN_VEHICLE_SLACK_KEY] })
+        .build()
+}
+
+/// Returns time in vehicle of the activity: a duration between departure from the trip start
+/// and activity's service start.
+pub fn get_time_in_vehicle(route: &Route, trip_start: &Activity, activity: &Activity) -> Duration {
+    activity.schedule.departure - get_service_duration(route, activity) - trip_start.schedule.departure
+}
+
+struct TimeInVehicleConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    route_intervals: Arc<dyn RouteIntervals + Send + Sync>,
+    limit_fn: TimeInVehicleLimitFn,
+}
+
+impl TimeInVehicleConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let target = activity_ctx.target;
+
+        // NOTE marker job starts a new trip, so goods after it are loaded later
+        if target.job.as_ref().map_or(false, |job| self.route_intervals.is_marker_job(&Job::Single(job.clone()))) {
+            return None;
+        }
+
+        let slack = route_ctx.state().get_activity_state::<Duration>(TIME_IN_VEHICLE_SLACK_KEY, activity_ctx.index);
+        let limit = target.job.as_ref().and_then(|single| (self.limit_fn)(single.as_ref()));
+
+        if slack.is_none() && limit.is_none() {
+            return None;
+        }
+
+        let (service_start, departure) = self.get_target_schedule(route, activity_ctx);
+
+        let delay = activity_ctx.next.map_or(0., |next| {
+            let next_arrival = departure
+                + self.transport.duration(
+                    route,
+                    target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(departure),
+                );
+
+            (next_arrival - next.schedule.arrival).max(0.)
+        });
+
+        if slack.map_or(false, |&slack| delay > slack) {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let limit = limit?;
+        let (start_idx, _) = get_trip_interval(self.route_intervals.as_ref(), route_ctx, activity_ctx.index);
+        let trip_departure = route.tour.get(start_idx).map_or(0., |start| start.schedule.departure);
+
+        if service_start - trip_departure > limit {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
+
+    /// Returns service start and departure time at the target activity.
+    fn get_target_schedule(&self, route: &Route, activity_ctx: &ActivityContext) -> (Timestamp, Timestamp) {
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+        let service_start = (arrival + get_setup_duration(target, prev.place.location)).max(target.place.time.start);
+
+        (service_start, service_start + get_service_duration(route, target))
+    }
+}
+
+impl FeatureConstraint for TimeInVehicleConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct TimeInVehicleState {
+    route_intervals: Arc<dyn RouteIntervals + Send + Sync>,
+    limit_fn: TimeInVehicleLimitFn,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for TimeInVehicleState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route();
+        let intervals = self
+            .route_intervals
+            .get_marker_intervals(route_ctx)
+            .cloned()
+            .unwrap_or_else(|| vec![(0, route.tour.total() - 1)]);
+
+        // NOTE slack at activity index is the min slack of limited activities after it within the same trip
+        let slacks = intervals
+            .into_iter()
+            .filter_map(|(start_idx, end_idx)| route.tour.get(start_idx).map(|start| (start_idx, end_idx, start)))
+            .flat_map(|(start_idx, end_idx, start)| {
+                (start_idx + 1..=end_idx)
+                    .rev()
+                    .scan(None, move |min_slack: &mut Option<Duration>, idx| {
+                        let activity = route.tour.get(idx)?;
+                        let slack = activity
+                            .job
+                            .as_ref()
+                            .and_then(|single| (self.limit_fn)(single.as_ref()))
+                            .map(|limit| limit - get_time_in_vehicle(route, start, activity));
+
+                        *min_slack = match (*min_slack, slack) {
+                            (Some(min_slack), Some(slack)) => Some(min_slack.min(slack)),
+                            (min_slack, slack) => min_slack.or(slack),
+                        };
+
+                        Some((idx - 1, *min_slack))
+                    })
+                    .filter_map(|(idx, slack)| slack.map(|slack| (idx, slack)))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        slacks.into_iter().for_each(|(idx, slack)| {
+            route_ctx.state_mut().put_activity_state(TIME_IN_VEHICLE_SLACK_KEY, idx, slack);
+        });
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+/// Returns route interval which contains activity at given index.
+fn get_trip_interval(
+    route_intervals: &(dyn RouteIntervals + Send + Sync),
+    route_ctx: &RouteContext,
+    activity_idx: usize,
+) -> (usize, usize) {
+    route_intervals
+        .get_marker_intervals(route_ctx)
+        .and_then(|intervals| intervals.iter().find(|(_, end_idx)| activity_idx <= *end_idx).copied())
+        .unwrap_or((0, route_ctx.route().tour.total() - 1))
+}
@@ -5,7 +5,7 @@
 mod tour_limits_test;
 
 use super::*;
-use crate::construction::enablers::calculate_travel_delta;
+use crate::construction::enablers::{calculate_travel_delta, RouteIntervals};
 use crate::models::common::{Distance, Duration};
 use crate::models::problem::{Actor, TransportCost};
 
@@ -13,6 +13,20 @@ use crate::models::problem::{Actor, TransportCost};
 pub type ActivitySizeResolver = Arc<dyn Fn(&Actor) -> Option<usize> + Sync + Send>;
 /// A function to resolve travel limit.
 pub type TravelLimitFn<T> = Arc<dyn Fn(&Actor) -> Option<T> + Send + Sync>;
+/// A function to resolve trip limits.
+pub type TripLimitFn = Arc<dyn Fn(&Actor) -> Option<TripLimits> + Send + Sync>;
+
+/// Specifies limits applied to each trip of the tour. A trip is a route interval between
+/// marker jobs (e.g. reloads) or between a marker job and a vehicle terminal.
+#[derive(Clone, Debug, Default)]
+pub struct TripLimits {
+    /// Max traveling distance per trip.
+    pub max_distance: Option<Distance>,
+    /// Max duration per trip.
+    pub max_duration: Option<Duration>,
+    /// Max amount of job activities per trip, marker jobs are not counted.
+    pub max_size: Option<usize>,
+}
 
 /// Creates a limit for activity amount in a tour.
 /// This is a hard constraint.
@@ -50,6 +64,45 @@ pub fn create_travel_limit_feature(
         .build()
 }
 
+/// Creates a trip limits such as distance, duration and amount of job activities.
+/// Trips are defined by route intervals, so the limits are applied to the whole tour when
+/// it has no marker jobs. This is a hard constraint.
+pub fn create_trip_limit_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    route_intervals: Arc<dyn RouteIntervals + Send + Sync>,
+    trip_limit_fn: TripLimitFn,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(TripLimitConstraint { code, transport, route_intervals, trip_limit_fn })
+        .build()
+}
+
+/// Returns distance, duration and amount of job activities of the trip specified by route interval.
+/// Trip ends with arrival at the next marker job activity or at the last activity of the tour.
+/// NOTE relies on distance states calculated by schedule update.
+pub fn get_trip_usage(route_ctx: &RouteContext, interval: (usize, usize)) -> (Distance, Duration, usize) {
+    let (start_idx, end_idx) = interval;
+    let tour = &route_ctx.route().tour;
+    let trip_end_idx = (end_idx + 1).min(tour.total() - 1);
+
+    let get_distance = |idx: usize| {
+        route_ctx.state().get_activity_state::<Distance>(TOTAL_DISTANCE_KEY, idx).copied().unwrap_or_default()
+    };
+
+    let departure = tour.get(start_idx).map_or(0., |activity| activity.schedule.departure);
+    let trip_end = match tour.get(end_idx + 1) {
+        Some(activity) => activity.schedule.arrival,
+        None => tour.get(end_idx).map_or(departure, |activity| activity.schedule.departure),
+    };
+
+    let size = tour.activities_slice(start_idx + 1, end_idx).iter().filter(|a| a.job.is_some()).count();
+
+    (get_distance(trip_end_idx) - get_distance(start_idx), trip_end - departure, size)
+}
+
 struct ActivityLimitConstraint {
     code: ViolationCode,
     limit_fn: ActivitySizeResolver,
@@ -155,3 +208,59 @@ impl FeatureState for TravelLimitState {
         self.state_keys.iter()
     }
 }
+
+struct TripLimitConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    route_intervals: Arc<dyn RouteIntervals + Send + Sync>,
+    trip_limit_fn: TripLimitFn,
+}
+
+impl FeatureConstraint for TripLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let is_marker = activity_ctx
+                    .target
+                    .job
+                    .as_ref()
+                    .map_or(false, |job| self.route_intervals.is_marker_job(&Job::Single(job.clone())));
+
+                // NOTE marker job starts a new trip, so it can only reduce the current one
+                if is_marker {
+                    return None;
+                }
+
+                let limits = (self.trip_limit_fn)(route_ctx.route().actor.as_ref())?;
+                let interval = self
+                    .route_intervals
+                    .get_marker_intervals(route_ctx)
+                    .and_then(|intervals| intervals.iter().find(|(_, end_idx)| activity_ctx.index <= *end_idx).copied())
+                    .unwrap_or((0, route_ctx.route().tour.total() - 1));
+                let (distance, duration, size) = get_trip_usage(route_ctx, interval);
+
+                if limits.max_size.map_or(false, |max_size| size + 1 > max_size) {
+                    return ConstraintViolation::skip(self.code);
+                }
+
+                if limits.max_distance.is_some() || limits.max_duration.is_some() {
+                    let (change_distance, change_duration) =
+                        calculate_travel_delta(route_ctx, activity_ctx, self.transport.as_ref());
+
+                    if limits.max_distance.map_or(false, |max_distance| distance + change_distance > max_distance)
+                        || limits.max_duration.map_or(false, |max_duration| duration + change_duration > max_duration)
+                    {
+                        return ConstraintViolation::skip(self.code);
+                    }
+                }
+
+                None
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
@@ -7,7 +7,9 @@ mod transport_test;
 use super::*;
 use crate::construction::enablers::{update_route_schedule, ScheduleStateKeys};
 use crate::models::common::Timestamp;
-use crate::models::problem::{ActivityCost, Single, TransportCost, TravelTime};
+use crate::models::problem::{
+    get_service_duration, get_setup_duration, ActivityCost, Single, TransportCost, TravelTime,
+};
 use crate::models::solution::Activity;
 
 // TODO
@@ -141,22 +143,30 @@ impl TransportConstraint {
             return ConstraintViolation::fail(self.code);
         }
 
-        let (next_act_location, latest_arr_time_at_next) = if let Some(next) = next {
+        // NOTE setup duration depends on previous location, so latest times here are for service start
+        let next_act = next.unwrap_or(target);
+        let latest_start_time_at_next = if let Some(next) = next {
             let latest_arrival =
-                route_ctx.state().get_activity_state(LATEST_ARRIVAL_KEY, activity_ctx.index + 1).copied();
-            (next.place.location, latest_arrival.unwrap_or(next.place.time.end))
+                route_ctx.state().get_activity_state::<f64>(LATEST_ARRIVAL_KEY, activity_ctx.index + 1).copied();
+            latest_arrival
+                .map_or(next.place.time.end, |arrival| arrival + get_setup_duration(next, prev.place.location))
         } else {
             // open vrp
-            (target.place.location, target.place.time.end.min(actor.detail.time.end))
+            target.place.time.end.min(actor.detail.time.end)
         };
 
         let arr_time_at_next = departure
-            + self.transport.duration(route, prev.place.location, next_act_location, TravelTime::Departure(departure));
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                next_act.place.location,
+                TravelTime::Departure(departure),
+            );
 
-        if arr_time_at_next > latest_arr_time_at_next {
+        if arr_time_at_next + get_setup_duration(next_act, prev.place.location) > latest_start_time_at_next {
             return ConstraintViolation::fail(self.code);
         }
-        if target.place.time.start > latest_arr_time_at_next {
+        if target.place.time.start > latest_start_time_at_next {
             return ConstraintViolation::skip(self.code);
         }
 
@@ -167,19 +177,21 @@ impl TransportConstraint {
                 target.place.location,
                 TravelTime::Departure(departure),
             );
+        let start_time_at_target = arr_time_at_target + get_setup_duration(target, prev.place.location);
 
+        let latest_arr_time_at_next = latest_start_time_at_next - get_setup_duration(next_act, target.place.location);
         let latest_departure_at_target = latest_arr_time_at_next
             - self.transport.duration(
                 route,
                 target.place.location,
-                next_act_location,
+                next_act.place.location,
                 TravelTime::Arrival(latest_arr_time_at_next),
             );
 
-        let latest_arr_time_at_target =
+        let latest_start_time_at_target =
             target.place.time.end.min(self.activity.estimate_arrival(route, target, latest_departure_at_target));
 
-        if arr_time_at_target > latest_arr_time_at_target {
+        if start_time_at_target > latest_start_time_at_target {
             return ConstraintViolation::skip(self.code);
         }
 
@@ -187,13 +199,13 @@ impl TransportConstraint {
             return ConstraintViolation::success();
         }
 
-        let end_time_at_target = self.activity.estimate_departure(route, target, arr_time_at_target);
+        let end_time_at_target = self.activity.estimate_departure(route, target, start_time_at_target);
 
         let arr_time_at_next = end_time_at_target
             + self.transport.duration(
                 route,
                 target.place.location,
-                next_act_location,
+                next_act.place.location,
                 TravelTime::Departure(end_time_at_target),
             );
 
@@ -248,7 +260,12 @@ impl TransportObjective {
             (0., 0., 0.)
         };
 
-        let new_costs = tp_cost_left + tp_cost_right + act_cost_left + act_cost_right;
+        let new_costs = tp_cost_left
+            + tp_cost_right
+            + act_cost_left
+            + act_cost_right
+            + self.estimate_load_cost(route_ctx, activity_ctx)
+            + self.estimate_tier_cost(route_ctx, activity_ctx);
 
         // no jobs yet or open vrp.
         if !route_ctx.route().tour.has_jobs() || next.is_none() {
@@ -269,6 +286,65 @@ impl TransportObjective {
         new_costs - old_costs
     }
 
+    fn estimate_load_cost(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route();
+        let costs = &route.actor.vehicle.costs;
+
+        let rate = match costs.per_distance_loaded {
+            Some(per_distance_loaded) => per_distance_loaded - costs.per_distance,
+            None => return Cost::default(),
+        };
+
+        // NOTE load change caused by target's demand on the rest of the tour is not considered here
+        let ratio = route_ctx.state().get_activity_state::<f64>(LOAD_RATIO_KEY, activity_ctx.index).copied();
+        let ratio = ratio.unwrap_or(0.);
+
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let departure = TravelTime::Departure(prev.schedule.departure);
+        let distance = |from: &Activity, to: &Activity| {
+            self.transport.distance(route, from.place.location, to.place.location, departure)
+        };
+
+        let extra_distance =
+            distance(prev, target) + activity_ctx.next.map_or(0., |next| distance(target, next) - distance(prev, next));
+
+        extra_distance * rate * ratio
+    }
+
+    fn estimate_tier_cost(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route();
+        let costs = &route.actor.vehicle.costs;
+
+        if !costs.has_tiers() {
+            return Cost::default();
+        }
+
+        // NOTE waiting time changes are not considered here to keep estimation cheap
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let departure = TravelTime::Departure(prev.schedule.departure);
+        let leg = |from: &Activity, to: &Activity| {
+            let (from, to) = (from.place.location, to.place.location);
+            (self.transport.duration(route, from, to, departure), self.transport.distance(route, from, to, departure))
+        };
+
+        let (duration, distance) = leg(prev, target);
+        let (extra_duration, extra_distance) = activity_ctx.next.map_or((duration, distance), |next| {
+            let ((right_duration, right_distance), (old_duration, old_distance)) = (leg(target, next), leg(prev, next));
+            (duration + right_duration - old_duration, distance + right_distance - old_distance)
+        });
+
+        let total_duration = route_ctx.state().get_route_state::<f64>(TOTAL_DURATION_KEY).copied().unwrap_or(0.);
+        let total_distance = route_ctx.state().get_route_state::<f64>(TOTAL_DISTANCE_KEY).copied().unwrap_or(0.);
+
+        let (old_time_cost, old_distance_cost) = costs.get_tier_costs(total_duration, total_distance);
+        let (new_time_cost, new_distance_cost) = costs.get_tier_costs(
+            total_duration + extra_duration + get_service_duration(route, target),
+            total_distance + extra_distance,
+        );
+
+        new_time_cost + new_distance_cost - old_time_cost - old_distance_cost
+    }
+
     fn analyze_route_leg(
         &self,
         route_ctx: &RouteContext,
@@ -278,13 +354,17 @@ impl TransportObjective {
     ) -> (Cost, Cost, Timestamp) {
         let route = route_ctx.route();
 
+        let actor = route.actor.as_ref();
+
+        let setup = get_setup_duration(end, start.place.location);
         let arrival = time
             + self.transport.duration(route, start.place.location, end.place.location, TravelTime::Departure(time));
-        let departure = self.activity.estimate_departure(route, end, arrival);
+        let departure = self.activity.estimate_departure(route, end, arrival + setup);
 
         let transport_cost =
             self.transport.cost(route, start.place.location, end.place.location, TravelTime::Departure(time));
-        let activity_cost = self.activity.cost(route, end, arrival);
+        let activity_cost = self.activity.cost(route, end, arrival + setup)
+            + setup * (actor.driver.costs.per_service_time + actor.vehicle.costs.per_service_time);
 
         (transport_cost, activity_cost, departure)
     }
@@ -322,6 +402,7 @@ impl TransportState {
             schedule_state_keys.latest_arrival,
             schedule_state_keys.total_duration,
             schedule_state_keys.total_distance,
+            schedule_state_keys.time_window_penalty,
         ];
 
         Self { schedule_state_keys: ScheduleStateKeys::default(), transport, activity, all_state_keys }
@@ -361,6 +442,7 @@ impl Default for ScheduleStateKeys {
             waiting_time: WAITING_KEY,
             total_distance: TOTAL_DISTANCE_KEY,
             total_duration: TOTAL_DURATION_KEY,
+            time_window_penalty: TIME_WINDOW_PENALTY_KEY,
         }
     }
 }
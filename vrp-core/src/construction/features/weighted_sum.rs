@@ -0,0 +1,56 @@
+//! Provides a feature to combine multiple objectives as their weighted sum.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/weighted_sum_test.rs"]
+mod weighted_sum_test;
+
+use super::*;
+
+/// Specifies an objective with its weight.
+type WeightedObjective = (Arc<dyn FeatureObjective<Solution = InsertionContext> + Send + Sync>, Cost);
+
+/// Creates a feature which objective is a weighted sum of objectives of given features. Constraints
+/// and states of all features are kept as is.
+pub fn create_weighted_sum_feature(name: &str, features: &[(Feature, Cost)]) -> Result<Feature, GenericError> {
+    if features.is_empty() {
+        return Err("weighted sum feature requires at least one feature".into());
+    }
+
+    let objectives = features
+        .iter()
+        .map(|(feature, weight)| {
+            feature
+                .objective
+                .clone()
+                .map(|objective| (objective, *weight))
+                .ok_or_else(|| GenericError::from(format!("feature '{}' has no objective", feature.name)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let weighted =
+        FeatureBuilder::default().with_name(name).with_objective(WeightedSumObjective { objectives }).build()?;
+
+    let features = std::iter::once(weighted)
+        .chain(features.iter().map(|(feature, _)| Feature { objective: None, ..feature.clone() }))
+        .collect::<Vec<_>>();
+
+    FeatureBuilder::combine(name, features.as_slice())
+}
+
+struct WeightedSumObjective {
+    objectives: Vec<WeightedObjective>,
+}
+
+impl Objective for WeightedSumObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.objectives.iter().map(|(objective, weight)| objective.fitness(solution) * weight).sum()
+    }
+}
+
+impl FeatureObjective for WeightedSumObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        self.objectives.iter().map(|(objective, weight)| objective.estimate(move_ctx) * weight).sum()
+    }
+}
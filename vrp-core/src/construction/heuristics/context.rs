@@ -2,7 +2,9 @@
 #[path = "../../../tests/unit/construction/heuristics/context_test.rs"]
 mod context_test;
 
-use crate::construction::features::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
+use crate::construction::features::{
+    CHANGED_ROUTES_KEY, LOAD_COST_KEY, TIME_WINDOW_PENALTY_KEY, TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY,
+};
 use crate::construction::heuristics::factories::*;
 use crate::models::common::Cost;
 use crate::models::problem::*;
@@ -145,8 +147,17 @@ impl SolutionContext {
             let actor = &route_ctx.route.actor;
             let distance = route_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
             let duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+            let penalty = route_ctx.state.get_route_state::<f64>(TIME_WINDOW_PENALTY_KEY).cloned().unwrap_or(0.);
+            let load_cost = route_ctx.state.get_route_state::<f64>(LOAD_COST_KEY).cloned().unwrap_or(0.);
 
-            acc + get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
+            let (time_tier_cost, distance_tier_cost) = actor.vehicle.costs.get_tier_costs(duration, distance);
+
+            acc + get_cost(&actor.vehicle.costs, distance, duration)
+                + get_cost(&actor.driver.costs, distance, duration)
+                + time_tier_cost
+                + distance_tier_cost
+                + penalty
+                + load_cost
         })
     }
 
@@ -162,14 +173,24 @@ impl SolutionContext {
 
     /// Keep routes for which given predicate returns true.
     pub fn keep_routes(&mut self, predicate: &dyn Fn(&RouteContext) -> bool) {
+        let changed = self.take_changed_routes();
+
         // as for 1.68, drain_filter is not yet stable (see https://github.com/rust-lang/rust/issues/43244)
-        let (keep, remove): (Vec<_>, Vec<_>) = self.routes.drain(0..).partition(predicate);
+        let (keep, remove): (Vec<_>, Vec<_>) =
+            self.routes.drain(0..).enumerate().partition(|(_, route_ctx)| predicate(route_ctx));
 
-        remove.into_iter().for_each(|route_ctx| {
+        remove.into_iter().for_each(|(_, route_ctx)| {
             assert!(self.registry.free_route(route_ctx));
         });
 
-        self.routes = keep;
+        // NOTE keep marked routes consistent with their new indices
+        if !changed.is_empty() {
+            self.mark_routes_changed(
+                keep.iter().enumerate().filter(|(_, (idx, _))| changed.contains(idx)).map(|(new_idx, _)| new_idx),
+            );
+        }
+
+        self.routes = keep.into_iter().map(|(_, route_ctx)| route_ctx).collect();
     }
 
     /// Removes empty routes from solution context.
@@ -177,6 +198,24 @@ impl SolutionContext {
         self.keep_routes(&|route_ctx| route_ctx.route().tour.has_jobs())
     }
 
+    /// Marks routes which are changed as a side effect of changes in other routes, e.g. when schedules
+    /// are synchronized across routes. Their state is updated by all features once the current
+    /// feature state update is finished.
+    pub fn mark_routes_changed(&mut self, route_indices: impl IntoIterator<Item = usize>) {
+        let mut changed = self.take_changed_routes();
+        changed.extend(route_indices);
+
+        self.state.insert(CHANGED_ROUTES_KEY, Arc::new(changed));
+    }
+
+    /// Takes indices of routes marked as changed.
+    pub(crate) fn take_changed_routes(&mut self) -> HashSet<usize> {
+        self.state
+            .remove(&CHANGED_ROUTES_KEY)
+            .and_then(|state| state.downcast_ref::<HashSet<usize>>().cloned())
+            .unwrap_or_default()
+    }
+
     /// Creates a deep copy of `SolutionContext`.
     pub fn deep_copy(&self) -> Self {
         Self {
@@ -530,6 +569,12 @@ impl RegistryContext {
         }
     }
 
+    /// Returns all routes from the index, including ones which are already in use.
+    /// NOTE: these routes are templates for new routes, so they should have no jobs.
+    pub fn all_routes_mut(&mut self) -> impl Iterator<Item = &mut RouteContext> {
+        self.index.values_mut()
+    }
+
     /// Return back route to be reused again.
     /// Returns whether the route was not present in the registry.
     pub fn free_route(&mut self, route: RouteContext) -> bool {
@@ -467,7 +467,8 @@ impl GoalContext {
 
     /// Accepts job insertion.
     pub fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
-        accept_insertion_with_states(&self.states, solution_ctx, route_index, job)
+        accept_insertion_with_states(&self.states, solution_ctx, route_index, job);
+        accept_changed_routes_with_states(&self.states, solution_ctx);
     }
 
     /// Accepts route state.
@@ -478,6 +479,7 @@ impl GoalContext {
     /// Accepts solution state.
     pub fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
         accept_solution_state_with_states(&self.states, solution_ctx);
+        accept_changed_routes_with_states(&self.states, solution_ctx);
     }
 
     /// Notifies about failed attempt to insert given jobs into given routes (indices).
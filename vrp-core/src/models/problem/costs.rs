@@ -3,6 +3,7 @@
 mod costs_test;
 
 use crate::models::common::*;
+use crate::models::problem::{ServiceDurationDimension, SetupDurationDimension, TimeWindowToleranceDimension};
 use crate::models::solution::{Activity, Route};
 use hashbrown::HashMap;
 use rosomaxa::prelude::GenericError;
@@ -25,10 +26,11 @@ pub trait ActivityCost {
         let actor = route.actor.as_ref();
 
         let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0. };
-        let service = activity.place.duration;
+        let service = get_service_duration(route, activity);
 
         waiting * (actor.driver.costs.per_waiting_time + actor.vehicle.costs.per_waiting_time)
             + service * (actor.driver.costs.per_service_time + actor.vehicle.costs.per_service_time)
+            + get_time_window_penalty(route, activity, arrival)
     }
 
     /// Estimates departure time for activity and actor at given arrival time.
@@ -38,17 +40,66 @@ pub trait ActivityCost {
     fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp;
 }
 
+/// Returns a penalty for starting activity outside of its job place's original (soft) time window.
+pub fn get_time_window_penalty(route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
+    activity.job.as_ref().and_then(|single| single.dimens.get_time_window_tolerance(activity.place.idx)).map_or(
+        0.,
+        |tolerance| {
+            let service_start = arrival.max(activity.place.time.start);
+            tolerance.get_penalty(&get_original_time_window(route, activity), service_start)
+        },
+    )
+}
+
+/// Returns job's time window which was used to insert the activity in the route. Time window of the
+/// activity can be narrowed by features which synchronize schedules across different routes.
+pub fn get_original_time_window(route: &Route, activity: &Activity) -> TimeWindow {
+    let start_time = route.tour.start().map_or(0., |start| start.schedule.departure);
+
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.places.get(activity.place.idx))
+        .and_then(|place| {
+            place
+                .times
+                .iter()
+                .map(|time| time.to_time_window(start_time))
+                .find(|time| time.start <= activity.place.time.start && activity.place.time.end <= time.end)
+        })
+        .unwrap_or_else(|| activity.place.time.clone())
+}
+
+/// Returns a service duration of the activity performed by the route's actor.
+pub fn get_service_duration(route: &Route, activity: &Activity) -> Duration {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.dimens.get_service_duration_fn(activity.place.idx))
+        .map_or(activity.place.duration, |duration_fn| duration_fn(route.actor.as_ref(), activity.place.duration))
+}
+
+/// Returns a setup duration of the activity when it is reached from given location. Setup is
+/// incurred only once for consecutive activities at the same location.
+pub fn get_setup_duration(activity: &Activity, prev_location: Location) -> Duration {
+    if activity.place.location == prev_location {
+        return Duration::default();
+    }
+
+    activity.job.as_ref().and_then(|single| single.dimens.get_setup_duration(activity.place.idx)).unwrap_or_default()
+}
+
 /// An actor independent activity costs.
 #[derive(Default)]
 pub struct SimpleActivityCost {}
 
 impl ActivityCost for SimpleActivityCost {
-    fn estimate_departure(&self, _: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
-        arrival.max(activity.place.time.start) + activity.place.duration
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        arrival.max(activity.place.time.start) + get_service_duration(route, activity)
     }
 
-    fn estimate_arrival(&self, _: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
-        activity.place.time.end.min(departure - activity.place.duration)
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        activity.place.time.end.min(departure - get_service_duration(route, activity))
     }
 }
 
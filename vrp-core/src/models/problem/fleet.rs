@@ -5,6 +5,7 @@ mod fleet_test;
 use crate::models::common::*;
 use crate::utils::short_type_name;
 use hashbrown::{HashMap, HashSet};
+use rosomaxa::prelude::GenericError;
 use std::cmp::Ordering::Less;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
@@ -23,14 +24,52 @@ pub struct Costs {
     pub per_waiting_time: f64,
     /// Cost per service time unit.
     pub per_service_time: f64,
+    /// Cost per distance unit when vehicle is fully loaded. If it is set, actual cost per distance unit
+    /// is interpolated between `per_distance` (empty vehicle) and this value using current load.
+    pub per_distance_loaded: Option<f64>,
+    /// Time cost tiers sorted by threshold: they replace time costs once total tour duration exceeds threshold.
+    pub time_tiers: Vec<CostTier>,
+    /// Distance cost tiers sorted by threshold: they replace distance cost once total tour distance exceeds threshold.
+    pub distance_tiers: Vec<CostTier>,
 }
 
-/// Represents driver detail (reserved for future use).
+impl Costs {
+    /// Returns extra time and distance costs caused by cost tiers for given total tour duration and distance.
+    pub fn get_tier_costs(&self, duration: Duration, distance: Distance) -> (Cost, Cost) {
+        let per_time = self.per_driving_time.max(self.per_service_time).max(self.per_waiting_time);
+
+        (
+            get_tier_cost(&self.time_tiers, per_time, duration),
+            get_tier_cost(&self.distance_tiers, self.per_distance, distance),
+        )
+    }
+
+    /// Checks whether costs have any cost tiers.
+    pub fn has_tiers(&self) -> bool {
+        !self.time_tiers.is_empty() || !self.distance_tiers.is_empty()
+    }
+}
+
+/// Represents a cost tier: a cost per unit which is applied once total amount of units exceeds threshold.
+#[derive(Clone, Debug)]
+pub struct CostTier {
+    /// A total amount of units (duration or distance) per tour after which the tier is applied.
+    pub threshold: f64,
+    /// A cost per unit beyond the threshold.
+    pub cost: f64,
+}
+
+/// Represents driver detail (driver shift).
 #[derive(Clone, Hash, Eq, PartialEq)]
-pub struct DriverDetail {}
+pub struct DriverDetail {
+    /// Time window when driver is allowed to work.
+    pub time: TimeWindow,
+}
 
-/// Represents a driver, person who drives Vehicle. Reserved for future usage, e.g. to allow
-/// reuse same vehicle more than once at different times.
+/// Represents a driver, person who drives Vehicle. A driver without details is not limited in time
+/// and can drive any vehicle at any time (e.g. implicit driver of each vehicle). A driver with details
+/// is paired only with vehicles available within driver's shifts and cannot drive more than one vehicle
+/// at the same time.
 pub struct Driver {
     /// Specifies operating costs for driver.
     pub costs: Costs,
@@ -113,6 +152,9 @@ impl Debug for Actor {
 /// A grouping function for collection of actors.
 pub type ActorGroupKeyFn = Box<dyn Fn(&[Arc<Actor>]) -> Box<dyn Fn(&Arc<Actor>) -> usize + Send + Sync>>;
 
+/// A function which checks whether the driver can drive the vehicle.
+pub type ActorPairingFn = Box<dyn Fn(&Driver, &Vehicle) -> bool>;
+
 /// Represents available resources to serve jobs.
 pub struct Fleet {
     /// All fleet drivers.
@@ -129,50 +171,159 @@ pub struct Fleet {
 
     /// A grouped actors.
     pub groups: HashMap<usize, HashSet<Arc<Actor>>>,
+
+    /// Actors which cannot be used together with the given one (e.g. they share the same vehicle
+    /// shift or the same driver at overlapping time).
+    pub conflicts: HashMap<Arc<Actor>, Vec<Arc<Actor>>>,
 }
 
 impl Fleet {
-    /// Creates a new instance of `Fleet`.
+    /// Creates a new instance of `Fleet`. Actors are created from all driver-vehicle pairs
+    /// which have overlapping working time. Panics if no actor can be created.
     pub fn new(drivers: Vec<Arc<Driver>>, vehicles: Vec<Arc<Vehicle>>, group_key: ActorGroupKeyFn) -> Fleet {
-        // TODO we should also consider multiple drivers to support smart vehicle-driver assignment.
-        assert_eq!(drivers.len(), 1);
-        assert!(!vehicles.is_empty());
+        Self::new_with_pairing(drivers, vehicles, group_key, Box::new(|_, _| true)).expect("cannot create fleet")
+    }
+
+    /// Creates a new instance of `Fleet`. Actors are created only from driver-vehicle pairs which
+    /// are accepted by the pairing function and have overlapping working time. Returns an error
+    /// if no actor can be created.
+    pub fn new_with_pairing(
+        drivers: Vec<Arc<Driver>>,
+        vehicles: Vec<Arc<Vehicle>>,
+        group_key: ActorGroupKeyFn,
+        pairing: ActorPairingFn,
+    ) -> Result<Fleet, GenericError> {
+        if drivers.is_empty() {
+            return Err("fleet has no drivers".into());
+        }
+
+        if vehicles.is_empty() {
+            return Err("fleet has no vehicles".into());
+        }
 
         let profiles: HashMap<usize, Profile> = vehicles.iter().map(|v| (v.profile.index, v.profile.clone())).collect();
         let mut profiles = profiles.into_iter().collect::<Vec<_>>();
         profiles.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Less));
         let (_, profiles): (Vec<_>, Vec<_>) = profiles.into_iter().unzip();
 
-        let actors = vehicles
+        // NOTE actors created from the same vehicle detail cannot be used together
+        let vehicle_slots = vehicles
             .iter()
-            .flat_map(|vehicle| {
-                vehicle.details.iter().map(|detail| {
-                    Arc::new(Actor {
-                        vehicle: vehicle.clone(),
-                        driver: drivers.first().unwrap().clone(),
-                        detail: ActorDetail {
-                            start: detail.start.clone(),
-                            end: detail.end.clone(),
-                            time: TimeWindow {
-                                start: detail.start.as_ref().and_then(|s| s.time.earliest).unwrap_or(0.),
-                                end: detail.end.as_ref().and_then(|e| e.time.latest).unwrap_or(f64::MAX),
-                            },
-                        },
+            .flat_map(|vehicle| vehicle.details.iter().map(move |detail| (vehicle, detail)))
+            .map(|(vehicle, detail)| {
+                drivers
+                    .iter()
+                    .filter(|driver| (*pairing)(driver, vehicle))
+                    .flat_map(|driver| {
+                        let driver_times = if driver.details.is_empty() {
+                            vec![None]
+                        } else {
+                            driver.details.iter().map(|detail| Some(&detail.time)).collect()
+                        };
+
+                        driver_times.into_iter().filter_map(move |driver_time| {
+                            create_actor_detail(detail, driver_time).map(|detail| {
+                                Arc::new(Actor { vehicle: vehicle.clone(), driver: driver.clone(), detail })
+                            })
+                        })
                     })
-                })
+                    .collect::<Vec<_>>()
             })
             .collect::<Vec<_>>();
 
+        let actors = vehicle_slots.iter().flat_map(|slot| slot.iter().cloned()).collect::<Vec<_>>();
+        if actors.is_empty() {
+            return Err("no driver can be paired with any vehicle at overlapping working time".into());
+        }
+
+        let conflicts = get_actor_conflicts(vehicle_slots.as_slice());
+
         let group_key = (*group_key)(&actors);
         let groups: HashMap<_, HashSet<_>> = actors.iter().cloned().fold(HashMap::new(), |mut acc, actor| {
             acc.entry((*group_key)(&actor)).or_default().insert(actor.clone());
             acc
         });
 
-        Fleet { drivers, vehicles, profiles, actors, groups }
+        Ok(Fleet { drivers, vehicles, profiles, actors, groups, conflicts })
     }
 }
 
+fn create_actor_detail(detail: &VehicleDetail, driver_time: Option<&TimeWindow>) -> Option<ActorDetail> {
+    let time = TimeWindow {
+        start: detail.start.as_ref().and_then(|s| s.time.earliest).unwrap_or(0.),
+        end: detail.end.as_ref().and_then(|e| e.time.latest).unwrap_or(f64::MAX),
+    };
+
+    let driver_time = match driver_time {
+        Some(driver_time) => driver_time,
+        None => return Some(ActorDetail { start: detail.start.clone(), end: detail.end.clone(), time }),
+    };
+
+    let time = time.overlapping(driver_time).filter(|time| time.end > time.start)?;
+
+    let start = detail.start.as_ref().map(|start| VehiclePlace {
+        location: start.location,
+        time: TimeInterval { earliest: Some(time.start), latest: start.time.latest.map(|latest| latest.min(time.end)) },
+    });
+    let end = detail.end.as_ref().map(|end| VehiclePlace {
+        location: end.location,
+        time: TimeInterval {
+            earliest: end.time.earliest.map(|earliest| earliest.max(time.start)),
+            latest: Some(time.end),
+        },
+    });
+
+    Some(ActorDetail { start, end, time })
+}
+
+/// Returns extra cost of given value over base cost applied to the whole value.
+fn get_tier_cost(tiers: &[CostTier], base_cost: f64, value: f64) -> Cost {
+    let (_, cost) = tiers.iter().fold((base_cost, Cost::default()), |(prev_cost, acc), tier| {
+        (tier.cost, acc + (value - tier.threshold).max(0.) * (tier.cost - prev_cost))
+    });
+
+    cost
+}
+
+fn get_actor_conflicts(vehicle_slots: &[Vec<Arc<Actor>>]) -> HashMap<Arc<Actor>, Vec<Arc<Actor>>> {
+    let mut conflicts: HashMap<Arc<Actor>, HashSet<Arc<Actor>>> = HashMap::new();
+
+    vehicle_slots.iter().filter(|slot| slot.len() > 1).for_each(|slot| {
+        slot.iter().for_each(|actor| {
+            let others = slot.iter().filter(|other| *other != actor).cloned();
+            conflicts.entry(actor.clone()).or_default().extend(others);
+        })
+    });
+
+    let mut driver_actors = vehicle_slots
+        .iter()
+        .flat_map(|slot| slot.iter())
+        .filter(|actor| !actor.driver.details.is_empty())
+        .fold(HashMap::<*const Driver, Vec<_>>::new(), |mut acc, actor| {
+            acc.entry(Arc::as_ptr(&actor.driver)).or_default().push(actor.clone());
+            acc
+        });
+
+    // NOTE sort actors of the same driver by start time, so only actors overlapping in time are visited
+    driver_actors.values_mut().for_each(|actors| {
+        actors.sort_by(|a, b| a.detail.time.start.total_cmp(&b.detail.time.start));
+
+        actors.iter().enumerate().for_each(|(idx, actor)| {
+            actors
+                .iter()
+                .skip(idx + 1)
+                .take_while(|other| other.detail.time.start < actor.detail.time.end)
+                .filter(|other| other.detail.time.intersects_exclusive(&actor.detail.time))
+                .for_each(|other| {
+                    conflicts.entry(actor.clone()).or_default().insert(other.clone());
+                    conflicts.entry(other.clone()).or_default().insert(actor.clone());
+                });
+        })
+    });
+
+    conflicts.into_iter().map(|(actor, others)| (actor, others.into_iter().collect())).collect()
+}
+
 impl Debug for Fleet {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct(short_type_name::<Self>())
@@ -3,7 +3,7 @@
 mod jobs_test;
 
 use crate::models::common::*;
-use crate::models::problem::{Costs, Fleet, TransportCost};
+use crate::models::problem::{Actor, Costs, Fleet, TransportCost};
 use crate::utils::short_type_name;
 use hashbrown::HashMap;
 use rosomaxa::utils::compare_floats_f32;
@@ -101,6 +101,210 @@ impl Debug for Single {
     }
 }
 
+/// Specifies how much job place's time windows can be violated and what is the price of it.
+/// Time windows of such place are expected to be already extended by max tolerance values,
+/// so the rest of the logic treats them as hard ones.
+#[derive(Clone, Debug, Default)]
+pub struct TimeWindowTolerance {
+    /// Allowed deviation before time window start.
+    pub earliness: Option<TimeDeviation>,
+    /// Allowed deviation after time window end.
+    pub lateness: Option<TimeDeviation>,
+}
+
+/// Specifies max allowed time deviation and its penalty.
+#[derive(Clone, Debug)]
+pub struct TimeDeviation {
+    /// Max allowed deviation.
+    pub max: Duration,
+    /// A penalty per time unit of the deviation.
+    pub penalty: Cost,
+}
+
+impl TimeWindowTolerance {
+    /// Restores original time window from the one extended by tolerance values.
+    pub fn get_original(&self, time: &TimeWindow) -> TimeWindow {
+        let earliness = self.earliness.as_ref().map_or(0., |earliness| earliness.max);
+        let lateness = self.lateness.as_ref().map_or(0., |lateness| lateness.max);
+
+        TimeWindow::new(time.start + earliness, time.end - lateness)
+    }
+
+    /// Returns earliness and lateness of the service started at given time within extended time window.
+    pub fn get_deviation(&self, time: &TimeWindow, service_start: Timestamp) -> (Duration, Duration) {
+        let original = self.get_original(time);
+
+        ((original.start - service_start).max(0.), (service_start - original.end).max(0.))
+    }
+
+    /// Returns a penalty for the service started at given time within extended time window.
+    pub fn get_penalty(&self, time: &TimeWindow, service_start: Timestamp) -> Cost {
+        let (earliness, lateness) = self.get_deviation(time, service_start);
+
+        earliness * self.earliness.as_ref().map_or(0., |earliness| earliness.penalty)
+            + lateness * self.lateness.as_ref().map_or(0., |lateness| lateness.penalty)
+    }
+}
+
+/// A trait to get or set time window tolerance of job places.
+pub trait TimeWindowToleranceDimension {
+    /// Sets time window tolerance for job places specified by their indices.
+    fn set_time_window_tolerance(&mut self, tolerance: Vec<(usize, TimeWindowTolerance)>) -> &mut Self;
+    /// Gets time window tolerance of job place with given index.
+    fn get_time_window_tolerance(&self, place_idx: usize) -> Option<&TimeWindowTolerance>;
+}
+
+impl TimeWindowToleranceDimension for Dimensions {
+    fn set_time_window_tolerance(&mut self, tolerance: Vec<(usize, TimeWindowTolerance)>) -> &mut Self {
+        self.set_value(TIME_WINDOW_TOLERANCE_DIMENSION_KEY, tolerance);
+        self
+    }
+
+    fn get_time_window_tolerance(&self, place_idx: usize) -> Option<&TimeWindowTolerance> {
+        self.get_value::<Vec<(usize, TimeWindowTolerance)>>(TIME_WINDOW_TOLERANCE_DIMENSION_KEY)
+            .and_then(|tolerance| tolerance.iter().find(|(idx, _)| *idx == place_idx))
+            .map(|(_, tolerance)| tolerance)
+    }
+}
+
+const TIME_WINDOW_TOLERANCE_DIMENSION_KEY: &str = "twt";
+
+/// A trait to get or set setup duration of job places. Setup duration is incurred only when
+/// a vehicle arrives at the place from a different location, e.g. to park and enter a building.
+pub trait SetupDurationDimension {
+    /// Sets setup duration for job places specified by their indices.
+    fn set_setup_durations(&mut self, durations: Vec<(usize, Duration)>) -> &mut Self;
+    /// Gets setup duration of job place with given index.
+    fn get_setup_duration(&self, place_idx: usize) -> Option<Duration>;
+}
+
+impl SetupDurationDimension for Dimensions {
+    fn set_setup_durations(&mut self, durations: Vec<(usize, Duration)>) -> &mut Self {
+        self.set_value(SETUP_DURATION_DIMENSION_KEY, durations);
+        self
+    }
+
+    fn get_setup_duration(&self, place_idx: usize) -> Option<Duration> {
+        self.get_value::<Vec<(usize, Duration)>>(SETUP_DURATION_DIMENSION_KEY)
+            .and_then(|durations| durations.iter().find(|(idx, _)| *idx == place_idx))
+            .map(|(_, duration)| *duration)
+    }
+}
+
+const SETUP_DURATION_DIMENSION_KEY: &str = "sdr";
+
+/// Specifies a function which returns service duration of job place for given actor and
+/// the place's default duration.
+pub type ServiceDurationFn = Arc<dyn Fn(&Actor, Duration) -> Duration + Send + Sync>;
+
+/// A trait to get or set actor dependent service duration of job places.
+pub trait ServiceDurationDimension {
+    /// Sets service duration functions for job places specified by their indices.
+    fn set_service_duration_fns(&mut self, durations: Vec<(usize, ServiceDurationFn)>) -> &mut Self;
+    /// Gets service duration function of job place with given index.
+    fn get_service_duration_fn(&self, place_idx: usize) -> Option<&ServiceDurationFn>;
+}
+
+impl ServiceDurationDimension for Dimensions {
+    fn set_service_duration_fns(&mut self, durations: Vec<(usize, ServiceDurationFn)>) -> &mut Self {
+        self.set_value(SERVICE_DURATION_DIMENSION_KEY, durations);
+        self
+    }
+
+    fn get_service_duration_fn(&self, place_idx: usize) -> Option<&ServiceDurationFn> {
+        self.get_value::<Vec<(usize, ServiceDurationFn)>>(SERVICE_DURATION_DIMENSION_KEY)
+            .and_then(|durations| durations.iter().find(|(idx, _)| *idx == place_idx))
+            .map(|(_, duration_fn)| duration_fn)
+    }
+}
+
+const SERVICE_DURATION_DIMENSION_KEY: &str = "sdf";
+
+/// Specifies a partial job created from the original one by splitting its demand, so it can be
+/// served by multiple visits, potentially, by different vehicles.
+#[derive(Clone, Debug)]
+pub struct PartialJob {
+    /// An id of the original job shared by all its parts.
+    pub id: String,
+    /// A part index.
+    pub index: usize,
+    /// Total amount of parts created from the original job.
+    pub total: usize,
+}
+
+/// A trait to get or set partial job details.
+pub trait PartialJobDimension {
+    /// Sets partial job details.
+    fn set_partial_job(&mut self, partial: PartialJob) -> &mut Self;
+    /// Gets partial job details.
+    fn get_partial_job(&self) -> Option<&PartialJob>;
+}
+
+impl PartialJobDimension for Dimensions {
+    fn set_partial_job(&mut self, partial: PartialJob) -> &mut Self {
+        self.set_value(PARTIAL_JOB_DIMENSION_KEY, partial);
+        self
+    }
+
+    fn get_partial_job(&self) -> Option<&PartialJob> {
+        self.get_value(PARTIAL_JOB_DIMENSION_KEY)
+    }
+}
+
+const PARTIAL_JOB_DIMENSION_KEY: &str = "prt";
+
+/// Specifies a job variant: one of alternative jobs created from the same original job where
+/// exactly one of them should be served. A variant can be served by several jobs together,
+/// e.g. by legs of the shipment transferred between vehicles.
+#[derive(Clone, Debug)]
+pub struct JobAlternative {
+    /// An id of the original job shared by all its variants.
+    pub id: String,
+    /// A variant index.
+    pub index: usize,
+    /// Total amount of variants.
+    pub total: usize,
+    /// Amount of jobs which serve the variant together.
+    pub parts: usize,
+}
+
+/// A trait to get or set job alternative details.
+pub trait JobAlternativeDimension {
+    /// Sets job alternative details.
+    fn set_job_alternative(&mut self, alternative: JobAlternative) -> &mut Self;
+    /// Gets job alternative details.
+    fn get_job_alternative(&self) -> Option<&JobAlternative>;
+}
+
+impl JobAlternativeDimension for Dimensions {
+    fn set_job_alternative(&mut self, alternative: JobAlternative) -> &mut Self {
+        self.set_value(JOB_ALTERNATIVE_DIMENSION_KEY, alternative);
+        self
+    }
+
+    fn get_job_alternative(&self) -> Option<&JobAlternative> {
+        self.get_value(JOB_ALTERNATIVE_DIMENSION_KEY)
+    }
+}
+
+const JOB_ALTERNATIVE_DIMENSION_KEY: &str = "alt";
+
+impl Single {
+    /// Splits the job with given id into given amount of partial jobs. Each partial job has the same
+    /// places and dimensions as the original one, `dimens_fn` is called to adjust them, e.g. set part's demand.
+    pub fn split(&self, id: &str, total: usize, dimens_fn: impl Fn(usize, &mut Dimensions)) -> Vec<Arc<Single>> {
+        (0..total)
+            .map(|index| {
+                let mut dimens = self.dimens.clone();
+                dimens.set_partial_job(PartialJob { id: id.to_string(), index, total });
+                (dimens_fn)(index, &mut dimens);
+
+                Arc::new(Single { places: self.places.clone(), dimens })
+            })
+            .collect()
+    }
+}
+
 /// Represents a job which consists of multiple sub jobs.
 /// All of these jobs must be performed or none of them. Order can be controlled
 /// via specific dimension value.
@@ -445,6 +649,9 @@ fn get_avg_profile_costs(fleet: &Fleet) -> HashMap<usize, Costs> {
                     per_driving_time: get_avg_by(costs, |c| c.per_driving_time),
                     per_waiting_time: get_avg_by(costs, |c| c.per_waiting_time),
                     per_service_time: get_avg_by(costs, |c| c.per_service_time),
+                    per_distance_loaded: None,
+                    time_tiers: vec![],
+                    distance_tiers: vec![],
                 },
             )
         })
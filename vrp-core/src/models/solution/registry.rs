@@ -12,6 +12,8 @@ pub struct Registry {
     available: HashMap<usize, HashSet<Arc<Actor>>>,
     index: HashMap<Arc<Actor>, usize>,
     all: Vec<Arc<Actor>>,
+    conflicts: Arc<HashMap<Arc<Actor>, Vec<Arc<Actor>>>>,
+    used: HashSet<Arc<Actor>>,
     random: Arc<dyn Random + Send + Sync>,
 }
 
@@ -24,19 +26,44 @@ impl Registry {
             .flat_map(|(group_id, actors)| actors.iter().map(|a| (a.clone(), *group_id)).collect::<Vec<_>>())
             .collect();
 
-        Self { available: fleet.groups.clone(), index, all: fleet.actors.to_vec(), random }
+        Self {
+            available: fleet.groups.clone(),
+            index,
+            all: fleet.actors.to_vec(),
+            conflicts: Arc::new(fleet.conflicts.clone()),
+            used: HashSet::default(),
+            random,
+        }
     }
 
-    /// Removes an actor from the list of available actors.
+    /// Removes an actor from the list of available actors together with actors conflicting with it.
     /// Returns whether the actor was present in the registry.
     pub fn use_actor(&mut self, actor: &Arc<Actor>) -> bool {
-        self.available.get_mut(self.index.get(actor).expect("unknown actor")).unwrap().remove(actor)
+        let is_removed = self.available.get_mut(self.index.get(actor).expect("unknown actor")).unwrap().remove(actor);
+
+        if is_removed {
+            self.used.insert(actor.clone());
+            self.get_conflicts(actor).iter().for_each(|other| {
+                self.remove_available(other);
+            });
+        }
+
+        is_removed
     }
 
-    /// Adds actor to the list of available actors.
+    /// Adds actor to the list of available actors together with actors which are not conflicting anymore.
     /// Returns whether the actor was not present in the registry.
     pub fn free_actor(&mut self, actor: &Arc<Actor>) -> bool {
-        self.available.get_mut(self.index.get(actor).expect("unknown actor")).unwrap().insert(actor.clone())
+        self.used.remove(actor);
+
+        self.get_conflicts(actor).iter().for_each(|other| {
+            if !self.is_blocked(other) {
+                self.insert_available(other);
+            }
+        });
+
+        !self.is_blocked(actor)
+            && self.available.get_mut(self.index.get(actor).expect("unknown actor")).unwrap().insert(actor.clone())
     }
 
     /// Returns all actors.
@@ -64,6 +91,8 @@ impl Registry {
             available: self.available.clone(),
             index: self.index.clone(),
             all: self.all.clone(),
+            conflicts: self.conflicts.clone(),
+            used: self.used.clone(),
             random: self.random.clone(),
         }
     }
@@ -86,7 +115,28 @@ impl Registry {
                 .map(|(actor, idx)| (actor.clone(), *idx))
                 .collect(),
             all: self.all.iter().filter(|actor| filter(actor.as_ref())).cloned().collect(),
+            conflicts: self.conflicts.clone(),
+            used: self.used.clone(),
             random: self.random.clone(),
         }
     }
+
+    fn get_conflicts(&self, actor: &Arc<Actor>) -> Vec<Arc<Actor>> {
+        self.conflicts.get(actor).cloned().unwrap_or_default()
+    }
+
+    fn is_blocked(&self, actor: &Arc<Actor>) -> bool {
+        self.used.contains(actor)
+            || self.conflicts.get(actor).map_or(false, |others| others.iter().any(|other| self.used.contains(other)))
+    }
+
+    // NOTE conflicting actor can be missing in the index of sliced registry
+
+    fn remove_available(&mut self, actor: &Arc<Actor>) -> bool {
+        self.index.get(actor).and_then(|idx| self.available.get_mut(idx)).map_or(false, |set| set.remove(actor))
+    }
+
+    fn insert_available(&mut self, actor: &Arc<Actor>) -> bool {
+        self.index.get(actor).and_then(|idx| self.available.get_mut(idx)).map_or(false, |set| set.insert(actor.clone()))
+    }
 }
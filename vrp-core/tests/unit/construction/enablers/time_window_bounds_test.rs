@@ -0,0 +1,104 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::Single;
+
+const FIRST_KEY: StateKey = StateKey(1000);
+const SECOND_KEY: StateKey = StateKey(1001);
+
+fn create_single(id: &str, time: (f64, f64)) -> Arc<Single> {
+    SingleBuilder::default().id(id).location(Some(10)).times(vec![TimeWindow::new(time.0, time.1)]).build_shared()
+}
+
+fn create_solution_ctx(singles: &[&Arc<Single>]) -> SolutionContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let activities = singles.iter().map(|single| {
+        let time = single.places.first().unwrap().times.first().unwrap().to_time_window(0.);
+        ActivityBuilder::with_location_and_tw(10, time).job(Some((*single).clone())).build()
+    });
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build();
+
+    SolutionContext { routes: vec![route_ctx], ..create_empty_solution_context() }
+}
+
+fn propagate(solution_ctx: &mut SolutionContext, feature_key: StateKey, bounds: Vec<(&Arc<Single>, (f64, f64))>) {
+    let bounds = bounds
+        .into_iter()
+        .map(|(single, (start, end))| (Job::Single(single.clone()), TimeWindow::new(start, end)))
+        .collect::<HashMap<_, _>>();
+
+    propagate_time_window_bounds(
+        solution_ctx,
+        feature_key,
+        &TestTransportCost::default(),
+        &TestActivityCost::default(),
+        |_| bounds.clone(),
+    );
+}
+
+fn get_time_window(solution_ctx: &SolutionContext, single: &Arc<Single>) -> TimeWindow {
+    let route = solution_ctx.routes[0].route();
+    route.tour.get(route.tour.index(&Job::Single(single.clone())).unwrap()).unwrap().place.time.clone()
+}
+
+parameterized_test! {can_intersect_bounds_of_features, (first, second, expected), {
+    can_intersect_bounds_of_features_impl(first, second, TimeWindow::new(expected.0, expected.1));
+}}
+
+can_intersect_bounds_of_features! {
+    case01_overlapping: ((20., 60.), (40., 80.), (40., 60.)),
+    case02_nested: ((20., 80.), (40., 60.), (40., 60.)),
+    case03_disjoint: ((20., 30.), (40., 60.), (0., 100.)),
+}
+
+fn can_intersect_bounds_of_features_impl(first: (f64, f64), second: (f64, f64), expected: TimeWindow) {
+    let job = create_single("job1", (0., 100.));
+    let mut solution_ctx = create_solution_ctx(&[&job]);
+
+    propagate(&mut solution_ctx, FIRST_KEY, vec![(&job, first)]);
+    propagate(&mut solution_ctx, SECOND_KEY, vec![(&job, second)]);
+
+    assert_eq!(get_time_window(&solution_ctx, &job), expected);
+}
+
+#[test]
+fn can_restore_time_window_when_feature_bounds_removed() {
+    let job = create_single("job1", (0., 100.));
+    let mut solution_ctx = create_solution_ctx(&[&job]);
+    propagate(&mut solution_ctx, FIRST_KEY, vec![(&job, (20., 60.))]);
+    propagate(&mut solution_ctx, SECOND_KEY, vec![(&job, (40., 80.))]);
+
+    propagate(&mut solution_ctx, SECOND_KEY, vec![]);
+    assert_eq!(get_time_window(&solution_ctx, &job), TimeWindow::new(20., 60.));
+
+    propagate(&mut solution_ctx, FIRST_KEY, vec![]);
+    assert_eq!(get_time_window(&solution_ctx, &job), TimeWindow::new(0., 100.));
+}
+
+#[test]
+fn can_get_bounded_time_window_without_feature_bounds() {
+    let job = create_single("job1", (0., 100.));
+    let mut solution_ctx = create_solution_ctx(&[&job]);
+    propagate(&mut solution_ctx, FIRST_KEY, vec![(&job, (20., 60.))]);
+    propagate(&mut solution_ctx, SECOND_KEY, vec![(&job, (40., 80.))]);
+    let route = solution_ctx.routes[0].route();
+    let activity = route.tour.get(1).unwrap();
+
+    assert_eq!(get_bounded_time_window(&solution_ctx, FIRST_KEY, route, activity), TimeWindow::new(40., 80.));
+    assert_eq!(get_bounded_time_window(&solution_ctx, SECOND_KEY, route, activity), TimeWindow::new(20., 60.));
+}
+
+#[test]
+fn can_mark_only_changed_routes() {
+    let job = create_single("job1", (0., 100.));
+    let mut solution_ctx = create_solution_ctx(&[&job]);
+
+    propagate(&mut solution_ctx, FIRST_KEY, vec![(&job, (20., 60.))]);
+    assert_eq!(solution_ctx.take_changed_routes(), HashSet::from_iter([0]));
+
+    propagate(&mut solution_ctx, FIRST_KEY, vec![(&job, (20., 60.))]);
+    assert!(solution_ctx.take_changed_routes().is_empty());
+}
@@ -5,7 +5,7 @@ use crate::helpers::models::domain::create_empty_solution_context;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
 use crate::models::common::{Demand, DemandDimension, SingleDimLoad};
-use crate::models::problem::{Job, Vehicle};
+use crate::models::problem::{Costs, Job, Vehicle};
 use crate::models::solution::Activity;
 
 const VIOLATION_CODE: ViolationCode = 2;
@@ -192,3 +192,40 @@ fn can_merge_jobs_with_demand_impl(
         (Err(result), Err(expected)) => assert_eq!(result, expected),
     }
 }
+
+parameterized_test! {can_calculate_load_dependent_costs, (per_distance_loaded, expected), {
+    can_calculate_load_dependent_costs_impl(per_distance_loaded, expected);
+}}
+
+can_calculate_load_dependent_costs! {
+    case01_no_loaded_costs: (None, None),
+    case02_same_costs: (Some(1.), Some(0.)),
+    case03_higher_costs: (Some(3.), Some(16.)),
+}
+
+fn can_calculate_load_dependent_costs_impl(per_distance_loaded: Option<f64>, expected: Option<f64>) {
+    let vehicle =
+        VehicleBuilder::default().id("v1").capacity(10).costs(Costs { per_distance_loaded, ..test_costs() }).build();
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(create_activity_with_simple_demand(-4))
+                .add_activity(create_activity_with_simple_demand(-2))
+                .build(),
+        )
+        .build();
+    (1..=3).for_each(|activity_idx| {
+        route_ctx.state_mut().put_activity_state(TOTAL_DISTANCE_KEY, activity_idx, activity_idx as f64 * 10.)
+    });
+
+    create_feature().state.unwrap().accept_route_state(&mut route_ctx);
+
+    let state = route_ctx.state();
+    assert_eq!(state.get_route_state::<f64>(LOAD_COST_KEY).copied(), expected);
+    if expected.is_some() {
+        assert_eq!(state.get_activity_state::<f64>(LOAD_RATIO_KEY, 0).copied(), Some(0.6));
+        assert_eq!(state.get_activity_state::<f64>(LOAD_RATIO_KEY, 1).copied(), Some(0.2));
+    }
+}
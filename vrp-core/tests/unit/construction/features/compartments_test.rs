@@ -0,0 +1,214 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+use crate::models::problem::{Fleet, Vehicle};
+
+const VIOLATION_CODE: ViolationCode = 1;
+const MARKER_DURATION: Duration = 5.;
+
+fn create_compartments(capacities: Vec<(i32, Duration)>) -> Vec<Compartment> {
+    capacities.into_iter().map(|(capacity, cleaning_duration)| Compartment { capacity, cleaning_duration }).collect()
+}
+
+fn create_accessor() -> CompartmentAccessor {
+    CompartmentAccessor {
+        compartments_fn: Arc::new(|vehicle: &Vehicle| vehicle.dimens.get_value("compartments")),
+        product_fn: Arc::new(|single: &Single| single.dimens.get_value("product")),
+        marker_fn: Arc::new(|activity: &Activity| {
+            activity.job.as_ref().map_or(false, |single| single.dimens.get_value::<bool>("marker").is_some())
+        }),
+    }
+}
+
+fn create_fleet(compartments: Vec<Compartment>) -> Fleet {
+    let mut vehicle = test_vehicle_with_id("v1");
+    vehicle.dimens.set_value("compartments", compartments);
+
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build()
+}
+
+fn create_product_activity(location: Location, product: usize, delivery: i32) -> Activity {
+    let mut dimens = Dimensions::default();
+    dimens.set_value(
+        "product",
+        ProductDemand {
+            product,
+            demand: Demand { pickup: Default::default(), delivery: (SingleDimLoad::new(delivery), Default::default()) },
+        },
+    );
+
+    ActivityBuilder::with_location(location)
+        .job(Some(SingleBuilder::default().location(Some(location)).dimens(dimens).build_shared()))
+        .build()
+}
+
+fn create_marker_activity(location: Location) -> Activity {
+    let mut dimens = Dimensions::default();
+    dimens.set_value("marker", true);
+    let single =
+        SingleBuilder::default().location(Some(location)).duration(MARKER_DURATION).dimens(dimens).build_shared();
+
+    ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::max(), MARKER_DURATION)
+        .job(Some(single))
+        .build()
+}
+
+fn create_route_ctx(fleet: &Fleet, activities: Vec<Activity>) -> RouteContext {
+    RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(fleet, "v1").add_activities(activities).build())
+        .build()
+}
+
+fn create_feature() -> Feature {
+    create_compartment_feature("compartments", VIOLATION_CODE, TestTransportCost::new_shared(), create_accessor())
+        .unwrap()
+}
+
+parameterized_test! {can_assign_products_to_compartments, (max_loads, capacities, last_products, expected), {
+    can_assign_products_to_compartments_impl(max_loads, capacities, last_products, expected);
+}}
+
+can_assign_products_to_compartments! {
+    case01_single_product: (vec![(0, 15)], vec![10, 10, 5], vec![None, None, None], Some(vec![Some(0), Some(0), None])),
+    case02_two_products: (vec![(0, 15), (1, 5)], vec![10, 10, 5], vec![None, None, None], Some(vec![Some(0), Some(0), Some(1)])),
+    case03_cannot_assign: (vec![(0, 12), (1, 12)], vec![10, 10, 5], vec![None, None, None], None),
+    case04_backtracking: (vec![(0, 10), (1, 5)], vec![5, 5, 5], vec![None, None, None], Some(vec![Some(0), Some(0), Some(1)])),
+    case05_prefer_last: (vec![(0, 10), (1, 5)], vec![10, 10, 5], vec![Some(1), None, None], Some(vec![Some(1), Some(0), None])),
+    case06_no_loads: (vec![], vec![10], vec![Some(1)], Some(vec![None])),
+}
+
+fn can_assign_products_to_compartments_impl(
+    max_loads: Vec<(usize, i32)>,
+    capacities: Vec<i32>,
+    last_products: Vec<Option<usize>>,
+    expected: Option<Vec<Option<usize>>>,
+) {
+    let compartments = create_compartments(capacities.into_iter().map(|capacity| (capacity, 0.)).collect());
+
+    let result = assign_products(max_loads, &compartments, &last_products);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_product_insertion, (target_product, target_delivery, expected), {
+    can_evaluate_product_insertion_impl(target_product, target_delivery, expected);
+}}
+
+can_evaluate_product_insertion! {
+    case01_same_product: (0, 2, None),
+    case02_other_product_fits: (1, 4, None),
+    case03_other_product_does_not_fit: (1, 6, ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_evaluate_product_insertion_impl(
+    target_product: usize,
+    target_delivery: i32,
+    expected: Option<ConstraintViolation>,
+) {
+    let fleet = create_fleet(create_compartments(vec![(10, 0.), (5, 0.)]));
+    let route_ctx = create_route_ctx(&fleet, vec![create_product_activity(10, 0, 8)]);
+    let target = create_product_activity(20, target_product, target_delivery);
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+
+    let result = create_feature().constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_add_cleaning_duration_to_trip_marker() {
+    let fleet = create_fleet(create_compartments(vec![(10, 3.)]));
+    let mut route_ctx = create_route_ctx(
+        &fleet,
+        vec![create_product_activity(10, 0, 8), create_marker_activity(20), create_product_activity(30, 1, 8)],
+    );
+
+    create_feature().state.unwrap().accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.route().tour.get(2).unwrap().place.duration, MARKER_DURATION + 3.);
+}
+
+#[test]
+fn can_get_compartment_usage() {
+    let fleet = create_fleet(create_compartments(vec![(10, 0.), (5, 0.)]));
+    let route_ctx = create_route_ctx(
+        &fleet,
+        vec![
+            create_product_activity(10, 0, 8),
+            create_product_activity(20, 1, 4),
+            create_marker_activity(30),
+            create_product_activity(40, 1, 12),
+        ],
+    );
+
+    let usage = get_compartment_usage(route_ctx.route(), &create_accessor()).unwrap();
+
+    assert_eq!(
+        usage,
+        vec![
+            vec![(Some(0), 8), (Some(1), 4)],
+            vec![(Some(0), 0), (Some(1), 4)],
+            vec![(Some(0), 0), (Some(1), 0)],
+            vec![(Some(1), 10), (Some(1), 2)],
+            vec![(Some(1), 0), (Some(1), 0)],
+            vec![(Some(1), 0), (Some(1), 0)],
+        ]
+    );
+}
+
+parameterized_test! {can_detect_full_last_trip, (products, expected), {
+    can_detect_full_last_trip_impl(products, expected);
+}}
+
+can_detect_full_last_trip! {
+    case01_free_compartment: (vec![0], false),
+    case02_all_occupied: (vec![0, 1], true),
+    case03_no_products: (vec![], false),
+}
+
+fn can_detect_full_last_trip_impl(products: Vec<usize>, expected: bool) {
+    let fleet = create_fleet(create_compartments(vec![(10, 0.), (5, 0.)]));
+    let activities = products
+        .into_iter()
+        .enumerate()
+        .map(|(idx, product)| create_product_activity((idx + 1) * 10, product, 2))
+        .collect();
+    let route_ctx = create_route_ctx(&fleet, activities);
+
+    let result = is_last_trip_full(route_ctx.route(), &create_accessor());
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_trips_merge, (left_products, right_products, expected), {
+    can_check_trips_merge_impl(left_products, right_products, expected);
+}}
+
+can_check_trips_merge! {
+    case01_same_products: (vec![0], vec![0], true),
+    case02_fit_into_compartments: (vec![0], vec![1], true),
+    case03_too_many_products: (vec![0, 1], vec![2], false),
+}
+
+fn can_check_trips_merge_impl(left_products: Vec<usize>, right_products: Vec<usize>, expected: bool) {
+    let fleet = create_fleet(create_compartments(vec![(10, 0.), (5, 0.)]));
+    let (left_size, right_size) = (left_products.len(), right_products.len());
+    let activities = left_products
+        .into_iter()
+        .map(|product| create_product_activity(10, product, 2))
+        .chain(once(create_marker_activity(20)))
+        .chain(right_products.into_iter().map(|product| create_product_activity(30, product, 2)))
+        .collect();
+    let route_ctx = create_route_ctx(&fleet, activities);
+
+    let result =
+        can_merge_trips(route_ctx.route(), &create_accessor(), 0..left_size, left_size + 1..left_size + 1 + right_size);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,164 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::{Fleet, Single};
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(test_vehicle_with_id("v2"))
+        .build()
+}
+
+fn create_single(id: &str, location: Location, duration: Duration, time: (f64, f64)) -> Arc<Single> {
+    SingleBuilder::default()
+        .id(id)
+        .location(Some(location))
+        .duration(duration)
+        .times(vec![TimeWindow::new(time.0, time.1)])
+        .build_shared()
+}
+
+fn create_activity(single: &Arc<Single>) -> Activity {
+    let place = single.places.first().unwrap();
+    let time = place.times.first().unwrap().to_time_window(0.);
+
+    ActivityBuilder::with_location_tw_and_duration(place.location.unwrap(), time, place.duration)
+        .job(Some(single.clone()))
+        .build()
+}
+
+fn create_feature(slots: usize) -> Feature {
+    create_dock_slots_feature(
+        "dock_slots",
+        VIOLATION_CODE,
+        vec![(0, slots)].into_iter().collect(),
+        Arc::new(|single, _| single.dimens.get_id().filter(|id| id.starts_with("dock")).map(|_| 0)),
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+fn create_solution_ctx(fleet: &Fleet, routes: Vec<(&str, Vec<&Arc<Single>>)>) -> SolutionContext {
+    let routes = routes
+        .into_iter()
+        .map(|(vehicle_id, singles)| {
+            let activities = singles.into_iter().map(create_activity);
+            let mut route_ctx = RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(fleet, vehicle_id).add_activities(activities).build())
+                .build();
+            update_route_schedule(
+                &mut route_ctx,
+                &TestActivityCost::default(),
+                &TestTransportCost::default(),
+                &ScheduleStateKeys::default(),
+            );
+
+            route_ctx
+        })
+        .collect();
+
+    SolutionContext { routes, ..create_empty_solution_context() }
+}
+
+fn get_service_start(solution_ctx: &SolutionContext, single: &Arc<Single>) -> Option<Timestamp> {
+    let job = Job::Single(single.clone());
+    solution_ctx
+        .routes
+        .iter()
+        .find_map(|route_ctx| route_ctx.route().tour.index(&job).and_then(|idx| route_ctx.route().tour.get(idx)))
+        .map(|activity| activity.schedule.arrival.max(activity.place.time.start))
+}
+
+parameterized_test! {can_queue_activities_at_busy_dock, (slots, expected_starts), {
+    can_queue_activities_at_busy_dock_impl(slots, expected_starts);
+}}
+
+can_queue_activities_at_busy_dock! {
+    case01_single_slot: (1, (10., 20.)),
+    case02_two_slots: (2, (10., 10.)),
+}
+
+fn can_queue_activities_at_busy_dock_impl(slots: usize, expected_starts: (Timestamp, Timestamp)) {
+    let fleet = create_fleet();
+    let (first, second) = (create_single("dock1", 10, 10., (0., 100.)), create_single("dock2", 10, 10., (0., 100.)));
+    let feature = create_feature(slots);
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![("v1", vec![&first]), ("v2", vec![&second])]);
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.unassigned.is_empty());
+    assert_eq!(get_service_start(&solution_ctx, &first), Some(expected_starts.0));
+    assert_eq!(get_service_start(&solution_ctx, &second), Some(expected_starts.1));
+}
+
+parameterized_test! {can_evaluate_insertion_at_busy_dock, (job_id, target_end, has_next, expected), {
+    can_evaluate_insertion_at_busy_dock_impl(job_id, target_end, has_next, expected);
+}}
+
+can_evaluate_insertion_at_busy_dock! {
+    case01_can_wait: ("dock2", 100., false, None),
+    case02_cannot_wait: ("dock2", 15., false, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_waiting_delays_next: ("dock2", 100., true, ConstraintViolation::skip(VIOLATION_CODE)),
+    case04_no_dock: ("job2", 15., false, None),
+}
+
+fn can_evaluate_insertion_at_busy_dock_impl(
+    job_id: &str,
+    target_end: Timestamp,
+    has_next: bool,
+    expected: Option<ConstraintViolation>,
+) {
+    let fleet = create_fleet();
+    let (first, second) =
+        (create_single("dock1", 10, 10., (0., 100.)), create_single(job_id, 10, 10., (0., target_end)));
+    let other = create_single("job3", 20, 0., (0., 30.));
+    let feature = create_feature(1);
+    let second_route = if has_next { vec![&other] } else { vec![] };
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![("v1", vec![&first]), ("v2", second_route)]);
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    let route_ctx = &solution_ctx.routes[1];
+    let target = create_activity(&second);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+    let result = feature.constraint.as_ref().unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_remove_activity_without_free_slot_in_time_window() {
+    let fleet = create_fleet();
+    let (first, second) = (create_single("dock1", 10, 10., (0., 100.)), create_single("dock2", 10, 10., (0., 15.)));
+    let feature = create_feature(1);
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![("v1", vec![&first]), ("v2", vec![&second])]);
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.unassigned.len(), 1);
+    assert!(solution_ctx.unassigned.contains_key(&Job::Single(second)));
+    assert!(solution_ctx.unassigned.values().all(|info| matches!(info, UnassignmentInfo::Simple(VIOLATION_CODE))));
+    assert_eq!(get_service_start(&solution_ctx, &first), Some(10.));
+}
+
+#[test]
+fn can_merge_only_jobs_without_docks() {
+    let feature = create_feature(1);
+    let constraint = feature.constraint.as_ref().unwrap();
+    let dock = Job::Single(create_single("dock1", 10, 10., (0., 100.)));
+    let regular = Job::Single(create_single("job1", 10, 10., (0., 100.)));
+
+    assert!(constraint.merge(regular.clone(), regular.clone()).is_ok());
+    assert_eq!(constraint.merge(regular.clone(), dock.clone()).err(), Some(VIOLATION_CODE));
+    assert_eq!(constraint.merge(dock, regular).err(), Some(VIOLATION_CODE));
+}
@@ -0,0 +1,145 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::Single;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_variant(id: &str, index: usize, total: usize) -> Arc<Single> {
+    create_job_with_alternative(
+        format!("{id}_variant_{index}").as_str(),
+        JobAlternative { id: id.to_string(), index, total, parts: 1 },
+    )
+}
+
+fn create_variant_part(id: &str, index: usize, total: usize, part: usize) -> Arc<Single> {
+    create_job_with_alternative(
+        format!("{id}_variant_{index}_part_{part}").as_str(),
+        JobAlternative { id: id.to_string(), index, total, parts: 2 },
+    )
+}
+
+fn create_job_with_alternative(job_id: &str, alternative: JobAlternative) -> Arc<Single> {
+    let mut dimens = Dimensions::default();
+    dimens.set_id(job_id);
+    dimens.set_job_alternative(alternative);
+
+    SingleBuilder::default().dimens(dimens).build_shared()
+}
+
+fn create_solution_ctx(assigned: Vec<&Arc<Single>>, required: Vec<&Arc<Single>>) -> SolutionContext {
+    let fleet = test_fleet();
+    let activities = assigned.into_iter().map(|single| ActivityBuilder::default().job(Some(single.clone())).build());
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build();
+
+    SolutionContext {
+        required: required.into_iter().map(|single| Job::Single(single.clone())).collect(),
+        routes: vec![route_ctx],
+        ..create_empty_solution_context()
+    }
+}
+
+fn get_job_ids(jobs: &[Job]) -> Vec<String> {
+    let mut ids = jobs.iter().map(|job| job.dimens().get_id().unwrap().clone()).collect::<Vec<_>>();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn can_ignore_variants_when_sibling_is_assigned() {
+    let (variant0, variant1, variant2) =
+        (create_variant("job1", 0, 3), create_variant("job1", 1, 3), create_variant("job1", 2, 3));
+    let other = create_variant("job2", 0, 2);
+    let mut solution_ctx = create_solution_ctx(vec![&variant0], vec![&variant1, &variant2, &other]);
+    let feature = create_job_alternatives_feature("job_alternatives", VIOLATION_CODE).unwrap();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(get_job_ids(&solution_ctx.required), vec!["job2_variant_0"]);
+    assert_eq!(get_job_ids(&solution_ctx.ignored), vec!["job1_variant_1", "job1_variant_2"]);
+}
+
+#[test]
+fn can_promote_variants_when_sibling_is_unassigned() {
+    let (variant0, variant1) = (create_variant("job1", 0, 2), create_variant("job1", 1, 2));
+    let mut solution_ctx = create_solution_ctx(vec![], vec![&variant0]);
+    solution_ctx.ignored.push(Job::Single(variant1));
+    let feature = create_job_alternatives_feature("job_alternatives", VIOLATION_CODE).unwrap();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(get_job_ids(&solution_ctx.required), vec!["job1_variant_0", "job1_variant_1"]);
+    assert!(solution_ctx.ignored.is_empty());
+}
+
+parameterized_test! {can_evaluate_variant_insertion, (assigned, job, expected), {
+    can_evaluate_variant_insertion_impl(assigned, job, expected);
+}}
+
+can_evaluate_variant_insertion! {
+    case01_sibling_assigned: (("job1", 0), ("job1", 1), Some(VIOLATION_CODE)),
+    case02_other_assigned: (("job1", 0), ("job2", 1), None),
+    case03_same_variant_assigned: (("job1", 1), ("job1", 1), None),
+}
+
+fn can_evaluate_variant_insertion_impl(assigned: (&str, usize), job: (&str, usize), expected: Option<ViolationCode>) {
+    let assigned = create_variant(assigned.0, assigned.1, 2);
+    let job = Job::Single(create_variant(job.0, job.1, 2));
+    let mut solution_ctx = create_solution_ctx(vec![&assigned], vec![]);
+    let feature = create_job_alternatives_feature("job_alternatives", VIOLATION_CODE).unwrap();
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.first().unwrap();
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, route_ctx, &job));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+#[test]
+fn can_reject_merge_of_variants() {
+    let feature = create_job_alternatives_feature("job_alternatives", VIOLATION_CODE).unwrap();
+    let constraint = feature.constraint.unwrap();
+
+    let result =
+        constraint.merge(Job::Single(create_variant("job1", 0, 2)), SingleBuilder::default().build_as_job_ref());
+
+    assert_eq!(result.map(|_| ()), Err(VIOLATION_CODE));
+}
+
+#[test]
+fn can_ignore_unassigned_variants_when_sibling_is_assigned() {
+    let (variant0, variant1) = (create_variant("job1", 0, 2), create_variant("job1", 1, 2));
+    let mut solution_ctx = create_solution_ctx(vec![&variant0], vec![]);
+    solution_ctx.unassigned.insert(Job::Single(variant1), UnassignmentInfo::Unknown);
+    let feature = create_job_alternatives_feature("job_alternatives", VIOLATION_CODE).unwrap();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.unassigned.is_empty());
+    assert_eq!(get_job_ids(&solution_ctx.ignored), vec!["job1_variant_1"]);
+}
+
+parameterized_test! {can_remove_partially_served_variants, (assigned_parts, expected_unassigned), {
+    can_remove_partially_served_variants_impl(assigned_parts, expected_unassigned);
+}}
+
+can_remove_partially_served_variants! {
+    case01_all_parts_assigned: (2, vec![]),
+    case02_one_part_assigned: (1, vec!["job1_variant_1_part_0"]),
+}
+
+fn can_remove_partially_served_variants_impl(assigned_parts: usize, expected_unassigned: Vec<&str>) {
+    let parts = [create_variant_part("job1", 1, 2, 0), create_variant_part("job1", 1, 2, 1)];
+    let mut solution_ctx = create_solution_ctx(parts.iter().take(assigned_parts).collect(), vec![]);
+    let feature = create_job_alternatives_feature("job_alternatives", VIOLATION_CODE).unwrap();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    let unassigned = solution_ctx.unassigned.keys().cloned().collect::<Vec<_>>();
+    let expected_assigned = if expected_unassigned.is_empty() { assigned_parts } else { 0 };
+    assert_eq!(get_job_ids(&unassigned), expected_unassigned);
+    assert_eq!(solution_ctx.routes[0].route().tour.job_count(), expected_assigned);
+}
@@ -0,0 +1,129 @@
+use super::*;
+use crate::construction::enablers::{update_route_schedule, ScheduleStateKeys};
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_route_ctx(fleet: &Fleet, duration: f64) -> RouteContext {
+    let mut route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(fleet, "v1").build()).build();
+    route_ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, duration);
+
+    route_ctx
+}
+
+fn create_solution_ctx(fleet: &Fleet, durations: &[f64]) -> SolutionContext {
+    SolutionContext {
+        routes: durations.iter().map(|duration| create_route_ctx(fleet, *duration)).collect(),
+        ..create_empty_solution_context()
+    }
+}
+
+#[test]
+fn can_calculate_makespan_fitness() {
+    let fleet = test_fleet();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution = create_solution_ctx(&fleet, &[10., 30., 20.]);
+    let feature = create_feature();
+
+    assert_eq!(feature.objective.as_ref().unwrap().fitness(&insertion_ctx), 30.);
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+
+    assert_eq!(insertion_ctx.solution.state.get(&MAKESPAN_KEY).and_then(|s| s.downcast_ref::<f64>()), Some(&30.));
+}
+
+#[test]
+fn can_keep_routes_unchanged_when_makespan_is_the_same() {
+    let fleet = test_fleet();
+    let mut solution_ctx = create_solution_ctx(&fleet, &[10., 30., 20.]);
+    let feature = create_feature();
+    let state = feature.state.as_ref().unwrap();
+    state.accept_solution_state(&mut solution_ctx);
+    solution_ctx.routes.iter_mut().for_each(|route_ctx| route_ctx.mark_stale(false));
+
+    state.accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.routes.iter().all(|route_ctx| !route_ctx.is_stale()));
+    assert!(solution_ctx
+        .routes
+        .iter()
+        .all(|route_ctx| route_ctx.state().get_route_state::<f64>(MAKESPAN_KEY) == Some(&30.)));
+}
+
+fn create_feature() -> Feature {
+    create_minimize_makespan_feature("makespan", TestTransportCost::new_shared(), TestActivityCost::new_shared())
+        .unwrap()
+}
+
+#[test]
+fn can_skip_route_level_estimate() {
+    let fleet = test_fleet();
+    let solution_ctx = create_solution_ctx(&fleet, &[10.]);
+    let route_ctx = create_route_ctx(&fleet, 30.);
+    let job = SingleBuilder::default().duration(5.).build_as_job_ref();
+
+    let estimate = create_feature().objective.unwrap().estimate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+    assert_eq!(estimate, 0.);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (other_duration, job_start, target_location, target_duration, expected), {
+    can_estimate_activity_insertion_impl(other_duration, job_start, target_location, target_duration, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case01_no_detour: (0., 0., 5, 0., 0.),
+    case02_detour_exceeds_makespan: (0., 0., 15, 0., 10.),
+    case03_detour_below_makespan: (50., 0., 15, 0., 0.),
+    case04_detour_partially_exceeds_makespan: (25., 0., 15, 0., 5.),
+    case05_service_duration: (0., 0., 5, 5., 5.),
+    case06_waiting_absorbs_delay: (0., 30., 15, 0., 0.),
+    case07_waiting_absorbs_delay_partially: (0., 30., 15, 15., 5.),
+}
+
+fn can_estimate_activity_insertion_impl(
+    other_duration: f64,
+    job_start: f64,
+    target_location: Location,
+    target_duration: f64,
+    expected: Cost,
+) {
+    let fleet = test_fleet();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(
+                    ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::new(job_start, 1000.), 0.).build(),
+                )
+                .build(),
+        )
+        .build();
+    update_route_schedule(
+        &mut route_ctx,
+        &TestActivityCost::default(),
+        &TestTransportCost::default(),
+        &ScheduleStateKeys::default(),
+    );
+    let mut solution_ctx = SolutionContext {
+        routes: vec![route_ctx, create_route_ctx(&fleet, other_duration)],
+        ..create_empty_solution_context()
+    };
+    let feature = create_feature();
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = &solution_ctx.routes[0];
+    let target =
+        ActivityBuilder::with_location_tw_and_duration(target_location, TimeWindow::new(0., 1000.), target_duration)
+            .build();
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let estimate = feature.objective.unwrap().estimate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(estimate, expected);
+}
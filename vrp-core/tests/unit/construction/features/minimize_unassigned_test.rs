@@ -1,5 +1,6 @@
 use super::*;
 use crate::helpers::models::domain::{create_empty_insertion_context, create_simple_insertion_ctx};
+use crate::helpers::models::problem::SingleBuilder;
 use std::cmp::Ordering;
 
 #[test]
@@ -13,3 +14,25 @@ fn can_properly_estimate_empty_solution() {
 
     assert_eq!(result, Ordering::Greater);
 }
+
+#[test]
+fn can_count_unassigned_job_alternatives_once() {
+    let create_variant = |id: &str, index: usize| {
+        let mut dimens = Dimensions::default();
+        dimens.set_id(format!("{id}_{index}").as_str());
+        dimens.set_job_alternative(JobAlternative { id: id.to_string(), index, total: 2, parts: 1 });
+        SingleBuilder::default().dimens(dimens).build_as_job_ref()
+    };
+    let mut insertion_ctx = create_empty_insertion_context();
+    vec![create_variant("job1", 0), create_variant("job1", 1), SingleBuilder::default().id("job2").build_as_job_ref()]
+        .into_iter()
+        .for_each(|job| {
+            insertion_ctx.solution.unassigned.insert(job, UnassignmentInfo::Unknown);
+        });
+    let objective =
+        create_minimize_unassigned_jobs_feature("minimize_unassigned", Arc::new(|_, _| 1.)).unwrap().objective.unwrap();
+
+    let fitness = objective.fitness(&insertion_ctx);
+
+    assert_eq!(fitness, 2.);
+}
@@ -0,0 +1,94 @@
+use super::*;
+use crate::helpers::models::domain::{create_empty_solution_context, create_registry_context};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::Single;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_parts(id: &str, total: usize) -> Vec<Arc<Single>> {
+    SingleBuilder::default().id(id).build().split(id, total, |_, _| {})
+}
+
+fn create_solution_ctx(assigned: Vec<&Arc<Single>>, required: Vec<&Arc<Single>>) -> SolutionContext {
+    let fleet = test_fleet();
+    let activities = assigned.into_iter().map(|single| ActivityBuilder::default().job(Some(single.clone())).build());
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build();
+
+    let mut registry = create_registry_context(&fleet);
+    registry.get_route(&route_ctx.route().actor).expect("route should be available");
+
+    SolutionContext {
+        required: required.into_iter().map(|single| Job::Single(single.clone())).collect(),
+        routes: vec![route_ctx],
+        registry,
+        ..create_empty_solution_context()
+    }
+}
+
+parameterized_test! {can_remove_parts_of_incomplete_job, (assigned, required, expected_assigned), {
+    can_remove_parts_of_incomplete_job_impl(assigned, required, expected_assigned);
+}}
+
+can_remove_parts_of_incomplete_job! {
+    case01_all_assigned: (vec![0, 1, 2], vec![], 3),
+    case02_one_missing: (vec![0, 1], vec![], 0),
+    case03_one_required: (vec![0, 1], vec![2], 2),
+    case04_none_assigned: (vec![], vec![], 0),
+}
+
+fn can_remove_parts_of_incomplete_job_impl(assigned: Vec<usize>, required: Vec<usize>, expected_assigned: usize) {
+    let parts = create_parts("job1", 3);
+    let mut solution_ctx = create_solution_ctx(
+        assigned.iter().map(|&idx| &parts[idx]).collect(),
+        required.iter().map(|&idx| &parts[idx]).collect(),
+    );
+    let feature = create_partial_jobs_feature("partial_jobs", VIOLATION_CODE).unwrap();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(
+        solution_ctx.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(),
+        expected_assigned
+    );
+    assert_eq!(solution_ctx.unassigned.len(), assigned.len() - expected_assigned);
+    assert!(solution_ctx.unassigned.values().all(|info| matches!(info, UnassignmentInfo::Simple(VIOLATION_CODE))));
+}
+
+#[test]
+fn can_keep_parts_of_other_complete_job() {
+    let (first, second) = (create_parts("job1", 2), create_parts("job2", 2));
+    let mut solution_ctx = create_solution_ctx(vec![&first[0], &second[0], &second[1]], vec![]);
+    let feature = create_partial_jobs_feature("partial_jobs", VIOLATION_CODE).unwrap();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[0].route().tour.job_count(), 2);
+    assert!(solution_ctx.unassigned.contains_key(&Job::Single(first[0].clone())));
+}
+
+#[test]
+fn can_keep_parts_of_incomplete_job_when_one_is_locked() {
+    let parts = create_parts("job1", 3);
+    let mut solution_ctx = create_solution_ctx(vec![&parts[0], &parts[1]], vec![]);
+    solution_ctx.locked.insert(Job::Single(parts[0].clone()));
+    let feature = create_partial_jobs_feature("partial_jobs", VIOLATION_CODE).unwrap();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[0].route().tour.job_count(), 2);
+    assert!(solution_ctx.unassigned.is_empty());
+}
+
+#[test]
+fn can_reject_merge_of_partial_jobs() {
+    let parts = create_parts("job1", 2);
+    let other = Job::Single(SingleBuilder::default().id("job2").build_shared());
+    let constraint = create_partial_jobs_feature("partial_jobs", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    assert_eq!(constraint.merge(other.clone(), Job::Single(parts[0].clone())).map(|_| ()), Err(VIOLATION_CODE));
+    assert_eq!(constraint.merge(Job::Single(parts[1].clone()), other.clone()).map(|_| ()), Err(VIOLATION_CODE));
+    assert!(constraint.merge(other.clone(), other).is_ok());
+}
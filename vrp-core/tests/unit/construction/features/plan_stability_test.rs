@@ -0,0 +1,112 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::IdDimension;
+
+const STATE_KEY: StateKey = StateKey(1);
+
+fn create_activity(id: &str) -> Activity {
+    let mut single = SingleBuilder::default().build();
+    single.dimens.set_id(id);
+
+    Activity { job: Some(Arc::new(single)), ..ActivityBuilder::default().build() }
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle_id: &str, job_ids: &[&str]) -> RouteContext {
+    RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(fleet, vehicle_id)
+                .add_activities(job_ids.iter().map(|id| create_activity(id)))
+                .build(),
+        )
+        .build()
+}
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build()
+}
+
+fn get_position_fn() -> ReferencePositionFn {
+    let reference = [("job1", "v1", 0), ("job2", "v1", 1), ("job3", "v1", 2), ("job4", "v2", 0)];
+
+    Arc::new(move |actor, job| {
+        let job_id = job.dimens().get_id()?;
+        let vehicle_id = actor.vehicle.dimens.get_id()?;
+
+        reference.iter().find(|(id, ..)| id == job_id).map(|(_, reference_id, order)| {
+            if reference_id == vehicle_id {
+                ReferencePosition::SameActor(*order)
+            } else {
+                ReferencePosition::OtherActor
+            }
+        })
+    })
+}
+
+fn create_feature(weights: PlanChangeWeights) -> Feature {
+    create_plan_stability_feature("plan_stability", STATE_KEY, weights, get_position_fn()).unwrap()
+}
+
+#[test]
+fn can_get_plan_changes() {
+    let fleet = create_fleet();
+    let routes = vec![
+        create_route_ctx(&fleet, "v1", &["job3", "job1", "job4", "job2", "job5"]),
+        create_route_ctx(&fleet, "v2", &["job2"]),
+    ];
+    let weights = PlanChangeWeights { reassignment: 10., sequence: 1. };
+
+    let changes = get_plan_changes(routes.as_slice(), &weights, &get_position_fn());
+
+    assert_eq!(changes, 21.);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (route_jobs, index, target, expected), {
+    can_estimate_activity_insertion_impl(route_jobs, index, target, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case01_keep_order: (&["job1", "job3"], 1, "job2", 0.),
+    case02_insert_before: (&["job1", "job3"], 0, "job2", 1.),
+    case03_insert_after: (&["job1", "job3"], 2, "job2", 1.),
+    case04_after_changed_order: (&["job3", "job1"], 2, "job2", 0.),
+    case05_reassignment: (&["job1", "job3"], 1, "job4", 10.),
+    case06_unknown_job: (&["job3", "job1"], 1, "job5", 0.),
+    case07_empty_route: (&[], 0, "job2", 0.),
+}
+
+fn can_estimate_activity_insertion_impl(route_jobs: &[&str], index: usize, target: &str, expected: Cost) {
+    let fleet = create_fleet();
+    let route_ctx = create_route_ctx(&fleet, "v1", route_jobs);
+    let target = create_activity(target);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+    let objective = create_feature(PlanChangeWeights { reassignment: 10., sequence: 1. }).objective.unwrap();
+
+    let estimate = objective.estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(estimate, expected);
+}
+
+#[test]
+fn can_cache_plan_changes_in_solution_state() {
+    let fleet = create_fleet();
+    let mut solution_ctx = SolutionContext {
+        routes: vec![create_route_ctx(&fleet, "v1", &["job2", "job1"])],
+        ..create_empty_solution_context()
+    };
+    let state = create_feature(PlanChangeWeights::default()).state.unwrap();
+
+    state.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.state.get(&STATE_KEY).and_then(|value| value.downcast_ref::<Cost>()), Some(&1.));
+}
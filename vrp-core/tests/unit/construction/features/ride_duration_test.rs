@@ -0,0 +1,120 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::Single;
+use crate::models::solution::Activity;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_multi_with_limit(locations: Vec<Location>, limit: RideDurationLimit) -> Arc<Multi> {
+    let mut dimens = Dimensions::default();
+    dimens.set_value("ride_limit", limit);
+
+    let singles =
+        locations.into_iter().map(|location| SingleBuilder::default().location(Some(location)).build_shared());
+
+    Multi::new_shared(singles.collect(), dimens)
+}
+
+fn create_activity(single: &Arc<Single>) -> Activity {
+    let location = single.places.first().and_then(|place| place.location).unwrap();
+    ActivityBuilder::with_location(location).job(Some(single.clone())).build()
+}
+
+fn create_feature() -> Feature {
+    create_max_ride_duration_feature(
+        "ride_duration",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        Arc::new(|multi| multi.dimens.get_value("ride_limit")),
+    )
+    .unwrap()
+}
+
+fn create_route_ctx(feature: &Feature, activities: Vec<Activity>) -> RouteContext {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&test_fleet(), "v1").add_activities(activities).build())
+        .build();
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn evaluate_target(
+    feature: &Feature,
+    route_ctx: &RouteContext,
+    index: usize,
+    target: &Activity,
+) -> Option<ConstraintViolation> {
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    feature.constraint.as_ref().unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx))
+}
+
+parameterized_test! {can_limit_ride_duration_when_inserting_inside_ride, (target_location, max_duration, expected), {
+    can_limit_ride_duration_when_inserting_inside_ride_impl(target_location, max_duration, expected);
+}}
+
+can_limit_ride_duration_when_inserting_inside_ride! {
+    case01_no_delay: (15, 10., None),
+    case02_delay_within_limit: (25, 20., None),
+    case03_delay_exceeds_limit: (25, 15., ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_limit_ride_duration_when_inserting_inside_ride_impl(
+    target_location: Location,
+    max_duration: Duration,
+    expected: Option<ConstraintViolation>,
+) {
+    let multi =
+        create_multi_with_limit(vec![10, 20], RideDurationLimit { max_duration: Some(max_duration), max_factor: None });
+    let feature = create_feature();
+    let route_ctx = create_route_ctx(&feature, multi.jobs.iter().map(create_activity).collect());
+    let target = ActivityBuilder::with_location(target_location).build();
+
+    let result = evaluate_target(&feature, &route_ctx, 1, &target);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_limit_ride_duration_when_inserting_last_activity, (limit, expected), {
+    can_limit_ride_duration_when_inserting_last_activity_impl(limit, expected);
+}}
+
+can_limit_ride_duration_when_inserting_last_activity! {
+    case01_max_duration_ok: (RideDurationLimit { max_duration: Some(20.), max_factor: None }, None),
+    case02_max_duration_fail: (RideDurationLimit { max_duration: Some(15.), max_factor: None }, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_factor_ok: (RideDurationLimit { max_duration: None, max_factor: Some(2.) }, None),
+    case04_factor_fail: (RideDurationLimit { max_duration: None, max_factor: Some(0.5) }, ConstraintViolation::skip(VIOLATION_CODE)),
+    case05_both_fail: (RideDurationLimit { max_duration: Some(15.), max_factor: Some(2.) }, ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_limit_ride_duration_when_inserting_last_activity_impl(
+    limit: RideDurationLimit,
+    expected: Option<ConstraintViolation>,
+) {
+    let multi = create_multi_with_limit(vec![10, 30], limit);
+    let feature = create_feature();
+    let route_ctx = create_route_ctx(&feature, vec![create_activity(&multi.jobs[0])]);
+    let target = create_activity(&multi.jobs[1]);
+
+    let result = evaluate_target(&feature, &route_ctx, 1, &target);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_get_ride_duration_of_inserted_job() {
+    let multi = create_multi_with_limit(vec![10, 30], RideDurationLimit::default());
+    let route = RouteBuilder::default()
+        .with_vehicle(&test_fleet(), "v1")
+        .add_activities(multi.jobs.iter().map(create_activity))
+        .build();
+
+    assert_eq!(get_ride_duration(&route, &multi), Some(20.));
+}
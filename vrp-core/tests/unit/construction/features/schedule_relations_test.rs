@@ -0,0 +1,221 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::{Fleet, Multi, Single};
+use crate::models::solution::Activity;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(test_vehicle_with_id("v2"))
+        .build()
+}
+
+fn create_single(id: &str, location: Location, duration: Duration, time: (f64, f64)) -> Arc<Single> {
+    SingleBuilder::default()
+        .id(id)
+        .location(Some(location))
+        .duration(duration)
+        .times(vec![TimeWindow::new(time.0, time.1)])
+        .build_shared()
+}
+
+fn create_activity(single: &Arc<Single>) -> Activity {
+    let place = single.places.first().unwrap();
+    let time = place.times.first().unwrap().to_time_window(0.);
+
+    ActivityBuilder::with_location_tw_and_duration(place.location.unwrap(), time, place.duration)
+        .job(Some(single.clone()))
+        .build()
+}
+
+fn create_feature(relation_type: ScheduleRelationType, singles: &[Arc<Single>]) -> Feature {
+    create_schedule_relation_feature(
+        "schedule_relations",
+        VIOLATION_CODE,
+        vec![ScheduleRelation { relation_type, jobs: singles.iter().cloned().map(Job::Single).collect() }],
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+fn create_solution_ctx(fleet: &Fleet, routes: Vec<(&str, Vec<&Arc<Single>>)>) -> SolutionContext {
+    let routes = routes
+        .into_iter()
+        .map(|(vehicle_id, singles)| {
+            let activities = singles.into_iter().map(create_activity);
+            let mut route_ctx = RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(fleet, vehicle_id).add_activities(activities).build())
+                .build();
+            update_route_schedule(
+                &mut route_ctx,
+                &TestActivityCost::default(),
+                &TestTransportCost::default(),
+                &ScheduleStateKeys::default(),
+            );
+
+            route_ctx
+        })
+        .collect();
+
+    SolutionContext { routes, ..create_empty_solution_context() }
+}
+
+fn get_service_start(solution_ctx: &SolutionContext, single: &Arc<Single>) -> Option<Timestamp> {
+    let job = Job::Single(single.clone());
+    solution_ctx
+        .routes
+        .iter()
+        .find_map(|route_ctx| route_ctx.route().tour.index(&job).and_then(|idx| route_ctx.route().tour.get(idx)))
+        .map(|activity| activity.schedule.arrival.max(activity.place.time.start))
+}
+
+fn evaluate_target(
+    feature: &Feature,
+    route_ctx: &RouteContext,
+    index: usize,
+    target: &Activity,
+) -> Option<ConstraintViolation> {
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    feature.constraint.as_ref().unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx))
+}
+
+parameterized_test! {can_synchronize_service_start_across_routes, (tolerance, expected_starts), {
+    can_synchronize_service_start_across_routes_impl(tolerance, expected_starts);
+}}
+
+can_synchronize_service_start_across_routes! {
+    case01_no_tolerance: (0., (30., 30.)),
+    case02_with_tolerance: (5., (25., 30.)),
+    case03_big_tolerance: (50., (10., 30.)),
+}
+
+fn can_synchronize_service_start_across_routes_impl(tolerance: Duration, expected_starts: (Timestamp, Timestamp)) {
+    let fleet = create_fleet();
+    let (first, second) = (create_single("job1", 10, 0., (0., 100.)), create_single("job2", 30, 0., (0., 100.)));
+    let feature = create_feature(ScheduleRelationType::Sync { tolerance }, &[first.clone(), second.clone()]);
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![("v1", vec![&first]), ("v2", vec![&second])]);
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.unassigned.is_empty());
+    assert_eq!(get_service_start(&solution_ctx, &first), Some(expected_starts.0));
+    assert_eq!(get_service_start(&solution_ctx, &second), Some(expected_starts.1));
+}
+
+parameterized_test! {can_evaluate_precedence_insertion, (offset, target_end, has_next, expected), {
+    can_evaluate_precedence_insertion_impl(offset, target_end, has_next, expected);
+}}
+
+can_evaluate_precedence_insertion! {
+    case01_can_wait: (5., 100., false, None),
+    case02_cannot_wait: (5., 30., false, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_waiting_delays_next: (5., 100., true, ConstraintViolation::skip(VIOLATION_CODE)),
+    case04_waiting_does_not_delay_next: (0., 100., true, None),
+}
+
+fn can_evaluate_precedence_insertion_impl(
+    offset: Duration,
+    target_end: Timestamp,
+    has_next: bool,
+    expected: Option<ConstraintViolation>,
+) {
+    let fleet = create_fleet();
+    let (first, second) = (create_single("job1", 20, 10., (0., 100.)), create_single("job2", 10, 0., (0., target_end)));
+    let other = create_single("job3", 20, 0., (0., 40.));
+    let feature = create_feature(ScheduleRelationType::Precedence { offset }, &[first.clone(), second.clone()]);
+    let second_route = if has_next { vec![&other] } else { vec![] };
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![("v1", vec![&first]), ("v2", second_route)]);
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    let result = evaluate_target(&feature, &solution_ctx.routes[1], 0, &create_activity(&second));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reject_precedence_insertion_in_wrong_order() {
+    let fleet = create_fleet();
+    let (first, second) = (create_single("job1", 20, 0., (0., 100.)), create_single("job2", 10, 0., (0., 100.)));
+    let feature = create_feature(ScheduleRelationType::Precedence { offset: 0. }, &[first.clone(), second.clone()]);
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![("v1", vec![&second])]);
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let target = create_activity(&first);
+
+    assert_eq!(
+        evaluate_target(&feature, &solution_ctx.routes[0], 0, &target),
+        ConstraintViolation::skip(VIOLATION_CODE)
+    );
+    assert_eq!(
+        evaluate_target(&feature, &solution_ctx.routes[0], 1, &target),
+        ConstraintViolation::skip(VIOLATION_CODE)
+    );
+}
+
+#[test]
+fn can_reject_sync_partner_in_the_same_route() {
+    let fleet = create_fleet();
+    let (first, second) = (create_single("job1", 10, 0., (0., 100.)), create_single("job2", 20, 0., (0., 100.)));
+    let feature = create_feature(ScheduleRelationType::Sync { tolerance: 0. }, &[first.clone(), second.clone()]);
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![("v1", vec![&first])]);
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let job = Job::Single(second);
+
+    let result = feature.constraint.as_ref().unwrap().evaluate(&MoveContext::route(
+        &solution_ctx,
+        &solution_ctx.routes[0],
+        &job,
+    ));
+
+    assert_eq!(result, ConstraintViolation::fail(VIOLATION_CODE));
+}
+
+#[test]
+fn can_remove_jobs_of_violated_relation() {
+    let fleet = create_fleet();
+    let (first, second) = (create_single("job1", 10, 0., (0., 10.)), create_single("job2", 50, 0., (0., 100.)));
+    let feature = create_feature(ScheduleRelationType::Sync { tolerance: 5. }, &[first.clone(), second.clone()]);
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![("v1", vec![&first]), ("v2", vec![&second])]);
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.unassigned.len(), 2);
+    assert!(solution_ctx.unassigned.values().all(|info| matches!(info, UnassignmentInfo::Simple(VIOLATION_CODE))));
+    assert!(solution_ctx.routes.iter().all(|route_ctx| !route_ctx.route().tour.has_jobs()));
+}
+
+#[test]
+fn can_remove_multi_jobs_of_violated_relation() {
+    let fleet = create_fleet();
+    let create_multi = |id: &str, first: Arc<Single>, second: Arc<Single>| {
+        let mut dimens = Dimensions::default();
+        dimens.set_id(id);
+        Multi::new_shared(vec![first, second], dimens)
+    };
+    let first = create_multi("job1", create_single("p1", 10, 0., (0., 100.)), create_single("d1", 20, 0., (0., 100.)));
+    let second = create_multi("job2", create_single("p2", 5, 0., (0., 10.)), create_single("d2", 30, 0., (0., 100.)));
+    let feature = create_feature(
+        ScheduleRelationType::Precedence { offset: 0. },
+        &[first.jobs[1].clone(), second.jobs[0].clone()],
+    );
+    let mut solution_ctx =
+        create_solution_ctx(&fleet, vec![("v1", first.jobs.iter().collect()), ("v2", second.jobs.iter().collect())]);
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.unassigned.len(), 2);
+    assert!(solution_ctx.unassigned.contains_key(&Job::Multi(first)));
+    assert!(solution_ctx.unassigned.contains_key(&Job::Multi(second)));
+    assert!(solution_ctx.routes.iter().all(|route_ctx| !route_ctx.route().tour.has_jobs()));
+}
@@ -29,8 +29,15 @@ fn create_resource_activity(capacity: i32, resource_id: Option<SharedResourceId>
     Activity { job: Some(Arc::new(single)), ..ActivityBuilder::default().build() }
 }
 
+fn create_supply_activity(supply: i32, resource_id: SharedResourceId) -> Activity {
+    let mut single = SingleBuilder::default().build();
+    single.dimens.set_value("supply", (supply, resource_id));
+
+    Activity { job: Some(Arc::new(single)), ..ActivityBuilder::default().build() }
+}
+
 fn create_feature(total_jobs: usize) -> Feature {
-    create_shared_resource_feature::<SingleDimLoad>(
+    create_shared_resource_feature_with_supply::<SingleDimLoad>(
         "shared_resource",
         total_jobs,
         VIOLATION_CODE,
@@ -42,6 +49,12 @@ fn create_feature(total_jobs: usize) -> Feature {
             })
         }),
         create_resource_demand_fn(),
+        Arc::new(|single| {
+            single
+                .dimens
+                .get_value::<(i32, SharedResourceId)>("supply")
+                .map(|&(supply, resource_id)| (SingleDimLoad::new(supply), resource_id))
+        }),
     )
     .unwrap()
 }
@@ -62,6 +75,7 @@ fn create_route_ctx(
         }
         NormalResource(capacity) => create_resource_activity(*capacity, None),
         Usage(demand) => create_usage_activity(*demand),
+        Supply(resource_id, supply) => create_supply_activity(*supply, *resource_id),
     });
     let mut route_ctx = RouteContextBuilder::default()
         .with_route(RouteBuilder::default().with_vehicle(fleet, vehicle_id).add_activities(activities).build())
@@ -112,6 +126,7 @@ enum ActivityType {
     Usage(i32),
     NormalResource(i32),
     SharedResource(usize),
+    Supply(usize, i32),
 }
 
 parameterized_test! {can_update_resource_consumption, (resources, activities, total_jobs, expected_resources), {
@@ -142,6 +157,18 @@ can_update_resource_consumption! {
         Some(100),
         vec![vec![None, None, None, None, None], vec![None, None, None, None, None]],
     ),
+
+    case_05_supplied_resource: (vec![(0, 0)],
+        vec![vec![Usage(2), SharedResource(0), Usage(2)], vec![Supply(0, 5), Usage(2)]],
+        None,
+        vec![vec![None, None, Some(3), None, None], vec![None, None, None, None]],
+    ),
+
+    case_06_supplied_resource_with_capacity: (vec![(0, 2)],
+        vec![vec![Usage(2), SharedResource(0), Usage(2)], vec![Supply(0, 5), Supply(0, 1)]],
+        None,
+        vec![vec![None, None, Some(6), None, None], vec![None, None, None, None]],
+    ),
 }
 
 fn can_update_resource_consumption_impl(
@@ -280,3 +307,29 @@ fn can_constraint_activity_impl(
 
     assert_eq!(result.map(|result| result.code), expected)
 }
+
+parameterized_test! {can_remove_exceeded_consumption, (supply, expected_unassigned), {
+    can_remove_exceeded_consumption_impl(supply, expected_unassigned);
+}}
+
+can_remove_exceeded_consumption! {
+    case_01_enough_supply: (5, 0),
+    case_02_one_job_exceeds: (4, 1),
+    case_03_no_supply: (0, 2),
+}
+
+fn can_remove_exceeded_consumption_impl(supply: i32, expected_unassigned: usize) {
+    let activities = vec![vec![SharedResource(0), Usage(2), Usage(3)], vec![Supply(0, supply)]];
+    let total_jobs = activities[0].len() + activities[1].len();
+    let mut solution_ctx = create_solution_ctx(vec![(0, 0)], activities, false);
+    let state = create_feature(total_jobs).state.unwrap();
+
+    state.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.unassigned.len(), expected_unassigned);
+    assert_eq!(solution_ctx.routes[0].route().tour.job_count(), 3 - expected_unassigned);
+    assert!(solution_ctx
+        .unassigned
+        .values()
+        .all(|info| matches!(info, UnassignmentInfo::Simple(code) if *code == VIOLATION_CODE)));
+}
@@ -0,0 +1,122 @@
+use super::*;
+use crate::construction::enablers::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Dimensions, Location, ValueDimension};
+
+const VIOLATION_CODE: ViolationCode = 1;
+const RELOAD_LOCATION: Location = 30;
+
+fn create_route_intervals() -> FixedRouteIntervals {
+    FixedRouteIntervals {
+        is_marker_single_fn: Box::new(|single| single.places.iter().any(|p| p.location == Some(RELOAD_LOCATION))),
+        is_new_interval_needed_fn: Box::new(|_| false),
+        is_obsolete_interval_fn: Box::new(|_, _, _| false),
+        is_assignable_fn: Box::new(|_, _| true),
+        intervals_key: RELOAD_INTERVALS_KEY,
+    }
+}
+
+fn create_activity(location: Location, limit: Option<Duration>) -> Activity {
+    let mut dimens = Dimensions::default();
+    if let Some(limit) = limit {
+        dimens.set_value("tiv_limit", limit);
+    }
+
+    ActivityBuilder::with_location(location)
+        .job(Some(SingleBuilder::default().location(Some(location)).dimens(dimens).build_shared()))
+        .build()
+}
+
+fn create_feature(route_intervals: FixedRouteIntervals) -> Feature {
+    create_max_time_in_vehicle_feature(
+        "time_in_vehicle",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        Arc::new(route_intervals),
+        Arc::new(|single| single.dimens.get_value::<Duration>("tiv_limit").copied()),
+    )
+    .unwrap()
+}
+
+fn create_route_ctx(
+    feature: &Feature,
+    route_intervals: &FixedRouteIntervals,
+    limits: [Option<Duration>; 3],
+) -> RouteContext {
+    let activities = vec![
+        create_activity(10, limits[0]),
+        create_activity(20, limits[1]),
+        create_activity(RELOAD_LOCATION, None),
+        create_activity(40, limits[2]),
+    ];
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&test_fleet(), "v1").add_activities(activities).build())
+        .build();
+    update_route_schedule(
+        &mut route_ctx,
+        &TestActivityCost::default(),
+        &TestTransportCost::default(),
+        &ScheduleStateKeys::default(),
+    );
+
+    let intervals = get_route_intervals(route_ctx.route(), |activity| {
+        activity.job.as_ref().map_or(false, |job| route_intervals.is_marker_job(&Job::Single(job.clone())))
+    });
+    route_ctx.state_mut().put_route_state(RELOAD_INTERVALS_KEY, intervals);
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_limit_time_in_vehicle, (limits, index, target, expected), {
+    can_limit_time_in_vehicle_impl(limits, index, target, expected);
+}}
+
+can_limit_time_in_vehicle! {
+    case01_no_delay: ([None, Some(20.), None], 1, (15, None), None),
+    case02_delay_within_slack: ([None, Some(30.), None], 1, (5, None), None),
+    case03_delay_exceeds_slack: ([None, Some(25.), None], 1, (5, None), ConstraintViolation::skip(VIOLATION_CODE)),
+    case04_target_within_limit: ([None, None, None], 2, (25, Some(25.)), None),
+    case05_target_exceeds_limit: ([None, None, None], 2, (25, Some(20.)), ConstraintViolation::skip(VIOLATION_CODE)),
+    case06_second_trip_target_within_limit: ([None, None, None], 3, (45, Some(15.)), None),
+    case07_second_trip_target_exceeds_limit: ([None, None, None], 3, (45, Some(10.)), ConstraintViolation::skip(VIOLATION_CODE)),
+    case08_second_trip_delay_exceeds_slack: ([Some(10.), Some(20.), Some(15.)], 3, (25, None), ConstraintViolation::skip(VIOLATION_CODE)),
+    case09_second_trip_ignores_first_trip: ([Some(10.), Some(20.), Some(20.)], 3, (25, None), None),
+    case10_marker_target: ([Some(10.), Some(20.), Some(10.)], 1, (RELOAD_LOCATION, None), None),
+}
+
+fn can_limit_time_in_vehicle_impl(
+    limits: [Option<Duration>; 3],
+    index: usize,
+    target: (Location, Option<Duration>),
+    expected: Option<ConstraintViolation>,
+) {
+    let route_intervals = create_route_intervals();
+    let feature = create_feature(create_route_intervals());
+    let route_ctx = create_route_ctx(&feature, &route_intervals, limits);
+    let target = create_activity(target.0, target.1);
+
+    let result = feature.constraint.as_ref().unwrap().evaluate(&MoveContext::activity(
+        &route_ctx,
+        &ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(index).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(index + 1),
+        },
+    ));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_get_time_in_vehicle_from_trip_start() {
+    let route_intervals = create_route_intervals();
+    let feature = create_feature(create_route_intervals());
+    let route_ctx = create_route_ctx(&feature, &route_intervals, [None, None, None]);
+    let tour = &route_ctx.route().tour;
+
+    assert_eq!(get_time_in_vehicle(route_ctx.route(), tour.get(0).unwrap(), tour.get(2).unwrap()), 20.);
+    assert_eq!(get_time_in_vehicle(route_ctx.route(), tour.get(3).unwrap(), tour.get(4).unwrap()), 10.);
+}
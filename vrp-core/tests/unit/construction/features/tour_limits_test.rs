@@ -166,3 +166,98 @@ mod traveling {
         assert_eq!(result, ConstraintViolation::skip(DURATION_CODE));
     }
 }
+
+mod trip {
+    use super::*;
+    use crate::construction::enablers::*;
+    use crate::models::common::*;
+
+    const VIOLATION_CODE: ViolationCode = 4;
+    const RELOAD_LOCATION: Location = 30;
+
+    fn create_route_intervals() -> FixedRouteIntervals {
+        FixedRouteIntervals {
+            is_marker_single_fn: Box::new(|single| single.places.iter().any(|p| p.location == Some(RELOAD_LOCATION))),
+            is_new_interval_needed_fn: Box::new(|_| false),
+            is_obsolete_interval_fn: Box::new(|_, _, _| false),
+            is_assignable_fn: Box::new(|_, _| true),
+            intervals_key: RELOAD_INTERVALS_KEY,
+        }
+    }
+
+    fn create_route_ctx(route_intervals: &FixedRouteIntervals) -> RouteContext {
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&test_fleet(), "v1")
+                    .add_activities(
+                        [10, 20, RELOAD_LOCATION, 40].into_iter().map(|l| ActivityBuilder::with_location(l).build()),
+                    )
+                    .build(),
+            )
+            .build();
+        update_route_schedule(
+            &mut route_ctx,
+            &TestActivityCost::default(),
+            &TestTransportCost::default(),
+            &ScheduleStateKeys::default(),
+        );
+
+        let intervals = get_route_intervals(route_ctx.route(), |activity| {
+            activity.job.as_ref().map_or(false, |job| route_intervals.is_marker_job(&Job::Single(job.clone())))
+        });
+        route_ctx.state_mut().put_route_state(RELOAD_INTERVALS_KEY, intervals);
+
+        route_ctx
+    }
+
+    parameterized_test! {can_check_trip_limits, (limits, index, location, expected), {
+        can_check_trip_limits_impl(limits, index, location, expected);
+    }}
+
+    can_check_trip_limits! {
+        case01_size_first_trip: ((None, None, Some(2)), 1, 15, ConstraintViolation::skip(VIOLATION_CODE)),
+        case02_size_second_trip: ((None, None, Some(2)), 3, 35, None),
+        case03_size_marker: ((None, None, Some(0)), 1, RELOAD_LOCATION, None),
+
+        case04_distance_first_trip: ((Some(55.), None, None), 1, 5, None),
+        case05_distance_first_trip: ((Some(35.), None, None), 1, 5, ConstraintViolation::skip(VIOLATION_CODE)),
+        case06_distance_second_trip: ((Some(55.), None, None), 4, 45, ConstraintViolation::skip(VIOLATION_CODE)),
+
+        case07_duration_first_trip: ((None, Some(55.), None), 1, 5, None),
+        case08_duration_first_trip: ((None, Some(35.), None), 1, 5, ConstraintViolation::skip(VIOLATION_CODE)),
+        case09_duration_second_trip: ((None, Some(55.), None), 4, 45, ConstraintViolation::skip(VIOLATION_CODE)),
+    }
+
+    fn can_check_trip_limits_impl(
+        limits: (Option<Distance>, Option<Duration>, Option<usize>),
+        index: usize,
+        location: Location,
+        expected: Option<ConstraintViolation>,
+    ) {
+        let route_intervals = create_route_intervals();
+        let route_ctx = create_route_ctx(&route_intervals);
+        let (max_distance, max_duration, max_size) = limits;
+        let feature = create_trip_limit_feature(
+            "trip_limit",
+            VIOLATION_CODE,
+            TestTransportCost::new_shared(),
+            Arc::new(route_intervals),
+            Arc::new(move |_| Some(TripLimits { max_distance, max_duration, max_size })),
+        )
+        .unwrap();
+        let target = ActivityBuilder::with_location(location).build();
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &route_ctx,
+            &ActivityContext {
+                index,
+                prev: route_ctx.route().tour.get(index).unwrap(),
+                target: &target,
+                next: route_ctx.route().tour.get(index + 1),
+            },
+        ));
+
+        assert_eq!(result, expected);
+    }
+}
@@ -176,6 +176,37 @@ mod timing {
         assert_eq!(route_ctx.route().tour.get(2).unwrap().schedule, Schedule { arrival: 35., departure: 60. });
     }
 
+    #[test]
+    fn can_update_activity_schedule_with_setup_duration() {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![VehicleBuilder::default().id("v1").build()])
+            .build();
+        let mut dimens = Dimensions::default();
+        dimens.set_setup_durations(vec![(0, 5.)]);
+        let single = SingleBuilder::default().dimens(dimens).build_shared();
+        let create_activity = |location: Location| {
+            ActivityBuilder::with_location_tw_and_duration(location, DEFAULT_ACTIVITY_TIME_WINDOW, 1.)
+                .job(Some(single.clone()))
+                .build()
+        };
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&fleet, "v1")
+                    .add_activities(vec![create_activity(10), create_activity(10), create_activity(20)])
+                    .build(),
+            )
+            .build();
+
+        create_feature().state.unwrap().accept_route_state(&mut route_ctx);
+
+        let tour = &route_ctx.route().tour;
+        assert_eq!(tour.get(1).unwrap().schedule, Schedule { arrival: 10., departure: 16. });
+        assert_eq!(tour.get(2).unwrap().schedule, Schedule { arrival: 16., departure: 17. });
+        assert_eq!(tour.get(3).unwrap().schedule, Schedule { arrival: 27., departure: 33. });
+    }
+
     #[test]
     fn can_calculate_soft_activity_cost_for_empty_tour() {
         let fleet = FleetBuilder::default()
@@ -0,0 +1,99 @@
+use super::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_test_insertion_ctx(route_count: usize) -> InsertionContext {
+    let mut insertion_ctx = create_empty_insertion_context();
+    let problem = insertion_ctx.problem.clone();
+
+    (0..route_count).for_each(|_| {
+        insertion_ctx.solution.routes.push(
+            RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(problem.fleet.as_ref(), "v1").build())
+                .build(),
+        );
+    });
+
+    insertion_ctx
+}
+
+fn create_test_feature(min_tours_weight: Cost, max_tours_weight: Cost) -> Feature {
+    create_weighted_sum_feature(
+        "weighted",
+        &[
+            (create_minimize_tours_feature("min_tours").unwrap(), min_tours_weight),
+            (create_maximize_tours_feature("max_tours").unwrap(), max_tours_weight),
+        ],
+    )
+    .unwrap()
+}
+
+parameterized_test! {can_calculate_weighted_fitness, (route_count, weights, expected), {
+    can_calculate_weighted_fitness_impl(route_count, weights, expected);
+}}
+
+can_calculate_weighted_fitness! {
+    case01: (2, (10., 1.), 18.),
+    case02: (2, (1., 1.), 0.),
+    case03: (3, (0.5, 2.), -4.5),
+    case04: (0, (10., 1.), 0.),
+}
+
+fn can_calculate_weighted_fitness_impl(route_count: usize, weights: (Cost, Cost), expected: Cost) {
+    let insertion_ctx = create_test_insertion_ctx(route_count);
+    let objective = create_test_feature(weights.0, weights.1).objective.unwrap();
+
+    let fitness = objective.fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
+
+#[test]
+fn can_estimate_weighted_insertion_cost() {
+    let solution_ctx = create_empty_solution_context();
+    let fleet = test_fleet();
+    let route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build();
+    let job = SingleBuilder::default().build_as_job_ref();
+    let objective = create_test_feature(10., 1.).objective.unwrap();
+
+    let estimate = objective.estimate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+    assert_eq!(estimate, 9.);
+}
+
+#[test]
+fn can_keep_constraints_and_states_of_features() {
+    let feature = create_weighted_sum_feature(
+        "weighted",
+        &[
+            (
+                create_minimize_transport_costs_feature(
+                    "transport",
+                    TestTransportCost::new_shared(),
+                    TestActivityCost::new_shared(),
+                    1,
+                )
+                .unwrap(),
+                1.,
+            ),
+            (create_minimize_tours_feature("min_tours").unwrap(), 100.),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(feature.name, "weighted");
+    assert!(feature.objective.is_some());
+    assert!(feature.constraint.is_some());
+    assert!(feature.state.is_some());
+}
+
+#[test]
+fn can_detect_invalid_features() {
+    let order_fn = TourOrderFn::Left(Arc::new(|_| OrderResult::Default));
+    let hard_feature = create_tour_order_hard_feature("tour_order", 1, order_fn).unwrap();
+
+    assert!(create_weighted_sum_feature("weighted", &[]).is_err());
+    assert!(create_weighted_sum_feature("weighted", &[(hard_feature, 1.)]).is_err());
+}
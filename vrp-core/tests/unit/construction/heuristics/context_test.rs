@@ -1,10 +1,12 @@
-use crate::construction::heuristics::{RouteState, StateKey, UnassignmentInfo};
+use crate::construction::heuristics::{RouteState, SolutionContext, StateKey, UnassignmentInfo};
 use crate::helpers::construction::features::create_goal_ctx_with_transport;
 use crate::helpers::construction::heuristics::create_insertion_context;
-use crate::helpers::models::domain::test_random;
-use crate::helpers::models::problem::{test_fleet, SingleBuilder};
+use crate::helpers::models::domain::{create_empty_solution_context, create_registry_context, test_random};
+use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
+use crate::models::common::IdDimension;
 use crate::models::solution::Registry;
+use hashbrown::HashSet;
 
 #[test]
 fn can_put_and_get_activity_state() {
@@ -107,3 +109,30 @@ fn can_use_debug_fmt_for_insertion_ctx() {
     assert!(result.contains("unassigned"));
     assert!(result.contains("id: \"single\""));
 }
+
+#[test]
+fn can_keep_changed_routes_consistent_when_routes_are_removed() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2"), test_vehicle_with_id("v3")])
+        .build();
+    let mut registry = create_registry_context(&fleet);
+    let routes = ["v1", "v2", "v3"]
+        .into_iter()
+        .map(|vehicle_id| {
+            let route_ctx = RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(&fleet, vehicle_id).build())
+                .build();
+            registry.get_route(&route_ctx.route().actor).expect("route should be available");
+
+            route_ctx
+        })
+        .collect();
+    let mut solution_ctx = SolutionContext { routes, registry, ..create_empty_solution_context() };
+    solution_ctx.mark_routes_changed([0, 2]);
+
+    solution_ctx.keep_routes(&|route_ctx| route_ctx.route().actor.vehicle.dimens.get_id().unwrap() != "v1");
+
+    assert_eq!(solution_ctx.routes.len(), 2);
+    assert_eq!(solution_ctx.take_changed_routes(), HashSet::from_iter([1]));
+}
@@ -137,6 +137,68 @@ pub fn can_evaluate_constraints() {
     );
 }
 
+#[test]
+fn can_accept_state_of_routes_changed_by_other_routes() {
+    const ACCEPTED_KEY: StateKey = StateKey(1000);
+
+    struct ChangingFeatureState {
+        state_keys: Vec<StateKey>,
+    }
+
+    impl FeatureState for ChangingFeatureState {
+        fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+        fn accept_route_state(&self, _: &mut RouteContext) {}
+
+        fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+            solution_ctx.mark_routes_changed([1]);
+        }
+
+        fn state_keys(&self) -> Iter<StateKey> {
+            self.state_keys.iter()
+        }
+    }
+
+    struct TrackingFeatureState {
+        state_keys: Vec<StateKey>,
+    }
+
+    impl FeatureState for TrackingFeatureState {
+        fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+        fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+            route_ctx.state_mut().put_route_state(ACCEPTED_KEY, true);
+        }
+
+        fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+        fn state_keys(&self) -> Iter<StateKey> {
+            self.state_keys.iter()
+        }
+    }
+
+    let features = vec![
+        Feature {
+            state: Some(Arc::new(ChangingFeatureState { state_keys: vec![] })),
+            ..create_constraint_feature("changing", None)
+        },
+        Feature {
+            state: Some(Arc::new(TrackingFeatureState { state_keys: vec![ACCEPTED_KEY] })),
+            ..create_constraint_feature("tracking", None)
+        },
+    ];
+    let goal_ctx = create_goal_ctx_with_features(features, vec![]);
+    let mut solution_ctx = create_empty_insertion_context().solution;
+    solution_ctx.routes = vec![RouteContext::new(test_actor()), RouteContext::new(test_actor())];
+
+    goal_ctx.accept_solution_state(&mut solution_ctx);
+
+    let is_accepted =
+        |route_idx: usize| solution_ctx.routes[route_idx].state().get_route_state::<bool>(ACCEPTED_KEY).is_some();
+    assert!(!is_accepted(0));
+    assert!(is_accepted(1));
+}
+
 parameterized_test! {can_use_objective_estimate, (feature_names, feature_map, expected_cost), {
     can_use_objective_estimate_impl(feature_names, feature_map, expected_cost);
 }}
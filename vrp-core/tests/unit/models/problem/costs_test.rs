@@ -1,5 +1,7 @@
 use super::*;
-use crate::helpers::models::solution::test_actor_with_profile;
+use crate::helpers::models::problem::SingleBuilder;
+use crate::helpers::models::solution::{test_actor_with_profile, ActivityBuilder};
+use crate::models::problem::{ServiceDurationDimension, ServiceDurationFn, TimeDeviation, TimeWindowTolerance};
 
 fn create_matrix_data(
     profile: Profile,
@@ -114,6 +116,54 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance_approx(&p1, 0, 1), 5.);
 }
 
+#[test]
+fn can_estimate_departure_with_actor_dependent_service_duration() {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let mut dimens = Dimensions::default();
+    let duration_fn: ServiceDurationFn = Arc::new(|_, duration| duration * 2.);
+    dimens.set_service_duration_fns(vec![(0, duration_fn)]);
+    let single = SingleBuilder::default().dimens(dimens).build_shared();
+    let activity =
+        ActivityBuilder::with_location_tw_and_duration(0, TimeWindow::new(0., 100.), 10.).job(Some(single)).build();
+
+    assert_eq!(get_service_duration(&route, &activity), 20.);
+    assert_eq!(SimpleActivityCost::default().estimate_departure(&route, &activity, 5.), 25.);
+    assert_eq!(SimpleActivityCost::default().estimate_arrival(&route, &activity, 50.), 30.);
+}
+
+parameterized_test! {can_calculate_time_window_penalty_with_narrowed_activity_time, (activity_time, arrival, expected), {
+    can_calculate_time_window_penalty_with_narrowed_activity_time_impl(activity_time, arrival, expected);
+}}
+
+can_calculate_time_window_penalty_with_narrowed_activity_time! {
+    case01_original: ((5., 25.), 7., 6.),
+    case02_narrowed_in_time: ((18., 25.), 15., 0.),
+    case03_narrowed_late: ((18., 25.), 23., 9.),
+}
+
+fn can_calculate_time_window_penalty_with_narrowed_activity_time_impl(
+    activity_time: (Timestamp, Timestamp),
+    arrival: Timestamp,
+    expected: Cost,
+) {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let mut dimens = Dimensions::default();
+    dimens.set_time_window_tolerance(vec![(
+        0,
+        TimeWindowTolerance {
+            earliness: Some(TimeDeviation { max: 5., penalty: 2. }),
+            lateness: Some(TimeDeviation { max: 5., penalty: 3. }),
+        },
+    )]);
+    let single = SingleBuilder::default().dimens(dimens).times(vec![TimeWindow::new(5., 25.)]).build_shared();
+    let activity =
+        ActivityBuilder::with_location_tw_and_duration(0, TimeWindow::new(activity_time.0, activity_time.1), 0.)
+            .job(Some(single))
+            .build();
+
+    assert_eq!(get_time_window_penalty(&route, &activity, arrival), expected);
+}
+
 mod objective {
     use super::*;
     use crate::construction::heuristics::{InsertionContext, MoveContext, StateKey};
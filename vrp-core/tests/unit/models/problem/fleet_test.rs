@@ -1,4 +1,7 @@
-use crate::helpers::models::problem::{test_driver, test_vehicle, FleetBuilder};
+use crate::helpers::models::problem::*;
+use crate::models::common::{IdDimension, TimeInterval, TimeWindow};
+use crate::models::problem::{CostTier, Costs, Driver, DriverDetail, Fleet, VehicleDetail, VehiclePlace};
+use std::sync::Arc;
 
 #[test]
 fn fleet_creates_unique_profiles_from_vehicles() {
@@ -19,3 +22,157 @@ fn fleet_creates_unique_profiles_from_vehicles() {
         vec![profile1, profile2]
     )
 }
+
+fn create_driver_with_shifts(shifts: Vec<(f64, f64)>) -> Driver {
+    Driver {
+        details: shifts.into_iter().map(|(start, end)| DriverDetail { time: TimeWindow::new(start, end) }).collect(),
+        ..test_driver()
+    }
+}
+
+#[test]
+fn fleet_creates_actors_from_driver_vehicle_pairs_with_overlapping_time() {
+    let fleet = FleetBuilder::default()
+        .add_driver(create_driver_with_shifts(vec![(0., 100.), (2000., 3000.)]))
+        .add_driver(create_driver_with_shifts(vec![(50., 150.)]))
+        .add_vehicle(VehicleBuilder::default().id("v1").details(vec![test_vehicle_detail()]).build())
+        .build();
+
+    let mut times = fleet.actors.iter().map(|actor| actor.detail.time.clone()).collect::<Vec<_>>();
+    times.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+    assert_eq!(times, vec![TimeWindow::new(0., 100.), TimeWindow::new(50., 150.)]);
+    assert_eq!(fleet.actors[0].detail.start.as_ref().unwrap().time.earliest, Some(fleet.actors[0].detail.time.start));
+    assert!(fleet.actors.iter().all(|actor| fleet.conflicts.get(actor).map_or(0, |others| others.len()) == 1));
+}
+
+#[test]
+fn fleet_creates_conflicts_for_driver_with_overlapping_vehicles() {
+    let fleet = FleetBuilder::default()
+        .add_driver(create_driver_with_shifts(vec![(0., 100.)]))
+        .add_vehicle(VehicleBuilder::default().id("v1").details(vec![test_vehicle_detail()]).build())
+        .add_vehicle(VehicleBuilder::default().id("v2").details(vec![test_vehicle_detail()]).build())
+        .build();
+
+    assert_eq!(fleet.actors.len(), 2);
+    assert!(fleet.actors.iter().all(|actor| fleet.conflicts.get(actor).map_or(0, |others| others.len()) == 1));
+}
+
+#[test]
+fn fleet_does_not_create_conflicts_for_driver_without_details() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").details(vec![test_vehicle_detail()]).build())
+        .add_vehicle(VehicleBuilder::default().id("v2").details(vec![test_vehicle_detail()]).build())
+        .build();
+
+    assert_eq!(fleet.actors.len(), 2);
+    assert!(fleet.conflicts.is_empty());
+}
+
+#[test]
+fn fleet_creates_actors_only_from_paired_drivers_and_vehicles() {
+    let drivers = vec![Arc::new(create_driver_with_shifts(vec![(0., 100.)])), Arc::new(test_driver())];
+    let vehicles = vec![
+        Arc::new(VehicleBuilder::default().id("v1").details(vec![test_vehicle_detail()]).build()),
+        Arc::new(VehicleBuilder::default().id("v2").details(vec![test_vehicle_detail()]).build()),
+    ];
+    let first_driver = drivers[0].clone();
+
+    let fleet = Fleet::new_with_pairing(
+        drivers,
+        vehicles,
+        Box::new(|_| Box::new(|_| 0)),
+        Box::new(move |driver, vehicle| {
+            std::ptr::eq(driver, first_driver.as_ref()) == (vehicle.dimens.get_id().unwrap() == "v1")
+        }),
+    )
+    .expect("cannot create fleet");
+
+    assert_eq!(fleet.actors.len(), 2);
+    assert!(fleet.conflicts.is_empty());
+}
+
+parameterized_test! {can_return_error_when_no_actors_can_be_created, (driver_shifts, is_err), {
+    can_return_error_when_no_actors_can_be_created_impl(driver_shifts, is_err);
+}}
+
+can_return_error_when_no_actors_can_be_created! {
+    case01_no_drivers: (None, true),
+    case02_no_overlap: (Some(vec![(DEFAULT_ACTOR_TIME_WINDOW.end, DEFAULT_ACTOR_TIME_WINDOW.end + 100.)]), true),
+    case03_overlap: (Some(vec![(0., 100.)]), false),
+}
+
+fn can_return_error_when_no_actors_can_be_created_impl(driver_shifts: Option<Vec<(f64, f64)>>, is_err: bool) {
+    let drivers = driver_shifts.map(|shifts| vec![Arc::new(create_driver_with_shifts(shifts))]).unwrap_or_default();
+    let vehicles = vec![Arc::new(VehicleBuilder::default().id("v1").details(vec![test_vehicle_detail()]).build())];
+
+    let result = Fleet::new_with_pairing(drivers, vehicles, Box::new(|_| Box::new(|_| 0)), Box::new(|_, _| true));
+
+    assert_eq!(result.is_err(), is_err);
+}
+
+parameterized_test! {can_create_conflicts_for_driver_shifts, (vehicle_shifts, expected), {
+    can_create_conflicts_for_driver_shifts_impl(vehicle_shifts, expected);
+}}
+
+can_create_conflicts_for_driver_shifts! {
+    case01_no_overlap: (vec![(0., 10.), (10., 20.), (20., 30.)], vec![0, 0, 0]),
+    case02_chain_overlap: (vec![(0., 15.), (10., 25.), (20., 30.)], vec![1, 2, 1]),
+    case03_full_overlap: (vec![(0., 30.), (10., 15.), (20., 25.)], vec![2, 1, 1]),
+}
+
+fn can_create_conflicts_for_driver_shifts_impl(vehicle_shifts: Vec<(f64, f64)>, expected: Vec<usize>) {
+    let vehicles = vehicle_shifts
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (start, end))| {
+            let detail = VehicleDetail {
+                start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: Some(start), latest: None } }),
+                end: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: None, latest: Some(end) } }),
+            };
+            VehicleBuilder::default().id(format!("v{idx}").as_str()).details(vec![detail]).build()
+        })
+        .collect();
+    let fleet =
+        FleetBuilder::default().add_driver(create_driver_with_shifts(vec![(0., 100.)])).add_vehicles(vehicles).build();
+
+    let mut conflicts = fleet
+        .actors
+        .iter()
+        .map(|actor| (actor.detail.time.start, fleet.conflicts.get(actor).map_or(0, |others| others.len())))
+        .collect::<Vec<_>>();
+    conflicts.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    assert_eq!(conflicts.into_iter().map(|(_, count)| count).collect::<Vec<_>>(), expected);
+}
+
+parameterized_test! {can_calculate_tier_costs, (time_tiers, distance_tiers, duration, distance, expected), {
+    can_calculate_tier_costs_impl(time_tiers, distance_tiers, duration, distance, expected);
+}}
+
+can_calculate_tier_costs! {
+    case01_no_tiers: (vec![], vec![], 100., 100., (0., 0.)),
+    case02_below_threshold: (vec![(50., 2.)], vec![(200., 3.)], 40., 100., (0., 0.)),
+    case03_above_threshold: (vec![(50., 2.)], vec![(20., 3.)], 60., 100., (10., 160.)),
+    case04_multiple_tiers: (vec![(50., 2.), (80., 4.)], vec![], 100., 100., (90., 0.)),
+    case05_cheaper_tier: (vec![], vec![(20., 0.)], 100., 100., (0., -80.)),
+}
+
+fn can_calculate_tier_costs_impl(
+    time_tiers: Vec<(f64, f64)>,
+    distance_tiers: Vec<(f64, f64)>,
+    duration: f64,
+    distance: f64,
+    expected: (f64, f64),
+) {
+    let create_tiers = |tiers: Vec<(f64, f64)>| {
+        tiers.into_iter().map(|(threshold, cost)| CostTier { threshold, cost }).collect::<Vec<_>>()
+    };
+    let costs =
+        Costs { time_tiers: create_tiers(time_tiers), distance_tiers: create_tiers(distance_tiers), ..test_costs() };
+
+    let result = costs.get_tier_costs(duration, distance);
+
+    assert_eq!(result, expected);
+}
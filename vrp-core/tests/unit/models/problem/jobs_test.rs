@@ -92,7 +92,16 @@ fn create_only_distance_transport_cost() -> Arc<dyn TransportCost + Sync + Send>
 }
 
 fn create_costs() -> Costs {
-    Costs { fixed: 10.0, per_distance: 1.0, per_driving_time: 1.0, per_waiting_time: 1.0, per_service_time: 1.0 }
+    Costs {
+        fixed: 10.0,
+        per_distance: 1.0,
+        per_driving_time: 1.0,
+        per_waiting_time: 1.0,
+        per_service_time: 1.0,
+        per_distance_loaded: None,
+        time_tiers: vec![],
+        distance_tiers: vec![],
+    }
 }
 
 #[test]
@@ -251,3 +260,56 @@ fn can_handle_negative_distances_durations_impl(transport_costs: Arc<dyn Transpo
             .all(|(_, cost)| { (cost as LowPrecisionCost - UNREACHABLE_COST).abs() < f32::EPSILON }));
     }
 }
+
+parameterized_test! {can_calculate_time_window_tolerance_penalty, (service_start, expected_deviation, expected_penalty), {
+    can_calculate_time_window_tolerance_penalty_impl(service_start, expected_deviation, expected_penalty);
+}}
+
+can_calculate_time_window_tolerance_penalty! {
+    case01: (5., (5., 0.), 10.),
+    case02: (10., (0., 0.), 0.),
+    case03: (15., (0., 0.), 0.),
+    case04: (20., (0., 0.), 0.),
+    case05: (23., (0., 3.), 9.),
+}
+
+fn can_calculate_time_window_tolerance_penalty_impl(
+    service_start: Timestamp,
+    expected_deviation: (Duration, Duration),
+    expected_penalty: Cost,
+) {
+    let tolerance = TimeWindowTolerance {
+        earliness: Some(TimeDeviation { max: 5., penalty: 2. }),
+        lateness: Some(TimeDeviation { max: 5., penalty: 3. }),
+    };
+    let extended = TimeWindow::new(5., 25.);
+
+    assert_eq!(tolerance.get_original(&extended), TimeWindow::new(10., 20.));
+    assert_eq!(tolerance.get_deviation(&extended, service_start), expected_deviation);
+    assert_eq!(tolerance.get_penalty(&extended, service_start), expected_penalty);
+}
+
+#[test]
+fn can_split_single_job() {
+    let single = SingleBuilder::default().id("job1").location(Some(3)).duration(5.).build();
+
+    let parts = single.split("job1", 3, |index, dimens| {
+        dimens.set_demand(Demand::<SingleDimLoad> {
+            pickup: Default::default(),
+            delivery: (SingleDimLoad::new(index as i32 + 1), Default::default()),
+        });
+    });
+
+    assert_eq!(parts.len(), 3);
+    parts.iter().enumerate().for_each(|(index, part)| {
+        let partial = part.dimens.get_partial_job().expect("partial job");
+        let demand: &Demand<SingleDimLoad> = part.dimens.get_demand().expect("demand");
+
+        assert_eq!((partial.id.as_str(), partial.index, partial.total), ("job1", index, 3));
+        assert_eq!(demand.delivery.0.value, index as i32 + 1);
+        assert_eq!(part.dimens.get_id(), single.dimens.get_id());
+        assert_eq!(part.places[0].location, Some(3));
+        assert_eq!(part.places[0].duration, 5.);
+    });
+    assert!(single.dimens.get_partial_job().is_none());
+}
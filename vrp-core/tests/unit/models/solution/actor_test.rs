@@ -1,7 +1,7 @@
 use crate::helpers::models::domain::test_random;
 use crate::helpers::models::problem::{test_driver, test_vehicle_detail, FleetBuilder, VehicleBuilder};
-use crate::models::common::TimeInterval;
-use crate::models::problem::{Actor, VehicleDetail, VehiclePlace};
+use crate::models::common::{TimeInterval, TimeWindow};
+use crate::models::problem::{Actor, Driver, DriverDetail, VehicleDetail, VehiclePlace};
 use crate::models::solution::Registry;
 use std::cmp::Ordering::Less;
 use std::sync::Arc;
@@ -66,3 +66,23 @@ fn create_two_test_vehicle_details() -> Vec<VehicleDetail> {
         },
     ]
 }
+
+#[test]
+fn can_block_and_release_conflicting_actors_in_registry() {
+    let driver = Driver { details: vec![DriverDetail { time: TimeWindow::new(0., 1000.) }], ..test_driver() };
+    let fleet = FleetBuilder::default()
+        .add_driver(driver)
+        .add_vehicles(vec![
+            VehicleBuilder::default().id("v1").details(vec![test_vehicle_detail()]).build(),
+            VehicleBuilder::default().id("v2").details(vec![test_vehicle_detail()]).build(),
+        ])
+        .build();
+    let mut registry = Registry::new(&fleet, test_random());
+    let actor = registry.available().next().unwrap();
+
+    assert!(registry.use_actor(&actor));
+    assert_eq!(registry.available().count(), 0);
+
+    assert!(registry.free_actor(&actor));
+    assert_eq!(registry.available().count(), 2);
+}
@@ -4,18 +4,31 @@ mod assignment_test;
 
 use super::*;
 use crate::format::solution::activity_matcher::*;
-use crate::format::{get_coord_index, get_job_index};
+use crate::format::{get_coord_index, get_day_index, get_horizon_start, get_job_index};
+use crate::parse_time;
 use crate::utils::combine_error_results;
 use hashbrown::HashSet;
 use std::cmp::Ordering;
 use vrp_core::construction::clustering::vicinity::ServingPolicy;
+use vrp_core::models::common::{Load, MultiDimLoad};
+use vrp_core::models::problem::{SetupDurationDimension, TimeWindowToleranceDimension};
 use vrp_core::models::solution::Place;
 use vrp_core::prelude::compare_floats;
 use vrp_core::utils::GenericError;
 
 /// Checks assignment of jobs and vehicles.
 pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_vehicles(ctx), check_jobs_presence(ctx), check_jobs_match(ctx), check_groups(ctx)])
+    combine_error_results(&[
+        check_vehicles(ctx),
+        check_drivers(ctx),
+        check_jobs_presence(ctx),
+        check_split_jobs(ctx),
+        check_periodic_jobs(ctx),
+        check_jobs_match(ctx),
+        check_time_window_deviations(ctx),
+        check_setup_durations(ctx),
+        check_groups(ctx),
+    ])
 }
 
 /// Checks that vehicles in each tour are used once per shift and they are known in problem.
@@ -38,6 +51,68 @@ fn check_vehicles(ctx: &CheckerContext) -> Result<(), GenericError> {
     Ok(())
 }
 
+/// Checks that drivers are known, work within their shifts and drive only one vehicle at the same time.
+fn check_drivers(ctx: &CheckerContext) -> Result<(), GenericError> {
+    let drivers = match ctx.problem.fleet.drivers.as_ref() {
+        Some(drivers) => drivers,
+        None => {
+            return match ctx.solution.tours.iter().find(|tour| tour.driver_id.is_some()) {
+                Some(tour) => Err(format!("tour '{}' has driver, but fleet has no drivers", tour.vehicle_id).into()),
+                None => Ok(()),
+            };
+        }
+    };
+
+    let driver_shifts = drivers
+        .iter()
+        .flat_map(|driver| driver.driver_ids.iter().map(move |driver_id| (driver_id, &driver.shifts)))
+        .collect::<HashMap<_, _>>();
+
+    let tour_times = ctx
+        .solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let driver_id =
+                tour.driver_id.as_ref().ok_or_else(|| format!("tour '{}' has no driver assigned", tour.vehicle_id))?;
+            let shifts =
+                driver_shifts.get(driver_id).ok_or_else(|| format!("used driver with unknown id: '{driver_id}'"))?;
+
+            let start = tour.stops.first().map(|stop| parse_time(&stop.schedule().departure));
+            let end = tour.stops.last().map(|stop| parse_time(&stop.schedule().arrival));
+            let time = match (start, end) {
+                (Some(start), Some(end)) => TimeWindow::new(start, end),
+                _ => return Err(format!("tour '{}' has no stops", tour.vehicle_id)),
+            };
+
+            let is_within_shift = shifts
+                .iter()
+                .map(|shift| TimeWindow::new(parse_time(&shift.start), parse_time(&shift.end)))
+                .any(|shift| shift.start <= time.start && time.end <= shift.end);
+
+            if is_within_shift {
+                Ok((driver_id, &tour.vehicle_id, time))
+            } else {
+                Err(format!("driver '{driver_id}' works outside of its shifts in tour '{}'", tour.vehicle_id))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    tour_times.iter().enumerate().try_for_each(|(idx, (driver_id, vehicle_id, time))| {
+        match tour_times
+            .iter()
+            .skip(idx + 1)
+            .find(|(other_id, _, other_time)| other_id == driver_id && other_time.intersects_exclusive(time))
+        {
+            Some((_, other_vehicle_id, _)) => Err(format!(
+                "driver '{driver_id}' is used in tours '{vehicle_id}' and '{other_vehicle_id}' at the same time"
+            )
+            .into()),
+            None => Ok(()),
+        }
+    })
+}
+
 /// Checks job task rules.
 fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), GenericError> {
     struct JobAssignment {
@@ -56,7 +131,13 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), GenericError> {
     };
     let activity_types: HashSet<_> = vec!["pickup", "delivery", "service", "replacement"].into_iter().collect();
 
-    let all_jobs = ctx.problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect::<HashMap<_, _>>();
+    let all_jobs = &ctx.job_map;
+    let split_jobs = get_split_job_ids(ctx);
+    let periodic_jobs = get_periodic_job_ids(ctx);
+    // NOTE transferred jobs are served by two tours, their legs are checked separately
+    let transfer_jobs = get_transfer_job_ids(ctx);
+    // NOTE periodic jobs are checked separately
+    let multi_visit_jobs = split_jobs.union(&periodic_jobs).cloned().collect::<HashSet<_>>();
     let mut used_jobs = HashMap::<String, JobAssignment>::new();
 
     ctx.solution.tours.iter().try_for_each(|tour| {
@@ -70,7 +151,10 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), GenericError> {
                 let asgn =
                     used_jobs.entry(activity.job_id.clone()).or_insert_with(|| new_assignment(tour_info.clone()));
 
-                if asgn.tour_info != tour_info {
+                if asgn.tour_info != tour_info
+                    && !multi_visit_jobs.contains(&activity.job_id)
+                    && !transfer_jobs.contains(&activity.job_id)
+                {
                     return Err(GenericError::from(format!("job served in multiple tours: '{}'", activity.job_id)));
                 }
 
@@ -95,13 +179,20 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), GenericError> {
             + job.replacements.as_ref().map_or(0, |r| r.len());
         let assigned_tasks = asgn.pickups.len() + asgn.deliveries.len() + asgn.services.len() + asgn.replacements.len();
 
-        if expected_tasks != assigned_tasks {
+        if periodic_jobs.contains(id) {
+            return Ok(());
+        }
+
+        if expected_tasks != assigned_tasks && !split_jobs.contains(id) {
             return Err(GenericError::from(format!(
                 "not all tasks served for '{id}', expected: {expected_tasks}, assigned: {assigned_tasks}"
             )));
         }
 
-        if !asgn.deliveries.is_empty() && asgn.pickups.iter().max() > asgn.deliveries.iter().min() {
+        if !transfer_jobs.contains(id)
+            && !asgn.deliveries.is_empty()
+            && asgn.pickups.iter().max() > asgn.deliveries.iter().min()
+        {
             return Err(GenericError::from(format!("found pickup after delivery for '{id}'")));
         }
 
@@ -127,7 +218,7 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), GenericError> {
             return Err(format!("unknown job id in the list of unassigned jobs: '{job_id}'").into());
         }
 
-        if used_jobs.contains_key(job_id) {
+        if used_jobs.contains_key(job_id) && !multi_visit_jobs.contains(job_id) {
             return Err(format!("job present as assigned and unassigned: '{job_id}'").into());
         }
 
@@ -135,7 +226,7 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), GenericError> {
     })?;
 
     let all_used_job =
-        unique_unassigned_jobs.into_iter().chain(used_jobs.into_iter().map(|(id, _)| id)).collect::<Vec<_>>();
+        unique_unassigned_jobs.into_iter().chain(used_jobs.into_iter().map(|(id, _)| id)).collect::<HashSet<_>>();
 
     if all_used_job.len() != all_jobs.len() {
         return Err(format!(
@@ -149,6 +240,182 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), GenericError> {
     Ok(())
 }
 
+/// Checks that demand of splittable jobs is served correctly.
+fn check_split_jobs(ctx: &CheckerContext) -> Result<(), GenericError> {
+    let split_jobs = get_split_job_ids(ctx);
+
+    let served = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().flat_map(|stop| stop.activities().iter()))
+        .filter(|activity| split_jobs.contains(&activity.job_id))
+        .try_fold(HashMap::<String, MultiDimLoad>::new(), |mut acc, activity| {
+            let quantity =
+                activity.quantity.clone().or_else(|| get_split_task_demand(ctx, &activity.job_id)).ok_or_else(
+                    || GenericError::from(format!("cannot get quantity of splittable job '{}'", activity.job_id)),
+                )?;
+
+            let served = acc.entry(activity.job_id.clone()).or_default();
+            *served = *served + MultiDimLoad::new(quantity);
+
+            Ok::<_, GenericError>(acc)
+        })?;
+
+    served.iter().try_for_each(|(job_id, served)| {
+        let demand = get_split_task_demand(ctx, job_id).map(MultiDimLoad::new).unwrap_or_default();
+        if !demand.can_fit(served) {
+            return Err(GenericError::from(format!("served quantity exceeds demand of splittable job '{job_id}'")));
+        }
+
+        Ok(())
+    })?;
+
+    ctx.solution.split_jobs.iter().flat_map(|split_jobs| split_jobs.iter()).try_for_each(|split_job| {
+        let served = served.get(&split_job.job_id).cloned().unwrap_or_default();
+        let demand = get_split_task_demand(ctx, &split_job.job_id)
+            .ok_or_else(|| format!("unknown splittable job id: '{}'", split_job.job_id))?;
+
+        let reported_served = MultiDimLoad::new(split_job.served.clone());
+        let reported_unserved = MultiDimLoad::new(split_job.unserved.clone());
+        let visits = split_job
+            .visits
+            .iter()
+            .fold(MultiDimLoad::default(), |acc, visit| acc + MultiDimLoad::new(visit.quantity.clone()));
+
+        let is_valid = served.as_vec() == reported_served.as_vec()
+            && visits.as_vec() == reported_served.as_vec()
+            && (reported_served + reported_unserved).as_vec() == MultiDimLoad::new(demand).as_vec();
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(format!("split job '{}' does not match its visits", split_job.job_id).into())
+        }
+    })
+}
+
+/// Checks that periodic jobs are visited according to their visiting rules.
+fn check_periodic_jobs(ctx: &CheckerContext) -> Result<(), GenericError> {
+    let horizon_start = get_horizon_start(&ctx.problem);
+    let activity_types: HashSet<_> = vec!["pickup", "delivery", "service", "replacement"].into_iter().collect();
+    let unassigned_ids = ctx
+        .solution
+        .unassigned
+        .iter()
+        .flat_map(|jobs| jobs.iter().map(|job| job.job_id.clone()))
+        .collect::<HashSet<_>>();
+
+    let visits = ctx.solution.tours.iter().try_fold(HashMap::<String, Vec<usize>>::new(), |mut acc, tour| {
+        let shift = ctx
+            .get_vehicle(&tour.vehicle_id)?
+            .shifts
+            .get(tour.shift_index)
+            .ok_or_else(|| format!("cannot find shift {} of vehicle '{}'", tour.shift_index, tour.vehicle_id))?;
+        let day = get_day_index(horizon_start, parse_time(&shift.start.earliest));
+
+        let tasks = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .filter(|activity| activity_types.contains(activity.activity_type.as_str()))
+            .filter_map(|activity| {
+                ctx.get_job_by_id(&activity.job_id)
+                    .filter(|job| job.periodic.is_some())
+                    .map(|job| (job, activity.activity_type.as_str()))
+            })
+            .fold(HashMap::<String, (&Job, Vec<&str>)>::new(), |mut acc, (job, activity_type)| {
+                acc.entry(job.id.clone()).or_insert_with(|| (job, vec![])).1.push(activity_type);
+                acc
+            });
+
+        tasks.into_iter().try_for_each(|(job_id, (job, activity_types))| {
+            let is_pickup_after_delivery = activity_types
+                .iter()
+                .skip_while(|activity_type| **activity_type != "delivery")
+                .any(|activity_type| *activity_type == "pickup");
+
+            if activity_types.len() != get_job_tasks(job).count() || is_pickup_after_delivery {
+                return Err(GenericError::from(format!(
+                    "periodic job '{job_id}' is not properly visited in tour of vehicle '{}'",
+                    tour.vehicle_id
+                )));
+            }
+
+            acc.entry(job_id).or_default().push(day);
+
+            Ok(())
+        })?;
+
+        Ok::<_, GenericError>(acc)
+    })?;
+
+    ctx.problem.plan.jobs.iter().filter_map(|job| job.periodic.as_ref().map(|periodic| (job, periodic))).try_for_each(
+        |(job, periodic)| {
+            let mut days = visits.get(&job.id).cloned().unwrap_or_default();
+            days.sort_unstable();
+
+            let max_visits = periodic.max_frequency.unwrap_or(periodic.frequency).max(periodic.frequency);
+            let min_spacing = periodic.min_spacing.unwrap_or(1).max(1);
+
+            let is_valid_spacing = days.windows(2).all(|pair| {
+                let spacing = pair[1] - pair[0];
+                spacing >= min_spacing && periodic.max_spacing.map_or(true, |max| spacing <= max)
+            });
+            let is_valid_pattern = days.is_empty()
+                || periodic.day_patterns.as_ref().map_or(true, |patterns| {
+                    patterns.iter().any(|pattern| {
+                        let pattern = pattern.iter().collect::<HashSet<_>>();
+                        pattern.len() == days.len() && days.iter().all(|day| pattern.contains(day))
+                    })
+                });
+
+            if days.len() > max_visits || !is_valid_spacing || !is_valid_pattern {
+                return Err(GenericError::from(format!("periodic job '{}' has invalid visit days: {days:?}", job.id)));
+            }
+
+            let is_unassigned = days.is_empty() || days.len() < periodic.frequency;
+            if is_unassigned != unassigned_ids.contains(&job.id) {
+                return Err(GenericError::from(format!(
+                    "periodic job '{}' has {} visits, but its presence in unassigned list is wrong",
+                    job.id,
+                    days.len()
+                )));
+            }
+
+            Ok(())
+        },
+    )
+}
+
+fn get_periodic_job_ids(ctx: &CheckerContext) -> HashSet<String> {
+    ctx.problem.plan.jobs.iter().filter(|job| job.periodic.is_some()).map(|job| job.id.clone()).collect()
+}
+
+fn get_transfer_job_ids(ctx: &CheckerContext) -> HashSet<String> {
+    ctx.problem.plan.jobs.iter().filter(|job| job.transfers.is_some()).map(|job| job.id.clone()).collect()
+}
+
+fn get_split_job_ids(ctx: &CheckerContext) -> HashSet<String> {
+    ctx.problem
+        .plan
+        .jobs
+        .iter()
+        .filter(|job| get_job_tasks(job).any(|task| task.splittable.unwrap_or(false)))
+        .map(|job| job.id.clone())
+        .collect()
+}
+
+fn get_split_task_demand(ctx: &CheckerContext, job_id: &str) -> Option<Vec<i32>> {
+    ctx.get_job_by_id(job_id).and_then(|job| get_job_tasks(job).next()).and_then(|task| task.demand.clone())
+}
+
+fn get_job_tasks(job: &Job) -> impl Iterator<Item = &JobTask> + '_ {
+    [&job.pickups, &job.deliveries, &job.replacements, &job.services]
+        .into_iter()
+        .flat_map(|tasks| tasks.iter().flat_map(|tasks| tasks.iter()))
+}
+
 /// Checks job constraint violations.
 fn check_jobs_match(ctx: &CheckerContext) -> Result<(), GenericError> {
     let job_index = get_job_index(&ctx.core_problem);
@@ -177,8 +444,10 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), GenericError> {
                                                 true
                                             }
                                         }
-                                        Ok(Some(JobInfo(_, _, place, time))) => {
-                                            is_valid_job_info(ctx, stop, activity, *idx, place, time)
+                                        Ok(Some(JobInfo(_, single, place, time))) => {
+                                            let duration = ctx.get_service_duration(tour, &single, &place);
+                                            let place = Place { duration, ..place };
+                                            is_valid_job_info(ctx, tour, stop, activity, *idx, place, time)
                                         }
                                         _ => false,
                                     }
@@ -209,6 +478,7 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), GenericError> {
 
 fn is_valid_job_info(
     ctx: &CheckerContext,
+    tour: &Tour,
     stop: &PointStop,
     activity: &Activity,
     activity_idx: usize,
@@ -219,7 +489,12 @@ fn is_valid_job_info(
     let parking = ctx.clustering.as_ref().map(|config| config.serving.get_parking()).unwrap_or(0.);
     let commute_profile = ctx.clustering.as_ref().map(|config| config.profile.clone());
     let domain_commute = ctx.get_commute_info(commute_profile, parking, stop, activity_idx);
-    let extra_time = get_extra_time(stop, activity, &place).unwrap_or(0.);
+    let extra_time = get_extra_time(stop, activity, &place).unwrap_or(0.)
+        + match activity.activity_type.as_str() {
+            "reload" => get_cleaning_duration(ctx, tour, stop),
+            "recharge" => get_charging_duration(ctx, tour, stop),
+            _ => 0.,
+        };
 
     match (&ctx.clustering, &activity.commute, domain_commute) {
         (_, _, Err(_)) | (_, None, Ok(Some(_))) | (_, Some(_), Ok(None)) | (&None, &Some(_), Ok(Some(_))) => true,
@@ -256,6 +531,83 @@ fn is_valid_job_info(
     }
 }
 
+/// Checks that reported earliness and lateness of activities match soft time window deviations.
+fn check_time_window_deviations(ctx: &CheckerContext) -> Result<(), GenericError> {
+    let job_index = get_job_index(&ctx.core_problem);
+    let coord_index = get_coord_index(&ctx.core_problem);
+
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        tour.stops.iter().filter_map(|stop| stop.as_point()).try_for_each(|stop| {
+            stop.activities.iter().try_for_each(|activity| {
+                let expected = match try_match_point_job(tour, stop, activity, job_index, coord_index) {
+                    Ok(Some(JobInfo(_, single, place, time))) => single
+                        .dimens
+                        .get_time_window_tolerance(place.idx)
+                        .map(|tolerance| tolerance.get_deviation(&place.time, time.start))
+                        .unwrap_or((0., 0.)),
+                    _ => (0., 0.),
+                };
+
+                let actual = (activity.earliness.unwrap_or(0), activity.lateness.unwrap_or(0));
+                let expected = (expected.0.round() as i64, expected.1.round() as i64);
+
+                if actual != expected {
+                    Err(format!(
+                        "time window deviation mismatch for activity '{}' in tour '{}': \
+                         expected earliness/lateness {:?}, got {:?}",
+                        activity.job_id, tour.vehicle_id, expected, actual
+                    )
+                    .into())
+                } else {
+                    Ok(())
+                }
+            })
+        })
+    })
+}
+
+/// Checks that setup time is reported once per stop and job activities start after it.
+fn check_setup_durations(ctx: &CheckerContext) -> Result<(), GenericError> {
+    let job_index = get_job_index(&ctx.core_problem);
+    let coord_index = get_coord_index(&ctx.core_problem);
+
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        tour.stops.iter().filter_map(|stop| stop.as_point()).try_for_each(|stop| {
+            let expected = stop
+                .activities
+                .first()
+                .filter(|activity| activity.commute.is_none())
+                .and_then(|activity| try_match_point_job(tour, stop, activity, job_index, coord_index).ok().flatten())
+                .and_then(|JobInfo(_, single, place, _)| single.dimens.get_setup_duration(place.idx))
+                .map_or(0, |setup| setup.round() as i64);
+
+            let actual =
+                stop.setup.as_ref().map_or(0, |setup| (parse_time(&setup.end) - parse_time(&setup.start)) as i64);
+            if actual != expected {
+                return Err(format!(
+                    "setup time mismatch at stop with arrival '{}' in tour '{}': expected {}, got {}",
+                    stop.time.arrival, tour.vehicle_id, expected, actual
+                )
+                .into());
+            }
+
+            let setup_end = stop.setup.as_ref().map_or(stop.time.arrival.as_str(), |setup| setup.end.as_str());
+            let setup_end = parse_time(setup_end);
+            let activity_start =
+                stop.activities.iter().find_map(|activity| activity.time.as_ref().map(|time| time.start.as_str()));
+
+            match activity_start.map(parse_time) {
+                Some(start) if start < setup_end => Err(format!(
+                    "activity starts before setup ends at stop with arrival '{}' in tour '{}'",
+                    stop.time.arrival, tour.vehicle_id
+                )
+                .into()),
+                _ => Ok(()),
+            }
+        })
+    })
+}
+
 fn check_groups(ctx: &CheckerContext) -> Result<(), GenericError> {
     let violations = ctx
         .solution
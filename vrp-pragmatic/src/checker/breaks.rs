@@ -9,7 +9,7 @@ use vrp_core::utils::GenericError;
 
 /// Checks that breaks are properly assigned.
 pub fn check_breaks(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_break_assignment(context)])
+    combine_error_results(&[check_break_assignment(context), check_driving_time(context)])
 }
 
 fn check_break_assignment(context: &CheckerContext) -> Result<(), GenericError> {
@@ -20,11 +20,14 @@ fn check_break_assignment(context: &CheckerContext) -> Result<(), GenericError>
             .iter()
             .flat_map(|stop| stop.activities().iter())
             .filter(|activity| activity.activity_type == "break")
-            .count();
+            .count()
+            - get_driving_break_count(context, tour);
         let matched_break_count = tour.stops.iter().try_fold(0, |acc, stop| {
             stop.activities()
                 .windows(stop.activities().len().min(2))
                 .flat_map(|leg| as_leg_info_with_break(context, tour, stop, leg))
+                // NOTE driving breaks are checked separately
+                .filter(|(_, _, (_, vehicle_break))| !matches!(vehicle_break, VehicleBreak::Driving { .. }))
                 .try_fold::<_, _, Result<_, GenericError>>(
                     acc,
                     |acc, (from_loc, (from, to), (break_activity, vehicle_break))| {
@@ -55,6 +58,7 @@ fn check_break_assignment(context: &CheckerContext) -> Result<(), GenericError>
                                 None => from_loc == actual_loc || backward_loc == actual_loc,
                             }),
                             VehicleBreak::Required { .. } => actual_loc.is_none() || from_loc == actual_loc,
+                            VehicleBreak::Driving { .. } => true,
                         };
 
                         if !has_match {
@@ -108,6 +112,8 @@ fn check_break_assignment(context: &CheckerContext) -> Result<(), GenericError>
                         // NOTE: skip break if its end time is after tour end
                         break_tw.intersects(&tour_tw) && break_tw.end < tour_tw.end
                     }
+                    // NOTE driving breaks are checked separately
+                    VehicleBreak::Driving { .. } => false,
                 };
 
                 if should_assign {
@@ -193,9 +199,87 @@ pub(crate) fn get_break_time_window(tour: &Tour, vehicle_break: &VehicleBreak) -
 
             Ok(TimeWindow::new(start, end + duration))
         }
+        VehicleBreak::Driving { .. } => {
+            let arrival = tour
+                .stops
+                .last()
+                .map(|stop| parse_time(&stop.schedule().arrival))
+                .ok_or_else(|| format!("cannot get arrival time for tour: '{}'", tour.vehicle_id))?;
+
+            Ok(TimeWindow::new(departure, arrival))
+        }
     }
 }
 
+/// Checks that vehicle doesn't drive longer than allowed without a break.
+fn check_driving_time(context: &CheckerContext) -> Result<(), GenericError> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let vehicle_shift = context.get_vehicle_shift(tour)?;
+        let Some((max_driving_time, duration, split)) =
+            vehicle_shift.breaks.iter().flat_map(|breaks| breaks.iter()).find_map(|vehicle_break| match vehicle_break {
+                VehicleBreak::Driving { max_driving_time, duration, split } => {
+                    Some((*max_driving_time, *duration, split.clone()))
+                }
+                _ => None,
+            })
+        else {
+            return Ok(());
+        };
+
+        let (first_part, second_part) = split
+            .as_ref()
+            .and_then(|split| split.first().zip(split.last()))
+            .map_or((duration, duration), |(first, second)| (*first, *second));
+
+        tour.stops.iter().enumerate().try_fold(
+            (None, 0., false),
+            |(prev_departure, driving, is_pending), (stop_idx, stop)| {
+                let schedule = stop.schedule();
+                let driving = driving + prev_departure.map_or(0., |departure| parse_time(&schedule.arrival) - departure);
+
+                if driving > max_driving_time {
+                    return Err(format!(
+                        "driving time limit is exceeded at stop {stop_idx} for vehicle '{}': {driving} > {max_driving_time}",
+                        tour.vehicle_id
+                    ));
+                }
+
+                let (driving, is_pending) = stop
+                    .activities()
+                    .iter()
+                    .filter(|activity| activity.activity_type == "break")
+                    .map(|activity| context.get_activity_time(stop, activity).duration())
+                    .fold((driving, is_pending), |(driving, is_pending), break_duration| {
+                        let is_reset = break_duration >= duration || (is_pending && break_duration >= second_part);
+
+                        match (is_reset, break_duration >= first_part) {
+                            (true, _) => (0., false),
+                            (false, true) => (driving, true),
+                            (false, false) => (driving, is_pending),
+                        }
+                    });
+
+                Ok((Some(parse_time(&schedule.departure)), driving, is_pending))
+            },
+        )?;
+
+        Ok(())
+    })
+}
+
+fn get_driving_break_count(context: &CheckerContext, tour: &Tour) -> usize {
+    tour.stops
+        .iter()
+        .flat_map(|stop| {
+            stop.activities()
+                .iter()
+                .filter(|activity| activity.activity_type == "break")
+                .filter_map(move |activity| context.get_activity_type(tour, stop, activity).ok())
+        })
+        .filter(|activity_type| matches!(activity_type, ActivityType::Break(VehicleBreak::Driving { .. })))
+        .count()
+}
+
 fn get_break_violation_count(solution: &Solution, tour: &Tour) -> usize {
     solution.violations.as_ref().map_or(0, |violations| {
         violations
@@ -8,7 +8,7 @@ use std::iter::once;
 use vrp_core::models::common::{Load, MultiDimLoad};
 
 /// Checks that vehicle load is assigned correctly. The following rules are checked:
-/// * max vehicle's capacity is not violated
+/// * max vehicle's capacity (including trailer's one) is not violated
 /// * load change is correct
 pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
     combine_error_results(&[check_vehicle_load_assignment(context), check_resource_consumption(context)])
@@ -16,7 +16,9 @@ pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), Vec<GenericErr
 
 fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), GenericError> {
     context.solution.tours.iter().try_for_each::<_, Result<_, GenericError>>(|tour| {
-        let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.capacity.clone());
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let capacity = MultiDimLoad::new(vehicle.capacity.clone())
+            + vehicle.trailer.as_ref().map_or_else(MultiDimLoad::default, |t| MultiDimLoad::new(t.capacity.clone()));
         let intervals = get_intervals(context, tour);
 
         intervals
@@ -96,8 +98,9 @@ fn check_resource_consumption(context: &CheckerContext) -> Result<(), GenericErr
         .resources
         .iter()
         .flat_map(|resources| resources.iter().cloned())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, capacity } => (id, MultiDimLoad::new(capacity)),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, capacity } => Some((id, MultiDimLoad::new(capacity))),
+            VehicleResource::Dock { .. } | VehicleResource::Satellite { .. } => None,
         })
         .collect::<HashMap<_, _>>();
 
@@ -157,7 +160,7 @@ fn check_resource_consumption(context: &CheckerContext) -> Result<(), GenericErr
     })
 }
 
-enum DemandType {
+pub(super) enum DemandType {
     None,
     StaticPickup,
     StaticDelivery,
@@ -166,7 +169,7 @@ enum DemandType {
     DynamicDelivery,
 }
 
-fn get_demand(
+pub(super) fn get_demand(
     context: &CheckerContext,
     activity: &Activity,
     activity_type: &ActivityType,
@@ -177,7 +180,12 @@ fn get_demand(
         |job, task| {
             let is_dynamic = job.pickups.as_ref().map_or(false, |p| !p.is_empty())
                 && job.deliveries.as_ref().map_or(false, |p| !p.is_empty());
-            let demand = task.demand.clone().map_or_else(MultiDimLoad::default, MultiDimLoad::new);
+            // NOTE a visit of the splittable job serves only a part of the task demand
+            let demand = activity
+                .quantity
+                .clone()
+                .or_else(|| task.demand.clone())
+                .map_or_else(MultiDimLoad::default, MultiDimLoad::new);
 
             (is_dynamic, demand)
         },
@@ -0,0 +1,104 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/compartments_test.rs"]
+mod compartments_test;
+
+use super::*;
+use crate::utils::combine_error_results;
+
+/// Checks that vehicle compartments are used correctly. The following rules are checked:
+/// * compartments are reported for vehicles which have them
+/// * compartment load does not exceed its capacity
+/// * compartment product type is changed only at reload
+pub fn check_compartments(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
+    combine_error_results(&[check_compartment_usage(context)])
+}
+
+fn check_compartment_usage(context: &CheckerContext) -> Result<(), GenericError> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let compartments = vehicle.compartments.as_ref().filter(|compartments| !compartments.is_empty());
+
+        tour.stops.iter().filter_map(|stop| stop.as_point()).try_fold(
+            None,
+            |prev: Option<&Vec<CompartmentLoad>>, stop| -> Result<_, GenericError> {
+                let usage = match (compartments, stop.compartments.as_ref()) {
+                    (None, None) => return Ok(None),
+                    (Some(compartments), Some(usage))
+                        if compartments.len() == usage.len()
+                            && compartments.iter().zip(usage.iter()).all(|(c, u)| c.id == u.id) =>
+                    {
+                        usage
+                    }
+                    _ => {
+                        return Err(format!(
+                            "compartments usage does not match vehicle compartments in tour '{}'",
+                            tour.vehicle_id
+                        )
+                        .into())
+                    }
+                };
+
+                let compartments = compartments.expect("compartments are checked above");
+                if let Some(usage) = compartments.iter().zip(usage.iter()).find(|(c, u)| u.load > c.capacity) {
+                    return Err(format!(
+                        "load exceeds capacity of compartment '{}' in tour '{}'",
+                        usage.1.id, tour.vehicle_id
+                    )
+                    .into());
+                }
+
+                let is_reload = stop.activities.iter().any(|activity| activity.activity_type == "reload");
+                let has_product_change = prev.map_or(false, |prev| {
+                    prev.iter().zip(usage.iter()).any(|(prev, current)| match (&prev.product, &current.product) {
+                        (Some(prev), Some(current)) => prev != current,
+                        _ => false,
+                    })
+                });
+
+                if has_product_change && !is_reload {
+                    return Err(format!(
+                        "compartment product type is changed without reload in tour '{}'",
+                        tour.vehicle_id
+                    )
+                    .into());
+                }
+
+                Ok(Some(usage))
+            },
+        )?;
+
+        Ok(())
+    })
+}
+
+/// Returns cleaning duration of compartments which change product type at given stop.
+pub(crate) fn get_cleaning_duration(context: &CheckerContext, tour: &Tour, stop: &PointStop) -> f64 {
+    let compartments = match context.get_vehicle(&tour.vehicle_id).ok().and_then(|v| v.compartments.as_ref()) {
+        Some(compartments) => compartments,
+        None => return 0.,
+    };
+
+    let mut last_products = vec![None; compartments.len()];
+
+    for point in tour.stops.iter().filter_map(|stop| stop.as_point()) {
+        let usage = point.compartments.iter().flat_map(|usage| usage.iter());
+
+        if std::ptr::eq(point, stop) {
+            return compartments
+                .iter()
+                .zip(usage.zip(last_products.iter()))
+                .filter(|(_, (current, last))| match (&current.product, last) {
+                    (Some(current), Some(last)) => current != *last,
+                    _ => false,
+                })
+                .map(|(compartment, _)| compartment.cleaning_duration.unwrap_or(0.))
+                .sum();
+        }
+
+        usage.zip(last_products.iter_mut()).filter(|(usage, _)| usage.product.is_some()).for_each(|(usage, last)| {
+            *last = usage.product.as_ref();
+        });
+    }
+
+    0.
+}
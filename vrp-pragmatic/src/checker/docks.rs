@@ -0,0 +1,96 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/docks_test.rs"]
+mod docks_test;
+
+use super::*;
+use crate::utils::combine_error_results;
+
+/// Checks that docks are used correctly: amount of vehicles served simultaneously at the dock
+/// does not exceed its amount of slots.
+pub fn check_docks(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
+    combine_error_results(&[check_dock_slots(context)])
+}
+
+fn check_dock_slots(context: &CheckerContext) -> Result<(), GenericError> {
+    let slots = context
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Dock { id, slots } => Some((id.clone(), *slots)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    if slots.is_empty() {
+        return Ok(());
+    }
+
+    let mut intervals = HashMap::<String, Vec<(f64, f64)>>::new();
+    context.solution.tours.iter().try_for_each(|tour| {
+        tour.stops.iter().try_for_each(|stop| {
+            stop.activities().iter().try_for_each(|activity| {
+                let location = context.get_activity_location(stop, activity);
+                let activity_type = context.get_activity_type(tour, stop, activity)?;
+
+                let dock = match &activity_type {
+                    ActivityType::Job(_) => context.visit_job(
+                        activity,
+                        &activity_type,
+                        |_, task| {
+                            task.places
+                                .iter()
+                                .find(|place| {
+                                    Some(&place.location) == location.as_ref() && place.tag == activity.job_tag
+                                })
+                                .and_then(|place| place.dock.clone().map(|dock| (dock, place.duration)))
+                        },
+                        || None,
+                    )?,
+                    ActivityType::Reload(reload) => reload.dock.clone().map(|dock| (dock, reload.duration)),
+                    _ => None,
+                };
+
+                if let Some((dock, duration)) = dock {
+                    // NOTE service start is derived from departure as activity time can include waiting
+                    let departure = get_time_window(stop, activity).end;
+                    intervals.entry(dock).or_default().push((departure - duration, departure));
+                }
+
+                Ok::<_, GenericError>(())
+            })
+        })
+    })?;
+
+    intervals.into_iter().try_for_each(|(dock, intervals)| {
+        let slots = slots.get(&dock).cloned().ok_or_else(|| format!("cannot find dock with id '{dock}'"))?;
+
+        let mut events = intervals
+            .into_iter()
+            .filter(|(start, end)| end > start)
+            .flat_map(|(start, end)| [(start, 1), (end, -1)])
+            .collect::<Vec<_>>();
+        // NOTE departures go first to allow the next vehicle to start at the same time
+        events.sort_by(|(a_time, a_delta), (b_time, b_delta)| a_time.total_cmp(b_time).then(a_delta.cmp(b_delta)));
+
+        let max_used = events
+            .into_iter()
+            .scan(0_i64, |used, (_, delta)| {
+                *used += delta;
+                Some(*used)
+            })
+            .max()
+            .unwrap_or(0);
+
+        if max_used > slots as i64 {
+            Err(format!(
+                "dock slots violation, expected: not more than {slots} vehicles at dock '{dock}', got: {max_used}"
+            )
+            .into())
+        } else {
+            Ok(())
+        }
+    })
+}
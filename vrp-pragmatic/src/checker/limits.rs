@@ -4,10 +4,17 @@ mod limits_test;
 
 use super::*;
 use crate::utils::combine_error_results;
+use vrp_core::construction::features::RideDurationLimit;
 
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_shift_limits(context), check_shift_time(context)])
+    combine_error_results(&[
+        check_shift_limits(context),
+        check_trip_limits(context),
+        check_shift_time(context),
+        check_ride_durations(context),
+        check_time_in_vehicle(context),
+    ])
 }
 
 /// Check that shift limits are not violated:
@@ -56,6 +63,72 @@ fn check_shift_limits(context: &CheckerContext) -> Result<(), GenericError> {
     })
 }
 
+/// Checks that trip limits are not violated. Trips are separated by reload activities:
+/// * max trip distance
+/// * max trip duration
+/// * max trip size
+fn check_trip_limits(context: &CheckerContext) -> Result<(), GenericError> {
+    context.solution.tours.iter().try_for_each::<_, Result<_, GenericError>>(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let Some(limits) = vehicle.limits.as_ref().and_then(|limits| limits.trip.as_ref()) else {
+            return Ok(());
+        };
+
+        get_trips(context, tour).into_iter().enumerate().try_for_each(|(trip_idx, (distance, duration, size))| {
+            let violation = [
+                ("max trip distance", limits.max_distance, distance),
+                ("max trip duration", limits.max_duration, duration),
+                ("trip size", limits.size.map(|size| size as f64), size as f64),
+            ]
+            .into_iter()
+            .find(|(_, limit, actual)| limit.map_or(false, |limit| *actual > limit));
+
+            match violation {
+                Some((name, Some(limit), actual)) => Err(format!(
+                    "{} limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}, trip index: {}",
+                    name, limit, actual, tour.vehicle_id, tour.shift_index, trip_idx
+                )
+                .into()),
+                _ => Ok(()),
+            }
+        })
+    })
+}
+
+/// Returns distance, duration and amount of job activities for each trip of the tour.
+fn get_trips(context: &CheckerContext, tour: &Tour) -> Vec<(f64, f64, usize)> {
+    let mut trips = Vec::default();
+    let (mut start_distance, mut start_time, mut size, mut distance) = (0, 0., 0, 0);
+
+    tour.stops.iter().for_each(|stop| {
+        distance = stop.as_point().map_or(distance, |point| point.distance);
+
+        stop.activities().iter().enumerate().for_each(|(idx, activity)| {
+            // NOTE activity arrival is either stop arrival or departure from previous activity
+            let arrival = match idx {
+                0 => parse_time(&stop.schedule().arrival),
+                _ => context.get_activity_time(stop, &stop.activities()[idx - 1]).end,
+            };
+
+            match activity.activity_type.as_str() {
+                "departure" => start_time = context.get_activity_time(stop, activity).end,
+                "reload" => {
+                    trips.push(((distance - start_distance) as f64, arrival - start_time, size));
+                    (start_distance, start_time, size) = (distance, context.get_activity_time(stop, activity).end, 0);
+                }
+                "arrival" => {}
+                _ => size += 1,
+            }
+        });
+    });
+
+    if let Some(last) = tour.stops.last() {
+        trips.push(((distance - start_distance) as f64, parse_time(&last.schedule().departure) - start_time, size));
+    }
+
+    trips
+}
+
 fn check_shift_time(context: &CheckerContext) -> Result<(), GenericError> {
     context.solution.tours.iter().try_for_each::<_, Result<_, GenericError>>(|tour| {
         let vehicle = context.get_vehicle(&tour.vehicle_id)?;
@@ -87,3 +160,127 @@ fn check_shift_time(context: &CheckerContext) -> Result<(), GenericError> {
         }
     })
 }
+
+/// Checks that max ride duration of pickup and delivery jobs is not violated.
+fn check_ride_durations(context: &CheckerContext) -> Result<(), GenericError> {
+    let has_ride_limit = |job_id: &String| {
+        context.get_job_by_id(job_id).map_or(false, |job| job.max_ride_duration.or(job.max_ride_factor).is_some())
+    };
+
+    context.solution.tours.iter().try_for_each::<_, Result<_, GenericError>>(|tour| {
+        let rides = tour
+            .stops
+            .iter()
+            .flat_map(|stop| {
+                stop.activities().iter().enumerate().map(move |(idx, activity)| {
+                    // NOTE activity arrival is either stop arrival or departure from previous activity
+                    let arrival = match idx {
+                        0 => parse_time(&stop.schedule().arrival),
+                        _ => context.get_activity_time(stop, &stop.activities()[idx - 1]).end,
+                    };
+
+                    (stop, activity, arrival)
+                })
+            })
+            .filter(|(_, activity, _)| has_ride_limit(&activity.job_id))
+            .fold(HashMap::<_, Vec<_>>::default(), |mut acc, (stop, activity, arrival)| {
+                acc.entry(activity.job_id.clone()).or_default().push((stop, activity, arrival));
+                acc
+            });
+
+        rides.into_iter().try_for_each(|(job_id, activities)| {
+            let job = context.get_job_by_id(&job_id).ok_or_else(|| format!("cannot find job '{job_id}'"))?;
+            let (first_stop, first, _) = activities.first().ok_or("empty job activities")?;
+            let (last_stop, last, arrival) = activities.last().ok_or("empty job activities")?;
+
+            let departure = context.get_activity_time(first_stop, first).end;
+            let ride_duration = arrival - departure;
+
+            if last.ride_duration != Some(ride_duration.round() as i64) {
+                return Err(format!(
+                    "ride duration mismatch for job '{}', expected: {}, got: {:?}, vehicle id '{}'",
+                    job_id, ride_duration, last.ride_duration, tour.vehicle_id
+                )
+                .into());
+            }
+
+            let direct_duration = if job.max_ride_factor.is_some() {
+                let profile = context.get_vehicle_profile(&tour.vehicle_id)?;
+                let get_location_index = |stop, activity| {
+                    context
+                        .get_activity_location(stop, activity)
+                        .ok_or_else(|| GenericError::from(format!("cannot get location of job '{job_id}'")))
+                        .and_then(|location| context.get_location_index(&location))
+                };
+                let from_idx = get_location_index(first_stop, first)?;
+                let to_idx = get_location_index(last_stop, last)?;
+
+                context.get_matrix_data(&profile, from_idx, to_idx)?.1 as f64
+            } else {
+                0.
+            };
+
+            let limit = RideDurationLimit { max_duration: job.max_ride_duration, max_factor: job.max_ride_factor }
+                .get_limit(direct_duration);
+
+            match limit {
+                Some(limit) if ride_duration > limit => Err(format!(
+                    "max ride duration violation for job '{}', expected: not more than {}, got: {}, vehicle id '{}'",
+                    job_id, limit, ride_duration, tour.vehicle_id
+                )
+                .into()),
+                _ => Ok(()),
+            }
+        })
+    })
+}
+
+/// Checks that max time in vehicle of deliveries is not violated: it is measured from departure of
+/// the trip start (shift start or reload) till service start.
+fn check_time_in_vehicle(context: &CheckerContext) -> Result<(), GenericError> {
+    context.solution.tours.iter().try_for_each::<_, Result<_, GenericError>>(|tour| {
+        let mut trip_start = 0.;
+
+        tour.stops.iter().flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity))).try_for_each(
+            |(stop, activity)| {
+                let time = context.get_activity_time(stop, activity);
+
+                match activity.activity_type.as_str() {
+                    "departure" | "reload" => {
+                        trip_start = time.end;
+                        return Ok(());
+                    }
+                    "delivery" => {}
+                    _ => return Ok(()),
+                }
+
+                let activity_type = context.get_activity_type(tour, stop, activity)?;
+                let limit = context.visit_job(activity, &activity_type, |_, task| task.max_time_in_vehicle, || None)?;
+
+                let Some(limit) = limit else {
+                    return Ok(());
+                };
+
+                let time_in_vehicle = time.start - trip_start;
+
+                if activity.time_in_vehicle != Some(time_in_vehicle.round() as i64) {
+                    return Err(format!(
+                        "time in vehicle mismatch for job '{}', expected: {}, got: {:?}, vehicle id '{}'",
+                        activity.job_id, time_in_vehicle, activity.time_in_vehicle, tour.vehicle_id
+                    )
+                    .into());
+                }
+
+                if time_in_vehicle > limit {
+                    return Err(format!(
+                        "max time in vehicle violation for job '{}', expected: not more than {}, got: {}, vehicle id '{}'",
+                        activity.job_id, limit, time_in_vehicle, tour.vehicle_id
+                    )
+                    .into());
+                }
+
+                Ok(())
+            },
+        )
+    })
+}
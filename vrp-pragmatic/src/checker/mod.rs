@@ -5,6 +5,7 @@
 #[path = "../../tests/unit/checker/checker_test.rs"]
 mod checker_test;
 
+use crate::construction::enablers::{DriverTie, VehicleTie};
 use crate::format::problem::*;
 use crate::format::solution::*;
 use crate::format::{CoordIndex, Location};
@@ -14,7 +15,8 @@ use std::sync::Arc;
 use vrp_core::construction::clustering::vicinity::ClusterConfig;
 use vrp_core::construction::clustering::vicinity::VisitPolicy;
 use vrp_core::models::common::{Duration, Profile, TimeWindow};
-use vrp_core::models::solution::{Commute as DomainCommute, CommuteInfo as DomainCommuteInfo};
+use vrp_core::models::problem::{Actor, ServiceDurationDimension, Single};
+use vrp_core::models::solution::{Commute as DomainCommute, CommuteInfo as DomainCommuteInfo, Place};
 use vrp_core::models::Problem as CoreProblem;
 use vrp_core::prelude::GenericError;
 use vrp_core::solver::processing::VicinityDimension;
@@ -38,10 +40,11 @@ pub struct CheckerContext {
 /// Represents all possible activity types.
 enum ActivityType {
     Terminal,
-    Job(Job),
+    Job(Box<Job>),
     Break(VehicleBreak),
     Reload(VehicleReload),
     Recharge(VehicleRechargeStation),
+    TrailerPark(VehicleTrailerPark),
 }
 
 impl CheckerContext {
@@ -52,7 +55,7 @@ impl CheckerContext {
         matrices: Option<Vec<Matrix>>,
         solution: Solution,
     ) -> Result<Self, Vec<GenericError>> {
-        let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
+        let job_map = get_job_map(&problem, &solution);
         let clustering = core_problem.extras.get_cluster_config().cloned();
         let coord_index = CoordIndex::new(&problem);
         let profile_index = if matrices.is_none() {
@@ -72,11 +75,17 @@ impl CheckerContext {
         let (_, errors) = check_vehicle_load(self)
             .err()
             .into_iter()
+            .chain(check_compartments(self).err())
             .chain(check_relations(self).err())
             .chain(check_breaks(self).err())
             .chain(check_assignment(self).err())
             .chain(check_routing(self).err())
             .chain(check_limits(self).err())
+            .chain(check_docks(self).err())
+            .chain(check_zones(self).err())
+            .chain(check_recharges(self).err())
+            .chain(check_trailers(self).err())
+            .chain(check_satellites(self).err())
             .flatten()
             .fold((HashSet::new(), Vec::default()), |(mut used, mut errors), error| {
                 if !used.contains(&error) {
@@ -104,6 +113,24 @@ impl CheckerContext {
             .ok_or_else(|| format!("cannot find vehicle with id '{vehicle_id}'").into())
     }
 
+    /// Gets core actor used by the tour.
+    fn get_actor(&self, tour: &Tour) -> Option<&Arc<Actor>> {
+        self.core_problem.fleet.actors.iter().find(|actor| {
+            actor.vehicle.dimens.get_vehicle_id() == Some(&tour.vehicle_id)
+                && actor.vehicle.dimens.get_shift_index() == Some(tour.shift_index)
+                && tour.driver_id.as_ref().map_or(true, |id| actor.driver.dimens.get_driver_id() == Some(id))
+        })
+    }
+
+    /// Gets service duration of the job place when it is served by the tour's vehicle.
+    fn get_service_duration(&self, tour: &Tour, single: &Single, place: &Place) -> Duration {
+        single
+            .dimens
+            .get_service_duration_fn(place.idx)
+            .zip(self.get_actor(tour))
+            .map_or(place.duration, |(duration_fn, actor)| duration_fn(actor.as_ref(), place.duration))
+    }
+
     fn get_vehicle_profile(&self, vehicle_id: &str) -> Result<Profile, GenericError> {
         let profile = &self.get_vehicle(vehicle_id)?.profile;
         let index = self
@@ -177,7 +204,7 @@ impl CheckerContext {
             "pickup" | "delivery" | "service" | "replacement" => {
                 self.job_map.get(activity.job_id.as_str()).map_or_else(
                     || Err(format!("cannot find job with id '{}'", activity.job_id).into()),
-                    |job| Ok(ActivityType::Job(job.clone())),
+                    |job| Ok(ActivityType::Job(Box::new(job.clone()))),
                 )
             }
 
@@ -216,12 +243,24 @@ impl CheckerContext {
                 .map(|r| ActivityType::Recharge(r.clone()))
                 .ok_or_else(|| format!("cannot find recharge for tour '{}'", tour.vehicle_id).into()),
 
+            "decouple" | "couple" => self
+                .get_vehicle(&tour.vehicle_id)?
+                .trailer
+                .as_ref()
+                .and_then(|trailer| {
+                    trailer.parks.iter().find(|p| {
+                        location.as_ref().map_or(false, |location| p.location == *location) && p.tag == activity.job_tag
+                    })
+                })
+                .map(|p| ActivityType::TrailerPark(p.clone()))
+                .ok_or_else(|| format!("cannot find trailer park for tour '{}'", tour.vehicle_id).into()),
+
             _ => Err(format!("unknown activity type: '{}'", activity.activity_type).into()),
         }
     }
 
     fn get_job_by_id(&self, job_id: &str) -> Option<&Job> {
-        self.problem.plan.jobs.iter().find(|job| job.id == job_id)
+        self.job_map.get(job_id)
     }
 
     fn get_commute_info(
@@ -375,6 +414,47 @@ impl CheckerContext {
     }
 }
 
+/// Creates a map of jobs where job with alternatives is represented by its served variant and
+/// transferred job by tasks of both its legs.
+fn get_job_map(problem: &Problem, solution: &Solution) -> HashMap<String, Job> {
+    let activities = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .filter_map(|stop| match stop {
+            Stop::Point(point) => Some((point, stop.activities())),
+            Stop::Transit(_) => None,
+        })
+        .flat_map(|(stop, activities)| {
+            activities.iter().map(move |activity| {
+                let location = activity.location.as_ref().unwrap_or(&stop.location);
+                (activity.job_id.as_str(), (activity.activity_type.as_str(), location, activity.job_tag.as_ref()))
+            })
+        })
+        .fold(HashMap::<_, Vec<_>>::new(), |mut acc, (job_id, activity)| {
+            acc.entry(job_id).or_default().push(activity);
+            acc
+        });
+
+    problem
+        .plan
+        .jobs
+        .iter()
+        .map(|job| {
+            let job = activities
+                .get(job.id.as_str())
+                .and_then(|activities| {
+                    get_transferred_job(job, activities.as_slice())
+                        .or_else(|| get_served_job_variant(job, activities.as_slice()))
+                })
+                .or_else(|| get_served_job_variant(job, &[]))
+                .unwrap_or_else(|| job.clone());
+
+            (job.id.clone(), job)
+        })
+        .collect()
+}
+
 fn job_task_size(tasks: &Option<Vec<JobTask>>) -> usize {
     tasks.as_ref().map_or(0, |p| p.len())
 }
@@ -454,7 +534,19 @@ mod assignment;
 use crate::checker::assignment::check_assignment;
 
 mod capacity;
-use crate::checker::capacity::check_vehicle_load;
+use crate::checker::capacity::{check_vehicle_load, get_demand, DemandType};
+
+mod compartments;
+use crate::checker::compartments::{check_compartments, get_cleaning_duration};
+
+mod docks;
+use crate::checker::docks::check_docks;
+
+mod zones;
+use crate::checker::zones::check_zones;
+
+mod recharge;
+use crate::checker::recharge::{check_recharges, get_charging_duration};
 
 mod limits;
 use crate::checker::limits::check_limits;
@@ -467,3 +559,9 @@ use crate::checker::relations::check_relations;
 
 mod routing;
 use crate::checker::routing::check_routing;
+
+mod trailers;
+use crate::checker::trailers::check_trailers;
+
+mod satellites;
+use crate::checker::satellites::check_satellites;
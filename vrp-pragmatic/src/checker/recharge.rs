@@ -0,0 +1,142 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/recharge_test.rs"]
+mod recharge_test;
+
+use super::*;
+use crate::utils::combine_error_results;
+
+const SOC_TOLERANCE: f64 = 1E-6;
+
+/// Checks that vehicle battery state of charge is tracked correctly: it never goes below reserve
+/// and grows only at recharge stations with respect to their charging power.
+pub fn check_recharges(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
+    combine_error_results(&[check_state_of_charge(context)])
+}
+
+fn check_state_of_charge(context: &CheckerContext) -> Result<(), GenericError> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let Some(battery) = get_battery(context, tour) else {
+            return Ok(());
+        };
+
+        let reserve = battery.reserve.unwrap_or(0.);
+        let point_stops = tour.stops.iter().filter_map(|stop| stop.as_point().map(|point| (stop, point)));
+
+        point_stops.enumerate().try_fold(None, |prev, (stop_idx, (stop, point))| {
+            let soc = point.state_of_charge.ok_or_else(|| {
+                format!("no state of charge at stop {stop_idx} for vehicle '{}'", tour.vehicle_id)
+            })?;
+            let arrival = get_arrival_soc(&battery, vehicle, prev, point);
+
+            if arrival < reserve - SOC_TOLERANCE {
+                return Err(format!(
+                    "state of charge is below reserve at stop {stop_idx} for vehicle '{}': {arrival} < {reserve}",
+                    tour.vehicle_id
+                )
+                .into());
+            }
+
+            let station = stop.activities().iter().try_fold(None, |acc, activity| {
+                context.get_activity_type(tour, stop, activity).map(|activity_type| match activity_type {
+                    ActivityType::Recharge(station) => Some((station, activity)),
+                    _ => acc,
+                })
+            })?;
+
+            match station {
+                Some((station, activity)) => {
+                    if soc < arrival - SOC_TOLERANCE || soc > battery.capacity + SOC_TOLERANCE {
+                        return Err(format!(
+                            "invalid state of charge after recharge at stop {stop_idx} for vehicle '{}': {soc}",
+                            tour.vehicle_id
+                        )
+                        .into());
+                    }
+
+                    if let Some(power) = station.power {
+                        let time = context.get_activity_time(stop, activity);
+                        let expected = station.duration + (soc - arrival) / power;
+                        if time.duration() < expected - SOC_TOLERANCE {
+                            return Err(format!(
+                                "charging time is too short at stop {stop_idx} for vehicle '{}': {} < {expected}",
+                                tour.vehicle_id,
+                                time.duration()
+                            )
+                            .into());
+                        }
+                    }
+                }
+                None if (soc - arrival).abs() > SOC_TOLERANCE => {
+                    return Err(format!(
+                        "unexpected state of charge at stop {stop_idx} for vehicle '{}', expected: {arrival}, got: {soc}",
+                        tour.vehicle_id
+                    )
+                    .into());
+                }
+                None => {}
+            }
+
+            Ok::<_, GenericError>(Some(point))
+        })?;
+
+        Ok(())
+    })
+}
+
+/// Returns charging duration at recharge station visited at given stop.
+pub(crate) fn get_charging_duration(context: &CheckerContext, tour: &Tour, stop: &PointStop) -> f64 {
+    let (Some(battery), Ok(vehicle)) = (get_battery(context, tour), context.get_vehicle(&tour.vehicle_id)) else {
+        return 0.;
+    };
+
+    let power = context.get_vehicle_shift(tour).ok().and_then(|shift| {
+        shift.recharges?.stations.into_iter().find(|station| station.location == stop.location)?.power
+    });
+
+    let Some(power) = power else {
+        return 0.;
+    };
+
+    let mut prev = None;
+    for point in tour.stops.iter().filter_map(|stop| stop.as_point()) {
+        if std::ptr::eq(point, stop) {
+            let arrival = get_arrival_soc(&battery, vehicle, prev, point);
+            return point.state_of_charge.map_or(0., |soc| ((soc - arrival) / power).max(0.));
+        }
+
+        prev = Some(point);
+    }
+
+    0.
+}
+
+fn get_battery(context: &CheckerContext, tour: &Tour) -> Option<VehicleBattery> {
+    context.get_vehicle_shift(tour).ok()?.recharges?.battery
+}
+
+/// Returns state of charge at arrival to the stop.
+fn get_arrival_soc(
+    battery: &VehicleBattery,
+    vehicle: &VehicleType,
+    prev: Option<&PointStop>,
+    point: &PointStop,
+) -> f64 {
+    let Some(prev) = prev else {
+        return battery.initial.unwrap_or(battery.capacity);
+    };
+
+    let load_rate = battery.consumption_loaded.map_or(0., |loaded| (loaded - battery.consumption).max(0.));
+    let load_ratio = get_load_ratio(prev.load.as_slice(), vehicle.capacity.as_slice());
+    let distance = (point.distance - prev.distance) as f64;
+
+    prev.state_of_charge.unwrap_or_default() - distance * (battery.consumption + load_rate * load_ratio)
+}
+
+fn get_load_ratio(load: &[i32], capacity: &[i32]) -> f64 {
+    load.iter()
+        .zip(capacity.iter())
+        .filter(|(_, capacity)| **capacity > 0)
+        .fold(0_f64, |acc, (load, capacity)| acc.max(*load as f64 / *capacity as f64))
+        .clamp(0., 1.)
+}
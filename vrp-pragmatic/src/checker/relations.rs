@@ -4,11 +4,17 @@ mod relations_test;
 
 use super::*;
 use crate::utils::combine_error_results;
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
+use std::cmp::Ordering;
+use vrp_core::prelude::compare_floats;
 
 /// Checks relation rules.
 pub fn check_relations(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_relations_assignment(context)])
+    combine_error_results(&[
+        check_relations_assignment(context),
+        check_schedule_relations(context),
+        check_transfers(context),
+    ])
 }
 
 fn check_relations_assignment(context: &CheckerContext) -> Result<(), GenericError> {
@@ -16,8 +22,9 @@ fn check_relations_assignment(context: &CheckerContext) -> Result<(), GenericErr
 
     (0_usize..)
         .zip(context.problem.plan.relations.as_ref().map_or([].iter(), |relations| relations.iter()))
-        .try_for_each(|(idx, relation)| {
-            let tour = get_tour_by_vehicle_id(&relation.vehicle_id, relation.shift_index, &context.solution);
+        .filter_map(|(idx, relation)| relation.vehicle_id.as_ref().map(|vehicle_id| (idx, vehicle_id, relation)))
+        .try_for_each(|(idx, vehicle_id, relation)| {
+            let tour = get_tour_by_vehicle_id(vehicle_id, relation.shift_index, &context.solution);
             // NOTE tour can be absent for tour relation
             let tour = if let Ok(tour) = tour {
                 tour
@@ -88,12 +95,140 @@ fn check_relations_assignment(context: &CheckerContext) -> Result<(), GenericErr
                         Ok(())
                     }
                 }
+                RelationType::Sync | RelationType::Precedence => Ok(()),
             }
         })?;
 
     Ok(())
 }
 
+/// Checks that service start of jobs in sync and precedence relations follows relation rules.
+fn check_schedule_relations(context: &CheckerContext) -> Result<(), GenericError> {
+    let relations = context.problem.plan.relations.as_ref().map_or([].iter(), |relations| relations.iter());
+    let job_ids = relations
+        .filter(|relation| matches!(relation.type_field, RelationType::Sync | RelationType::Precedence))
+        .flat_map(|relation| relation.jobs.iter())
+        .collect::<HashSet<_>>();
+
+    // NOTE jobs in such relations have exactly one task, so service times are found by job id
+    let schedules = context
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| job_ids.contains(&activity.job_id))
+        .filter_map(|(stop, activity)| {
+            let duration = context
+                .get_job_by_id(&activity.job_id)
+                .and_then(|job| get_job_tasks(job).next())
+                .and_then(|task| task.places.first())
+                .map(|place| place.duration)?;
+            let departure = get_time_window(stop, activity).end;
+
+            Some((activity.job_id.clone(), (departure - duration, departure)))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let is_greater = |left: f64, right: f64| compare_floats(left, right) == Ordering::Greater;
+
+    (0_usize..)
+        .zip(context.problem.plan.relations.as_ref().map_or([].iter(), |relations| relations.iter()))
+        .try_for_each(|(idx, relation)| {
+            let offset = relation.offset.unwrap_or(0.);
+            let assigned = relation.jobs.iter().filter_map(|job_id| schedules.get(job_id));
+
+            let is_violated = match relation.type_field {
+                RelationType::Sync => {
+                    let (min_start, max_start) =
+                        assigned.fold((f64::MAX, f64::MIN), |(min, max), &(start, _)| (min.min(start), max.max(start)));
+
+                    is_greater(max_start - min_start, offset)
+                }
+                RelationType::Precedence => {
+                    relation.jobs.windows(2).any(|pair| match (schedules.get(&pair[0]), schedules.get(&pair[1])) {
+                        (Some(&(_, prev_departure)), Some(&(next_start, _))) => {
+                            is_greater(prev_departure + offset, next_start)
+                        }
+                        _ => false,
+                    })
+                }
+                RelationType::Any | RelationType::Sequence | RelationType::Strict => false,
+            };
+
+            if is_violated {
+                Err(format!("relation {idx} does not follow {:?} rule: {:?}", relation.type_field, relation.jobs)
+                    .into())
+            } else {
+                Ok(())
+            }
+        })
+}
+
+/// Checks that transferred job is dropped at the transfer place by one tour and picked up there by
+/// another one not earlier than it is dropped.
+fn check_transfers(context: &CheckerContext) -> Result<(), GenericError> {
+    context.problem.plan.jobs.iter().filter_map(|job| job.transfers.as_ref().map(|places| (job, places))).try_for_each(
+        |(job, places)| {
+            let activities = context
+                .solution
+                .tours
+                .iter()
+                .enumerate()
+                .flat_map(|(tour_idx, tour)| tour.stops.iter().map(move |stop| (tour_idx, stop)))
+                .flat_map(|(tour_idx, stop)| stop.activities().iter().map(move |activity| (tour_idx, stop, activity)))
+                .filter(|(_, _, activity)| activity.job_id == job.id)
+                .collect::<Vec<_>>();
+
+            let is_at_place = |stop: &Stop, activity: &Activity, place: &JobPlace| {
+                let location = activity.location.as_ref().or_else(|| stop.as_point().map(|stop| &stop.location));
+
+                location == Some(&place.location)
+                    && activity.job_tag.as_ref().map_or(true, |tag| place.tag.as_ref() == Some(tag))
+            };
+            let Some(place) = places
+                .iter()
+                .find(|place| activities.iter().any(|(_, stop, activity)| is_at_place(stop, activity, place)))
+            else {
+                return Ok(());
+            };
+
+            let find_transfer = |activity_type: &str| {
+                activities.iter().find(|(_, stop, activity)| {
+                    activity.activity_type == activity_type && is_at_place(stop, activity, place)
+                })
+            };
+            let (Some(&(drop_tour, drop_stop, drop)), Some(&(pickup_tour, pickup_stop, pickup))) =
+                (find_transfer("delivery"), find_transfer("pickup"))
+            else {
+                return Err(
+                    format!("transferred job '{}' is not dropped and picked up at transfer place", job.id).into()
+                );
+            };
+
+            let is_proper_leg = |tour_idx: usize| {
+                let types = activities
+                    .iter()
+                    .filter(|(idx, ..)| *idx == tour_idx)
+                    .map(|(_, _, activity)| activity.activity_type.as_str())
+                    .collect::<Vec<_>>();
+                types == ["pickup", "delivery"]
+            };
+            if drop_tour == pickup_tour || !is_proper_leg(drop_tour) || !is_proper_leg(pickup_tour) {
+                return Err(format!("transferred job '{}' is not served by two tours properly", job.id).into());
+            }
+
+            let drop_departure = get_time_window(drop_stop, drop).end;
+            let pickup_start = get_time_window(pickup_stop, pickup).end - place.duration;
+            if compare_floats(drop_departure, pickup_start) == Ordering::Greater {
+                Err(format!("transferred job '{}' is picked up before it is dropped at transfer place", job.id).into())
+            } else {
+                Ok(())
+            }
+        },
+    )
+}
+
 fn get_tour_by_vehicle_id(
     vehicle_id: &str,
     shift_index: Option<usize>,
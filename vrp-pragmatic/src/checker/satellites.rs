@@ -0,0 +1,117 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/satellites_test.rs"]
+mod satellites_test;
+
+use super::*;
+use crate::utils::combine_error_results;
+use std::cmp::Ordering;
+use vrp_core::models::common::{Load, MultiDimLoad};
+use vrp_core::prelude::compare_floats;
+
+/// Checks that satellites are used correctly. The following rules are checked:
+/// * vehicles starting at the satellite deliver not more than supplied by jobs with satellite id
+/// * vehicles starting at the satellite depart only after all supply jobs are delivered
+pub fn check_satellites(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
+    combine_error_results(&[check_satellite_supply(context), check_satellite_departure(context)])
+}
+
+fn check_satellite_supply(context: &CheckerContext) -> Result<(), GenericError> {
+    get_satellites(context).into_iter().try_for_each(|(id, location)| {
+        let (supply, _) = get_supply(context, id)?;
+        let consumed = get_satellite_tours(context, location).try_fold(MultiDimLoad::default(), |acc, tour| {
+            Ok::<_, GenericError>(acc + get_first_trip_delivery(context, tour)?)
+        })?;
+
+        if supply.can_fit(&consumed) {
+            Ok(())
+        } else {
+            Err(format!("satellite '{id}' loads more than supplied: {consumed} vs {supply}").into())
+        }
+    })
+}
+
+fn check_satellite_departure(context: &CheckerContext) -> Result<(), GenericError> {
+    get_satellites(context).into_iter().try_for_each(|(id, location)| {
+        let Some(ready) = get_supply(context, id)?.1 else {
+            return Ok(());
+        };
+
+        get_satellite_tours(context, location).try_for_each(|tour| {
+            let departure = tour.stops.first().map_or(0., |stop| parse_time(&stop.schedule().departure));
+
+            if compare_floats(departure, ready) == Ordering::Less {
+                Err(format!("tour '{}' departs from satellite '{id}' before it is supplied", tour.vehicle_id).into())
+            } else {
+                Ok(())
+            }
+        })
+    })
+}
+
+fn get_satellite_tours<'a>(context: &'a CheckerContext, location: &'a Location) -> impl Iterator<Item = &'a Tour> + 'a {
+    context.solution.tours.iter().filter(move |tour| {
+        tour.stops.first().and_then(|stop| stop.as_point()).map_or(false, |stop| &stop.location == location)
+    })
+}
+
+fn get_satellites(context: &CheckerContext) -> Vec<(&String, &Location)> {
+    context
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Satellite { id, location } => Some((id, location)),
+            VehicleResource::Reload { .. } | VehicleResource::Dock { .. } => None,
+        })
+        .collect()
+}
+
+/// Returns total supply of the satellite and departure time of the last supply job.
+fn get_supply(context: &CheckerContext, satellite_id: &str) -> Result<(MultiDimLoad, Option<f64>), GenericError> {
+    context.solution.tours.iter().try_fold((MultiDimLoad::default(), None), |acc, tour| {
+        tour.stops.iter().flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity))).try_fold(
+            acc,
+            |(supply, ready), (stop, activity)| {
+                let activity_type = context.get_activity_type(tour, stop, activity)?;
+                let is_supply = match &activity_type {
+                    ActivityType::Job(job) => job.satellite_id.as_deref() == Some(satellite_id),
+                    _ => false,
+                };
+
+                if !is_supply {
+                    return Ok((supply, ready));
+                }
+
+                let (_, demand) = get_demand(context, activity, &activity_type)?;
+                let departure = get_time_window(stop, activity).end;
+
+                Ok((supply + demand, Some(ready.map_or(departure, |ready: f64| ready.max(departure)))))
+            },
+        )
+    })
+}
+
+/// Returns total static delivery demand of the tour before the first reload.
+fn get_first_trip_delivery(context: &CheckerContext, tour: &Tour) -> Result<MultiDimLoad, GenericError> {
+    let activities = tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .map(|(stop, activity)| {
+            context.get_activity_type(tour, stop, activity).map(|activity_type| (activity, activity_type))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    activities.iter().take_while(|(_, activity_type)| !matches!(activity_type, ActivityType::Reload(_))).try_fold(
+        MultiDimLoad::default(),
+        |acc, (activity, activity_type)| {
+            let (demand_type, demand) = get_demand(context, activity, activity_type)?;
+            Ok(match demand_type {
+                DemandType::StaticDelivery => acc + demand,
+                _ => acc,
+            })
+        },
+    )
+}
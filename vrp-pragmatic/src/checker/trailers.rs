@@ -0,0 +1,146 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/trailers_test.rs"]
+mod trailers_test;
+
+use super::*;
+use crate::utils::combine_error_results;
+use vrp_core::models::common::{Load, MultiDimLoad};
+
+/// Checks that vehicle trailer is used correctly. The following rules are checked:
+/// * trailer is decoupled and coupled back at the same park, the tour ends with trailer
+/// * truck only jobs are served only when trailer is decoupled
+/// * truck alone can carry goods while trailer is decoupled
+pub fn check_trailers(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
+    combine_error_results(&[check_trailer_parks(context), check_truck_load(context)])
+}
+
+fn check_trailer_parks(context: &CheckerContext) -> Result<(), GenericError> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let has_trailer = context.get_vehicle(&tour.vehicle_id)?.trailer.is_some();
+
+        let park = get_tour_activities(context, tour)?.into_iter().try_fold(
+            None,
+            |park: Option<VehicleTrailerPark>, (_, activity, activity_type)| match (&activity_type, park) {
+                (ActivityType::TrailerPark(_), Some(_)) if activity.activity_type == "decouple" => {
+                    Err(format!("trailer is decoupled twice in tour '{}'", tour.vehicle_id))
+                }
+                (ActivityType::TrailerPark(current), None) if activity.activity_type == "decouple" => {
+                    Ok(Some(current.clone()))
+                }
+                (ActivityType::TrailerPark(current), Some(park)) if current.location == park.location => Ok(None),
+                (ActivityType::TrailerPark(_), _) => {
+                    Err(format!("trailer is not coupled at the same park in tour '{}'", tour.vehicle_id))
+                }
+                (ActivityType::Reload(_), Some(_)) => {
+                    Err(format!("reload while trailer is decoupled in tour '{}'", tour.vehicle_id))
+                }
+                (ActivityType::Job(job), None) if has_trailer && job.truck_only.unwrap_or(false) => {
+                    Err(format!("truck only job '{}' is served with trailer in tour '{}'", job.id, tour.vehicle_id))
+                }
+                (_, park) => Ok(park),
+            },
+        )?;
+
+        if park.is_some() {
+            Err(format!("trailer is not coupled back in tour '{}'", tour.vehicle_id).into())
+        } else {
+            Ok(())
+        }
+    })
+}
+
+fn check_truck_load(context: &CheckerContext) -> Result<(), GenericError> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let Some(trailer) = vehicle.trailer.as_ref() else {
+            return Ok(());
+        };
+
+        let truck = MultiDimLoad::new(vehicle.capacity.clone());
+        let trailer = MultiDimLoad::new(trailer.capacity.clone());
+
+        let activities = get_tour_activities(context, tour)?
+            .into_iter()
+            .map(|(stop, activity, activity_type)| {
+                get_demand(context, activity, &activity_type).map(|demand| (stop, activity, demand))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // NOTE not closed ranges are reported by trailer parks check
+        let ranges =
+            activities.iter().enumerate().fold(Vec::<(usize, Option<usize>)>::new(), |mut acc, (idx, item)| {
+                match item.1.activity_type.as_str() {
+                    "decouple" => acc.push((idx, None)),
+                    "couple" => {
+                        if let Some(range) = acc.last_mut() {
+                            range.1 = Some(idx)
+                        }
+                    }
+                    _ => {}
+                }
+
+                acc
+            });
+
+        ranges.into_iter().filter_map(|(start, end)| end.map(|end| (start, end))).try_for_each(|(start, end)| {
+            let (decouple_stop, ..) = activities[start];
+
+            // NOTE stop load is reported after all activities of the stop
+            let load = activities[start + 1..]
+                .iter()
+                .take_while(|(stop, ..)| std::ptr::eq(*stop, decouple_stop))
+                .fold(MultiDimLoad::new(decouple_stop.load().clone()), |acc, (_, _, demand)| acc - get_change(demand));
+
+            let range = &activities[start + 1..end];
+            let picked_up = range
+                .iter()
+                .filter(|(_, _, (demand_type, _))| matches!(demand_type, DemandType::DynamicPickup))
+                .map(|(_, activity, _)| activity.job_id.as_str())
+                .collect::<HashSet<_>>();
+
+            let truck_start = range.iter().fold(
+                MultiDimLoad::default(),
+                |acc, (_, activity, (demand_type, demand))| match demand_type {
+                    DemandType::StaticDelivery | DemandType::StaticPickupDelivery => acc + *demand,
+                    DemandType::DynamicDelivery if !picked_up.contains(activity.job_id.as_str()) => acc + *demand,
+                    _ => acc,
+                },
+            );
+
+            let overflow = (load - truck_start - trailer).max_load(MultiDimLoad::default());
+            let (_, max_load) = range.iter().fold((truck_start, truck_start), |(current, max), (_, _, demand)| {
+                let current = current + get_change(demand);
+                (current, max.max_load(current))
+            });
+
+            if truck.can_fit(&(max_load + overflow)) {
+                Ok(())
+            } else {
+                Err(format!("truck load exceeds its capacity while trailer is decoupled in tour '{}'", tour.vehicle_id)
+                    .into())
+            }
+        })
+    })
+}
+
+fn get_tour_activities<'a>(
+    context: &CheckerContext,
+    tour: &'a Tour,
+) -> Result<Vec<(&'a Stop, &'a Activity, ActivityType)>, GenericError> {
+    tour.stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .filter(|(stop, _)| stop.as_point().is_some())
+        .map(|(stop, activity)| {
+            context.get_activity_type(tour, stop, activity).map(|activity_type| (stop, activity, activity_type))
+        })
+        .collect()
+}
+
+fn get_change((demand_type, demand): &(DemandType, MultiDimLoad)) -> MultiDimLoad {
+    match demand_type {
+        DemandType::StaticDelivery | DemandType::DynamicDelivery => MultiDimLoad::default() - *demand,
+        DemandType::StaticPickup | DemandType::DynamicPickup => *demand,
+        DemandType::None | DemandType::StaticPickupDelivery => MultiDimLoad::default(),
+    }
+}
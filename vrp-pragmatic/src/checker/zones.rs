@@ -0,0 +1,61 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/zones_test.rs"]
+mod zones_test;
+
+use super::*;
+use crate::utils::{combine_error_results, is_inside_polygon};
+
+/// Checks that geofenced zones are respected: only allowed vehicle types serve jobs inside zones
+/// and zone entry fees are reported correctly.
+pub fn check_zones(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
+    combine_error_results(&[check_zone_access_and_fees(context)])
+}
+
+fn check_zone_access_and_fees(context: &CheckerContext) -> Result<(), GenericError> {
+    let zones = context.problem.plan.zones.iter().flatten().collect::<Vec<_>>();
+
+    if zones.is_empty() {
+        return Ok(());
+    }
+
+    context.solution.tours.iter().try_for_each(|tour| {
+        let mut visited = HashMap::<&String, f64>::new();
+
+        tour.stops.iter().try_for_each(|stop| {
+            stop.activities().iter().try_for_each(|activity| {
+                if !matches!(context.get_activity_type(tour, stop, activity)?, ActivityType::Job(_)) {
+                    return Ok(());
+                }
+
+                let Some(location) = context.get_activity_location(stop, activity) else {
+                    return Ok(());
+                };
+
+                zones.iter().filter(|zone| is_inside_polygon(&location, zone.polygon.as_slice())).try_for_each(|zone| {
+                    let is_allowed = zone.vehicle_type_ids.as_ref().is_none_or(|ids| ids.contains(&tour.type_id));
+                    if !is_allowed {
+                        return Err(format!(
+                            "zone access violation: job '{}' inside zone '{}' is served by vehicle type '{}'",
+                            activity.job_id, zone.id, tour.type_id
+                        )
+                        .into());
+                    }
+
+                    visited.insert(&zone.id, zone.fee.unwrap_or(0.));
+
+                    Ok::<_, GenericError>(())
+                })
+            })
+        })?;
+
+        let expected = visited.values().sum::<f64>();
+        let actual = tour.statistic.fees.unwrap_or(0.);
+
+        if (expected - actual).abs() > 1E-6 {
+            Err(format!("zone fees mismatch for vehicle '{}', expected: {expected}, got: {actual}", tour.vehicle_id)
+                .into())
+        } else {
+            Ok(())
+        }
+    })
+}
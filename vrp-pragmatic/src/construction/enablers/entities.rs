@@ -1,8 +1,11 @@
 //! Specifies different entities as extension points on Dimensions type.
 
-use crate::construction::features::{BreakPolicy, JobSkills};
+use crate::construction::features::{
+    BreakPolicy, JobSkills, PeriodicVisit, PlaceZones, RechargeBattery, TrailerCapacity,
+};
 use hashbrown::HashSet;
-use vrp_core::models::common::{Dimensions, ValueDimension};
+use vrp_core::construction::features::{Compartment, ProductDemand, RideDurationLimit, TripLimits};
+use vrp_core::models::common::{Dimensions, Duration, LoadOps, ValueDimension};
 
 /// Specifies vehicle entity.
 pub trait VehicleTie {
@@ -30,6 +33,41 @@ pub trait VehicleTie {
     fn get_tour_size(&self) -> Option<usize>;
     /// Sets vehicle's tour size.
     fn set_tour_size(&mut self, tour_size: usize) -> &mut Self;
+
+    /// Gets vehicle's trip limits.
+    fn get_trip_limits(&self) -> Option<&TripLimits>;
+    /// Sets vehicle's trip limits.
+    fn set_trip_limits(&mut self, limits: TripLimits) -> &mut Self;
+
+    /// Gets vehicle's shift day.
+    fn get_shift_day(&self) -> Option<usize>;
+    /// Sets vehicle's shift day.
+    fn set_shift_day(&mut self, day: usize) -> &mut Self;
+
+    /// Gets vehicle's compartments.
+    fn get_vehicle_compartments(&self) -> Option<&Vec<Compartment>>;
+    /// Sets vehicle's compartments.
+    fn set_vehicle_compartments(&mut self, compartments: Vec<Compartment>) -> &mut Self;
+
+    /// Gets vehicle's compartment ids.
+    fn get_vehicle_compartment_ids(&self) -> Option<&Vec<String>>;
+    /// Sets vehicle's compartment ids.
+    fn set_vehicle_compartment_ids(&mut self, ids: Vec<String>) -> &mut Self;
+
+    /// Gets vehicle's battery.
+    fn get_vehicle_battery(&self) -> Option<&RechargeBattery>;
+    /// Sets vehicle's battery.
+    fn set_vehicle_battery(&mut self, battery: RechargeBattery) -> &mut Self;
+
+    /// Gets vehicle's max driving time without a break.
+    fn get_vehicle_driving_limit(&self) -> Option<Duration>;
+    /// Sets vehicle's max driving time without a break.
+    fn set_vehicle_driving_limit(&mut self, limit: Duration) -> &mut Self;
+
+    /// Gets vehicle's truck and trailer capacities.
+    fn get_vehicle_trailer<T: LoadOps>(&self) -> Option<&TrailerCapacity<T>>;
+    /// Sets vehicle's truck and trailer capacities.
+    fn set_vehicle_trailer<T: LoadOps>(&mut self, trailer: TrailerCapacity<T>) -> &mut Self;
 }
 
 impl VehicleTie for Dimensions {
@@ -79,6 +117,130 @@ impl VehicleTie for Dimensions {
         self.set_value("tour_size", tour_size);
         self
     }
+
+    fn get_trip_limits(&self) -> Option<&TripLimits> {
+        self.get_value("trip_limits")
+    }
+
+    fn set_trip_limits(&mut self, limits: TripLimits) -> &mut Self {
+        self.set_value("trip_limits", limits);
+        self
+    }
+
+    fn get_shift_day(&self) -> Option<usize> {
+        self.get_value("shift_day").cloned()
+    }
+
+    fn set_shift_day(&mut self, day: usize) -> &mut Self {
+        self.set_value("shift_day", day);
+        self
+    }
+
+    fn get_vehicle_compartments(&self) -> Option<&Vec<Compartment>> {
+        self.get_value("vehicle_compartments")
+    }
+
+    fn set_vehicle_compartments(&mut self, compartments: Vec<Compartment>) -> &mut Self {
+        self.set_value("vehicle_compartments", compartments);
+        self
+    }
+
+    fn get_vehicle_compartment_ids(&self) -> Option<&Vec<String>> {
+        self.get_value("vehicle_compartment_ids")
+    }
+
+    fn set_vehicle_compartment_ids(&mut self, ids: Vec<String>) -> &mut Self {
+        self.set_value("vehicle_compartment_ids", ids);
+        self
+    }
+
+    fn get_vehicle_battery(&self) -> Option<&RechargeBattery> {
+        self.get_value("vehicle_battery")
+    }
+
+    fn set_vehicle_battery(&mut self, battery: RechargeBattery) -> &mut Self {
+        self.set_value("vehicle_battery", battery);
+        self
+    }
+
+    fn get_vehicle_driving_limit(&self) -> Option<Duration> {
+        self.get_value("vehicle_driving_limit").copied()
+    }
+
+    fn set_vehicle_driving_limit(&mut self, limit: Duration) -> &mut Self {
+        self.set_value("vehicle_driving_limit", limit);
+        self
+    }
+
+    fn get_vehicle_trailer<T: LoadOps>(&self) -> Option<&TrailerCapacity<T>> {
+        self.get_value("vehicle_trailer")
+    }
+
+    fn set_vehicle_trailer<T: LoadOps>(&mut self, trailer: TrailerCapacity<T>) -> &mut Self {
+        self.set_value("vehicle_trailer", trailer);
+        self
+    }
+}
+
+/// Specifies driver entity.
+pub trait DriverTie {
+    /// Gets driver's id.
+    fn get_driver_id(&self) -> Option<&String>;
+    /// Sets driver's id.
+    fn set_driver_id(&mut self, id: String) -> &mut Self;
+
+    /// Gets driver's type id.
+    fn get_driver_type(&self) -> Option<&String>;
+    /// Sets driver's type id.
+    fn set_driver_type(&mut self, id: String) -> &mut Self;
+
+    /// Gets driver's skills set.
+    fn get_driver_skills(&self) -> Option<&HashSet<String>>;
+    /// Sets driver's skills set.
+    fn set_driver_skills(&mut self, skills: HashSet<String>) -> &mut Self;
+
+    /// Gets vehicle types which can be driven by the driver.
+    fn get_driver_vehicle_types(&self) -> Option<&HashSet<String>>;
+    /// Sets vehicle types which can be driven by the driver.
+    fn set_driver_vehicle_types(&mut self, vehicle_types: HashSet<String>) -> &mut Self;
+}
+
+impl DriverTie for Dimensions {
+    fn get_driver_id(&self) -> Option<&String> {
+        self.get_value("driver_id")
+    }
+
+    fn set_driver_id(&mut self, id: String) -> &mut Self {
+        self.set_value("driver_id", id);
+        self
+    }
+
+    fn get_driver_type(&self) -> Option<&String> {
+        self.get_value("driver_type")
+    }
+
+    fn set_driver_type(&mut self, id: String) -> &mut Self {
+        self.set_value("driver_type", id);
+        self
+    }
+
+    fn get_driver_skills(&self) -> Option<&HashSet<String>> {
+        self.get_value("driver_skills")
+    }
+
+    fn set_driver_skills(&mut self, skills: HashSet<String>) -> &mut Self {
+        self.set_value("driver_skills", skills);
+        self
+    }
+
+    fn get_driver_vehicle_types(&self) -> Option<&HashSet<String>> {
+        self.get_value("driver_vehicle_types")
+    }
+
+    fn set_driver_vehicle_types(&mut self, vehicle_types: HashSet<String>) -> &mut Self {
+        self.set_value("driver_vehicle_types", vehicle_types);
+        self
+    }
 }
 
 /// Specifies job entity.
@@ -98,6 +260,16 @@ pub trait JobTie {
     /// Sets job place tags.
     fn set_place_tags(&mut self, tags: Option<Vec<(usize, String)>>) -> &mut Self;
 
+    /// Get job place docks.
+    fn get_place_docks(&self) -> Option<&Vec<(usize, String)>>;
+    /// Sets job place docks.
+    fn set_place_docks(&mut self, docks: Option<Vec<(usize, String)>>) -> &mut Self;
+
+    /// Gets geofenced zones of job places.
+    fn get_place_zones(&self) -> Option<&PlaceZones>;
+    /// Sets geofenced zones of job places.
+    fn set_place_zones(&mut self, zones: Option<PlaceZones>) -> &mut Self;
+
     /// Gets job order.
     fn get_job_order(&self) -> Option<i32>;
     /// Sets job order.
@@ -118,6 +290,46 @@ pub trait JobTie {
     /// Sets job compatibility.
     fn set_job_compatibility(&mut self, compatibility: Option<String>) -> &mut Self;
 
+    /// Gets job ride duration limit.
+    fn get_ride_duration_limit(&self) -> Option<&RideDurationLimit>;
+    /// Sets job ride duration limit.
+    fn set_ride_duration_limit(&mut self, limit: Option<RideDurationLimit>) -> &mut Self;
+
+    /// Gets job max time in vehicle.
+    fn get_max_time_in_vehicle(&self) -> Option<Duration>;
+    /// Sets job max time in vehicle.
+    fn set_max_time_in_vehicle(&mut self, limit: Option<Duration>) -> &mut Self;
+
+    /// Returns true if job can be served only by a truck without trailer.
+    fn get_job_truck_only(&self) -> bool;
+    /// Sets whether job can be served only by a truck without trailer.
+    fn set_job_truck_only(&mut self, truck_only: bool) -> &mut Self;
+
+    /// Gets id of satellite which is supplied by the job.
+    fn get_job_satellite(&self) -> Option<&String>;
+    /// Sets id of satellite which is supplied by the job.
+    fn set_job_satellite(&mut self, satellite_id: Option<String>) -> &mut Self;
+
+    /// Gets job product type.
+    fn get_job_product(&self) -> Option<&String>;
+    /// Sets job product type.
+    fn set_job_product(&mut self, product: Option<String>) -> &mut Self;
+
+    /// Gets job product demand.
+    fn get_product_demand(&self) -> Option<&ProductDemand>;
+    /// Sets job product demand.
+    fn set_product_demand(&mut self, demand: Option<ProductDemand>) -> &mut Self;
+
+    /// Gets periodic job visit.
+    fn get_periodic_visit(&self) -> Option<&PeriodicVisit>;
+    /// Sets periodic job visit.
+    fn set_periodic_visit(&mut self, visit: Option<PeriodicVisit>) -> &mut Self;
+
+    /// Gets charging power of the recharge station.
+    fn get_recharge_power(&self) -> Option<f64>;
+    /// Sets charging power of the recharge station.
+    fn set_recharge_power(&mut self, power: Option<f64>) -> &mut Self;
+
     /// Gets job (activity) type.
     fn get_job_type(&self) -> Option<&String>;
     /// Sets job (activity) type
@@ -164,6 +376,34 @@ impl JobTie for Dimensions {
         self
     }
 
+    fn get_place_docks(&self) -> Option<&Vec<(usize, String)>> {
+        self.get_value("job_docks")
+    }
+
+    fn set_place_docks(&mut self, docks: Option<Vec<(usize, String)>>) -> &mut Self {
+        if let Some(docks) = docks {
+            self.set_value("job_docks", docks);
+        } else {
+            self.remove("job_docks");
+        }
+
+        self
+    }
+
+    fn get_place_zones(&self) -> Option<&PlaceZones> {
+        self.get_value("job_zones")
+    }
+
+    fn set_place_zones(&mut self, zones: Option<PlaceZones>) -> &mut Self {
+        if let Some(zones) = zones {
+            self.set_value("job_zones", zones);
+        } else {
+            self.remove("job_zones");
+        }
+
+        self
+    }
+
     fn get_job_order(&self) -> Option<i32> {
         self.get_value("job_order").cloned()
     }
@@ -220,6 +460,118 @@ impl JobTie for Dimensions {
         self
     }
 
+    fn get_ride_duration_limit(&self) -> Option<&RideDurationLimit> {
+        self.get_value("job_ride_limit")
+    }
+
+    fn set_ride_duration_limit(&mut self, limit: Option<RideDurationLimit>) -> &mut Self {
+        if let Some(limit) = limit {
+            self.set_value("job_ride_limit", limit);
+        } else {
+            self.remove("job_ride_limit");
+        }
+
+        self
+    }
+
+    fn get_max_time_in_vehicle(&self) -> Option<Duration> {
+        self.get_value("job_time_in_vehicle").copied()
+    }
+
+    fn set_max_time_in_vehicle(&mut self, limit: Option<Duration>) -> &mut Self {
+        if let Some(limit) = limit {
+            self.set_value("job_time_in_vehicle", limit);
+        } else {
+            self.remove("job_time_in_vehicle");
+        }
+
+        self
+    }
+
+    fn get_job_truck_only(&self) -> bool {
+        self.get_value("job_truck_only").copied().unwrap_or(false)
+    }
+
+    fn set_job_truck_only(&mut self, truck_only: bool) -> &mut Self {
+        if truck_only {
+            self.set_value("job_truck_only", truck_only);
+        } else {
+            self.remove("job_truck_only");
+        }
+
+        self
+    }
+
+    fn get_job_satellite(&self) -> Option<&String> {
+        self.get_value("job_satellite")
+    }
+
+    fn set_job_satellite(&mut self, satellite_id: Option<String>) -> &mut Self {
+        if let Some(satellite_id) = satellite_id {
+            self.set_value("job_satellite", satellite_id);
+        } else {
+            self.remove("job_satellite");
+        }
+
+        self
+    }
+
+    fn get_job_product(&self) -> Option<&String> {
+        self.get_value("job_product")
+    }
+
+    fn set_job_product(&mut self, product: Option<String>) -> &mut Self {
+        if let Some(product) = product {
+            self.set_value("job_product", product);
+        } else {
+            self.remove("job_product");
+        }
+
+        self
+    }
+
+    fn get_product_demand(&self) -> Option<&ProductDemand> {
+        self.get_value("job_product_demand")
+    }
+
+    fn set_product_demand(&mut self, demand: Option<ProductDemand>) -> &mut Self {
+        if let Some(demand) = demand {
+            self.set_value("job_product_demand", demand);
+        } else {
+            self.remove("job_product_demand");
+        }
+
+        self
+    }
+
+    fn get_periodic_visit(&self) -> Option<&PeriodicVisit> {
+        self.get_value("job_periodic")
+    }
+
+    fn set_periodic_visit(&mut self, visit: Option<PeriodicVisit>) -> &mut Self {
+        if let Some(visit) = visit {
+            self.set_value("job_periodic", visit);
+        } else {
+            self.remove("job_periodic");
+        }
+
+        self
+    }
+
+    fn get_recharge_power(&self) -> Option<f64> {
+        self.get_value("recharge_power").copied()
+    }
+
+    fn set_recharge_power(&mut self, power: Option<f64>) -> &mut Self {
+        if let Some(power) = power {
+            self.set_value("recharge_power", power);
+        } else {
+            self.remove("recharge_power");
+        }
+
+        self
+    }
+
     fn get_job_type(&self) -> Option<&String> {
         self.get_value("job_type")
     }
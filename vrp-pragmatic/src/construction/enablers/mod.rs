@@ -1,6 +1,7 @@
 //! Provides extension logic for building various VRP features.
 
 use std::sync::Arc;
+use vrp_core::construction::features::CompartmentAccessor;
 use vrp_core::models::common::Dimensions;
 use vrp_core::models::problem::Single;
 use vrp_core::models::solution::{Activity, Route};
@@ -24,6 +25,16 @@ where
     activity.job.as_ref().and_then(|job| if condition(job) { Some(job) } else { None })
 }
 
+pub(crate) fn get_compartment_accessor() -> CompartmentAccessor {
+    CompartmentAccessor {
+        compartments_fn: Arc::new(|vehicle| vehicle.dimens.get_vehicle_compartments()),
+        product_fn: Arc::new(|single| single.dimens.get_product_demand()),
+        marker_fn: Arc::new(|activity| {
+            activity.job.as_ref().and_then(|single| single.dimens.get_job_type()).map_or(false, |t| t == "reload")
+        }),
+    }
+}
+
 pub(crate) fn get_shift_index(dimens: &Dimensions) -> usize {
     dimens.get_shift_index().expect("cannot get shift index")
 }
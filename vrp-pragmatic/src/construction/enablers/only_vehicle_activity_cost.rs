@@ -1,6 +1,6 @@
 use crate::core::models::solution::Route;
 use vrp_core::models::common::{Cost, Timestamp};
-use vrp_core::models::problem::{ActivityCost, SimpleActivityCost};
+use vrp_core::models::problem::{get_service_duration, get_time_window_penalty, ActivityCost, SimpleActivityCost};
 use vrp_core::models::solution::Activity;
 
 /// Uses costs only for a vehicle ignoring costs of a driver.
@@ -14,9 +14,11 @@ impl ActivityCost for OnlyVehicleActivityCost {
         let actor = route.actor.as_ref();
 
         let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0.0 };
-        let service = activity.place.duration;
+        let service = get_service_duration(route, activity);
 
-        waiting * actor.vehicle.costs.per_waiting_time + service * actor.vehicle.costs.per_service_time
+        waiting * actor.vehicle.costs.per_waiting_time
+            + service * actor.vehicle.costs.per_service_time
+            + get_time_window_penalty(route, activity, arrival)
     }
 
     fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
@@ -0,0 +1,362 @@
+//! A feature to schedule vehicle breaks based on accumulated driving time, e.g. to follow driving
+//! time regulations which require a rest after some amount of driving.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/driving_breaks_test.rs"]
+mod driving_breaks_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use hashbrown::HashSet;
+use std::iter::{once, repeat_n};
+use vrp_core::construction::enablers::*;
+use vrp_core::models::solution::{Activity, Route};
+
+/// Specifies a function which returns max driving time allowed without a break. It should return
+/// a fixed value for the same actor all the time.
+pub type DrivingLimitFn = Arc<dyn Fn(&Actor) -> Option<Duration> + Send + Sync>;
+
+/// Creates a feature to insert breaks into the tour, so that vehicle never drives longer than
+/// allowed without a rest. The first place of the break job is a full break, the second and the
+/// third ones are parts of the split break: the first part doesn't reset driving time, but the
+/// second one does when it follows the first part.
+pub fn create_driving_break_feature(
+    name: &str,
+    code: ViolationCode,
+    limit_fn: DrivingLimitFn,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+) -> Result<Feature, GenericError> {
+    let driving = Arc::new(DrivingModel { limit_fn, transport });
+
+    create_multi_trip_feature(
+        name,
+        code,
+        &[DRIVING_TIME_KEY, DRIVING_INTERVAL_KEY, DRIVING_BREAK_INTERVALS_KEY],
+        MarkerInsertionPolicy::Any,
+        Arc::new(DrivingBreakMultiTrip {
+            route_intervals: FixedRouteIntervals {
+                is_marker_single_fn: Box::new(is_driving_break_single),
+                is_new_interval_needed_fn: Box::new({
+                    let driving = driving.clone();
+                    move |route_ctx| {
+                        driving.get_limit(route_ctx.route()).map_or(false, |limit| {
+                            (0..route_ctx.route().tour.total())
+                                .any(|activity_idx| get_interval(route_ctx, activity_idx).0 > limit)
+                        })
+                    }
+                }),
+                is_obsolete_interval_fn: Box::new({
+                    let driving = driving.clone();
+                    move |route_ctx, _, right| {
+                        let route = route_ctx.route();
+                        let activities = route
+                            .tour
+                            .all_activities()
+                            .enumerate()
+                            .filter(|(activity_idx, _)| *activity_idx != right.start)
+                            .map(|(_, activity)| activity);
+
+                        driving
+                            .get_limit(route)
+                            .map_or(false, |limit| driving.simulate(route, activities.map(as_point)).is_feasible(limit))
+                    }
+                }),
+                is_assignable_fn: Box::new(|route, job| {
+                    job.as_single().map_or(false, |job| {
+                        is_correct_vehicle(route, get_vehicle_id_from_job(job), get_shift_index(&job.dimens))
+                    })
+                }),
+                intervals_key: DRIVING_BREAK_INTERVALS_KEY,
+            },
+            driving,
+            code,
+        }),
+    )
+}
+
+/// Represents an activity as a (location, departure, break place index) tuple.
+type DrivingPoint = (Location, Timestamp, Option<usize>);
+
+/// Keeps driving time accumulated along the route.
+#[derive(Default)]
+struct DrivingSchedule {
+    /// Driving time accumulated since the last break at departure from each activity.
+    departures: Vec<Duration>,
+    /// Total driving time of the interval between breaks which the activity's departure belongs to.
+    totals: Vec<Duration>,
+    /// Indices of activities which start a new interval.
+    starts: Vec<usize>,
+    /// True if some break part doesn't reset driving time and there is a pending part already.
+    has_redundant_part: bool,
+}
+
+impl DrivingSchedule {
+    fn is_feasible(&self, limit: Duration) -> bool {
+        !self.has_redundant_part && self.totals.iter().all(|total| *total <= limit)
+    }
+}
+
+struct DrivingModel {
+    limit_fn: DrivingLimitFn,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl DrivingModel {
+    fn get_limit(&self, route: &Route) -> Option<Duration> {
+        (self.limit_fn)(route.actor.as_ref())
+    }
+
+    fn get_duration(&self, route: &Route, from: (Location, Timestamp), to: Location) -> Duration {
+        self.transport.duration(route, from.0, to, TravelTime::Departure(from.1))
+    }
+
+    /// Calculates driving time along the given sequence of route points.
+    fn simulate(&self, route: &Route, points: impl Iterator<Item = DrivingPoint>) -> DrivingSchedule {
+        let mut schedule = DrivingSchedule { starts: vec![0], ..DrivingSchedule::default() };
+        let (mut driving, mut is_pending, mut prev) = (Duration::default(), false, None);
+
+        for (activity_idx, (location, departure, break_place)) in points.enumerate() {
+            driving += prev.map_or(Duration::default(), |prev| self.get_duration(route, prev, location));
+
+            let is_reset = match break_place {
+                Some(FULL_BREAK_PLACE) => true,
+                Some(SECOND_PART_PLACE) if is_pending => true,
+                Some(_) if is_pending => {
+                    schedule.has_redundant_part = true;
+                    false
+                }
+                Some(_) => {
+                    is_pending = true;
+                    false
+                }
+                None => false,
+            };
+
+            if is_reset {
+                let start_idx = schedule.starts.last().copied().unwrap_or_default();
+                schedule.totals.extend(repeat_n(driving, activity_idx - start_idx));
+                schedule.starts.push(activity_idx);
+                driving = Duration::default();
+                is_pending = false;
+            }
+
+            schedule.departures.push(driving);
+            prev = Some((location, departure));
+        }
+
+        let start_idx = schedule.starts.last().copied().unwrap_or_default();
+        schedule.totals.extend(repeat_n(driving, schedule.departures.len() - start_idx));
+
+        schedule
+    }
+}
+
+struct DrivingBreakMultiTrip {
+    route_intervals: FixedRouteIntervals,
+    driving: Arc<DrivingModel>,
+    code: ViolationCode,
+}
+
+impl MultiTrip for DrivingBreakMultiTrip {
+    fn get_route_intervals(&self) -> &dyn RouteIntervals {
+        &self.route_intervals
+    }
+
+    fn get_constraint(&self) -> &dyn FeatureConstraint {
+        self
+    }
+
+    fn recalculate_states(&self, route_ctx: &mut RouteContext) {
+        if self.driving.get_limit(route_ctx.route()).is_none() {
+            return;
+        }
+
+        let schedule = self.driving.simulate(route_ctx.route(), route_ctx.route().tour.all_activities().map(as_point));
+        let last_idx = schedule.departures.len() - 1;
+
+        let intervals = schedule
+            .starts
+            .iter()
+            .enumerate()
+            .map(|(idx, &start_idx)| {
+                let end_idx = schedule.starts.get(idx + 1).copied().unwrap_or(last_idx);
+                let total = schedule.totals[start_idx];
+
+                (start_idx, end_idx, total, get_break_position(&schedule, start_idx, end_idx))
+            })
+            .collect::<Vec<_>>();
+
+        let state = route_ctx.state_mut();
+        schedule.departures.iter().enumerate().for_each(|(activity_idx, driving)| {
+            state.put_activity_state(DRIVING_TIME_KEY, activity_idx, *driving);
+        });
+
+        intervals.into_iter().for_each(|(start_idx, end_idx, total, position)| {
+            let end_idx = if end_idx == last_idx { last_idx + 1 } else { end_idx };
+            (start_idx..end_idx).for_each(|activity_idx| {
+                state.put_activity_state(DRIVING_INTERVAL_KEY, activity_idx, (total, position));
+            });
+        });
+    }
+
+    fn try_recover(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], _: &[Job]) -> bool {
+        let routes = &mut solution_ctx.routes;
+
+        let jobs: HashSet<_> = if route_indices.is_empty() {
+            solution_ctx
+                .ignored
+                .iter()
+                .filter(|job| job.as_single().map_or(false, |single| is_driving_break_single(single.as_ref())))
+                .cloned()
+                .collect()
+        } else {
+            routes
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| route_indices.contains(idx))
+                .flat_map(|(_, route_ctx)| {
+                    solution_ctx
+                        .ignored
+                        .iter()
+                        .filter(|job| self.route_intervals.is_marker_assignable(route_ctx.route(), job))
+                })
+                .cloned()
+                .collect()
+        };
+
+        if jobs.is_empty() {
+            false
+        } else {
+            solution_ctx.ignored.retain(|job| !jobs.contains(job));
+            solution_ctx.locked.extend(jobs.iter().cloned());
+            solution_ctx.required.extend(jobs);
+
+            true
+        }
+    }
+}
+
+impl FeatureConstraint for DrivingBreakMultiTrip {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+impl DrivingBreakMultiTrip {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let limit = self.driving.get_limit(route)?;
+
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let (total, position) = get_interval(route_ctx, activity_ctx.index);
+
+        let is_violation = if is_driving_break(target) {
+            // NOTE break is allowed only at the position which splits the interval into two balanced
+            //      parts, the break at the end of the tour doesn't make sense. A break might not fix
+            //      the interval alone: the rest is done by the next breaks or split parts
+            activity_ctx.next.is_none() || position != Some(activity_ctx.index) || {
+                let activities = route.tour.all_activities();
+                let points = activities.clone().take(activity_ctx.index + 1).map(as_point).chain(once((
+                    target.place.location,
+                    prev.schedule.departure,
+                    Some(target.place.idx),
+                )));
+                let points = points.chain(activities.skip(activity_ctx.index + 1).map(as_point));
+
+                self.driving.simulate(route, points).has_redundant_part
+            }
+        } else {
+            let departure = (prev.place.location, prev.schedule.departure);
+            let prev_to_target = self.driving.get_duration(route, departure, target.place.location);
+
+            let delta = activity_ctx.next.map_or(prev_to_target, |next| {
+                let target_to_next =
+                    self.driving.get_duration(route, (target.place.location, departure.1), next.place.location);
+                let prev_to_next = self.driving.get_duration(route, departure, next.place.location);
+
+                prev_to_target + target_to_next - prev_to_next
+            });
+
+            // NOTE break is not moved together with the activity it was inserted after, so avoid detours
+            activity_ctx.next.map_or(false, is_driving_break) || total + delta > limit
+        };
+
+        if is_violation {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
+}
+
+const FULL_BREAK_PLACE: usize = 0;
+const SECOND_PART_PLACE: usize = 2;
+
+/// Returns an index of activity after which break splits the interval into two balanced parts.
+fn get_break_position(schedule: &DrivingSchedule, start_idx: usize, end_idx: usize) -> Option<usize> {
+    let total = schedule.totals[start_idx];
+    let half = total / 2.;
+
+    if total <= 0. {
+        return None;
+    }
+
+    // NOTE driving time at arrival to the activity
+    let get_arrival =
+        |activity_idx: usize| if activity_idx == end_idx { total } else { schedule.departures[activity_idx] };
+
+    let crossing_idx = (start_idx..end_idx).find(|&activity_idx| get_arrival(activity_idx + 1) >= half)?;
+
+    let position = if crossing_idx == start_idx {
+        crossing_idx + 1
+    } else if crossing_idx + 1 == end_idx || half - get_arrival(crossing_idx) <= get_arrival(crossing_idx + 1) - half {
+        crossing_idx
+    } else {
+        crossing_idx + 1
+    };
+
+    if position >= end_idx {
+        return None;
+    }
+
+    // NOTE move break after activities which are visited without driving
+    let position = (position..end_idx)
+        .take_while(|&activity_idx| activity_idx == position || get_arrival(activity_idx) == get_arrival(position))
+        .last()
+        .unwrap_or(position);
+
+    Some(position)
+}
+
+fn get_interval(route_ctx: &RouteContext, activity_idx: usize) -> (Duration, Option<usize>) {
+    route_ctx
+        .state()
+        .get_activity_state::<(Duration, Option<usize>)>(DRIVING_INTERVAL_KEY, activity_idx)
+        .copied()
+        .unwrap_or_default()
+}
+
+fn as_point(activity: &Activity) -> DrivingPoint {
+    let break_place = if is_driving_break(activity) { Some(activity.place.idx) } else { None };
+
+    (activity.place.location, activity.schedule.departure, break_place)
+}
+
+fn is_driving_break(activity: &Activity) -> bool {
+    activity.job.as_ref().map_or(false, |single| is_driving_break_single(single))
+}
+
+fn is_driving_break_single(single: &Single) -> bool {
+    single.dimens.get_job_type().map_or(false, |t| t == "driving_break")
+}
@@ -20,6 +20,26 @@ pub const RELOAD_RESOURCE_KEY: StateKey = StateKey(1003);
 pub const TOUR_COMPACTNESS_KEY: StateKey = StateKey(1004);
 /// A key to track fast service feature state.
 pub const FAST_SERVICE_KEY: StateKey = StateKey(1005);
+/// A key which tracks periodic job visits state.
+pub const PERIODIC_KEY: StateKey = StateKey(1006);
+/// A key which tracks geofenced zones visited by the tour.
+pub const ZONE_KEY: StateKey = StateKey(1007);
+/// A key which tracks vehicle battery state of charge.
+pub const RECHARGE_SOC_KEY: StateKey = StateKey(1008);
+/// A key which tracks driving time accumulated since the last break.
+pub const DRIVING_TIME_KEY: StateKey = StateKey(1009);
+/// A key which tracks total driving time and break position of the interval between breaks.
+pub const DRIVING_INTERVAL_KEY: StateKey = StateKey(1010);
+/// A key which tracks route intervals split by driving breaks.
+pub const DRIVING_BREAK_INTERVALS_KEY: StateKey = StateKey(1011);
+/// A key which tracks changes of the plan comparing to the reference one.
+pub const PLAN_STABILITY_KEY: StateKey = StateKey(1012);
+/// A key which tracks decoupled trailer ranges of the tour.
+pub const TRAILER_RANGES_KEY: StateKey = StateKey(1013);
+/// A key which tracks satellite inventory state.
+pub const SATELLITE_RESOURCE_KEY: StateKey = StateKey(1014);
+/// A key which tracks supply and loading times at satellites.
+pub const SATELLITE_SCHEDULE_KEY: StateKey = StateKey(1015);
 
 mod breaks;
 pub use self::breaks::*;
@@ -27,9 +47,15 @@ pub use self::breaks::*;
 pub mod compatibility;
 pub use self::compatibility::*;
 
+pub mod driving_breaks;
+pub use self::driving_breaks::*;
+
 pub mod groups;
 pub use self::groups::*;
 
+pub mod periodic;
+pub use self::periodic::*;
+
 pub mod reachable;
 pub use self::reachable::*;
 
@@ -39,5 +65,14 @@ pub use self::recharge::*;
 pub mod reloads;
 pub use self::reloads::*;
 
+pub mod satellites;
+pub use self::satellites::*;
+
 pub mod skills;
 pub use self::skills::*;
+
+pub mod trailers;
+pub use self::trailers::*;
+
+pub mod zones;
+pub use self::zones::*;
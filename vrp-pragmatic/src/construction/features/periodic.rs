@@ -0,0 +1,281 @@
+//! A feature to model periodic jobs which have to be visited multiple times within planning horizon.
+
+use super::*;
+use crate::construction::enablers::{JobTie, VehicleTie};
+use hashbrown::{HashMap, HashSet};
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/periodic_test.rs"]
+mod periodic_test;
+
+/// Specifies visiting rules of the periodic job.
+#[derive(Clone, Debug)]
+pub struct PeriodicPolicy {
+    /// A minimum amount of visits.
+    pub min_visits: usize,
+    /// Allowed visit day patterns.
+    pub day_patterns: Option<Vec<HashSet<usize>>>,
+    /// A minimum amount of days between two consecutive visits.
+    pub min_spacing: usize,
+    /// A maximum amount of days between two consecutive visits.
+    pub max_spacing: Option<usize>,
+}
+
+impl PeriodicPolicy {
+    /// Checks whether visits on given days follow the policy. Only patterns which have all days
+    /// within given available days are considered as they can be completed.
+    pub fn is_valid_days(&self, days: &[usize], available_days: &HashSet<usize>) -> bool {
+        let mut days = days.to_vec();
+        days.sort_unstable();
+
+        let is_valid_spacing = days.windows(2).all(|pair| {
+            let spacing = pair[1] - pair[0];
+            spacing > 0 && spacing >= self.min_spacing && self.max_spacing.map_or(true, |max| spacing <= max)
+        });
+
+        let is_valid_pattern = self.day_patterns.as_ref().map_or(true, |patterns| {
+            patterns.iter().any(|pattern| {
+                pattern.iter().all(|day| available_days.contains(day)) && days.iter().all(|day| pattern.contains(day))
+            })
+        });
+
+        is_valid_spacing && is_valid_pattern
+    }
+
+    /// Checks whether visits on given days complete the policy: there are at least min visits and,
+    /// if patterns are specified, visit days match exactly one of them.
+    pub fn is_complete_days(&self, days: &[usize]) -> bool {
+        let days = days.iter().copied().collect::<HashSet<_>>();
+
+        let is_complete_pattern = self.day_patterns.as_ref().map_or(true, |patterns| {
+            patterns.iter().any(|pattern| pattern.len() == days.len() && days.iter().all(|day| pattern.contains(day)))
+        });
+
+        days.len() >= self.min_visits && is_complete_pattern
+    }
+}
+
+/// Specifies a visit of the periodic job.
+#[derive(Clone, Debug)]
+pub struct PeriodicVisit {
+    /// A visit index.
+    pub index: usize,
+    /// A total amount of visits created for the periodic job.
+    pub total: usize,
+    /// Visiting rules shared by all visits of the periodic job.
+    pub policy: Arc<PeriodicPolicy>,
+}
+
+/// Creates a periodic job feature as hard constraint. Shift days of the fleet are used to exclude
+/// day patterns which cannot be completed.
+pub fn create_periodic_feature(
+    name: &str,
+    code: ViolationCode,
+    state_key: StateKey,
+    fleet: &Fleet,
+) -> Result<Feature, GenericError> {
+    let available_days =
+        Arc::new(fleet.actors.iter().filter_map(|actor| actor.vehicle.dimens.get_shift_day()).collect::<HashSet<_>>());
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(PeriodicConstraint { code, state_key, available_days: available_days.clone() })
+        .with_state(PeriodicState { code, state_key, available_days, state_keys: vec![state_key] })
+        .build()
+}
+
+struct PeriodicConstraint {
+    code: ViolationCode,
+    state_key: StateKey,
+    available_days: Arc<HashSet<usize>>,
+}
+
+impl FeatureConstraint for PeriodicConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => {
+                let (job_id, visit) = get_periodic_visit(job)?;
+                let day = match route_ctx.route().actor.vehicle.dimens.get_shift_day() {
+                    Some(day) => day,
+                    None => return ConstraintViolation::fail(self.code),
+                };
+
+                let days = solution_ctx
+                    .routes
+                    .iter()
+                    .filter(|rc| {
+                        rc.state()
+                            .get_route_state::<HashSet<String>>(self.state_key)
+                            .map_or(false, |job_ids| job_ids.contains(job_id))
+                    })
+                    .filter_map(|rc| rc.route().actor.vehicle.dimens.get_shift_day())
+                    .chain(std::iter::once(day))
+                    .collect::<Vec<_>>();
+
+                if visit.policy.is_valid_days(days.as_slice(), &self.available_days) {
+                    None
+                } else {
+                    ConstraintViolation::fail(self.code)
+                }
+            }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (get_periodic_visit(&source), get_periodic_visit(&candidate)) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
+
+struct PeriodicState {
+    code: ViolationCode,
+    state_key: StateKey,
+    available_days: Arc<HashSet<usize>>,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for PeriodicState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if get_periodic_visit(job).is_some() {
+            self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        }
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let job_ids = get_periodic_job_ids(route_ctx);
+        route_ctx.state_mut().put_route_state(self.state_key, job_ids);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.remove_invalid_visits(solution_ctx);
+        self.remove_incomplete_visits(solution_ctx);
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+impl PeriodicState {
+    /// Removes visits which violate periodic policy. This can happen when routes are modified
+    /// independently from each other, e.g. by decomposed search.
+    fn remove_invalid_visits(&self, solution_ctx: &mut SolutionContext) {
+        let mut visits = HashMap::<String, Vec<(usize, usize, Job)>>::new();
+        solution_ctx.routes.iter().enumerate().for_each(|(route_idx, route_ctx)| {
+            let day = route_ctx.route().actor.vehicle.dimens.get_shift_day().unwrap_or_default();
+            route_ctx.route().tour.jobs().for_each(|job| {
+                if let Some((job_id, _)) = get_periodic_visit(job) {
+                    visits.entry(job_id.clone()).or_default().push((day, route_idx, job.clone()));
+                }
+            });
+        });
+
+        let invalid = visits
+            .into_values()
+            .flat_map(|mut visits| {
+                // NOTE keep locked visits first and then greedily keep visits which do not break the policy
+                visits.sort_by_key(|(day, _, job)| (!solution_ctx.locked.contains(job), *day));
+
+                visits
+                    .into_iter()
+                    .fold((Vec::<usize>::new(), Vec::new()), |(mut days, mut invalid), (day, route_idx, job)| {
+                        let is_locked = solution_ctx.locked.contains(&job);
+                        let policy = &get_periodic_visit(&job).unwrap().1.policy;
+                        days.push(day);
+
+                        if !is_locked && !policy.is_valid_days(days.as_slice(), &self.available_days) {
+                            days.pop();
+                            invalid.push((route_idx, job));
+                        }
+
+                        (days, invalid)
+                    })
+                    .1
+            })
+            .collect::<Vec<_>>();
+
+        invalid.into_iter().for_each(|(route_idx, job)| {
+            solution_ctx.routes[route_idx].route_mut().tour.remove(&job);
+            solution_ctx.unassigned.insert(job, UnassignmentInfo::Simple(self.code));
+        });
+    }
+
+    /// Removes all visits of periodic jobs which do not complete the policy, e.g. have less than
+    /// min visits or do not follow a single day pattern.
+    fn remove_incomplete_visits(&self, solution_ctx: &mut SolutionContext) {
+        let incomplete = get_incomplete_visits(solution_ctx);
+        if incomplete.is_empty() {
+            return;
+        }
+
+        let route_indices = incomplete.iter().map(|(route_idx, _)| *route_idx).collect::<HashSet<_>>();
+        let job_ids = incomplete
+            .iter()
+            .filter_map(|(_, job)| get_periodic_visit(job).map(|(job_id, _)| job_id.clone()))
+            .collect::<HashSet<_>>();
+
+        incomplete.into_iter().for_each(|(route_idx, job)| {
+            solution_ctx.routes[route_idx].route_mut().tour.remove(&job);
+            solution_ctx.unassigned.insert(job, UnassignmentInfo::Simple(self.code));
+        });
+
+        // NOTE visits which were not inserted at all are reported with the same reason as removed ones
+        solution_ctx
+            .unassigned
+            .iter_mut()
+            .filter(|(job, _)| get_periodic_visit(job).map_or(false, |(job_id, _)| job_ids.contains(job_id)))
+            .for_each(|(_, info)| *info = UnassignmentInfo::Simple(self.code));
+        solution_ctx.mark_routes_changed(route_indices);
+        solution_ctx.keep_routes(&|route_ctx| route_ctx.route().tour.has_jobs());
+    }
+}
+
+fn get_periodic_visit(job: &Job) -> Option<(&String, &PeriodicVisit)> {
+    let dimens = job.dimens();
+    dimens.get_job_id().zip(dimens.get_periodic_visit())
+}
+
+/// Returns ids of periodic jobs visited by the route.
+fn get_periodic_job_ids(route_ctx: &RouteContext) -> HashSet<String> {
+    route_ctx.route().tour.jobs().filter_map(|job| get_periodic_visit(job).map(|(job_id, _)| job_id.clone())).collect()
+}
+
+/// Returns visits (with their route indices) of periodic jobs which do not complete their policy.
+/// Jobs with visits which still can be inserted or with locked visits are not considered.
+fn get_incomplete_visits(solution_ctx: &SolutionContext) -> Vec<(usize, Job)> {
+    let pending = solution_ctx
+        .required
+        .iter()
+        .chain(solution_ctx.locked.iter())
+        .filter_map(|job| get_periodic_visit(job).map(|(job_id, _)| job_id))
+        .collect::<HashSet<_>>();
+
+    let visits = solution_ctx.routes.iter().enumerate().fold(
+        HashMap::<&String, Vec<(usize, usize, &Job)>>::new(),
+        |mut acc, (route_idx, route_ctx)| {
+            let day = route_ctx.route().actor.vehicle.dimens.get_shift_day().unwrap_or_default();
+            route_ctx.route().tour.jobs().for_each(|job| {
+                if let Some((job_id, _)) = get_periodic_visit(job).filter(|(job_id, _)| !pending.contains(job_id)) {
+                    acc.entry(job_id).or_default().push((day, route_idx, job));
+                }
+            });
+            acc
+        },
+    );
+
+    visits
+        .into_values()
+        .filter(|visits| {
+            let days = visits.iter().map(|(day, _, _)| *day).collect::<Vec<_>>();
+            visits
+                .first()
+                .and_then(|(_, _, job)| get_periodic_visit(job))
+                .map_or(false, |(_, visit)| !visit.policy.is_complete_days(days.as_slice()))
+        })
+        .flat_map(|visits| visits.into_iter().map(|(_, route_idx, job)| (route_idx, job.clone())))
+        .collect()
+}
@@ -12,79 +12,120 @@ use std::cmp::Ordering;
 use std::sync::Arc;
 use vrp_core::construction::enablers::*;
 use vrp_core::construction::features::*;
-use vrp_core::models::solution::Route;
+use vrp_core::models::solution::Activity;
 
 /// Specifies a distance limit function for recharge. It should return a fixed value for the same
 /// actor all the time.
 pub type RechargeDistanceLimitFn = Arc<dyn Fn(&Actor) -> Option<Distance> + Send + Sync>;
+/// Specifies a function which returns vehicle battery model.
+pub type RechargeBatteryFn = Arc<dyn Fn(&Actor) -> Option<&RechargeBattery> + Send + Sync>;
+/// Specifies a function which returns charging power of the recharge station.
+pub type RechargePowerFn = Arc<dyn Fn(&Single) -> Option<f64> + Send + Sync>;
+/// Specifies a function which returns a ratio of job demand to vehicle capacity.
+pub type RechargeDemandRatioFn = Arc<dyn Fn(&Actor, &Single) -> f64 + Send + Sync>;
+
+/// Specifies a vehicle battery model used to track state of charge along the route.
+#[derive(Clone, Debug)]
+pub struct RechargeBattery {
+    /// Battery capacity.
+    pub capacity: f64,
+    /// State of charge at the start of the tour.
+    pub initial: f64,
+    /// A minimum state of charge which has to be kept all the time.
+    pub reserve: f64,
+    /// Energy consumed per distance unit by empty vehicle.
+    pub consumption: f64,
+    /// Energy consumed per distance unit by fully loaded vehicle. Actual consumption is interpolated
+    /// linearly using vehicle load ratio.
+    pub consumption_loaded: Option<f64>,
+}
+
+impl RechargeBattery {
+    /// Returns energy consumed on given distance with given vehicle load ratio.
+    pub fn get_consumption(&self, distance: Distance, load_ratio: f64) -> f64 {
+        distance * (self.consumption + self.get_load_rate() * load_ratio)
+    }
 
-/// Creates a feature to insert charge stations along the route.
+    /// Returns an extra consumption per distance unit at full load.
+    fn get_load_rate(&self) -> f64 {
+        self.consumption_loaded.map_or(0., |loaded| (loaded - self.consumption).max(0.))
+    }
+
+    /// Returns energy which can be consumed before next recharge.
+    fn get_usable_energy(&self, is_first_interval: bool) -> f64 {
+        if is_first_interval {
+            self.initial - self.reserve
+        } else {
+            self.capacity - self.reserve
+        }
+    }
+}
+
+/// Provides access to recharge related data.
+#[derive(Clone)]
+pub struct RechargeAccessor {
+    /// Returns max distance between recharges, used when vehicle has no battery model.
+    pub distance_limit_fn: RechargeDistanceLimitFn,
+    /// Returns vehicle battery model.
+    pub battery_fn: RechargeBatteryFn,
+    /// Returns charging power of the recharge station. When it is not set, charging duration is
+    /// fixed and the battery is charged fully.
+    pub power_fn: RechargePowerFn,
+    /// Returns a ratio of job demand to vehicle capacity.
+    pub demand_ratio_fn: RechargeDemandRatioFn,
+}
+
+/// Creates a feature to insert charge stations along the route. When vehicle has a battery model,
+/// energy consumption is tracked instead of traveled distance and recharge is partial: a station
+/// adds only energy needed to reach the next one (or the tour end) and its duration is extended
+/// by charging time when the station has charging power.
 pub fn create_recharge_feature(
     name: &str,
     code: ViolationCode,
-    distance_limit_fn: RechargeDistanceLimitFn,
+    accessor: RechargeAccessor,
     transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
 ) -> Result<Feature, GenericError> {
+    let energy = Arc::new(EnergyModel { accessor, transport: transport.clone() });
+
     create_multi_trip_feature(
         name,
         code,
-        &[RECHARGE_DISTANCE_KEY, RECHARGE_INTERVALS_KEY],
+        &[RECHARGE_DISTANCE_KEY, RECHARGE_INTERVALS_KEY, RECHARGE_SOC_KEY],
         MarkerInsertionPolicy::Any,
         Arc::new(RechargeableMultiTrip {
             route_intervals: FixedRouteIntervals {
                 is_marker_single_fn: Box::new(is_recharge_single),
                 is_new_interval_needed_fn: Box::new({
-                    let distance_limit_fn = distance_limit_fn.clone();
+                    let energy = energy.clone();
                     move |route_ctx| {
                         route_ctx
                             .route()
                             .tour
                             .end_idx()
                             .map(|end_idx| {
-                                let current: Distance = route_ctx
-                                    .state()
-                                    .get_activity_state(RECHARGE_DISTANCE_KEY, end_idx)
-                                    .copied()
-                                    .unwrap_or_default();
+                                let current = get_counter(route_ctx, end_idx);
+                                let is_first_interval = !route_ctx.route().tour.all_activities().any(is_recharge);
 
-                                (distance_limit_fn)(route_ctx.route().actor.as_ref())
+                                energy
+                                    .get_threshold(route_ctx.route().actor.as_ref(), is_first_interval)
                                     .map_or(false, |threshold| current > threshold)
                             })
                             .unwrap_or(false)
                     }
                 }),
                 is_obsolete_interval_fn: Box::new({
-                    let distance_limit_fn = distance_limit_fn.clone();
-                    let transport = transport.clone();
-                    let get_counter = move |route_ctx: &RouteContext, activity_idx: usize| {
-                        route_ctx
-                            .state()
-                            .get_activity_state(RECHARGE_DISTANCE_KEY, activity_idx)
-                            .copied()
-                            .unwrap_or(Distance::default())
-                    };
-                    let get_distance = move |route: &Route, from_idx: usize, to_idx: usize| {
-                        route.tour.get(from_idx).zip(route.tour.get(to_idx)).map_or(
-                            Distance::default(),
-                            |(from, to)| {
-                                transport.distance(
-                                    route,
-                                    from.place.location,
-                                    to.place.location,
-                                    TravelTime::Departure(from.schedule.departure),
-                                )
-                            },
-                        )
-                    };
+                    let energy = energy.clone();
                     move |route_ctx, left, right| {
                         let end_idx = get_end_idx(route_ctx, right.end);
 
-                        let new_distance = get_counter(route_ctx, left.end) + get_counter(route_ctx, end_idx)
+                        let new_energy = get_counter(route_ctx, left.end) + get_counter(route_ctx, end_idx)
                             - get_counter(route_ctx, right.start + 1)
-                            + get_distance(route_ctx.route(), left.end, right.start + 1);
+                            + energy.get_leg_energy(route_ctx, left.end, right.start + 1);
 
-                        (distance_limit_fn)(route_ctx.route().actor.as_ref())
-                            .map_or(false, |threshold| compare_floats(new_distance, threshold) != Ordering::Greater)
+                        energy
+                            .get_threshold(route_ctx.route().actor.as_ref(), left.start == 0)
+                            .map_or(false, |threshold| compare_floats(new_energy, threshold) != Ordering::Greater)
                     }
                 }),
                 is_assignable_fn: Box::new(|route, job| {
@@ -94,20 +135,76 @@ pub fn create_recharge_feature(
                 }),
                 intervals_key: RECHARGE_INTERVALS_KEY,
             },
-            transport,
+            energy,
+            activity,
             code,
-            distance_state_key: RECHARGE_DISTANCE_KEY,
-            distance_limit_fn,
         }),
     )
 }
 
+/// Returns battery state of charge after departure from the recharge station activity with given
+/// charging power and state of charge at arrival.
+pub fn get_charged_soc(activity: &Activity, battery: &RechargeBattery, power: Option<f64>, arrival: f64) -> f64 {
+    match power {
+        Some(power) => arrival + get_charging_duration(activity) * power,
+        None => battery.capacity.max(arrival),
+    }
+}
+
+struct EnergyModel {
+    accessor: RechargeAccessor,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl EnergyModel {
+    /// Returns true if recharge limits are defined for the actor.
+    fn is_enabled(&self, actor: &Actor) -> bool {
+        self.get_threshold(actor, true).is_some()
+    }
+
+    /// Returns energy (or distance when there is no battery) which can be used within the interval.
+    fn get_threshold(&self, actor: &Actor, is_first_interval: bool) -> Option<f64> {
+        match (self.accessor.battery_fn)(actor) {
+            Some(battery) => Some(battery.get_usable_energy(is_first_interval)),
+            None => (self.accessor.distance_limit_fn)(actor),
+        }
+    }
+
+    /// Returns energy consumed on the given distance.
+    fn get_energy(&self, actor: &Actor, distance: Distance, load_ratio: f64) -> f64 {
+        (self.accessor.battery_fn)(actor).map_or(distance, |battery| battery.get_consumption(distance, load_ratio))
+    }
+
+    /// Returns energy consumed between two activities of the route.
+    fn get_leg_energy(&self, route_ctx: &RouteContext, from_idx: usize, to_idx: usize) -> f64 {
+        let route = route_ctx.route();
+
+        route.tour.get(from_idx).zip(route.tour.get(to_idx)).map_or(0., |(from, to)| {
+            let distance = self.transport.distance(
+                route,
+                from.place.location,
+                to.place.location,
+                TravelTime::Departure(from.schedule.departure),
+            );
+
+            self.get_energy(route.actor.as_ref(), distance, get_load_ratio(route_ctx, from_idx))
+        })
+    }
+
+    /// Returns an upper bound of extra energy consumed within interval when job with given demand
+    /// ratio is inserted.
+    fn get_demand_energy(&self, actor: &Actor, interval_energy: f64, demand_ratio: f64) -> f64 {
+        (self.accessor.battery_fn)(actor)
+            .filter(|battery| battery.consumption > 0.)
+            .map_or(0., |battery| battery.get_load_rate() * demand_ratio * interval_energy / battery.consumption)
+    }
+}
+
 struct RechargeableMultiTrip {
     route_intervals: FixedRouteIntervals,
-    transport: Arc<dyn TransportCost + Send + Sync>,
+    energy: Arc<EnergyModel>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
     code: ViolationCode,
-    distance_state_key: StateKey,
-    distance_limit_fn: RechargeDistanceLimitFn,
 }
 
 impl MultiTrip for RechargeableMultiTrip {
@@ -120,42 +217,29 @@ impl MultiTrip for RechargeableMultiTrip {
     }
 
     fn recalculate_states(&self, route_ctx: &mut RouteContext) {
-        if (self.distance_limit_fn)(route_ctx.route().actor.as_ref()).is_none() {
+        if !self.energy.is_enabled(route_ctx.route().actor.as_ref()) {
             return;
         }
 
         let last_idx = route_ctx.route().tour.total() - 1;
         let marker_intervals = self.route_intervals.resolve_marker_intervals(route_ctx).collect::<Vec<_>>();
 
-        marker_intervals.into_iter().for_each(|(start_idx, end_idx)| {
-            let (route, state) = route_ctx.as_mut();
-
+        marker_intervals.iter().for_each(|&(start_idx, end_idx)| {
             let end_idx = if end_idx != last_idx { end_idx + 1 } else { end_idx };
 
-            let _ = route
-                .tour
-                .activities_slice(start_idx, end_idx)
-                .windows(2)
-                .enumerate()
-                .filter_map(|(leg_idx, leg)| match leg {
-                    [prev, next] => Some((start_idx + leg_idx, prev, next)),
-                    _ => None,
+            let counters = (start_idx..end_idx)
+                .scan(0., |acc, activity_idx| {
+                    *acc += self.energy.get_leg_energy(route_ctx, activity_idx, activity_idx + 1);
+                    Some((activity_idx + 1, *acc))
                 })
-                .fold(Distance::default(), |acc, (activity_idx, prev, next)| {
-                    let distance = self.transport.distance(
-                        route,
-                        prev.place.location,
-                        next.place.location,
-                        TravelTime::Departure(prev.schedule.departure),
-                    );
-                    let counter = acc + distance;
-                    let next_idx = activity_idx + 1;
-
-                    state.put_activity_state(self.distance_state_key, next_idx, counter);
-
-                    counter
-                });
+                .collect::<Vec<_>>();
+
+            counters.into_iter().for_each(|(activity_idx, counter)| {
+                route_ctx.state_mut().put_activity_state(RECHARGE_DISTANCE_KEY, activity_idx, counter);
+            });
         });
+
+        self.update_state_of_charge(route_ctx, marker_intervals.as_slice());
     }
 
     fn try_recover(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], _: &[Job]) -> bool {
@@ -218,42 +302,82 @@ impl RechargeableMultiTrip {
         route_ctx: &RouteContext,
         activity_ctx: &ActivityContext,
     ) -> Option<ConstraintViolation> {
-        let threshold = (self.distance_limit_fn)(route_ctx.route().actor.as_ref())?;
+        let actor = route_ctx.route().actor.as_ref();
+        if !self.energy.is_enabled(actor) {
+            return None;
+        }
 
-        let interval_distance = self
+        let (start_idx, end_idx) = self
             .route_intervals
             .resolve_marker_intervals(route_ctx)
             .find(|(_, end_idx)| activity_ctx.index <= *end_idx)
-            .map(|(_, end_idx)| get_end_idx(route_ctx, end_idx))
-            .map(|end_idx| self.get_distance(route_ctx, end_idx))
+            .map(|(start_idx, end_idx)| (start_idx, get_end_idx(route_ctx, end_idx)))
             .expect("invalid markers state");
 
+        let threshold = self.energy.get_threshold(actor, start_idx == 0)?;
+        let interval_energy = get_counter(route_ctx, end_idx);
+        let load_ratio = get_load_ratio(route_ctx, activity_ctx.index);
+
+        let ((prev_to_tar_distance, tar_to_next_distance), _) =
+            calculate_travel(route_ctx, activity_ctx, self.energy.transport.as_ref());
+
         let is_new_recharge = activity_ctx.target.job.as_ref().map_or(false, |job| is_recharge_single(job));
 
         let is_violation = if is_new_recharge {
-            let ((prev_to_tar_distance, tar_to_next_distance), _) =
-                calculate_travel(route_ctx, activity_ctx, self.transport.as_ref());
+            let prev_to_tar_energy = self.energy.get_energy(actor, prev_to_tar_distance, load_ratio);
+            let tar_to_next_energy = self.energy.get_energy(actor, tar_to_next_distance, load_ratio);
 
             // S ----- A ---- [X] ------ B ----- F
 
-            let current_distance = self.get_distance(route_ctx, activity_ctx.index);
+            let current_energy = get_counter(route_ctx, activity_ctx.index);
             // check S->X
-            let is_begin_violates = (current_distance + prev_to_tar_distance) > threshold;
+            let is_begin_violates = (current_energy + prev_to_tar_energy) > threshold;
             // check X->F
-            let is_end_violates = if activity_ctx.next.is_some() {
-                let next_distance = self.get_distance(route_ctx, activity_ctx.index + 1);
-                let new_interval_distance = interval_distance - next_distance;
-
-                (new_interval_distance + tar_to_next_distance) > threshold
+            let (is_end_violates, end_energy) = if activity_ctx.next.is_some() {
+                let next_energy = get_counter(route_ctx, activity_ctx.index + 1);
+                let new_interval_energy = interval_energy - next_energy + tar_to_next_energy;
+
+                (
+                    self.energy.get_threshold(actor, false).map_or(false, |limit| new_interval_energy > limit),
+                    new_interval_energy,
+                )
             } else {
-                false
+                (false, 0.)
             };
 
-            is_begin_violates || is_end_violates
-        } else {
-            let (distance_delta, _) = calculate_travel_delta(route_ctx, activity_ctx, self.transport.as_ref());
+            is_begin_violates || is_end_violates || {
+                let arrival = get_soc(route_ctx, activity_ctx.index) - prev_to_tar_energy;
+                let delay = self.get_charging_delay(actor, activity_ctx.target, arrival, end_energy);
 
-            (interval_distance + distance_delta) > threshold
+                !self.can_be_delayed(route_ctx, activity_ctx, activity_ctx.index + 1, delay)
+            }
+        } else {
+            let demand_ratio = activity_ctx
+                .target
+                .job
+                .as_ref()
+                .map_or(0., |single| (self.energy.accessor.demand_ratio_fn)(actor, single.as_ref()));
+            // NOTE target changes vehicle load in the interval, so the estimation is conservative
+            let new_ratio = (load_ratio + demand_ratio).min(1.);
+
+            let prev_to_next_distance = activity_ctx.next.map_or(Distance::default(), |next| {
+                let prev = activity_ctx.prev;
+                self.energy.transport.distance(
+                    route_ctx.route(),
+                    prev.place.location,
+                    next.place.location,
+                    TravelTime::Departure(prev.schedule.departure),
+                )
+            });
+
+            let energy_delta = self.energy.get_energy(actor, prev_to_tar_distance + tar_to_next_distance, new_ratio)
+                - self.energy.get_energy(actor, prev_to_next_distance, load_ratio)
+                + self.energy.get_demand_energy(actor, interval_energy, demand_ratio);
+
+            (interval_energy + energy_delta) > threshold
+                || self.get_affected_station(route_ctx, start_idx, end_idx).map_or(false, |(station_idx, power)| {
+                    !self.can_be_delayed(route_ctx, activity_ctx, station_idx, energy_delta.max(0.) / power)
+                })
         };
 
         if is_violation {
@@ -262,16 +386,188 @@ impl RechargeableMultiTrip {
             None
         }
     }
-}
 
-impl RechargeableMultiTrip {
-    fn get_distance(&self, route_ctx: &RouteContext, activity_idx: usize) -> Distance {
-        route_ctx
-            .state()
-            .get_activity_state::<Distance>(self.distance_state_key, activity_idx)
-            .copied()
-            .unwrap_or(Distance::default())
+    /// Returns a charging delay of the new recharge station with given arrival state of charge and
+    /// energy needed to reach the end of the interval.
+    fn get_charging_delay(&self, actor: &Actor, target: &Activity, arrival: f64, interval_energy: f64) -> Duration {
+        let battery = (self.energy.accessor.battery_fn)(actor);
+        let power = target.job.as_ref().and_then(|single| (self.energy.accessor.power_fn)(single));
+
+        battery.zip(power).map_or(Duration::default(), |(battery, power)| {
+            let departure = (battery.reserve + interval_energy).clamp(arrival, battery.capacity.max(arrival));
+            (departure - arrival) / power
+        })
     }
+
+    /// Returns a recharge station with the lowest charging power which has to charge more energy
+    /// when interval's consumption grows.
+    fn get_affected_station(&self, route_ctx: &RouteContext, start_idx: usize, end_idx: usize) -> Option<(usize, f64)> {
+        let route = route_ctx.route();
+        (self.energy.accessor.battery_fn)(route.actor.as_ref())?;
+
+        let get_power = |idx: usize| {
+            route
+                .tour
+                .get(idx)
+                .filter(|activity| is_recharge(activity))
+                .and_then(|activity| activity.job.as_ref())
+                .and_then(|single| (self.energy.accessor.power_fn)(single))
+                .map(|power| (idx, power))
+        };
+
+        // NOTE the station before interval charges more, otherwise the one at the end of interval
+        let stations = (if start_idx > 0 { get_power(start_idx) } else { None }).into_iter().chain(get_power(end_idx));
+
+        stations.fold(None, |acc: Option<(usize, f64)>, (idx, power)| match acc {
+            Some((acc_idx, acc_power)) => Some((acc_idx, acc_power.min(power))),
+            None => Some((idx, power)),
+        })
+    }
+
+    /// Checks whether the tour stays feasible when activities after the station are delayed.
+    fn can_be_delayed(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        station_idx: usize,
+        delay: Duration,
+    ) -> bool {
+        if delay <= 0. {
+            return true;
+        }
+
+        let route = route_ctx.route();
+        let transport = self.energy.transport.as_ref();
+        let get_latest_arrival = |idx: usize| {
+            route_ctx
+                .state()
+                .get_activity_state::<Timestamp>(LATEST_ARRIVAL_KEY, idx)
+                .copied()
+                .or_else(|| route.tour.get(idx).map(|activity| activity.place.time.end))
+                .unwrap_or(Timestamp::MAX)
+        };
+
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let target_arrival = prev.schedule.departure
+            + transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+        let target_departure = target_arrival.max(target.place.time.start) + target.place.duration;
+
+        // NOTE station is before the target: activities between them and the target itself are delayed
+        let is_before_target_ok = station_idx > activity_ctx.index || {
+            let after_station_idx = station_idx + 1;
+            let is_tour_ok = after_station_idx > activity_ctx.index
+                || route.tour.get(after_station_idx).map_or(true, |activity| {
+                    activity.schedule.arrival + delay <= get_latest_arrival(after_station_idx)
+                });
+
+            is_tour_ok && target_arrival + delay <= target.place.time.end
+        };
+
+        let is_next_ok = activity_ctx.next.map_or(true, |next| {
+            let next_arrival = target_departure
+                + transport.duration(
+                    route,
+                    target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(target_departure),
+                );
+
+            next_arrival.max(next.schedule.arrival) + delay <= get_latest_arrival(activity_ctx.index + 1)
+        });
+
+        is_before_target_ok && is_next_ok
+    }
+
+    /// Calculates state of charge at each activity and adjusts charging durations of recharge stations.
+    fn update_state_of_charge(&self, route_ctx: &mut RouteContext, marker_intervals: &[(usize, usize)]) {
+        let battery = match (self.energy.accessor.battery_fn)(route_ctx.route().actor.as_ref()) {
+            Some(battery) => battery.clone(),
+            None => return,
+        };
+
+        let mut durations = vec![];
+
+        marker_intervals.iter().fold(battery.initial, |soc, &(start_idx, end_idx)| {
+            let end_idx = get_end_idx(route_ctx, end_idx);
+            let interval_energy = get_counter(route_ctx, end_idx);
+
+            let soc = if start_idx > 0 {
+                let station = route_ctx.route().tour.get(start_idx).unwrap();
+                match station.job.as_ref().and_then(|single| (self.energy.accessor.power_fn)(single)) {
+                    Some(power) => {
+                        let departure = (battery.reserve + interval_energy).clamp(soc, battery.capacity.max(soc));
+                        let duration = get_base_duration(station) + (departure - soc) / power;
+                        if compare_floats(duration, station.place.duration) != Ordering::Equal {
+                            durations.push((start_idx, duration));
+                        }
+
+                        departure
+                    }
+                    None => battery.capacity.max(soc),
+                }
+            } else {
+                soc
+            };
+
+            let state = route_ctx.state_mut();
+            state.put_activity_state(RECHARGE_SOC_KEY, start_idx, soc);
+            (start_idx + 1..=end_idx).for_each(|activity_idx| {
+                let counter = state.get_activity_state::<f64>(RECHARGE_DISTANCE_KEY, activity_idx).copied();
+                state.put_activity_state(RECHARGE_SOC_KEY, activity_idx, soc - counter.unwrap_or_default());
+            });
+
+            soc - interval_energy
+        });
+
+        if !durations.is_empty() {
+            durations.into_iter().for_each(|(activity_idx, duration)| {
+                route_ctx.route_mut().tour.get_mut(activity_idx).unwrap().place.duration = duration;
+            });
+
+            // NOTE charging durations are known only when vehicle load is calculated, so schedule
+            // has to be updated once again
+            update_route_schedule(
+                route_ctx,
+                self.activity.as_ref(),
+                self.energy.transport.as_ref(),
+                &ScheduleStateKeys::default(),
+            );
+        }
+    }
+}
+
+fn get_counter(route_ctx: &RouteContext, activity_idx: usize) -> f64 {
+    route_ctx.state().get_activity_state::<f64>(RECHARGE_DISTANCE_KEY, activity_idx).copied().unwrap_or_default()
+}
+
+fn get_load_ratio(route_ctx: &RouteContext, activity_idx: usize) -> f64 {
+    route_ctx.state().get_activity_state::<f64>(LOAD_RATIO_KEY, activity_idx).copied().unwrap_or_default()
+}
+
+fn get_soc(route_ctx: &RouteContext, activity_idx: usize) -> f64 {
+    route_ctx.state().get_activity_state::<f64>(RECHARGE_SOC_KEY, activity_idx).copied().unwrap_or_default()
+}
+
+fn get_base_duration(activity: &Activity) -> Duration {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.places.get(activity.place.idx))
+        .map_or(activity.place.duration, |place| place.duration)
+}
+
+/// Returns a time spent on charging at the recharge station activity.
+fn get_charging_duration(activity: &Activity) -> Duration {
+    (activity.place.duration - get_base_duration(activity)).max(0.)
+}
+
+fn is_recharge(activity: &Activity) -> bool {
+    activity.job.as_ref().map_or(false, |single| is_recharge_single(single))
 }
 
 fn is_recharge_single(single: &Single) -> bool {
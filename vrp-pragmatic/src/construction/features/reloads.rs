@@ -14,6 +14,9 @@ use vrp_core::construction::enablers::{FixedRouteIntervals, RouteIntervals};
 use vrp_core::construction::features::*;
 use vrp_core::models::problem::Single;
 
+/// A threshold of trip limits usage after which a new trip is needed.
+const TRIP_LIMIT_THRESHOLD: f64 = 0.9;
+
 /// Specifies load schedule threshold function.
 pub type LoadScheduleThresholdFn<T> = Box<dyn Fn(&T) -> T + Send + Sync>;
 /// A factory function to create capacity feature.
@@ -72,6 +75,9 @@ fn create_reload_route_intervals<T: LoadOps>(
     load_schedule_threshold_fn: LoadScheduleThresholdFn<T>,
     place_capacity_threshold: Option<PlaceCapacityThresholdFn<T>>,
 ) -> FixedRouteIntervals {
+    let compartment_accessor = get_compartment_accessor();
+    let obsolete_compartment_accessor = compartment_accessor.clone();
+
     FixedRouteIntervals {
         is_marker_single_fn: Box::new(is_reload_single),
         is_new_interval_needed_fn: Box::new(move |route_ctx| {
@@ -90,8 +96,10 @@ fn create_reload_route_intervals<T: LoadOps>(
                     let threshold_capacity = (load_schedule_threshold_fn)(max_capacity);
 
                     current.partial_cmp(&threshold_capacity) != Some(Ordering::Less)
+                        || is_last_trip_full(route_ctx.route(), &compartment_accessor)
                 })
                 .unwrap_or(false)
+                || is_last_trip_limited(route_ctx)
         }),
         is_obsolete_interval_fn: Box::new(move |route_ctx, left, right| {
             let capacity: T = route_ctx.route().actor.vehicle.dimens.get_capacity().cloned().unwrap_or_default();
@@ -126,6 +134,8 @@ fn create_reload_route_intervals<T: LoadOps>(
                 capacity.can_fit(&new_max_load_left) && capacity.can_fit(&new_max_load_right);
 
             has_enough_vehicle_capacity
+                && can_merge_trips(route_ctx.route(), &obsolete_compartment_accessor, left.clone(), right.clone())
+                && can_merge_trip_limits(route_ctx, left.clone(), right.clone())
                 && place_capacity_threshold.as_ref().map_or(true, |place_capacity_threshold| {
                     // total static delivery at left
                     let left_delivery = fold_demand(left.start..right.end, |demand| demand.delivery.0);
@@ -172,6 +182,42 @@ where
     )
 }
 
+/// Checks whether the last trip of the route is close to any of vehicle's trip limits.
+fn is_last_trip_limited(route_ctx: &RouteContext) -> bool {
+    let Some(limits) = route_ctx.route().actor.vehicle.dimens.get_trip_limits() else {
+        return false;
+    };
+
+    let last_interval = route_ctx
+        .state()
+        .get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY)
+        .and_then(|intervals| intervals.last().copied())
+        .unwrap_or((0, route_ctx.route().tour.total() - 1));
+    let (distance, duration, size) = get_trip_usage(route_ctx, last_interval);
+
+    let is_reached =
+        |limit: Option<f64>, value: f64| limit.map_or(false, |limit| value >= limit * TRIP_LIMIT_THRESHOLD);
+
+    is_reached(limits.max_distance, distance)
+        || is_reached(limits.max_duration, duration)
+        || is_reached(limits.max_size.map(|size| size as f64), size as f64)
+}
+
+/// Checks whether two trips can be merged without violating vehicle's trip limits.
+/// NOTE travel via reload is used as an upper bound for the merged trip.
+fn can_merge_trip_limits(route_ctx: &RouteContext, left: Range<usize>, right: Range<usize>) -> bool {
+    route_ctx.route().actor.vehicle.dimens.get_trip_limits().map_or(true, |limits| {
+        let (left_distance, left_duration, left_size) = get_trip_usage(route_ctx, (left.start, left.end));
+        let (right_distance, right_duration, right_size) = get_trip_usage(route_ctx, (right.start, right.end));
+
+        let fits = |limit: Option<f64>, value: f64| limit.map_or(true, |limit| value <= limit);
+
+        fits(limits.max_distance, left_distance + right_distance)
+            && fits(limits.max_duration, left_duration + right_duration)
+            && fits(limits.max_size.map(|size| size as f64), (left_size + right_size) as f64)
+    })
+}
+
 fn is_reload_single(single: &Single) -> bool {
     single.dimens.get_job_type().map_or(false, |t| t == "reload")
 }
@@ -0,0 +1,360 @@
+//! A feature to model two-echelon routing: first-level vehicles deliver goods from the central depot
+//! to satellites and second-level vehicles start their shifts at satellites to serve customers.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/satellites_test.rs"]
+mod satellites_test;
+
+use super::*;
+use crate::construction::enablers::JobTie;
+use hashbrown::HashMap;
+use std::cmp::Ordering;
+use std::iter::once;
+use vrp_core::construction::enablers::{propagate_time_window_bounds, update_route_departure, ScheduleStateKeys};
+use vrp_core::construction::features::*;
+use vrp_core::models::solution::Route;
+
+/// Specifies satellite depots of the two-echelon problem.
+#[derive(Clone, Debug, Default)]
+pub struct Satellites {
+    /// Satellite resource ids by their locations: a vehicle which starts its shift at the satellite
+    /// location is loaded there.
+    pub locations: HashMap<Location, SharedResourceId>,
+    /// Satellite resource ids by satellite ids used by supply jobs.
+    pub ids: HashMap<String, SharedResourceId>,
+}
+
+/// Keeps time bounds of loading at satellites.
+#[derive(Clone, Debug, Default)]
+struct SatelliteSchedule {
+    /// The earliest departure from the satellite: all assigned supply jobs are delivered.
+    ready: HashMap<SharedResourceId, Timestamp>,
+    /// The latest time when the last supply job can be delivered to the satellite without delaying
+    /// second-level vehicles loaded there.
+    latest_ready: HashMap<SharedResourceId, Timestamp>,
+}
+
+/// Creates a feature to model satellite depots. A satellite inventory is a shared resource which is
+/// supplied by deliveries of jobs with satellite id and consumed by deliveries of vehicles starting
+/// their shifts at the satellite. Such vehicles cannot depart before all supply jobs are delivered,
+/// and supply jobs cannot be delivered later than the latest departure of such vehicles.
+/// This is a hard constraint.
+pub fn create_satellite_feature<T>(
+    name: &str,
+    total_jobs: usize,
+    code: ViolationCode,
+    satellites: Satellites,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Result<Feature, GenericError>
+where
+    T: SharedResource + LoadOps,
+{
+    let satellites = Arc::new(satellites);
+
+    let schedule = FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(SatelliteConstraint {
+            code,
+            satellites: satellites.clone(),
+            transport: transport.clone(),
+            activity: activity.clone(),
+        })
+        .with_state(SatelliteState {
+            satellites: satellites.clone(),
+            transport,
+            activity,
+            state_keys: vec![SATELLITE_SCHEDULE_KEY],
+        })
+        .build()?;
+
+    let resource = create_shared_resource_feature_with_supply(
+        name,
+        total_jobs,
+        code,
+        SATELLITE_RESOURCE_KEY,
+        Arc::new(|route_ctx| route_ctx.state().get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY)),
+        {
+            let satellites = satellites.clone();
+            // NOTE only the first interval starts at activity without job
+            Arc::new(move |activity| {
+                activity
+                    .job
+                    .is_none()
+                    .then(|| satellites.locations.get(&activity.place.location))
+                    .flatten()
+                    .map(|&resource_id| (T::default(), resource_id))
+            })
+        },
+        Arc::new(|single| single.dimens.get_demand().map(|demand: &Demand<T>| demand.delivery.0)),
+        Arc::new(move |single| {
+            get_supply_satellite(&satellites, single)
+                .zip(single.dimens.get_demand().map(|demand: &Demand<T>| demand.delivery.0))
+                .map(|(resource_id, supply)| (supply, resource_id))
+        }),
+    )?;
+
+    FeatureBuilder::combine(name, &[schedule, resource])
+}
+
+struct SatelliteConstraint {
+    code: ViolationCode,
+    satellites: Arc<Satellites>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl SatelliteConstraint {
+    fn evaluate_route(&self, route_ctx: &RouteContext, job: &Job) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let start_satellite = get_start_satellite(&self.satellites, route);
+
+        // NOTE vehicle loaded at the satellite cannot supply satellites
+        let is_supply =
+            job.as_single().map_or(false, |single| get_supply_satellite(&self.satellites, single).is_some());
+        if is_supply && start_satellite.is_some() {
+            return ConstraintViolation::fail(self.code);
+        }
+
+        let ready = start_satellite.zip(get_schedule(route_ctx)).and_then(|(id, schedule)| schedule.ready.get(&id));
+        let latest = route.tour.start().map_or(f64::MAX, |start| start.place.time.end);
+
+        match ready {
+            Some(&ready) if compare_floats(ready, latest) == Ordering::Greater => ConstraintViolation::fail(self.code),
+            _ => None,
+        }
+    }
+
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let target = activity_ctx.target;
+        let resource_id = target.job.as_ref().and_then(|single| get_supply_satellite(&self.satellites, single))?;
+        let latest_ready = get_schedule(route_ctx).and_then(|schedule| schedule.latest_ready.get(&resource_id))?;
+
+        let route = route_ctx.route();
+        let departure = activity_ctx.prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                activity_ctx.prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+        let departure = self.activity.estimate_departure(route, target, arrival);
+
+        if compare_floats(departure, *latest_ready) == Ordering::Greater {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
+}
+
+impl FeatureConstraint for SatelliteConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => self.evaluate_route(route_ctx, job),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let any_is_supply = once(&source)
+            .chain(once(&candidate))
+            .flat_map(|job| job.as_single())
+            .any(|single| get_supply_satellite(&self.satellites, single).is_some());
+
+        if any_is_supply {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+struct SatelliteState {
+    satellites: Arc<Satellites>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    state_keys: Vec<StateKey>,
+}
+
+impl SatelliteState {
+    /// Delays departure of second-level vehicles till their satellites are supplied, bounds time
+    /// windows of supply jobs by the latest departure of such vehicles and stores satellite schedule
+    /// in each route.
+    fn update_schedules(&self, solution_ctx: &mut SolutionContext) {
+        let ready = self.get_ready_times(&solution_ctx.routes);
+
+        let route_indices = solution_ctx
+            .routes
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(route_idx, route_ctx)| {
+                let start = route_ctx.route().tour.start().unwrap();
+                let departure = get_start_satellite(&self.satellites, route_ctx.route())
+                    .and_then(|resource_id| ready.get(&resource_id))
+                    .map(|&ready| ready.min(start.place.time.end))
+                    .filter(|&ready| compare_floats(ready, start.schedule.departure) == Ordering::Greater)?;
+
+                self.update_departure(route_ctx, departure);
+
+                Some(route_idx)
+            })
+            .collect::<Vec<_>>();
+        solution_ctx.mark_routes_changed(route_indices);
+
+        // NOTE empty routes depart as early as satellite allows
+        solution_ctx.registry.all_routes_mut().for_each(|route_ctx| {
+            let start = route_ctx.route().tour.start().unwrap();
+            let departure = get_start_satellite(&self.satellites, route_ctx.route())
+                .and_then(|resource_id| ready.get(&resource_id))
+                .map_or(start.place.time.start, |&ready| ready.clamp(start.place.time.start, start.place.time.end));
+
+            if compare_floats(departure, start.schedule.departure) != Ordering::Equal {
+                self.update_departure(route_ctx, departure);
+            }
+        });
+
+        let latest_ready = self.get_latest_ready_times(solution_ctx);
+
+        // NOTE bounded time windows make the transport constraint reject insertions which delay supply
+        propagate_time_window_bounds(
+            solution_ctx,
+            SATELLITE_SCHEDULE_KEY,
+            self.transport.as_ref(),
+            self.activity.as_ref(),
+            |solution_ctx| self.get_supply_bounds(solution_ctx, &latest_ready),
+        );
+
+        let schedule = Arc::new(SatelliteSchedule { ready, latest_ready });
+
+        solution_ctx.routes.iter_mut().chain(solution_ctx.registry.all_routes_mut()).for_each(|route_ctx| {
+            route_ctx.state_mut().put_route_state_raw(SATELLITE_SCHEDULE_KEY, schedule.clone());
+        });
+    }
+
+    /// Returns time window bounds of assigned supply jobs: the service should be finished till
+    /// the satellite is expected to be ready.
+    fn get_supply_bounds(
+        &self,
+        solution_ctx: &SolutionContext,
+        latest_ready: &HashMap<SharedResourceId, Timestamp>,
+    ) -> HashMap<Job, TimeWindow> {
+        solution_ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+            .filter_map(|activity| {
+                let single = activity.job.as_ref()?;
+                let latest_ready = latest_ready.get(&get_supply_satellite(&self.satellites, single)?)?;
+
+                Some((Job::Single(single.clone()), TimeWindow::new(0., latest_ready - activity.place.duration)))
+            })
+            .collect()
+    }
+
+    /// Returns departure time of the last assigned supply job for each satellite.
+    fn get_ready_times(&self, routes: &[RouteContext]) -> HashMap<SharedResourceId, Timestamp> {
+        routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+            .filter_map(|activity| {
+                activity
+                    .job
+                    .as_ref()
+                    .and_then(|single| get_supply_satellite(&self.satellites, single))
+                    .map(|resource_id| (resource_id, activity.schedule.departure))
+            })
+            .fold(HashMap::default(), |mut acc, (resource_id, departure)| {
+                let ready = acc.entry(resource_id).or_insert(departure);
+                *ready = ready.max(departure);
+
+                acc
+            })
+    }
+
+    /// Returns the latest departure time of second-level vehicles from each satellite.
+    fn get_latest_ready_times(&self, solution_ctx: &SolutionContext) -> HashMap<SharedResourceId, Timestamp> {
+        solution_ctx
+            .routes
+            .iter()
+            .filter(|route_ctx| route_ctx.route().tour.has_jobs())
+            .filter_map(|route_ctx| {
+                get_start_satellite(&self.satellites, route_ctx.route())
+                    .map(|resource_id| (resource_id, self.get_latest_departure(route_ctx)))
+            })
+            .fold(HashMap::default(), |mut acc, (resource_id, departure)| {
+                let latest = acc.entry(resource_id).or_insert(departure);
+                *latest = latest.min(departure);
+
+                acc
+            })
+    }
+
+    /// Returns the latest departure time from the route start which keeps the rest of tour feasible.
+    fn get_latest_departure(&self, route_ctx: &RouteContext) -> Timestamp {
+        let route = route_ctx.route();
+        let start = route.tour.start().unwrap();
+
+        route
+            .tour
+            .get(1)
+            .filter(|next| next.job.is_some())
+            .and_then(|next| {
+                let latest_arrival = *route_ctx.state().get_activity_state::<Timestamp>(LATEST_ARRIVAL_KEY, 1)?;
+                let duration = self.transport.duration(
+                    route,
+                    start.place.location,
+                    next.place.location,
+                    TravelTime::Arrival(latest_arrival),
+                );
+
+                Some(latest_arrival - duration)
+            })
+            .map_or(start.place.time.end, |departure| departure.min(start.place.time.end))
+    }
+
+    fn update_departure(&self, route_ctx: &mut RouteContext, departure: Timestamp) {
+        update_route_departure(
+            route_ctx,
+            self.activity.as_ref(),
+            self.transport.as_ref(),
+            departure,
+            &ScheduleStateKeys::default(),
+        );
+    }
+}
+
+impl FeatureState for SatelliteState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        self.update_schedules(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.update_schedules(solution_ctx);
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+/// Returns satellite resource id if the route starts at the satellite.
+fn get_start_satellite(satellites: &Satellites, route: &Route) -> Option<SharedResourceId> {
+    route.tour.start().and_then(|start| satellites.locations.get(&start.place.location)).copied()
+}
+
+/// Returns satellite resource id if the job supplies the satellite.
+fn get_supply_satellite(satellites: &Satellites, single: &Single) -> Option<SharedResourceId> {
+    single.dimens.get_job_satellite().and_then(|satellite_id| satellites.ids.get(satellite_id)).copied()
+}
+
+fn get_schedule(route_ctx: &RouteContext) -> Option<&SatelliteSchedule> {
+    route_ctx.state().get_route_state::<SatelliteSchedule>(SATELLITE_SCHEDULE_KEY)
+}
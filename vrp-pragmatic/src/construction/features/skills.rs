@@ -5,8 +5,9 @@
 mod skills_test;
 
 use super::*;
-use crate::construction::enablers::{JobTie, VehicleTie};
+use crate::construction::enablers::{DriverTie, JobTie, VehicleTie};
 use hashbrown::HashSet;
+use std::borrow::Cow;
 
 /// A job skills limitation for a vehicle.
 pub struct JobSkills {
@@ -42,7 +43,8 @@ impl FeatureConstraint for SkillsConstraint {
         match move_ctx {
             MoveContext::Route { route_ctx, job, .. } => {
                 if let Some(job_skills) = job.dimens().get_job_skills() {
-                    let vehicle_skills = route_ctx.route().actor.vehicle.dimens.get_vehicle_skills();
+                    let actor_skills = get_actor_skills(route_ctx.route().actor.as_ref());
+                    let vehicle_skills = actor_skills.as_deref();
                     let is_ok = check_all_of(job_skills, &vehicle_skills)
                         && check_one_of(job_skills, &vehicle_skills)
                         && check_none_of(job_skills, &vehicle_skills);
@@ -88,6 +90,17 @@ impl FeatureConstraint for SkillsConstraint {
     }
 }
 
+/// Returns skills of the actor: vehicle skills combined with driver ones.
+fn get_actor_skills(actor: &Actor) -> Option<Cow<'_, HashSet<String>>> {
+    match (actor.vehicle.dimens.get_vehicle_skills(), actor.driver.dimens.get_driver_skills()) {
+        (Some(vehicle_skills), Some(driver_skills)) => {
+            Some(Cow::Owned(vehicle_skills.union(driver_skills).cloned().collect()))
+        }
+        (Some(skills), None) | (None, Some(skills)) => Some(Cow::Borrowed(skills)),
+        (None, None) => None,
+    }
+}
+
 fn check_all_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String>>) -> bool {
     match (job_skills.all_of.as_ref(), vehicle_skills) {
         (Some(job_skills), Some(vehicle_skills)) => job_skills.is_subset(vehicle_skills),
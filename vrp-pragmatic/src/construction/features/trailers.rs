@@ -0,0 +1,359 @@
+//! A feature to model truck and trailer routing: a vehicle with trailer has to decouple it at a
+//! trailer park before serving truck only jobs and couple it back at the same park afterwards.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/trailers_test.rs"]
+mod trailers_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use hashbrown::HashSet;
+use std::iter::once;
+use std::marker::PhantomData;
+use vrp_core::construction::enablers::*;
+use vrp_core::models::solution::{Activity, Route};
+
+/// Specifies truck and trailer capacities of the vehicle with trailer. Vehicle capacity is
+/// expected to be a sum of both.
+#[derive(Clone, Debug)]
+pub struct TrailerCapacity<T: LoadOps> {
+    /// Capacity of the truck alone.
+    pub truck: T,
+    /// Capacity of the trailer.
+    pub trailer: T,
+}
+
+/// Creates a feature to serve truck only jobs by vehicles with trailer. A trailer park is modeled
+/// as a conditional job with two sub jobs: decouple and couple. While the trailer is parked, the
+/// truck alone carries goods delivered or picked up in between, the rest stays in the trailer.
+/// Route intervals are used to detect trips: decoupled part of the tour cannot include a marker job.
+pub fn create_trailer_feature<T: LoadOps>(
+    name: &str,
+    code: ViolationCode,
+    route_intervals: Arc<dyn RouteIntervals + Send + Sync>,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(TrailerConstraint::<T> { code, route_intervals, phantom: Default::default() })
+        .with_state(TrailerState::<T> {
+            context_transition: Box::new(ConcreteJobContextTransition {
+                remove_required: |_, _, job| is_trailer_park_job(job),
+                promote_required: |_, _, _| false,
+                remove_locked: |_, _, _| false,
+                promote_locked: |_, _, job| is_trailer_park_job(job),
+            }),
+            state_keys: vec![TRAILER_RANGES_KEY],
+            phantom: Default::default(),
+        })
+        .build()
+}
+
+/// Keeps decoupled ranges of the tour as (decouple index, couple index) pairs. The couple index
+/// is absent only while park job is being inserted.
+type DecoupledRanges = Vec<(usize, Option<usize>)>;
+
+struct TrailerConstraint<T: LoadOps> {
+    code: ViolationCode,
+    route_intervals: Arc<dyn RouteIntervals + Send + Sync>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> TrailerConstraint<T> {
+    fn evaluate_route(&self, route_ctx: &RouteContext, job: &Job) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+
+        if is_trailer_park_job(job) {
+            return if is_park_job_of_route(route, job) { None } else { ConstraintViolation::fail(self.code) };
+        }
+
+        // NOTE truck only job can be served only within decoupled part of the tour
+        let has_trailer = route.actor.vehicle.dimens.get_vehicle_trailer::<T>().is_some();
+        if has_trailer && is_truck_only_job(job) && !get_ranges(route_ctx).iter().any(|(_, end)| end.is_some()) {
+            return ConstraintViolation::fail(self.code);
+        }
+
+        None
+    }
+
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let trailer = route.actor.vehicle.dimens.get_vehicle_trailer::<T>()?;
+        let ranges = get_ranges(route_ctx);
+        let (index, target) = (activity_ctx.index, activity_ctx.target);
+
+        let is_valid = if is_decouple(target) {
+            get_decoupled_range(ranges, index).is_none()
+        } else if is_couple(target) {
+            // NOTE couple has to close the range opened by decouple of the same park job
+            ranges.iter().rev().find(|(start, _)| *start <= index).map_or(false, |&(start, end)| {
+                end.is_none() && !route.tour.activities_slice(start, index).iter().any(|a| self.is_marker(a))
+            })
+        } else {
+            match get_decoupled_range(ranges, index) {
+                Some((_, Some(_))) => !self.is_marker(target),
+                Some((_, None)) => false,
+                None => !is_truck_only(target),
+            }
+        };
+
+        let has_closed_ranges = ranges.iter().any(|(_, end)| end.is_some());
+        let needs_load_check = has_closed_ranges || is_couple(target);
+
+        if !is_valid || (needs_load_check && !self.can_fit_truck(route, trailer, index, target)) {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
+
+    /// Checks that the truck can carry goods within all decoupled ranges when target activity is
+    /// inserted after activity at given index.
+    fn can_fit_truck(&self, route: &Route, trailer: &TrailerCapacity<T>, index: usize, target: &Activity) -> bool {
+        let activities = route
+            .tour
+            .all_activities()
+            .enumerate()
+            .flat_map(|(idx, activity)| once(activity).chain((idx == index).then_some(target)))
+            .collect::<Vec<_>>();
+
+        activities.split(|activity| self.is_marker(activity)).all(|trip| {
+            let start_load = trip
+                .iter()
+                .filter_map(|activity| get_demand::<T>(activity))
+                .fold(T::default(), |acc, demand| acc + demand.delivery.0);
+
+            trip.iter()
+                .enumerate()
+                .try_fold(start_load, |load, (idx, activity)| {
+                    let is_feasible = !is_decouple(activity)
+                        || trip[idx + 1..]
+                            .iter()
+                            .position(|activity| is_couple(activity))
+                            .map_or(true, |end| can_fit_range(trailer, load, &trip[idx + 1..idx + 1 + end]));
+
+                    is_feasible.then(|| load + get_demand::<T>(activity).map_or_else(T::default, |d| d.change()))
+                })
+                .is_some()
+        })
+    }
+
+    fn is_marker(&self, activity: &Activity) -> bool {
+        activity.job.as_ref().map_or(false, |single| self.route_intervals.is_marker_job(&Job::Single(single.clone())))
+    }
+}
+
+impl<T: LoadOps> FeatureConstraint for TrailerConstraint<T> {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => self.evaluate_route(route_ctx, job),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let has_park = once(&source).chain(once(&candidate)).any(is_trailer_park_job);
+
+        if has_park || is_truck_only_job(&source) != is_truck_only_job(&candidate) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+struct TrailerState<T: LoadOps> {
+    context_transition: Box<dyn JobContextTransition + Send + Sync>,
+    state_keys: Vec<StateKey>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> TrailerState<T> {
+    /// Moves trailer park jobs of given routes without parks from ignored to required, so that truck
+    /// only jobs can be served. Optionally, parks of vehicles without routes are promoted too, so
+    /// that a new route can be started. Returns true if any job is promoted.
+    fn promote_parks(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], with_unused: bool) -> bool {
+        let routes = &solution_ctx.routes;
+        let jobs = solution_ctx
+            .ignored
+            .iter()
+            .filter(|job| is_trailer_park_job(job))
+            .filter(|job| match routes.iter().position(|route_ctx| is_park_job_of_route(route_ctx.route(), job)) {
+                Some(idx) => route_indices.contains(&idx) && !has_parks(routes[idx].route()),
+                None => with_unused,
+            })
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        if jobs.is_empty() {
+            false
+        } else {
+            solution_ctx.ignored.retain(|job| !jobs.contains(job));
+            solution_ctx.locked.extend(jobs.iter().cloned());
+            solution_ctx.required.extend(jobs);
+
+            true
+        }
+    }
+
+    /// Removes trailer parks which decoupled range has no truck only job.
+    fn remove_trivial_parks(&self, solution_ctx: &mut SolutionContext) {
+        let mut extra_ignored = Vec::new();
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let route = route_ctx.route();
+            let jobs = get_decoupled_ranges(route)
+                .into_iter()
+                .filter_map(|(start, end)| end.map(|end| (start, end)))
+                .filter(|&(start, end)| !route.tour.activities_slice(start, end).iter().any(is_truck_only))
+                .filter_map(|(start, _)| route.tour.get(start).and_then(|activity| activity.retrieve_job()))
+                .collect::<Vec<_>>();
+
+            jobs.into_iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(&job);
+                extra_ignored.push(job);
+            });
+        });
+
+        solution_ctx.ignored.extend(extra_ignored);
+        solution_ctx.keep_routes(&|route_ctx| route_ctx.route().tour.has_jobs());
+    }
+}
+
+impl<T: LoadOps> FeatureState for TrailerState<T> {
+    fn notify_failure(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], jobs: &[Job]) -> bool {
+        jobs.iter().any(is_truck_only_job) && self.promote_parks(solution_ctx, route_indices, true)
+    }
+
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+
+        // NOTE give a chance to the used route to serve truck only jobs
+        if !is_trailer_park_job(job) && solution_ctx.required.iter().any(is_truck_only_job) {
+            self.promote_parks(solution_ctx, &[route_index], false);
+        }
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        if route_ctx.route().actor.vehicle.dimens.get_vehicle_trailer::<T>().is_some() {
+            let ranges = get_decoupled_ranges(route_ctx.route());
+            route_ctx.state_mut().put_route_state(TRAILER_RANGES_KEY, ranges);
+        }
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        process_conditional_jobs(solution_ctx, None, self.context_transition.as_ref());
+
+        // NOTE park jobs are not reported as unassigned
+        let unassigned = solution_ctx.unassigned.extract_if(|job, _| is_trailer_park_job(job)).map(|(job, _)| job);
+        solution_ctx.ignored.extend(unassigned.collect::<Vec<_>>());
+
+        self.remove_trivial_parks(solution_ctx);
+
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+/// Checks whether the truck alone can carry goods within decoupled range: deliveries of the range
+/// are moved to the truck on decouple and pickups stay on it till couple. The rest of the load
+/// stays in the trailer, its overflow of trailer capacity has to be carried by the truck.
+fn can_fit_range<T: LoadOps>(trailer: &TrailerCapacity<T>, load: T, range: &[&Activity]) -> bool {
+    let picked_up = range
+        .iter()
+        .filter(|activity| get_demand::<T>(activity).map_or(false, |demand| demand.pickup.1.is_not_empty()))
+        .filter_map(|activity| activity.retrieve_job())
+        .collect::<HashSet<_>>();
+
+    let truck_load = range
+        .iter()
+        .filter_map(|activity| get_demand::<T>(activity).map(|demand| (activity, demand)))
+        .fold(T::default(), |acc, (activity, demand)| {
+            let is_picked_up = demand.delivery.1.is_not_empty()
+                && activity.retrieve_job().map_or(false, |job| picked_up.contains(&job));
+
+            acc + demand.delivery.0 + if is_picked_up { T::default() } else { demand.delivery.1 }
+        });
+
+    let overflow = (load - truck_load - trailer.trailer).max_load(T::default());
+    let (_, max_load) = range.iter().fold((truck_load, truck_load), |(current, max), activity| {
+        let current = current + get_demand::<T>(activity).map_or_else(T::default, |demand| demand.change());
+        (current, max.max_load(current))
+    });
+
+    trailer.truck.can_fit(&(max_load + overflow))
+}
+
+fn get_ranges(route_ctx: &RouteContext) -> &[(usize, Option<usize>)] {
+    route_ctx.state().get_route_state::<DecoupledRanges>(TRAILER_RANGES_KEY).map_or(&[], |ranges| ranges.as_slice())
+}
+
+fn get_decoupled_ranges(route: &Route) -> DecoupledRanges {
+    route.tour.all_activities().enumerate().fold(DecoupledRanges::default(), |mut acc, (idx, activity)| {
+        if is_decouple(activity) {
+            acc.push((idx, None));
+        } else if let Some((_, end)) = acc.last_mut().filter(|_| is_couple(activity)) {
+            *end = Some(idx);
+        }
+
+        acc
+    })
+}
+
+/// Returns decoupled range which contains a position right after activity at given index.
+fn get_decoupled_range(ranges: &[(usize, Option<usize>)], index: usize) -> Option<(usize, Option<usize>)> {
+    ranges
+        .iter()
+        .rev()
+        .find(|(start, _)| *start <= index)
+        .filter(|(_, end)| end.map_or(true, |end| index < end))
+        .copied()
+}
+
+fn get_demand<T: LoadOps>(activity: &Activity) -> Option<&Demand<T>> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_demand())
+}
+
+fn has_parks(route: &Route) -> bool {
+    route.tour.all_activities().any(is_decouple)
+}
+
+fn is_park_job_of_route(route: &Route, job: &Job) -> bool {
+    is_trailer_park_job(job)
+        && job.as_multi().and_then(|multi| multi.jobs.first()).map_or(false, |s| is_single_belongs_to_route(route, s))
+}
+
+fn is_trailer_park_job(job: &Job) -> bool {
+    job.as_multi().map_or(false, |multi| multi.dimens.get_job_type().map_or(false, |t| t == "trailer"))
+}
+
+fn is_truck_only_job(job: &Job) -> bool {
+    match job {
+        Job::Single(single) => single.dimens.get_job_truck_only(),
+        Job::Multi(multi) => multi.jobs.iter().any(|single| single.dimens.get_job_truck_only()),
+    }
+}
+
+fn is_truck_only(activity: &Activity) -> bool {
+    activity.job.as_ref().map_or(false, |single| single.dimens.get_job_truck_only())
+}
+
+fn is_decouple(activity: &Activity) -> bool {
+    is_park_activity(activity, "decouple")
+}
+
+fn is_couple(activity: &Activity) -> bool {
+    is_park_activity(activity, "couple")
+}
+
+fn is_park_activity(activity: &Activity, park_type: &str) -> bool {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_type()).map_or(false, |t| t == park_type)
+}
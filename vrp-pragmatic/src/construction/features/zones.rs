@@ -0,0 +1,193 @@
+//! A feature to model geofenced zones which restrict vehicle access and charge an entry fee.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/zones_test.rs"]
+mod zones_test;
+
+use super::*;
+use crate::construction::enablers::{JobTie, VehicleTie};
+use hashbrown::{HashMap, HashSet};
+use vrp_core::models::solution::Activity;
+use vrp_core::rosomaxa::prelude::Objective;
+
+/// Geofenced zones of job places specified by place index.
+pub type PlaceZones = Vec<(usize, Vec<Arc<GeoZone>>)>;
+
+/// Specifies a geofenced zone.
+#[derive(Clone, Debug)]
+pub struct GeoZone {
+    /// A zone index.
+    pub index: usize,
+    /// Vehicle types allowed to serve jobs inside the zone. All vehicle types are allowed if it is not set.
+    pub vehicle_types: Option<HashSet<String>>,
+    /// An entry fee which is paid once per tour.
+    pub fee: Cost,
+}
+
+impl GeoZone {
+    /// Checks whether the vehicle type is allowed to serve jobs inside the zone.
+    pub fn is_allowed(&self, vehicle_type: Option<&String>) -> bool {
+        self.vehicle_types.as_ref().map_or(true, |vehicle_types| {
+            vehicle_type.map_or(false, |vehicle_type| vehicle_types.contains(vehicle_type))
+        })
+    }
+}
+
+/// Creates a zone feature as hard constraint on vehicle access.
+pub fn create_zone_feature(name: &str, code: ViolationCode, state_key: StateKey) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(ZoneConstraint { code })
+        .with_state(ZoneState { state_key, state_keys: vec![state_key] })
+        .build()
+}
+
+/// Extends an objective of the given feature with zone entry fees tracked by zone feature's state.
+/// Only job places are considered: travel through the zone between them is free of charge.
+pub fn add_zone_fees(feature: Feature, state_key: StateKey) -> Result<Feature, GenericError> {
+    let inner = feature.objective.clone().ok_or_else(|| format!("feature '{}' has no objective", feature.name))?;
+
+    FeatureBuilder::from_feature(feature).with_objective(ZoneFeeObjective { inner, state_key }).build()
+}
+
+/// Returns zones of the job place used by the activity.
+pub fn get_activity_zones(activity: &Activity) -> &[Arc<GeoZone>] {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.dimens.get_place_zones())
+        .and_then(|zones| zones.iter().find(|(place_idx, _)| *place_idx == activity.place.idx))
+        .map_or(&[], |(_, zones)| zones.as_slice())
+}
+
+struct ZoneConstraint {
+    code: ViolationCode,
+}
+
+impl ZoneConstraint {
+    fn is_allowed_single(&self, single: &Single, vehicle_type: Option<&String>) -> bool {
+        match single.dimens.get_place_zones() {
+            Some(zones) => (0..single.places.len()).any(|place_idx| {
+                zones
+                    .iter()
+                    .find(|(idx, _)| *idx == place_idx)
+                    .map_or(true, |(_, zones)| zones.iter().all(|zone| zone.is_allowed(vehicle_type)))
+            }),
+            None => true,
+        }
+    }
+}
+
+impl FeatureConstraint for ZoneConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let vehicle_type = route_ctx.route().actor.vehicle.dimens.get_vehicle_type();
+                let is_allowed = match job {
+                    Job::Single(single) => self.is_allowed_single(single, vehicle_type),
+                    Job::Multi(multi) => multi.jobs.iter().all(|single| self.is_allowed_single(single, vehicle_type)),
+                };
+
+                if is_allowed {
+                    None
+                } else {
+                    ConstraintViolation::fail(self.code)
+                }
+            }
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let vehicle_type = route_ctx.route().actor.vehicle.dimens.get_vehicle_type();
+
+                // NOTE job can have alternative places outside of the zone, so skip the place only
+                if get_activity_zones(activity_ctx.target).iter().all(|zone| zone.is_allowed(vehicle_type)) {
+                    None
+                } else {
+                    ConstraintViolation::skip(self.code)
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (source.dimens().get_place_zones(), candidate.dimens().get_place_zones()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
+
+struct ZoneFeeObjective {
+    inner: Arc<dyn FeatureObjective<Solution = InsertionContext> + Send + Sync>,
+    state_key: StateKey,
+}
+
+impl Objective for ZoneFeeObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let fees = solution
+            .solution
+            .routes
+            .iter()
+            .filter_map(|route_ctx| route_ctx.state().get_route_state::<HashMap<usize, Cost>>(self.state_key))
+            .flat_map(|zones| zones.values())
+            .sum::<Cost>();
+
+        self.inner.fitness(solution) + fees
+    }
+}
+
+impl FeatureObjective for ZoneFeeObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        let fees = match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let visited = route_ctx.state().get_route_state::<HashMap<usize, Cost>>(self.state_key);
+
+                get_activity_zones(activity_ctx.target)
+                    .iter()
+                    .filter(|zone| visited.map_or(true, |visited| !visited.contains_key(&zone.index)))
+                    .map(|zone| (zone.index, zone.fee))
+                    .collect::<HashMap<_, _>>()
+                    .values()
+                    .sum()
+            }
+        };
+
+        self.inner.estimate(move_ctx) + fees
+    }
+}
+
+struct ZoneState {
+    state_key: StateKey,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for ZoneState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let zones = get_route_zones(route_ctx);
+        route_ctx.state_mut().put_route_state(self.state_key, zones);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+/// Returns zones visited by the route with their entry fees.
+fn get_route_zones(route_ctx: &RouteContext) -> HashMap<usize, Cost> {
+    route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .flat_map(|activity| get_activity_zones(activity).iter())
+        .map(|zone| (zone.index, zone.fee))
+        .collect()
+}
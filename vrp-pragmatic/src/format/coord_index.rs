@@ -4,7 +4,7 @@
 #[path = "../../tests/unit/format/coord_index_test.rs"]
 mod coord_index_test;
 
-use crate::format::problem::{Problem, VehicleBreak};
+use crate::format::problem::{get_job_variant_tasks, Problem, VehicleBreak, VehicleResource};
 use crate::format::{CustomLocationType, Location};
 use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering::Less;
@@ -37,7 +37,10 @@ impl CoordIndex {
                 .chain(job.deliveries.iter())
                 .chain(job.replacements.iter())
                 .chain(job.services.iter())
-                .flat_map(|tasks| tasks.iter().flat_map(|task| task.places.iter()))
+                .flat_map(|tasks| tasks.iter())
+                .chain(get_job_variant_tasks(job))
+                .flat_map(|task| task.places.iter())
+                .chain(job.transfers.iter().flatten())
                 .for_each(|place| {
                     index.add(&place.location);
                 });
@@ -57,7 +60,7 @@ impl CoordIndex {
                         .iter()
                         .filter_map(|vehicle_break| match vehicle_break {
                             VehicleBreak::Optional { places, .. } => Some(places),
-                            VehicleBreak::Required { .. } => None,
+                            VehicleBreak::Required { .. } | VehicleBreak::Driving { .. } => None,
                         })
                         .flat_map(|places| places.iter())
                         .filter_map(|place| place.location.as_ref())
@@ -72,8 +75,23 @@ impl CoordIndex {
                     recharges.stations.iter().for_each(|station| index.add(&station.location));
                 }
             });
+
+            if let Some(trailer) = &vehicle.trailer {
+                trailer.parks.iter().for_each(|park| index.add(&park.location));
+            }
         });
 
+        problem
+            .fleet
+            .resources
+            .iter()
+            .flatten()
+            .filter_map(|resource| match resource {
+                VehicleResource::Satellite { location, .. } => Some(location),
+                VehicleResource::Reload { .. } | VehicleResource::Dock { .. } => None,
+            })
+            .for_each(|location| index.add(location));
+
         index.max_matrix_index = index.direct_index.len().max(1) - 1;
 
         let start_offset = index.direct_index.len() * index.direct_index.len();
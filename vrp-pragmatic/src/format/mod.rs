@@ -4,18 +4,21 @@
 
 extern crate serde_json;
 
+use crate::construction::enablers::JobTie;
 use hashbrown::HashMap;
 use serde::{Deserialize, Serialize};
 use vrp_core::construction::enablers::ReservedTimesIndex;
 use vrp_core::models::common::{Distance, Duration};
 use vrp_core::models::problem::Job as CoreJob;
+use vrp_core::models::problem::PartialJobDimension;
 use vrp_core::models::Problem as CoreProblem;
-use vrp_core::prelude::GenericError;
+use vrp_core::prelude::{compare_floats, GenericError};
 
 mod coord_index;
 pub use self::coord_index::CoordIndex;
 
 pub mod problem;
+pub mod replan;
 pub mod solution;
 
 /// Represents a location type.
@@ -187,6 +190,19 @@ const GROUP_CONSTRAINT_CODE: i32 = 12;
 const COMPATIBILITY_CONSTRAINT_CODE: i32 = 13;
 const RELOAD_RESOURCE_CONSTRAINT_CODE: i32 = 14;
 const RECHARGE_CONSTRAINT_CODE: i32 = 15;
+const RIDE_DURATION_CONSTRAINT_CODE: i32 = 16;
+const COMPARTMENT_CONSTRAINT_CODE: i32 = 17;
+const SCHEDULE_RELATION_CONSTRAINT_CODE: i32 = 18;
+const PERIODIC_CONSTRAINT_CODE: i32 = 19;
+const DOCK_SLOTS_CONSTRAINT_CODE: i32 = 20;
+const ZONE_CONSTRAINT_CODE: i32 = 21;
+const DRIVING_BREAK_CONSTRAINT_CODE: i32 = 22;
+const JOB_ALTERNATIVES_CONSTRAINT_CODE: i32 = 23;
+const TRIP_LIMIT_CONSTRAINT_CODE: i32 = 24;
+const TIME_IN_VEHICLE_CONSTRAINT_CODE: i32 = 25;
+const TRAILER_CONSTRAINT_CODE: i32 = 26;
+const SATELLITE_CONSTRAINT_CODE: i32 = 27;
+const SPLIT_JOB_CONSTRAINT_CODE: i32 = 28;
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
@@ -200,6 +216,64 @@ pub fn get_job_index(problem: &CoreProblem) -> &JobIndex {
         .unwrap_or_else(|| panic!("cannot get job index!"))
 }
 
+/// Returns all jobs created from the job with given id: parts of the splittable job, visits of the
+/// periodic job or the job itself. Please note, that job index maps the original id only to the first of them.
+pub fn get_jobs_by_id(job_index: &JobIndex, job_id: &str) -> Vec<CoreJob> {
+    let Some(job) = job_index.get(job_id) else {
+        return vec![];
+    };
+
+    let dimens = job.dimens();
+    let job_ids: Vec<String> = match (dimens.get_partial_job(), dimens.get_periodic_visit()) {
+        (Some(partial), _) => (0..partial.total).map(|index| get_partial_job_id(job_id, index)).collect(),
+        (_, Some(visit)) => (0..visit.total).map(|index| get_visit_job_id(job_id, index)).collect(),
+        _ => return vec![job.clone()],
+    };
+
+    job_ids.iter().filter_map(|job_id| job_index.get(job_id)).cloned().collect()
+}
+
+/// Returns an id used to store a partial job of the splittable job in job index.
+pub(crate) fn get_partial_job_id(job_id: &str, index: usize) -> String {
+    format!("{job_id}_part_{index}")
+}
+
+/// Returns an id used to store a visit of the periodic job in job index.
+pub(crate) fn get_visit_job_id(job_id: &str, index: usize) -> String {
+    format!("{job_id}_visit_{index}")
+}
+
+/// Returns an id used to store a variant of the job with alternatives in job index.
+pub(crate) fn get_variant_job_id(job_id: &str, index: usize) -> String {
+    format!("{job_id}_variant_{index}")
+}
+
+/// Returns an id used to store a leg of the transferred job in job index.
+pub(crate) fn get_transfer_job_id(job_id: &str, index: usize, leg: usize) -> String {
+    format!("{job_id}_transfer_{index}_{leg}")
+}
+
+/// Returns start of the planning horizon: beginning of the UTC day when the earliest vehicle shift starts.
+pub(crate) fn get_horizon_start(problem: &problem::Problem) -> f64 {
+    let earliest = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .map(|shift| crate::parse_time(&shift.start.earliest))
+        .min_by(|a, b| compare_floats(*a, *b))
+        .unwrap_or(0.);
+
+    (earliest / SECONDS_IN_DAY).floor() * SECONDS_IN_DAY
+}
+
+/// Returns a zero-based index of the planning horizon day which given time belongs to.
+pub(crate) fn get_day_index(horizon_start: f64, time: f64) -> usize {
+    ((time - horizon_start) / SECONDS_IN_DAY).floor().max(0.) as usize
+}
+
+const SECONDS_IN_DAY: f64 = 86400.;
+
 /// Gets coord index from core problem definition.
 pub fn get_coord_index(problem: &CoreProblem) -> &CoordIndex {
     problem.extras.get("coord_index").and_then(|s| s.downcast_ref::<CoordIndex>()).expect("cannot get coord index!")
@@ -3,14 +3,17 @@
 mod fleet_reader_test;
 
 use super::*;
-use crate::construction::enablers::{create_typed_actor_groups, UnknownLocationFallback, VehicleTie};
+use crate::construction::enablers::{create_typed_actor_groups, DriverTie, UnknownLocationFallback, VehicleTie};
+use crate::construction::features::{RechargeBattery, TrailerCapacity};
 use crate::get_unique_locations;
 use crate::utils::get_approx_transportation;
 use crate::Location as ApiLocation;
 use hashbrown::HashSet;
 use std::cmp::Ordering;
+use vrp_core::construction::features::{Compartment, TripLimits};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
+use vrp_core::utils::compare_floats;
 
 pub(super) fn get_profile_index_map(api_problem: &ApiProblem) -> HashMap<String, usize> {
     api_problem.fleet.profiles.iter().fold(Default::default(), |mut acc, profile| {
@@ -91,8 +94,13 @@ pub(super) fn create_transport_costs(
     }
 }
 
-pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> CoreFleet {
+pub(super) fn read_fleet(
+    api_problem: &ApiProblem,
+    props: &ProblemProperties,
+    coord_index: &CoordIndex,
+) -> Result<CoreFleet, GenericError> {
     let profile_indices = get_profile_index_map(api_problem);
+    let horizon_start = get_horizon_start(api_problem);
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
 
     api_problem.fleet.vehicles.iter().for_each(|vehicle| {
@@ -102,12 +110,20 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
             per_driving_time: vehicle.costs.time,
             per_waiting_time: vehicle.costs.time,
             per_service_time: vehicle.costs.time,
+            per_distance_loaded: vehicle.costs.distance_loaded,
+            time_tiers: read_cost_tiers(vehicle.costs.time_tiers.as_ref()),
+            distance_tiers: read_cost_tiers(vehicle.costs.distance_tiers.as_ref()),
         };
 
         let index = *profile_indices.get(&vehicle.profile.matrix).unwrap();
         let profile = Profile::new(index, vehicle.profile.scale);
 
         let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
+        let trip_limits = vehicle.limits.as_ref().and_then(|l| l.trip.as_ref()).map(|trip| TripLimits {
+            max_distance: trip.max_distance,
+            max_duration: trip.max_duration,
+            max_size: trip.size,
+        });
 
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
             let start = {
@@ -123,6 +139,12 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                 (location, time)
             });
 
+            let driving_limit =
+                shift.breaks.iter().flat_map(|breaks| breaks.iter()).find_map(|vehicle_break| match vehicle_break {
+                    VehicleBreak::Driving { max_driving_time, .. } => Some(*max_driving_time),
+                    _ => None,
+                });
+
             let details = vec![VehicleDetail {
                 start: Some(VehiclePlace {
                     location: start.0,
@@ -146,16 +168,67 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_tour_size(tour_size);
                 }
 
+                if let Some(trip_limits) = trip_limits.clone() {
+                    dimens.set_trip_limits(trip_limits);
+                }
+
+                if props.has_periodic_jobs {
+                    dimens.set_shift_day(get_day_index(horizon_start, start.1));
+                }
+
                 if props.has_multi_dimen_capacity {
                     dimens.set_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
                 } else {
                     dimens.set_capacity(SingleDimLoad::new(*vehicle.capacity.first().unwrap()));
                 }
 
+                // NOTE vehicle capacity includes trailer capacity, truck capacity is used only when it is decoupled
+                if let Some(trailer) = vehicle.trailer.as_ref() {
+                    if props.has_multi_dimen_capacity {
+                        let truck = MultiDimLoad::new(vehicle.capacity.clone());
+                        let trailer = MultiDimLoad::new(trailer.capacity.clone());
+                        dimens.set_capacity(truck + trailer).set_vehicle_trailer(TrailerCapacity { truck, trailer });
+                    } else {
+                        let truck = SingleDimLoad::new(*vehicle.capacity.first().unwrap());
+                        let trailer = SingleDimLoad::new(*trailer.capacity.first().unwrap());
+                        dimens.set_capacity(truck + trailer).set_vehicle_trailer(TrailerCapacity { truck, trailer });
+                    }
+                }
+
                 if let Some(skills) = vehicle.skills.as_ref() {
                     dimens.set_vehicle_skills(skills.iter().cloned().collect::<HashSet<_>>());
                 }
 
+                if let Some(compartments) = vehicle.compartments.as_ref() {
+                    dimens
+                        .set_vehicle_compartments(
+                            compartments
+                                .iter()
+                                .map(|compartment| Compartment {
+                                    capacity: compartment.capacity,
+                                    cleaning_duration: compartment.cleaning_duration.unwrap_or(0.),
+                                })
+                                .collect(),
+                        )
+                        .set_vehicle_compartment_ids(
+                            compartments.iter().map(|compartment| compartment.id.clone()).collect(),
+                        );
+                }
+
+                if let Some(driving_limit) = driving_limit {
+                    dimens.set_vehicle_driving_limit(driving_limit);
+                }
+
+                if let Some(battery) = shift.recharges.as_ref().and_then(|recharges| recharges.battery.as_ref()) {
+                    dimens.set_vehicle_battery(RechargeBattery {
+                        capacity: battery.capacity,
+                        initial: battery.initial.unwrap_or(battery.capacity),
+                        reserve: battery.reserve.unwrap_or(0.),
+                        consumption: battery.consumption,
+                        consumption_loaded: battery.consumption_loaded,
+                    });
+                }
+
                 vehicles.push(Arc::new(Vehicle {
                     profile: profile.clone(),
                     costs: costs.clone(),
@@ -166,19 +239,86 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         }
     });
 
-    let drivers = vec![Arc::new(Driver {
-        costs: Costs {
-            fixed: 0.0,
-            per_distance: 0.0,
-            per_driving_time: 0.0,
-            per_waiting_time: 0.0,
-            per_service_time: 0.0,
-        },
-        dimens: Default::default(),
-        details: vec![],
-    })];
-
-    CoreFleet::new(drivers, vehicles, Box::new(|actors| create_typed_actor_groups(actors)))
+    let drivers = read_drivers(api_problem);
+
+    CoreFleet::new_with_pairing(
+        drivers,
+        vehicles,
+        Box::new(|actors| create_typed_actor_groups(actors)),
+        Box::new(|driver, vehicle| {
+            driver.dimens.get_driver_vehicle_types().map_or(true, |vehicle_types| {
+                vehicle.dimens.get_vehicle_type().map_or(false, |vehicle_type| vehicle_types.contains(vehicle_type))
+            })
+        }),
+    )
+}
+
+fn read_cost_tiers(tiers: Option<&Vec<VehicleCostTier>>) -> Vec<CostTier> {
+    let mut tiers = tiers
+        .iter()
+        .flat_map(|tiers| tiers.iter())
+        .map(|tier| CostTier { threshold: tier.threshold, cost: tier.cost })
+        .collect::<Vec<_>>();
+    tiers.sort_by(|a, b| compare_floats(a.threshold, b.threshold));
+
+    tiers
+}
+
+fn read_drivers(api_problem: &ApiProblem) -> Vec<Arc<Driver>> {
+    match api_problem.fleet.drivers.as_ref() {
+        Some(drivers) => drivers
+            .iter()
+            .flat_map(|driver| {
+                let costs = Costs {
+                    fixed: driver.costs.fixed.unwrap_or(0.),
+                    per_distance: driver.costs.distance,
+                    per_driving_time: driver.costs.time,
+                    per_waiting_time: driver.costs.time,
+                    per_service_time: driver.costs.time,
+                    per_distance_loaded: None,
+                    time_tiers: vec![],
+                    distance_tiers: vec![],
+                };
+
+                let details = driver
+                    .shifts
+                    .iter()
+                    .map(|shift| DriverDetail {
+                        time: TimeWindow::new(parse_time(&shift.start), parse_time(&shift.end)),
+                    })
+                    .collect::<Vec<_>>();
+
+                driver.driver_ids.iter().map(move |driver_id| {
+                    let mut dimens: Dimensions = Default::default();
+                    dimens.set_driver_type(driver.type_id.clone()).set_driver_id(driver_id.clone());
+
+                    if let Some(skills) = driver.skills.as_ref() {
+                        dimens.set_driver_skills(skills.iter().cloned().collect::<HashSet<_>>());
+                    }
+
+                    if let Some(vehicle_type_ids) = driver.vehicle_type_ids.as_ref() {
+                        dimens.set_driver_vehicle_types(vehicle_type_ids.iter().cloned().collect::<HashSet<_>>());
+                    }
+
+                    Arc::new(Driver { costs: costs.clone(), dimens, details: details.clone() })
+                })
+            })
+            .collect(),
+        None => vec![Arc::new(Driver {
+            costs: Costs {
+                fixed: 0.0,
+                per_distance: 0.0,
+                per_driving_time: 0.0,
+                per_waiting_time: 0.0,
+                per_service_time: 0.0,
+                per_distance_loaded: None,
+                time_tiers: vec![],
+                distance_tiers: vec![],
+            },
+            dimens: Default::default(),
+            details: vec![],
+        })],
+    }
 }
 
 /// Creates a matrices using approximation.
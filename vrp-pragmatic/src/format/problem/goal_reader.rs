@@ -1,11 +1,11 @@
 use super::*;
-use crate::construction::enablers::{JobTie, VehicleTie};
+use crate::construction::enablers::{get_compartment_accessor, JobTie, VehicleTie};
 use crate::construction::features::*;
 use hashbrown::HashSet;
 use vrp_core::construction::clustering::vicinity::ClusterDimension;
-use vrp_core::construction::enablers::NoRouteIntervals;
+use vrp_core::construction::enablers::{NoRouteIntervals, RouteIntervals};
 use vrp_core::construction::features::*;
-use vrp_core::models::common::{LoadOps, MultiDimLoad, SingleDimLoad};
+use vrp_core::models::common::{CapacityDimension, Demand, DemandDimension, LoadOps, MultiDimLoad, SingleDimLoad};
 use vrp_core::models::problem::{Actor, Single, TransportCost};
 use vrp_core::models::{Feature, Goal, GoalContext};
 
@@ -18,29 +18,92 @@ pub(super) fn create_goal_context(
 
     // TODO what's about performance implications on order of features when they are evaluated?
 
+    // NOTE compartments feature changes reload durations, so its state has to be accepted before schedule update
+    if props.has_compartments {
+        features.push(create_compartment_feature(
+            "compartments",
+            COMPARTMENT_CONSTRAINT_CODE,
+            blocks.transport.clone(),
+            get_compartment_accessor(),
+        )?)
+    }
+
     let objective_features = get_objective_features(api_problem, blocks, props)?;
     let (global_objective_map, local_objective_map) = extract_feature_map(objective_features.as_slice())?;
     features.extend(objective_features.into_iter().flat_map(|features| features.into_iter()));
 
+    // NOTE schedule relations narrow time windows of related jobs, so they go right after schedule update
+    if props.has_schedule_relations || props.has_transfers {
+        features.push(create_schedule_relation_feature(
+            "schedule_relations",
+            SCHEDULE_RELATION_CONSTRAINT_CODE,
+            get_schedule_relations(api_problem, blocks.job_index.as_ref()),
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+        )?)
+    }
+
+    // NOTE dock slots also narrow time windows to model queueing at busy docks
+    if props.has_docks {
+        features.push(get_dock_slots_feature("dock_slots", api_problem, blocks)?)
+    }
+
+    // NOTE satellites delay departure of second-level vehicles till their supply is delivered
+    if props.has_satellites {
+        features.push(get_satellite_feature("satellites", api_problem, blocks, props)?)
+    }
+
     if props.has_unreachable_locations {
         features.push(create_reachable_feature("reachable", blocks.transport.clone(), REACHABLE_CONSTRAINT_CODE)?)
     }
 
     features.push(get_capacity_feature("capacity", api_problem, blocks, props)?);
 
+    // NOTE recharge feature relies on vehicle load to calculate energy consumption and can change
+    // charging durations, so it goes right after capacity
+    if props.has_recharges {
+        features.push(get_recharge_feature("recharge", api_problem, blocks, props)?);
+    }
+
+    if props.has_trailers {
+        features.push(get_trailer_feature("trailer", props)?);
+    }
+
     if props.has_tour_travel_limits {
         features.push(get_tour_limit_feature("tour_limit", api_problem, blocks.transport.clone())?)
     }
 
+    if props.has_trip_limits {
+        features.push(get_trip_limit_feature("trip_limit", blocks.transport.clone(), props)?)
+    }
+
+    if props.has_ride_limits {
+        features.push(create_max_ride_duration_feature(
+            "ride_duration",
+            RIDE_DURATION_CONSTRAINT_CODE,
+            blocks.transport.clone(),
+            Arc::new(|multi| multi.dimens.get_ride_duration_limit()),
+        )?)
+    }
+
+    if props.has_time_in_vehicle_limits {
+        features.push(get_time_in_vehicle_feature("time_in_vehicle", blocks.transport.clone(), props)?)
+    }
+
     if props.has_breaks {
         features.push(create_optional_break_feature("break", BREAK_CONSTRAINT_CODE)?)
     }
 
-    if props.has_recharges {
-        features.push(get_recharge_feature("recharge", api_problem, blocks.transport.clone())?);
+    if props.has_driving_breaks {
+        features.push(create_driving_break_feature(
+            "driving_break",
+            DRIVING_BREAK_CONSTRAINT_CODE,
+            Arc::new(|actor| actor.vehicle.dimens.get_vehicle_driving_limit()),
+            blocks.transport.clone(),
+        )?)
     }
 
-    if props.has_order && !global_objective_map.iter().flat_map(|o| o.iter()).any(|name| *name == "tour_order") {
+    if props.has_order && !get_objectives(api_problem, props).iter().flatten().any(is_tour_order_objective) {
         features.push(create_tour_order_hard_feature("tour_order", TOUR_ORDER_CONSTRAINT_CODE, get_tour_order_fn())?)
     }
 
@@ -52,10 +115,31 @@ pub(super) fn create_goal_context(
         features.push(create_group_feature("group", blocks.jobs.size(), GROUP_CONSTRAINT_CODE, GROUP_KEY)?);
     }
 
+    if props.has_periodic_jobs {
+        features.push(create_periodic_feature(
+            "periodic",
+            PERIODIC_CONSTRAINT_CODE,
+            PERIODIC_KEY,
+            blocks.fleet.as_ref(),
+        )?);
+    }
+
+    if props.has_split_jobs {
+        features.push(create_partial_jobs_feature("split_jobs", SPLIT_JOB_CONSTRAINT_CODE)?);
+    }
+
+    if props.has_alternatives || props.has_transfers {
+        features.push(create_job_alternatives_feature("job_alternatives", JOB_ALTERNATIVES_CONSTRAINT_CODE)?);
+    }
+
     if props.has_skills {
         features.push(create_skills_feature("skills", SKILL_CONSTRAINT_CODE)?)
     }
 
+    if props.has_zones {
+        features.push(create_zone_feature("zones", ZONE_CONSTRAINT_CODE, ZONE_KEY)?)
+    }
+
     if !blocks.locks.is_empty() {
         features.push(create_locked_jobs_feature(
             "locked_jobs",
@@ -87,132 +171,148 @@ fn get_objective_features(
 
     objectives
         .iter()
-        .map(|objectives| {
-            objectives
-                .iter()
-                .map(|objective| match objective {
-                    Objective::MinimizeCost => create_minimize_transport_costs_feature(
-                        "min_cost",
-                        blocks.transport.clone(),
-                        blocks.activity.clone(),
-                        TIME_CONSTRAINT_CODE,
-                    ),
-                    Objective::MinimizeDistance => create_minimize_distance_feature(
-                        "min_distance",
-                        blocks.transport.clone(),
-                        blocks.activity.clone(),
-                        TIME_CONSTRAINT_CODE,
-                    ),
-                    Objective::MinimizeDuration => create_minimize_duration_feature(
-                        "min_duration",
-                        blocks.transport.clone(),
-                        blocks.activity.clone(),
-                        TIME_CONSTRAINT_CODE,
-                    ),
-                    Objective::MinimizeTours => create_minimize_tours_feature("min_tours"),
-                    Objective::MaximizeTours => create_maximize_tours_feature("max_tours"),
-                    Objective::MaximizeValue { breaks } => create_maximize_total_job_value_feature(
-                        "max_value",
-                        JobReadValueFn::Left(Arc::new({
-                            let break_value = *breaks;
-                            move |job| {
-                                job.dimens().get_job_value().unwrap_or_else(|| {
-                                    job.dimens()
-                                        .get_job_type()
-                                        .zip(break_value)
-                                        .filter(|(job_type, _)| *job_type == "break")
-                                        .map(|(_, break_value)| break_value)
-                                        .unwrap_or(0.)
-                                })
-                            }
-                        })),
-                        Arc::new(|job, value| match job {
-                            CoreJob::Single(single) => {
-                                let mut dimens = single.dimens.clone();
-                                dimens.set_job_value(Some(value));
-
-                                CoreJob::Single(Arc::new(Single { places: single.places.clone(), dimens }))
-                            }
-                            _ => job.clone(),
-                        }),
-                        -1,
-                    ),
-                    Objective::MinimizeUnassigned { breaks } => create_minimize_unassigned_jobs_feature(
-                        "min_unassigned",
-                        Arc::new({
-                            let break_value = *breaks;
-                            let default_value = 1.;
-                            move |_, job| {
-                                if let Some(clusters) = job.dimens().get_cluster() {
-                                    clusters.len() as f64 * default_value
-                                } else {
-                                    job.dimens().get_job_type().map_or(default_value, |job_type| {
-                                        match job_type.as_str() {
-                                            "break" => break_value.unwrap_or(default_value),
-                                            "reload" => 0.,
-                                            _ => default_value,
-                                        }
-                                    })
-                                }
-                            }
-                        }),
-                    ),
-                    Objective::MinimizeArrivalTime => create_minimize_arrival_time_feature("min_arrival_time"),
-                    Objective::BalanceMaxLoad { options } => {
-                        if props.has_multi_dimen_capacity {
-                            create_max_load_balanced_feature::<MultiDimLoad>(
-                                "max_load_balance",
-                                get_threshold(options),
-                                Arc::new(|loaded, capacity| {
-                                    let mut max_ratio = 0_f64;
-
-                                    for (idx, value) in capacity.load.iter().enumerate() {
-                                        let ratio = loaded.load[idx] as f64 / *value as f64;
-                                        max_ratio = max_ratio.max(ratio);
-                                    }
-
-                                    max_ratio
-                                }),
-                            )
-                        } else {
-                            create_max_load_balanced_feature::<SingleDimLoad>(
-                                "max_load_balance",
-                                get_threshold(options),
-                                Arc::new(|loaded, capacity| loaded.value as f64 / capacity.value as f64),
-                            )
-                        }
-                    }
-                    Objective::BalanceActivities { options } => {
-                        create_activity_balanced_feature("activity_balance", get_threshold(options))
-                    }
-                    Objective::BalanceDistance { options } => {
-                        create_distance_balanced_feature("distance_balance", get_threshold(options))
-                    }
-                    Objective::BalanceDuration { options } => {
-                        create_duration_balanced_feature("duration_balance", get_threshold(options))
-                    }
-                    Objective::CompactTour { options } => {
-                        let thresholds = Some((options.threshold, options.distance));
-                        create_tour_compactness_feature(
-                            "tour_compact",
-                            blocks.jobs.clone(),
-                            options.job_radius,
-                            TOUR_COMPACTNESS_KEY,
-                            thresholds,
-                        )
-                    }
-                    Objective::TourOrder => {
-                        create_tour_order_soft_feature("tour_order", TOUR_ORDER_KEY, get_tour_order_fn())
-                    }
-                    Objective::FastService { tolerance } => {
-                        get_fast_service_feature("fast_service", blocks, props, *tolerance)
-                    }
-                })
-                .collect()
-        })
+        .map(|objectives| objectives.iter().map(|objective| get_objective_feature(objective, blocks, props)).collect())
         .collect()
 }
 
+fn get_objective_feature(
+    objective: &Objective,
+    blocks: &ProblemBlocks,
+    props: &ProblemProperties,
+) -> Result<Feature, GenericError> {
+    match objective {
+        Objective::MinimizeCost => create_minimize_transport_costs_feature(
+            "min_cost",
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+            TIME_CONSTRAINT_CODE,
+        )
+        .and_then(|feature| {
+            // NOTE zone entry fees are part of the tour cost
+            if props.has_zone_fees {
+                add_zone_fees(feature, ZONE_KEY)
+            } else {
+                Ok(feature)
+            }
+        }),
+        Objective::MinimizeDistance => create_minimize_distance_feature(
+            "min_distance",
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+            TIME_CONSTRAINT_CODE,
+        ),
+        Objective::MinimizeDuration => create_minimize_duration_feature(
+            "min_duration",
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+            TIME_CONSTRAINT_CODE,
+        ),
+        Objective::MinimizeTours => create_minimize_tours_feature("min_tours"),
+        Objective::MaximizeTours => create_maximize_tours_feature("max_tours"),
+        Objective::MaximizeValue { breaks } => create_maximize_total_job_value_feature(
+            "max_value",
+            JobReadValueFn::Left(Arc::new({
+                let break_value = *breaks;
+                move |job| {
+                    job.dimens().get_job_value().unwrap_or_else(|| {
+                        job.dimens()
+                            .get_job_type()
+                            .zip(break_value)
+                            .filter(|(job_type, _)| *job_type == "break")
+                            .map(|(_, break_value)| break_value)
+                            .unwrap_or(0.)
+                    })
+                }
+            })),
+            Arc::new(|job, value| match job {
+                CoreJob::Single(single) => {
+                    let mut dimens = single.dimens.clone();
+                    dimens.set_job_value(Some(value));
+
+                    CoreJob::Single(Arc::new(Single { places: single.places.clone(), dimens }))
+                }
+                _ => job.clone(),
+            }),
+            -1,
+        ),
+        Objective::MinimizeUnassigned { breaks } => create_minimize_unassigned_jobs_feature(
+            "min_unassigned",
+            Arc::new({
+                let break_value = *breaks;
+                let default_value = 1.;
+                move |_, job| {
+                    if let Some(clusters) = job.dimens().get_cluster() {
+                        clusters.len() as f64 * default_value
+                    } else {
+                        job.dimens().get_job_type().map_or(default_value, |job_type| match job_type.as_str() {
+                            "break" => break_value.unwrap_or(default_value),
+                            "reload" => 0.,
+                            _ => default_value,
+                        })
+                    }
+                }
+            }),
+        ),
+        Objective::MinimizeArrivalTime => create_minimize_arrival_time_feature("min_arrival_time"),
+        Objective::MinimizeMakespan => {
+            create_minimize_makespan_feature("min_makespan", blocks.transport.clone(), blocks.activity.clone())
+        }
+        Objective::BalanceMaxLoad { options } => {
+            if props.has_multi_dimen_capacity {
+                create_max_load_balanced_feature::<MultiDimLoad>(
+                    "max_load_balance",
+                    get_threshold(options),
+                    Arc::new(|loaded, capacity| {
+                        let mut max_ratio = 0_f64;
+
+                        for (idx, value) in capacity.load.iter().enumerate() {
+                            let ratio = loaded.load[idx] as f64 / *value as f64;
+                            max_ratio = max_ratio.max(ratio);
+                        }
+
+                        max_ratio
+                    }),
+                )
+            } else {
+                create_max_load_balanced_feature::<SingleDimLoad>(
+                    "max_load_balance",
+                    get_threshold(options),
+                    Arc::new(|loaded, capacity| loaded.value as f64 / capacity.value as f64),
+                )
+            }
+        }
+        Objective::BalanceActivities { options } => {
+            create_activity_balanced_feature("activity_balance", get_threshold(options))
+        }
+        Objective::BalanceDistance { options } => {
+            create_distance_balanced_feature("distance_balance", get_threshold(options))
+        }
+        Objective::BalanceDuration { options } => {
+            create_duration_balanced_feature("duration_balance", get_threshold(options))
+        }
+        Objective::CompactTour { options } => {
+            let thresholds = Some((options.threshold, options.distance));
+            create_tour_compactness_feature(
+                "tour_compact",
+                blocks.jobs.clone(),
+                options.job_radius,
+                TOUR_COMPACTNESS_KEY,
+                thresholds,
+            )
+        }
+        Objective::TourOrder => create_tour_order_soft_feature("tour_order", TOUR_ORDER_KEY, get_tour_order_fn()),
+        Objective::FastService { tolerance } => get_fast_service_feature("fast_service", blocks, props, *tolerance),
+        Objective::MinimizePlanChanges { reference, options } => {
+            get_plan_stability_feature("plan_stability", reference.as_slice(), options.as_ref())
+        }
+        Objective::Weighted { objectives } => objectives
+            .iter()
+            .map(|weighted| get_objective_feature(&weighted.objective, blocks, props).map(|f| (f, weighted.weight)))
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(|features| create_weighted_sum_feature("weighted", features.as_slice())),
+    }
+}
+
 fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<Vec<Objective>> {
     if let Some(objectives) = api_problem.objectives.clone() {
         objectives
@@ -231,6 +331,16 @@ fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<Ve
     }
 }
 
+fn is_tour_order_objective(objective: &Objective) -> bool {
+    match objective {
+        Objective::TourOrder => true,
+        Objective::Weighted { objectives } => {
+            objectives.iter().any(|weighted| is_tour_order_objective(&weighted.objective))
+        }
+        _ => false,
+    }
+}
+
 const RELOAD_THRESHOLD: f64 = 0.9;
 
 fn get_capacity_feature(
@@ -384,10 +494,62 @@ fn get_tour_limit_feature(
     )
 }
 
+fn get_trip_limit_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    props: &ProblemProperties,
+) -> Result<Feature, GenericError> {
+    create_trip_limit_feature(
+        name,
+        TRIP_LIMIT_CONSTRAINT_CODE,
+        transport,
+        get_trip_route_intervals(props),
+        Arc::new(|actor| actor.vehicle.dimens.get_trip_limits().cloned()),
+    )
+}
+
+fn get_time_in_vehicle_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    props: &ProblemProperties,
+) -> Result<Feature, GenericError> {
+    create_max_time_in_vehicle_feature(
+        name,
+        TIME_IN_VEHICLE_CONSTRAINT_CODE,
+        transport,
+        get_trip_route_intervals(props),
+        Arc::new(|single| single.dimens.get_max_time_in_vehicle()),
+    )
+}
+
+fn get_trip_route_intervals(props: &ProblemProperties) -> Arc<dyn RouteIntervals + Send + Sync> {
+    // NOTE trips are separated by reloads, otherwise the whole tour is a single trip
+    match (props.has_reloads, props.has_multi_dimen_capacity) {
+        (true, true) => {
+            create_simple_reload_route_intervals(Box::new(|capacity: &MultiDimLoad| *capacity * RELOAD_THRESHOLD))
+        }
+        (true, false) => {
+            create_simple_reload_route_intervals(Box::new(|capacity: &SingleDimLoad| *capacity * RELOAD_THRESHOLD))
+        }
+        (false, _) => Arc::new(NoRouteIntervals::default()),
+    }
+}
+
+fn get_trailer_feature(name: &str, props: &ProblemProperties) -> Result<Feature, GenericError> {
+    let route_intervals = get_trip_route_intervals(props);
+
+    if props.has_multi_dimen_capacity {
+        create_trailer_feature::<MultiDimLoad>(name, TRAILER_CONSTRAINT_CODE, route_intervals)
+    } else {
+        create_trailer_feature::<SingleDimLoad>(name, TRAILER_CONSTRAINT_CODE, route_intervals)
+    }
+}
+
 fn get_recharge_feature(
     name: &str,
     api_problem: &ApiProblem,
-    transport: Arc<dyn TransportCost + Send + Sync>,
+    blocks: &ProblemBlocks,
+    props: &ProblemProperties,
 ) -> Result<Feature, GenericError> {
     let distance_limit_index: HashMap<_, HashMap<_, _>> =
         api_problem.fleet.vehicles.iter().fold(HashMap::default(), |mut acc, vehicle_type| {
@@ -396,7 +558,7 @@ fn get_recharge_feature(
                 .iter()
                 .enumerate()
                 .flat_map(|(shift_idx, shift)| {
-                    shift.recharges.as_ref().map(|recharges| (shift_idx, recharges.max_distance))
+                    shift.recharges.as_ref().and_then(|recharges| recharges.max_distance).map(|max| (shift_idx, max))
                 })
                 .for_each(|(shift_idx, max_distance)| {
                     acc.entry(vehicle_type.type_id.clone()).or_default().insert(shift_idx, max_distance);
@@ -411,7 +573,167 @@ fn get_recharge_feature(
         )
     });
 
-    create_recharge_feature(name, RECHARGE_CONSTRAINT_CODE, distance_limit_fn, transport)
+    let demand_ratio_fn: RechargeDemandRatioFn = if props.has_multi_dimen_capacity {
+        Arc::new(get_demand_ratio::<MultiDimLoad>)
+    } else {
+        Arc::new(get_demand_ratio::<SingleDimLoad>)
+    };
+
+    create_recharge_feature(
+        name,
+        RECHARGE_CONSTRAINT_CODE,
+        RechargeAccessor {
+            distance_limit_fn,
+            battery_fn: Arc::new(|actor| actor.vehicle.dimens.get_vehicle_battery()),
+            power_fn: Arc::new(|single| single.dimens.get_recharge_power()),
+            demand_ratio_fn,
+        },
+        blocks.transport.clone(),
+        blocks.activity.clone(),
+    )
+}
+
+fn get_demand_ratio<T: LoadOps>(actor: &Actor, single: &Single) -> f64 {
+    let capacity: Option<&T> = actor.vehicle.dimens.get_capacity();
+    let demand: Option<&Demand<T>> = single.dimens.get_demand();
+
+    capacity
+        .zip(demand)
+        .map(|(capacity, demand)| {
+            let pickup = demand.pickup.0 + demand.pickup.1;
+            let delivery = demand.delivery.0 + demand.delivery.1;
+
+            pickup.ratio(capacity).max(delivery.ratio(capacity))
+        })
+        .filter(|ratio| !ratio.is_nan())
+        .map_or(0., |ratio| ratio.clamp(0., 1.))
+}
+
+fn get_dock_slots_feature(
+    name: &str,
+    api_problem: &ApiProblem,
+    blocks: &ProblemBlocks,
+) -> Result<Feature, GenericError> {
+    let docks = api_problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Dock { id, slots } => Some((id.clone(), *slots)),
+            VehicleResource::Reload { .. } | VehicleResource::Satellite { .. } => None,
+        })
+        .enumerate()
+        .map(|(dock_id, (id, slots))| (id, (dock_id, slots)))
+        .collect::<HashMap<_, _>>();
+
+    let slots = docks.values().cloned().collect();
+
+    create_dock_slots_feature(
+        name,
+        DOCK_SLOTS_CONSTRAINT_CODE,
+        slots,
+        Arc::new(move |single, place_idx| {
+            single
+                .dimens
+                .get_place_docks()
+                .and_then(|docks| docks.iter().find(|(idx, _)| *idx == place_idx))
+                .and_then(|(_, dock)| docks.get(dock))
+                .map(|(dock_id, _)| *dock_id)
+        }),
+        blocks.transport.clone(),
+        blocks.activity.clone(),
+    )
+}
+
+fn get_satellite_feature(
+    name: &str,
+    api_problem: &ApiProblem,
+    blocks: &ProblemBlocks,
+    props: &ProblemProperties,
+) -> Result<Feature, GenericError> {
+    let satellites = api_problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Satellite { id, location } => Some((id, location)),
+            VehicleResource::Reload { .. } | VehicleResource::Dock { .. } => None,
+        })
+        .enumerate()
+        .fold(Satellites::default(), |mut acc, (resource_id, (id, location))| {
+            acc.ids.insert(id.clone(), resource_id);
+            if let Some(location) = blocks.coord_index.get_by_loc(location) {
+                acc.locations.insert(location, resource_id);
+            }
+
+            acc
+        });
+
+    let (total_jobs, transport, activity) = (blocks.jobs.size(), blocks.transport.clone(), blocks.activity.clone());
+    if props.has_multi_dimen_capacity {
+        create_satellite_feature::<MultiDimLoad>(
+            name,
+            total_jobs,
+            SATELLITE_CONSTRAINT_CODE,
+            satellites,
+            transport,
+            activity,
+        )
+    } else {
+        create_satellite_feature::<SingleDimLoad>(
+            name,
+            total_jobs,
+            SATELLITE_CONSTRAINT_CODE,
+            satellites,
+            transport,
+            activity,
+        )
+    }
+}
+
+fn get_schedule_relations(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<ScheduleRelation> {
+    api_problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter())
+        .filter_map(|relation| {
+            let offset = relation.offset.unwrap_or(0.);
+            let relation_type = match relation.type_field {
+                RelationType::Sync => ScheduleRelationType::Sync { tolerance: offset },
+                RelationType::Precedence => ScheduleRelationType::Precedence { offset },
+                RelationType::Any | RelationType::Sequence | RelationType::Strict => return None,
+            };
+            let jobs = relation.jobs.iter().filter_map(|job_id| job_index.get(job_id).cloned()).collect();
+
+            Some(ScheduleRelation { relation_type, jobs })
+        })
+        .chain(get_transfer_relations(api_problem, job_index))
+        .collect()
+}
+
+/// Returns precedence relations between drop and pickup at the transfer place for each transfer of the job.
+fn get_transfer_relations<'a>(
+    api_problem: &'a ApiProblem,
+    job_index: &'a JobIndex,
+) -> impl Iterator<Item = ScheduleRelation> + 'a {
+    api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| (1..=job.transfers.as_ref().map_or(0, |transfers| transfers.len())).map(move |idx| (job, idx)))
+        .filter_map(|(job, index)| {
+            let get_leg = |leg| job_index.get(&get_transfer_job_id(&job.id, index, leg)).and_then(|job| job.as_multi());
+            let drop = get_leg(0)?.jobs.last()?.clone();
+            let pickup = get_leg(1)?.jobs.first()?.clone();
+
+            Some(ScheduleRelation {
+                relation_type: ScheduleRelationType::Precedence { offset: 0. },
+                jobs: vec![CoreJob::Single(drop), CoreJob::Single(pickup)],
+            })
+        })
 }
 
 fn get_reload_resources<T>(
@@ -429,8 +751,9 @@ where
         .as_ref()
         .iter()
         .flat_map(|resources| resources.iter())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, capacity } => (id.clone(), capacity.clone()),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, capacity } => Some((id.clone(), capacity.clone())),
+            VehicleResource::Dock { .. } | VehicleResource::Satellite { .. } => None,
         })
         .collect::<Vec<_>>();
     let total_resources_specified = available_resources.len();
@@ -515,6 +838,40 @@ fn get_threshold(options: &Option<BalanceOptions>) -> Option<f64> {
     options.as_ref().and_then(|o| o.threshold)
 }
 
+fn get_plan_stability_feature(
+    name: &str,
+    reference: &[ReferenceTour],
+    options: Option<&PlanChangeOptions>,
+) -> Result<Feature, GenericError> {
+    let positions = reference
+        .iter()
+        .flat_map(|tour| {
+            tour.jobs.iter().enumerate().map(|(order, job_id)| (job_id.clone(), (tour.vehicle_id.clone(), order)))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let default_weights = PlanChangeWeights::default();
+    let weights = PlanChangeWeights {
+        reassignment: options.and_then(|options| options.reassignment).unwrap_or(default_weights.reassignment),
+        sequence: options.and_then(|options| options.sequence).unwrap_or(default_weights.sequence),
+    };
+
+    create_plan_stability_feature(
+        name,
+        PLAN_STABILITY_KEY,
+        weights,
+        Arc::new(move |actor, job| {
+            let (vehicle_id, order) = job.dimens().get_job_id().and_then(|job_id| positions.get(job_id))?;
+
+            if actor.vehicle.dimens.get_vehicle_id() == Some(vehicle_id) {
+                Some(ReferencePosition::SameActor(*order))
+            } else {
+                Some(ReferencePosition::OtherActor)
+            }
+        }),
+    )
+}
+
 fn get_tour_order_fn() -> TourOrderFn {
     TourOrderFn::Left(Arc::new(|single| {
         single
@@ -524,7 +881,7 @@ fn get_tour_order_fn() -> TourOrderFn {
                 dimens.get_job_order().map(|order| OrderResult::Value(order as f64)).unwrap_or_else(|| {
                     dimens.get_job_type().map_or(OrderResult::Default, |v| {
                         match v.as_str() {
-                            "break" | "reload" => OrderResult::Ignored,
+                            "break" | "driving_break" | "reload" => OrderResult::Ignored,
                             // job without value
                             _ => OrderResult::Default,
                         }
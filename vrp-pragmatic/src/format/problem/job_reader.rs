@@ -1,14 +1,23 @@
-use crate::construction::enablers::{BreakTie, JobTie, VehicleTie};
-use crate::construction::features::{BreakPolicy, JobSkills as FeatureJobSkills};
+use crate::construction::enablers::{BreakTie, DriverTie, JobTie, VehicleTie};
+use crate::construction::features::{
+    BreakPolicy, GeoZone, JobSkills as FeatureJobSkills, PeriodicPolicy, PeriodicVisit, PlaceZones,
+};
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::JobSkills as ApiJobSkills;
 use crate::format::problem::*;
+use crate::format::{get_partial_job_id, get_transfer_job_id, get_variant_job_id, get_visit_job_id};
 use crate::format::{JobIndex, Location};
-use crate::utils::VariableJobPermutation;
-use hashbrown::HashMap;
+use crate::utils::{is_inside_polygon, VariableJobPermutation};
+use hashbrown::{HashMap, HashSet};
 use std::sync::Arc;
+use vrp_core::construction::features::{ProductDemand, RideDurationLimit};
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Actor, Fleet, Job, Jobs, Multi, Place, Single, TransportCost};
+use vrp_core::models::problem::{Actor, Fleet, Job, JobAlternative, JobAlternativeDimension, Jobs, Multi, Place};
+use vrp_core::models::problem::{
+    ServiceDurationDimension, ServiceDurationFn, SetupDurationDimension, TimeDeviation, TimeWindowTolerance,
+    TimeWindowToleranceDimension,
+};
+use vrp_core::models::problem::{Single, TransportCost};
 use vrp_core::models::{Lock, LockDetail, LockOrder, LockPosition};
 
 // TODO configure sample size
@@ -40,23 +49,33 @@ pub(super) fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<
         return vec![];
     }
 
-    let relations: HashMap<_, Vec<_>> =
-        api_problem.plan.relations.as_ref().unwrap().iter().fold(HashMap::new(), |mut acc, r| {
+    // NOTE sync and precedence relations are not locks, they are handled by schedule relation feature
+    let relations: HashMap<_, Vec<_>> = api_problem
+        .plan
+        .relations
+        .as_ref()
+        .unwrap()
+        .iter()
+        .filter_map(|r| {
+            let order = match r.type_field {
+                RelationType::Any => LockOrder::Any,
+                RelationType::Sequence => LockOrder::Sequence,
+                RelationType::Strict => LockOrder::Strict,
+                RelationType::Sync | RelationType::Precedence => return None,
+            };
+
+            r.vehicle_id.clone().map(|vehicle_id| (vehicle_id, order, r))
+        })
+        .fold(HashMap::new(), |mut acc, (vehicle_id, order, r)| {
             let shift_index = r.shift_index.unwrap_or_default();
-            acc.entry((r.vehicle_id.clone(), shift_index)).or_default().push(r.clone());
+            acc.entry((vehicle_id, shift_index)).or_default().push((order, r.clone()));
 
             acc
         });
 
     relations.into_iter().fold(vec![], |mut acc, ((vehicle_id, shift_index), rels)| {
         let condition = create_condition(vehicle_id.clone(), shift_index);
-        let details = rels.iter().fold(vec![], |mut acc, rel| {
-            let order = match rel.type_field {
-                RelationType::Any => LockOrder::Any,
-                RelationType::Sequence => LockOrder::Sequence,
-                RelationType::Strict => LockOrder::Strict,
-            };
-
+        let details = rels.into_iter().fold(vec![], |mut acc, (order, rel)| {
             let position = match (rel.jobs.first().map(|s| s.as_str()), rel.jobs.last().map(|s| s.as_str())) {
                 (Some("departure"), Some("arrival")) => LockPosition::Fixed,
                 (Some("departure"), _) => LockPosition::Departure,
@@ -106,64 +125,189 @@ fn read_required_jobs(
 ) -> (Vec<Job>, Vec<Arc<Lock>>) {
     let mut jobs = vec![];
     let has_multi_dimens = props.has_multi_dimen_capacity;
+    let product_index = get_product_index(api_problem);
+    let geo_zones = get_geo_zones(api_problem);
 
-    let get_single_from_task = |task: &JobTask, activity_type: &str, is_static_demand: bool| {
-        let absent = (empty(), empty());
-        let capacity = task.demand.clone().map_or_else(empty, MultiDimLoad::new);
-        let demand = if is_static_demand { (capacity, empty()) } else { (empty(), capacity) };
-
-        let demand = match activity_type {
-            "pickup" => Demand { pickup: demand, delivery: absent },
-            "delivery" => Demand { pickup: absent, delivery: demand },
-            "replacement" => Demand { pickup: demand, delivery: demand },
-            "service" => Demand { pickup: absent, delivery: absent },
-            _ => panic!("invalid activity type."),
-        };
+    let get_single_from_task = |job: &ApiJob, task: &JobTask, activity_type: &str, is_static_demand: bool| {
+        let demand = get_task_demand(task.demand.clone(), activity_type, is_static_demand);
 
         let places = task
             .places
             .iter()
-            .map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times), p.tag.clone()))
+            .map(|p| {
+                let times = parse_times_with_tolerance(&p.times, &p.tolerance);
+                (Some(p.location.clone()), p.duration, times, p.tag.clone())
+            })
             .collect();
 
-        get_single_with_extras(places, demand, &task.order, activity_type, has_multi_dimens, coord_index)
+        let product_demand = get_product_demand(job, &product_index, &demand);
+
+        let mut single =
+            get_single_with_extras(places, demand, &task.order, activity_type, has_multi_dimens, coord_index);
+        single
+            .dimens
+            .set_job_product(job.product.clone())
+            .set_product_demand(product_demand)
+            .set_max_time_in_vehicle(task.max_time_in_vehicle)
+            .set_job_truck_only(job.truck_only.unwrap_or(false))
+            .set_job_satellite(job.satellite_id.clone())
+            .set_place_docks(get_place_docks(task.places.iter().map(|place| &place.dock)))
+            .set_place_zones(get_place_zones(&geo_zones, task.places.iter().map(|place| &place.location)));
+
+        let tolerance = task
+            .places
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, place)| place.tolerance.as_ref().map(|tolerance| (idx, get_tolerance(tolerance))))
+            .collect::<Vec<_>>();
+
+        if !tolerance.is_empty() {
+            single.dimens.set_time_window_tolerance(tolerance);
+        }
+
+        let setup_durations = task
+            .places
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, place)| place.setup.filter(|setup| *setup > 0.).map(|setup| (idx, setup)))
+            .collect::<Vec<_>>();
+
+        if !setup_durations.is_empty() {
+            single.dimens.set_setup_durations(setup_durations);
+        }
+
+        let duration_fns = task
+            .places
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, place)| place.duration_rules.clone().map(|rules| (idx, get_service_duration_fn(rules))))
+            .collect::<Vec<_>>();
+
+        if !duration_fns.is_empty() {
+            single.dimens.set_service_duration_fns(duration_fns);
+        }
+
+        single
     };
 
-    api_problem.plan.jobs.iter().for_each(|job| {
+    let get_problem_job = |job: &ApiJob, periodic: Option<PeriodicVisit>, alternative: Option<JobAlternative>| {
         let pickups = job.pickups.as_ref().map_or(0, |p| p.len());
         let deliveries = job.deliveries.as_ref().map_or(0, |p| p.len());
         let is_static_demand = pickups == 0 || deliveries == 0;
 
-        let singles =
-            job.pickups
-                .iter()
-                .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "pickup", is_static_demand)))
-                .chain(job.deliveries.iter().flat_map(|tasks| {
-                    tasks.iter().map(|task| get_single_from_task(task, "delivery", is_static_demand))
-                }))
-                .chain(
-                    job.replacements
-                        .iter()
-                        .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "replacement", true))),
-                )
-                .chain(
-                    job.services
-                        .iter()
-                        .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "service", false))),
-                )
-                .collect::<Vec<_>>();
+        let singles = job
+            .pickups
+            .iter()
+            .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(job, task, "pickup", is_static_demand)))
+            .chain(job.deliveries.iter().flat_map(|tasks| {
+                tasks.iter().map(|task| get_single_from_task(job, task, "delivery", is_static_demand))
+            }))
+            .chain(
+                job.replacements
+                    .iter()
+                    .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(job, task, "replacement", true))),
+            )
+            .chain(
+                job.services
+                    .iter()
+                    .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(job, task, "service", false))),
+            )
+            .collect::<Vec<_>>();
 
         assert!(!singles.is_empty());
 
-        let problem_job = if singles.len() > 1 {
+        if singles.len() > 1 {
             let deliveries_start_index = job.pickups.as_ref().map_or(0, |p| p.len());
-            get_multi_job(job, singles, deliveries_start_index, random)
+            get_multi_job(job, singles, deliveries_start_index, periodic, alternative, random)
         } else {
-            get_single_job(job, singles.into_iter().next().unwrap())
-        };
+            get_single_job(job, singles.into_iter().next().unwrap(), periodic, alternative)
+        }
+    };
+
+    api_problem.plan.jobs.iter().for_each(|job| {
+        // NOTE transferred job is modeled as alternatives: direct variant and two legs per transfer place
+        if let Some(transfers) = job.transfers.as_ref().filter(|transfers| !transfers.is_empty()) {
+            let total = transfers.len() + 1;
+            let direct =
+                get_problem_job(job, None, Some(JobAlternative { id: job.id.clone(), index: 0, total, parts: 1 }));
+
+            job_index.insert(job.id.clone(), direct.clone());
+            job_index.insert(get_variant_job_id(&job.id, 0), direct.clone());
+            jobs.push(direct);
+
+            transfers.iter().enumerate().for_each(|(idx, transfer)| {
+                let index = idx + 1;
+                let alternative = JobAlternative { id: job.id.clone(), index, total, parts: 2 };
+                let (first, second) = get_job_transfer_legs(job, transfer);
+
+                [first, second].iter().enumerate().for_each(|(leg, leg_job)| {
+                    let leg_job = get_problem_job(leg_job, None, Some(alternative.clone()));
+                    job_index.insert(get_transfer_job_id(&job.id, index, leg), leg_job.clone());
+                    jobs.push(leg_job);
+                });
+            });
+
+            return;
+        }
+
+        if let Some(variants) = get_job_variants(job) {
+            let total = variants.len();
+            variants.into_iter().enumerate().for_each(|(index, variant)| {
+                let alternative = JobAlternative { id: job.id.clone(), index, total, parts: 1 };
+                let variant = get_problem_job(&variant, None, Some(alternative));
+
+                if index == 0 {
+                    job_index.insert(job.id.clone(), variant.clone());
+                }
+                job_index.insert(get_variant_job_id(&job.id, index), variant.clone());
+                jobs.push(variant);
+            });
+
+            return;
+        }
+
+        if let Some(visits) = get_periodic_visits(job) {
+            visits.into_iter().for_each(|visit| {
+                let index = visit.index;
+                let visit = get_problem_job(job, Some(visit), None);
+
+                if index == 0 {
+                    job_index.insert(job.id.clone(), visit.clone());
+                }
+                job_index.insert(get_visit_job_id(&job.id, index), visit.clone());
+                jobs.push(visit);
+            });
+
+            return;
+        }
+
+        let problem_job = get_problem_job(job, None, None);
+
+        let split_demands = get_splittable_task(job).and_then(|(task, activity_type)| {
+            get_split_demands(api_problem, task).map(|demands| (demands, activity_type))
+        });
+
+        match (split_demands, problem_job.as_single()) {
+            (Some((demands, activity_type)), Some(single)) => {
+                let parts = single.split(&job.id, demands.len(), |index, dimens| {
+                    let demand = get_task_demand(Some(demands[index].clone()), activity_type, true);
+                    dimens.set_product_demand(get_product_demand(job, &product_index, &demand));
+                    set_single_demand(dimens, demand, has_multi_dimens);
+                });
 
-        job_index.insert(job.id.clone(), problem_job.clone());
-        jobs.push(problem_job);
+                parts.into_iter().map(Job::Single).enumerate().for_each(|(index, part)| {
+                    if index == 0 {
+                        job_index.insert(job.id.clone(), part.clone());
+                    }
+                    job_index.insert(get_partial_job_id(&job.id, index), part.clone());
+                    jobs.push(part);
+                });
+            }
+            _ => {
+                job_index.insert(job.id.clone(), problem_job.clone());
+                jobs.push(problem_job);
+            }
+        }
     });
 
     (jobs, vec![])
@@ -180,6 +324,7 @@ fn read_conditional_jobs(
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
             if let Some(breaks) = &shift.breaks {
                 read_optional_breaks(coord_index, job_index, &mut jobs, vehicle, shift_index, breaks);
+                read_driving_breaks(coord_index, job_index, &mut jobs, vehicle, shift_index, breaks);
             }
 
             if let Some(reloads) = &shift.reloads {
@@ -189,6 +334,10 @@ fn read_conditional_jobs(
             if let Some(recharges) = &shift.recharges {
                 read_recharges(coord_index, job_index, &mut jobs, vehicle, shift_index, recharges);
             }
+
+            if let Some(trailer) = &vehicle.trailer {
+                read_trailer_parks(coord_index, job_index, &mut jobs, vehicle, shift_index, trailer);
+            }
         }
     });
 
@@ -206,7 +355,7 @@ fn read_optional_breaks(
     (1..)
         .zip(breaks.iter().filter_map(|vehicle_break| match vehicle_break {
             VehicleBreak::Optional { time, places, policy } => Some((time, places, policy)),
-            VehicleBreak::Required { .. } => None,
+            VehicleBreak::Required { .. } | VehicleBreak::Driving { .. } => None,
         }))
         .flat_map(|(break_idx, (break_time, break_places, policy))| {
             vehicle
@@ -251,6 +400,59 @@ fn read_optional_breaks(
         .for_each(|(job_id, single)| add_conditional_job(job_index, jobs, job_id, single));
 }
 
+fn read_driving_breaks(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    breaks: &[VehicleBreak],
+) {
+    let Some((max_driving_time, duration, split)) = breaks.iter().find_map(|vehicle_break| match vehicle_break {
+        VehicleBreak::Driving { max_driving_time, duration, split } => Some((*max_driving_time, *duration, split)),
+        _ => None,
+    }) else {
+        return;
+    };
+
+    let shift = &vehicle.shifts[shift_index];
+    let shift_duration = shift
+        .end
+        .as_ref()
+        .map(|end| parse_time(&end.latest) - parse_time(&shift.start.earliest))
+        .or_else(|| vehicle.limits.as_ref().and_then(|limits| limits.max_duration))
+        .unwrap_or(86400.);
+
+    // NOTE the first place is a full break, others are parts of the split break
+    let durations = std::iter::once(duration).chain(split.iter().flat_map(|split| split.iter().copied()));
+    let places = durations.map(|duration| (None, duration, parse_times(&None), None)).collect::<Vec<_>>();
+
+    let break_amount = (shift_duration / max_driving_time).ceil().max(0.) as usize;
+    let break_amount = if split.is_some() { break_amount * 2 } else { break_amount };
+
+    (1..=break_amount)
+        .flat_map(|break_idx| {
+            vehicle
+                .vehicle_ids
+                .iter()
+                .map(|vehicle_id| {
+                    let job_id = format!("{vehicle_id}_break_{shift_index}_{break_idx}");
+                    let job = get_conditional_job(
+                        coord_index,
+                        vehicle_id.clone(),
+                        &job_id,
+                        "driving_break",
+                        shift_index,
+                        places.clone(),
+                    );
+
+                    (job_id, job)
+                })
+                .collect::<Vec<_>>()
+        })
+        .for_each(|(job_id, single)| add_conditional_job(job_index, jobs, job_id, single));
+}
+
 fn read_reloads(
     coord_index: &CoordIndex,
     job_index: &mut JobIndex,
@@ -266,11 +468,19 @@ fn read_reloads(
         jobs,
         vehicle,
         shift_index,
-        reloads.iter().map(|reload| JobPlace {
-            location: reload.location.clone(),
-            duration: reload.duration,
-            times: reload.times.clone(),
-            tag: reload.tag.clone(),
+        reloads.iter().map(|reload| {
+            let place = JobPlace {
+                location: reload.location.clone(),
+                duration: reload.duration,
+                setup: None,
+                duration_rules: None,
+                times: reload.times.clone(),
+                tag: reload.tag.clone(),
+                tolerance: None,
+                dock: reload.dock.clone(),
+            };
+
+            (place, None)
         }),
     )
 }
@@ -290,10 +500,73 @@ fn read_recharges(
         jobs,
         vehicle,
         shift_index,
-        recharges.stations.iter().cloned(),
+        recharges.stations.iter().map(|station| {
+            let place = JobPlace {
+                location: station.location.clone(),
+                duration: station.duration,
+                setup: None,
+                duration_rules: None,
+                times: station.times.clone(),
+                tag: station.tag.clone(),
+                tolerance: None,
+                dock: station.dock.clone(),
+            };
+
+            (place, station.power)
+        }),
     )
 }
 
+fn read_trailer_parks(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    trailer: &VehicleTrailer,
+) {
+    (1..)
+        .zip(trailer.parks.iter())
+        .flat_map(|(park_idx, park)| {
+            vehicle
+                .vehicle_ids
+                .iter()
+                .map(|vehicle_id| {
+                    let job_id = format!("{vehicle_id}_trailer_{shift_index}_{park_idx}");
+
+                    // NOTE park is modeled as a job with two activities: decouple and couple back
+                    let singles = [("decouple", park.decouple_duration), ("couple", park.couple_duration)]
+                        .into_iter()
+                        .map(|(job_type, duration)| {
+                            let place = (Some(park.location.clone()), duration, parse_times(&None), park.tag.clone());
+                            Arc::new(get_conditional_job(
+                                coord_index,
+                                vehicle_id.clone(),
+                                &job_id,
+                                job_type,
+                                shift_index,
+                                vec![place],
+                            ))
+                        })
+                        .collect::<Vec<_>>();
+
+                    let mut dimens = Dimensions::default();
+                    dimens
+                        .set_job_id(job_id.clone())
+                        .set_job_type("trailer".to_string())
+                        .set_shift_index(shift_index)
+                        .set_vehicle_id(vehicle_id.clone());
+
+                    (job_id, Job::Multi(Multi::new_shared(singles, dimens)))
+                })
+                .collect::<Vec<_>>()
+        })
+        .for_each(|(job_id, job)| {
+            job_index.insert(job_id, job.clone());
+            jobs.push(job);
+        });
+}
+
 fn read_specific_job_places(
     job_type: &str,
     coord_index: &CoordIndex,
@@ -301,11 +574,11 @@ fn read_specific_job_places(
     jobs: &mut Vec<Job>,
     vehicle: &VehicleType,
     shift_index: usize,
-    get_places: impl Iterator<Item = JobPlace>,
+    get_places: impl Iterator<Item = (JobPlace, Option<f64>)>,
 ) {
     (1..)
         .zip(get_places)
-        .flat_map(|(place_idx, place)| {
+        .flat_map(|(place_idx, (place, power))| {
             vehicle
                 .vehicle_ids
                 .iter()
@@ -313,7 +586,7 @@ fn read_specific_job_places(
                     let job_id = format!("{vehicle_id}_{job_type}_{shift_index}_{place_idx}");
                     let times = parse_times(&place.times);
 
-                    let job = get_conditional_job(
+                    let mut job = get_conditional_job(
                         coord_index,
                         vehicle_id.clone(),
                         &job_id,
@@ -321,6 +594,7 @@ fn read_specific_job_places(
                         shift_index,
                         vec![(Some(place.location.clone()), place.duration, times, place.tag.clone())],
                     );
+                    job.dimens.set_place_docks(get_place_docks(std::iter::once(&place.dock))).set_recharge_power(power);
 
                     (job_id, job)
                 })
@@ -378,6 +652,58 @@ fn get_single(places: Vec<PlaceData>, coord_index: &CoordIndex) -> Single {
     Single { places, dimens }
 }
 
+fn get_place_docks<'a>(docks: impl Iterator<Item = &'a Option<String>>) -> Option<Vec<(usize, String)>> {
+    let docks =
+        docks.enumerate().filter_map(|(idx, dock)| dock.as_ref().map(|dock| (idx, dock.clone()))).collect::<Vec<_>>();
+
+    if docks.is_empty() {
+        None
+    } else {
+        Some(docks)
+    }
+}
+
+fn get_geo_zones(api_problem: &ApiProblem) -> Vec<(Arc<GeoZone>, &Vec<Location>)> {
+    api_problem
+        .plan
+        .zones
+        .iter()
+        .flat_map(|zones| zones.iter())
+        .enumerate()
+        .map(|(index, zone)| {
+            let vehicle_types = zone.vehicle_type_ids.as_ref().map(|ids| ids.iter().cloned().collect::<HashSet<_>>());
+            let geo_zone = GeoZone { index, vehicle_types, fee: zone.fee.unwrap_or_default() };
+
+            (Arc::new(geo_zone), &zone.polygon)
+        })
+        .collect()
+}
+
+fn get_place_zones<'a>(
+    geo_zones: &[(Arc<GeoZone>, &Vec<Location>)],
+    locations: impl Iterator<Item = &'a Location>,
+) -> Option<PlaceZones> {
+    let zones = locations
+        .enumerate()
+        .map(|(idx, location)| {
+            let zones = geo_zones
+                .iter()
+                .filter(|(_, polygon)| is_inside_polygon(location, polygon.as_slice()))
+                .map(|(zone, _)| zone.clone())
+                .collect::<Vec<_>>();
+
+            (idx, zones)
+        })
+        .filter(|(_, zones)| !zones.is_empty())
+        .collect::<Vec<_>>();
+
+    if zones.is_empty() {
+        None
+    } else {
+        Some(zones)
+    }
+}
+
 fn get_single_with_extras(
     places: Vec<PlaceData>,
     demand: Demand<MultiDimLoad>,
@@ -387,22 +713,44 @@ fn get_single_with_extras(
     coord_index: &CoordIndex,
 ) -> Single {
     let mut single = get_single(places, coord_index);
-    let dimens = &mut single.dimens;
 
+    set_single_demand(&mut single.dimens, demand, has_multi_dimens);
+    single.dimens.set_job_type(activity_type.to_string()).set_job_order(*order);
+
+    single
+}
+
+fn set_single_demand(dimens: &mut Dimensions, demand: Demand<MultiDimLoad>, has_multi_dimens: bool) {
     if has_multi_dimens {
         dimens.set_demand(demand);
     } else {
         dimens.set_demand(Demand {
-            pickup: (SingleDimLoad::new(demand.pickup.0.load[0]), SingleDimLoad::new(demand.pickup.1.load[0])),
-            delivery: (SingleDimLoad::new(demand.delivery.0.load[0]), SingleDimLoad::new(demand.delivery.1.load[0])),
+            pickup: (get_first_dimen(&demand.pickup.0), get_first_dimen(&demand.pickup.1)),
+            delivery: (get_first_dimen(&demand.delivery.0), get_first_dimen(&demand.delivery.1)),
         });
     }
-    dimens.set_job_type(activity_type.to_string()).set_job_order(*order);
+}
 
-    single
+fn get_task_demand(demand: Option<Vec<i32>>, activity_type: &str, is_static_demand: bool) -> Demand<MultiDimLoad> {
+    let absent = (empty(), empty());
+    let capacity = demand.map_or_else(empty, MultiDimLoad::new);
+    let demand = if is_static_demand { (capacity, empty()) } else { (empty(), capacity) };
+
+    match activity_type {
+        "pickup" => Demand { pickup: demand, delivery: absent },
+        "delivery" => Demand { pickup: absent, delivery: demand },
+        "replacement" => Demand { pickup: demand, delivery: demand },
+        "service" => Demand { pickup: absent, delivery: absent },
+        _ => panic!("invalid activity type."),
+    }
 }
 
-fn get_single_job(job: &ApiJob, single: Single) -> Job {
+fn get_single_job(
+    job: &ApiJob,
+    single: Single,
+    periodic: Option<PeriodicVisit>,
+    alternative: Option<JobAlternative>,
+) -> Job {
     let mut single = single;
     single
         .dimens
@@ -410,7 +758,12 @@ fn get_single_job(job: &ApiJob, single: Single) -> Job {
         .set_job_value(job.value)
         .set_job_group(job.group.clone())
         .set_job_compatibility(job.compatibility.clone())
-        .set_job_skills(get_skills(&job.skills));
+        .set_job_skills(get_skills(&job.skills))
+        .set_periodic_visit(periodic);
+
+    if let Some(alternative) = alternative {
+        single.dimens.set_job_alternative(alternative);
+    }
 
     Job::Single(Arc::new(single))
 }
@@ -419,6 +772,8 @@ fn get_multi_job(
     job: &ApiJob,
     singles: Vec<Single>,
     deliveries_start_index: usize,
+    periodic: Option<PeriodicVisit>,
+    alternative: Option<JobAlternative>,
     random: &Arc<dyn Random + Send + Sync>,
 ) -> Job {
     let mut dimens: Dimensions = Default::default();
@@ -427,7 +782,13 @@ fn get_multi_job(
         .set_job_value(job.value)
         .set_job_group(job.group.clone())
         .set_job_compatibility(job.compatibility.clone())
-        .set_job_skills(get_skills(&job.skills));
+        .set_job_skills(get_skills(&job.skills))
+        .set_ride_duration_limit(get_ride_duration_limit(job))
+        .set_periodic_visit(periodic);
+
+    if let Some(alternative) = alternative {
+        dimens.set_job_alternative(alternative);
+    }
 
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
 
@@ -463,6 +824,148 @@ fn get_skills(skills: &Option<ApiJobSkills>) -> Option<FeatureJobSkills> {
         .map(|skills| FeatureJobSkills::new(skills.all_of.clone(), skills.one_of.clone(), skills.none_of.clone()))
 }
 
+fn get_ride_duration_limit(job: &ApiJob) -> Option<RideDurationLimit> {
+    if job.max_ride_duration.is_some() || job.max_ride_factor.is_some() {
+        Some(RideDurationLimit { max_duration: job.max_ride_duration, max_factor: job.max_ride_factor })
+    } else {
+        None
+    }
+}
+
+fn get_product_index(api_problem: &ApiProblem) -> HashMap<String, usize> {
+    let mut products = api_problem.plan.jobs.iter().filter_map(|job| job.product.clone()).collect::<Vec<_>>();
+    products.sort();
+    products.dedup();
+
+    products.into_iter().enumerate().map(|(idx, product)| (product, idx)).collect()
+}
+
+fn get_product_demand(
+    job: &ApiJob,
+    product_index: &HashMap<String, usize>,
+    demand: &Demand<MultiDimLoad>,
+) -> Option<ProductDemand> {
+    job.product.as_ref().and_then(|product| product_index.get(product)).map(|&product| ProductDemand {
+        product,
+        demand: Demand {
+            pickup: (get_first_dimen(&demand.pickup.0), get_first_dimen(&demand.pickup.1)),
+            delivery: (get_first_dimen(&demand.delivery.0), get_first_dimen(&demand.delivery.1)),
+        },
+    })
+}
+
+/// Returns the only task of the job if it is splittable.
+fn get_splittable_task(job: &ApiJob) -> Option<(&JobTask, &'static str)> {
+    let tasks = [(&job.pickups, "pickup"), (&job.deliveries, "delivery"), (&job.replacements, "replacement")]
+        .into_iter()
+        .flat_map(|(tasks, activity_type)| tasks.iter().flat_map(|tasks| tasks.iter()).map(move |t| (t, activity_type)))
+        .chain(job.services.iter().flat_map(|tasks| tasks.iter()).map(|task| (task, "service")))
+        .collect::<Vec<_>>();
+
+    match tasks.as_slice() {
+        &[(task, activity_type)] if task.splittable.unwrap_or(false) => Some((task, activity_type)),
+        _ => None,
+    }
+}
+
+/// Returns demand of each part of the splittable task. The amount of parts is defined once when
+/// the problem is read: it is enough to fit each part into the vehicle with the smallest capacity,
+/// but a demand dimension is spread only over so many parts that each of them gets at least
+/// the task's minimum split. Dimensions with smaller demand are served by the first parts only.
+fn get_split_demands(api_problem: &ApiProblem, task: &JobTask) -> Option<Vec<Vec<i32>>> {
+    let demand = task.demand.as_ref()?;
+
+    // NOTE max amount of parts for each dimension based on the smallest capacity and min split
+    let dimension_parts = demand
+        .iter()
+        .enumerate()
+        .map(|(idx, &value)| {
+            let capacity_parts = api_problem
+                .fleet
+                .vehicles
+                .iter()
+                .filter_map(|vehicle| vehicle.capacity.get(idx).copied())
+                .filter(|&capacity| capacity > 0)
+                .min()
+                .map_or(1, |capacity| (value + capacity - 1) / capacity);
+
+            let min_split_parts = task
+                .min_split
+                .as_ref()
+                .and_then(|min_split| min_split.get(idx).copied())
+                .filter(|&min| min > 0)
+                .map_or(i32::MAX, |min| (value / min).max(1));
+
+            capacity_parts.min(min_split_parts).min(value).max(1)
+        })
+        .collect::<Vec<_>>();
+
+    let parts = dimension_parts.iter().copied().max().unwrap_or(1);
+    if parts < 2 {
+        return None;
+    }
+
+    Some(
+        (0..parts)
+            .map(|index| {
+                demand
+                    .iter()
+                    .zip(dimension_parts.iter())
+                    .map(|(&value, &dim_parts)| {
+                        if index < dim_parts {
+                            value / dim_parts + if index < value % dim_parts { 1 } else { 0 }
+                        } else {
+                            0
+                        }
+                    })
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+/// Returns all visits of the periodic job.
+fn get_periodic_visits(job: &ApiJob) -> Option<Vec<PeriodicVisit>> {
+    let periodic = job.periodic.as_ref()?;
+    let total = periodic.max_frequency.unwrap_or(periodic.frequency).max(periodic.frequency);
+
+    let policy = Arc::new(PeriodicPolicy {
+        min_visits: periodic.frequency,
+        day_patterns: periodic
+            .day_patterns
+            .as_ref()
+            .map(|patterns| patterns.iter().map(|pattern| pattern.iter().copied().collect()).collect()),
+        min_spacing: periodic.min_spacing.unwrap_or(1),
+        max_spacing: periodic.max_spacing,
+    });
+
+    Some((0..total).map(|index| PeriodicVisit { index, total, policy: policy.clone() }).collect())
+}
+
+/// Returns all variants of the job with alternatives as separate jobs.
+fn get_job_variants(job: &ApiJob) -> Option<Vec<ApiJob>> {
+    let variants = job.alternatives.as_ref().filter(|variants| !variants.is_empty())?;
+
+    Some(
+        variants
+            .iter()
+            .map(|variant| ApiJob {
+                pickups: variant.pickups.clone(),
+                deliveries: variant.deliveries.clone(),
+                replacements: variant.replacements.clone(),
+                services: variant.services.clone(),
+                value: variant.value.or(job.value),
+                alternatives: None,
+                ..job.clone()
+            })
+            .collect(),
+    )
+}
+
+fn get_first_dimen(load: &MultiDimLoad) -> SingleDimLoad {
+    SingleDimLoad::new(load.load[0])
+}
+
 fn empty() -> MultiDimLoad {
     MultiDimLoad::default()
 }
@@ -472,3 +975,52 @@ fn parse_times(times: &Option<Vec<Vec<String>>>) -> Vec<TimeSpan> {
         tws.iter().map(|tw| TimeSpan::Window(parse_time_window(tw))).collect()
     })
 }
+
+fn parse_times_with_tolerance(
+    times: &Option<Vec<Vec<String>>>,
+    tolerance: &Option<JobPlaceTolerance>,
+) -> Vec<TimeSpan> {
+    let (earliness, lateness) = tolerance.as_ref().map_or((0., 0.), |tolerance| {
+        (
+            tolerance.earliness.as_ref().map_or(0., |earliness| earliness.max),
+            tolerance.lateness.as_ref().map_or(0., |lateness| lateness.max),
+        )
+    });
+
+    match (times, earliness > 0. || lateness > 0.) {
+        (Some(tws), true) => tws
+            .iter()
+            .map(|tw| parse_time_window(tw))
+            .map(|tw| TimeSpan::Window(TimeWindow::new(tw.start - earliness, tw.end + lateness)))
+            .collect(),
+        _ => parse_times(times),
+    }
+}
+
+fn get_service_duration_fn(rules: Vec<JobPlaceDurationRule>) -> ServiceDurationFn {
+    Arc::new(move |actor, duration| {
+        let type_id = actor.vehicle.dimens.get_vehicle_type();
+        let (vehicle_skills, driver_skills) =
+            (actor.vehicle.dimens.get_vehicle_skills(), actor.driver.dimens.get_driver_skills());
+        let has_skill = |skill: &String| {
+            vehicle_skills.map_or(false, |skills| skills.contains(skill))
+                || driver_skills.map_or(false, |skills| skills.contains(skill))
+        };
+
+        rules
+            .iter()
+            .find(|rule| {
+                rule.vehicle_types.as_ref().map_or(true, |types| type_id.map_or(false, |id| types.contains(id)))
+                    && rule.skills.as_ref().map_or(true, |skills| skills.iter().all(has_skill))
+            })
+            .map_or(duration, |rule| rule.value.unwrap_or_else(|| duration * rule.multiplier.unwrap_or(1.)))
+    })
+}
+
+fn get_tolerance(tolerance: &JobPlaceTolerance) -> TimeWindowTolerance {
+    let get_deviation = |tolerance: &Option<TimeTolerance>| {
+        tolerance.as_ref().map(|t| TimeDeviation { max: t.max, penalty: t.penalty })
+    };
+
+    TimeWindowTolerance { earliness: get_deviation(&tolerance.earliness), lateness: get_deviation(&tolerance.lateness) }
+}
@@ -100,13 +100,108 @@ impl PragmaticProblem for (ApiProblem, Option<Vec<Matrix>>) {
 }
 
 pub(crate) fn get_job_tasks(job: &Job) -> impl Iterator<Item = &JobTask> {
-    job.pickups.iter().chain(job.deliveries.iter()).chain(job.services.iter()).chain(job.replacements.iter()).flatten()
+    job.pickups
+        .iter()
+        .chain(job.deliveries.iter())
+        .chain(job.services.iter())
+        .chain(job.replacements.iter())
+        .flatten()
+        .chain(get_job_variant_tasks(job))
+}
+
+/// Returns tasks of all job variants if job has alternatives.
+pub(crate) fn get_job_variant_tasks(job: &Job) -> impl Iterator<Item = &JobTask> {
+    job.alternatives.iter().flatten().flat_map(|variant| {
+        variant
+            .pickups
+            .iter()
+            .chain(variant.deliveries.iter())
+            .chain(variant.replacements.iter())
+            .chain(variant.services.iter())
+            .flatten()
+    })
+}
+
+/// Returns a job with tasks of the variant which can serve all given activities (activity type, location
+/// and tag) or `None` if the job has no alternatives or no such variant exists.
+pub(crate) fn get_served_job_variant(job: &Job, activities: &[(&str, &Location, Option<&String>)]) -> Option<Job> {
+    job.alternatives.iter().flatten().find_map(|variant| {
+        let variant_job = Job {
+            pickups: variant.pickups.clone(),
+            deliveries: variant.deliveries.clone(),
+            replacements: variant.replacements.clone(),
+            services: variant.services.clone(),
+            value: variant.value.or(job.value),
+            alternatives: None,
+            ..job.clone()
+        };
+
+        let is_served =
+            activities.iter().all(|(activity_type, location, tag)| {
+                let tasks = match *activity_type {
+                    "pickup" => variant_job.pickups.as_ref(),
+                    "delivery" => variant_job.deliveries.as_ref(),
+                    "replacement" => variant_job.replacements.as_ref(),
+                    "service" => variant_job.services.as_ref(),
+                    _ => None,
+                };
+
+                tasks.iter().flat_map(|tasks| tasks.iter()).flat_map(|task| task.places.iter()).any(|place| {
+                    place.location == **location && tag.map_or(true, |tag| place.tag.as_ref() == Some(tag))
+                })
+            });
+
+        is_served.then_some(variant_job)
+    })
+}
+
+/// Returns legs of the job transferred via the given place: the first leg delivers the shipment
+/// to the transfer place and the second one picks it up there.
+pub(crate) fn get_job_transfer_legs(job: &Job, transfer: &JobPlace) -> (Job, Job) {
+    let move_to_transfer = |tasks: &Option<Vec<JobTask>>| {
+        tasks.as_ref().map(|tasks| {
+            tasks.iter().map(|task| JobTask { places: vec![transfer.clone()], ..task.clone() }).collect::<Vec<_>>()
+        })
+    };
+
+    (
+        Job { deliveries: move_to_transfer(&job.deliveries), transfers: None, ..job.clone() },
+        Job { pickups: move_to_transfer(&job.pickups), transfers: None, ..job.clone() },
+    )
+}
+
+/// Returns a job with tasks of both legs if it is transferred via one of its transfer places,
+/// otherwise `None`. Activities are specified by activity type, location and tag.
+pub(crate) fn get_transferred_job(job: &Job, activities: &[(&str, &Location, Option<&String>)]) -> Option<Job> {
+    job.transfers
+        .iter()
+        .flatten()
+        .find(|transfer| {
+            activities.iter().any(|(activity_type, location, tag)| {
+                matches!(*activity_type, "pickup" | "delivery")
+                    && transfer.location == **location
+                    && tag.map_or(true, |tag| transfer.tag.as_ref() == Some(tag))
+            })
+        })
+        .map(|transfer| {
+            let (first, second) = get_job_transfer_legs(job, transfer);
+            let concat = |first: Option<Vec<JobTask>>, second: Option<Vec<JobTask>>| {
+                Some(first.into_iter().chain(second).flatten().collect())
+            };
+
+            Job {
+                pickups: concat(first.pickups, second.pickups),
+                deliveries: concat(first.deliveries, second.deliveries),
+                ..first
+            }
+        })
 }
 
 /// Keeps track of problem properties (e.g. features).
 struct ProblemProperties {
     has_multi_dimen_capacity: bool,
     has_breaks: bool,
+    has_driving_breaks: bool,
     has_skills: bool,
     has_unreachable_locations: bool,
     has_reloads: bool,
@@ -117,6 +212,20 @@ struct ProblemProperties {
     has_compatibility: bool,
     has_tour_size_limits: bool,
     has_tour_travel_limits: bool,
+    has_trip_limits: bool,
+    has_ride_limits: bool,
+    has_time_in_vehicle_limits: bool,
+    has_compartments: bool,
+    has_trailers: bool,
+    has_schedule_relations: bool,
+    has_periodic_jobs: bool,
+    has_split_jobs: bool,
+    has_alternatives: bool,
+    has_transfers: bool,
+    has_docks: bool,
+    has_satellites: bool,
+    has_zones: bool,
+    has_zone_fees: bool,
 }
 
 /// Keeps track of materialized problem building blocks.
@@ -20,9 +20,14 @@ pub enum RelationType {
     Sequence,
     /// Relation type which locks jobs in strict order, no insertions in between are allowed.
     Strict,
+    /// Relation type which synchronizes service start of jobs served by different vehicles.
+    Sync,
+    /// Relation type which forces jobs to be served in specific order by any vehicles.
+    Precedence,
 }
 
-/// Relation is the way to lock specific jobs to specific vehicles.
+/// Relation is the way to lock specific jobs to specific vehicles or to define schedule
+/// dependencies between jobs served by different vehicles.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Relation {
@@ -31,11 +36,16 @@ pub struct Relation {
     pub type_field: RelationType,
     /// List of job ids.
     pub jobs: Vec<String>,
-    /// Vehicle id.
-    pub vehicle_id: String,
+    /// Vehicle id. Required for `any`, `sequence` and `strict` relations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_id: Option<String>,
     /// Vehicle shift index.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shift_index: Option<usize>,
+    /// Time offset: max difference between service start times for `sync` relation or min time
+    /// between service end of the previous job and service start of the next one for `precedence`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<f64>,
 }
 
 /// A job skills limitation for a vehicle.
@@ -55,11 +65,19 @@ pub struct JobSkills {
 
 /// Specifies a place for sub job.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct JobPlace {
     /// A job place location.
     pub location: Location,
     /// A job place duration (service time).
     pub duration: f64,
+    /// A job place setup duration (e.g. parking, entering a building) which is incurred only once
+    /// when vehicle arrives from a different location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup: Option<f64>,
+    /// Vehicle dependent service duration rules. The first rule applicable to the vehicle is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_rules: Option<Vec<JobPlaceDurationRule>>,
     /// A list of job place time windows with time specified in RFC3339 format.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub times: Option<Vec<Vec<String>>>,
@@ -67,6 +85,50 @@ pub struct JobPlace {
     /// You can use it to identify used place in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+    /// Specifies how much time windows can be violated (soft time windows).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tolerance: Option<JobPlaceTolerance>,
+    /// A dock resource id. Limits amount of vehicles served simultaneously at the place.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dock: Option<String>,
+}
+
+/// Specifies a rule which changes job place duration for specific vehicles.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobPlaceDurationRule {
+    /// Vehicle type ids the rule is applicable to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_types: Option<Vec<String>>,
+    /// Skills which vehicle (or its driver) should have for the rule to be applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skills: Option<Vec<String>>,
+    /// A multiplier of the place duration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiplier: Option<f64>,
+    /// A duration which overrides the place duration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+}
+
+/// Specifies time window tolerance of a job place.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobPlaceTolerance {
+    /// Allows to start service before time window start.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earliness: Option<TimeTolerance>,
+    /// Allows to start service after time window end.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness: Option<TimeTolerance>,
+}
+
+/// Specifies max allowed time deviation and its penalty.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct TimeTolerance {
+    /// Max deviation in seconds.
+    pub max: f64,
+    /// A penalty per second of deviation.
+    pub penalty: f64,
 }
 
 /// Specifies a job task.
@@ -80,6 +142,16 @@ pub struct JobTask {
     /// An order, bigger value - later assignment in the route.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<i32>,
+    /// Specifies whether task demand can be split between multiple visits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splittable: Option<bool>,
+    /// A minimum demand served by one visit of the splittable task.
+    #[serde(rename = "minSplit", skip_serializing_if = "Option::is_none")]
+    pub min_split: Option<Vec<i32>>,
+    /// Max duration between departure from the loading point (shift start or reload) and service
+    /// start of the delivery task.
+    #[serde(rename = "maxTimeInVehicle", skip_serializing_if = "Option::is_none")]
+    pub max_time_in_vehicle: Option<f64>,
 }
 
 /// A customer job model. Actual tasks of the job specified by list of pickups and deliveries
@@ -87,6 +159,7 @@ pub struct JobTask {
 /// * all of them should be completed or none of them.
 /// * all pickups must be completed before any of deliveries.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Job {
     /// A job id.
     pub id: String,
@@ -122,6 +195,92 @@ pub struct Job {
     /// A compatibility group: jobs with different compatibility cannot be assigned to the same tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compatibility: Option<String>,
+
+    /// Max duration between departure from the first pickup and arrival at the last delivery.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ride_duration: Option<f64>,
+
+    /// Max ratio between ride duration and duration of the direct trip from pickup to delivery.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ride_factor: Option<f64>,
+
+    /// A product type of the job demand. Only one product type can be loaded into a vehicle compartment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<String>,
+
+    /// Specifies how often the job has to be visited within the planning horizon.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub periodic: Option<JobPeriodic>,
+
+    /// A list of alternative job variants: exactly one of them has to be served. When specified,
+    /// job's own task lists should be empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alternatives: Option<Vec<JobVariant>>,
+
+    /// Specifies that the job is accessible only by a truck without trailer: a vehicle with
+    /// trailer has to decouple it at one of the trailer parks before serving the job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truck_only: Option<bool>,
+
+    /// A list of transfer places (e.g. cross-dock hubs) where the shipment can be dropped by one
+    /// vehicle and picked up by another one. Applicable only for job with one pickup and one delivery.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfers: Option<Vec<JobPlace>>,
+
+    /// An id of satellite resource which is supplied by the job deliveries. Such job is served by
+    /// first-level vehicles and its delivered demand is available for loading at the satellite.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub satellite_id: Option<String>,
+}
+
+/// Specifies periodic job visits. Days are zero-based indices of UTC days counted from the day of
+/// the earliest vehicle shift start. Each visit has to be done on a different day.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobPeriodic {
+    /// A minimum amount of visits within the planning horizon.
+    pub frequency: usize,
+
+    /// A maximum amount of visits. Default is the same as `frequency`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_frequency: Option<usize>,
+
+    /// Allowed visit day patterns: visits have to be done on days of one of the patterns.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day_patterns: Option<Vec<Vec<usize>>>,
+
+    /// A minimum amount of days between two consecutive visits. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_spacing: Option<usize>,
+
+    /// A maximum amount of days between two consecutive visits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_spacing: Option<usize>,
+}
+
+/// Specifies a job variant: an alternative set of job tasks with own value.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobVariant {
+    /// A list of pickup tasks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pickups: Option<Vec<JobTask>>,
+
+    /// A list of delivery tasks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deliveries: Option<Vec<JobTask>>,
+
+    /// A list of replacement tasks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacements: Option<Vec<JobTask>>,
+
+    /// A list of service tasks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub services: Option<Vec<JobTask>>,
+
+    /// A variant value, overrides job value when specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
 }
 
 // region Clustering
@@ -212,6 +371,30 @@ pub struct VicinityFilteringPolicy {
 
 // endregion
 
+// region Zones
+
+/// Specifies a geofenced zone which restricts vehicle access and can charge an entry fee.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Zone {
+    /// An unique zone id.
+    pub id: String,
+
+    /// Polygon vertices specified as geocoordinates. The polygon is closed implicitly.
+    pub polygon: Vec<Location>,
+
+    /// Vehicle type ids which are allowed to serve jobs inside the zone. All vehicle types are
+    /// allowed if it is not specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_type_ids: Option<Vec<String>>,
+
+    /// An entry fee which is paid once per tour serving jobs inside the zone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee: Option<f64>,
+}
+
+// endregion
+
 /// A plan specifies work which has to be done.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct Plan {
@@ -225,6 +408,9 @@ pub struct Plan {
     /// Specifies clustering parameters.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clustering: Option<Clustering>,
+    /// List of geofenced zones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zones: Option<Vec<Zone>>,
 }
 
 // endregion
@@ -233,6 +419,7 @@ pub struct Plan {
 
 /// Specifies vehicle costs.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VehicleCosts {
     /// Fixed is cost of vehicle usage per tour.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -243,6 +430,29 @@ pub struct VehicleCosts {
 
     /// Cost per time unit.
     pub time: f64,
+
+    /// Cost per distance unit when vehicle is fully loaded. If specified, cost per distance unit is
+    /// interpolated between `distance` (empty vehicle) and this value using current vehicle load.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_loaded: Option<f64>,
+
+    /// Time cost tiers: each tier replaces time cost once total tour duration exceeds its threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_tiers: Option<Vec<VehicleCostTier>>,
+
+    /// Distance cost tiers: each tier replaces distance cost once total tour distance exceeds its threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_tiers: Option<Vec<VehicleCostTier>>,
+}
+
+/// Specifies vehicle cost tier.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleCostTier {
+    /// A total tour duration or distance after which the tier is applied.
+    pub threshold: f64,
+
+    /// Cost per time or distance unit beyond the threshold.
+    pub cost: f64,
 }
 
 /// Specifies vehicle shift start.
@@ -321,6 +531,10 @@ pub struct VehicleReload {
     /// A shared reload resource id.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_id: Option<String>,
+
+    /// A dock resource id. Limits amount of vehicles served simultaneously at the reload place.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dock: Option<String>,
 }
 
 /// Specifies vehicle recharge stations data.
@@ -328,14 +542,70 @@ pub struct VehicleReload {
 #[serde(rename_all = "camelCase")]
 pub struct VehicleRecharges {
     /// Maximum traveled distance before recharge station has to be visited.
-    pub max_distance: f64,
+    /// Ignored when battery is specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_distance: Option<f64>,
+
+    /// Vehicle battery model. When specified, battery state of charge is tracked instead of
+    /// traveled distance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub battery: Option<VehicleBattery>,
 
     /// Specifies list of recharge station. Each can be visited only once.
     pub stations: Vec<VehicleRechargeStation>,
 }
 
-/// Specifies type alias for vehicle recharge station.
-pub type VehicleRechargeStation = JobPlace;
+/// Specifies vehicle battery model.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleBattery {
+    /// Battery capacity, e.g. in kWh.
+    pub capacity: f64,
+
+    /// State of charge at the shift start. Full capacity when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial: Option<f64>,
+
+    /// Minimum state of charge which has to be kept all the time. Zero when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reserve: Option<f64>,
+
+    /// Energy consumed per distance unit (meter) by empty vehicle.
+    pub consumption: f64,
+
+    /// Energy consumed per distance unit (meter) by fully loaded vehicle. Actual consumption is
+    /// interpolated linearly using vehicle load ratio. Load doesn't affect consumption when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumption_loaded: Option<f64>,
+}
+
+/// Specifies vehicle recharge station.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleRechargeStation {
+    /// A station location.
+    pub location: Location,
+
+    /// A fixed duration of the station visit (service time).
+    pub duration: f64,
+
+    /// A list of time windows with time specified in RFC3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<Vec<Vec<String>>>,
+
+    /// A tag which will be propagated back within corresponding activity in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    /// Charging power: energy added per second. When specified, battery is charged partially and
+    /// charging time is added to the visit duration. Otherwise, battery is charged fully within
+    /// the fixed duration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power: Option<f64>,
+
+    /// A dock resource id. Limits amount of vehicles served simultaneously at the station.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dock: Option<String>,
+}
 
 /// Vehicle limits.
 #[derive(Clone, Deserialize, Debug, Serialize)]
@@ -356,6 +626,28 @@ pub struct VehicleLimits {
     /// No job activities restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tour_size: Option<usize>,
+
+    /// Limits applied to each trip between reloads.
+    /// No trip restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trip: Option<VehicleTripLimits>,
+}
+
+/// Vehicle trip limits. A trip is a part of the tour between vehicle start, reloads and vehicle end.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleTripLimits {
+    /// Max traveling distance per trip.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_distance: Option<f64>,
+
+    /// Max duration per trip.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_duration: Option<f64>,
+
+    /// Max amount of job activities per trip, reloads are not counted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<usize>,
 }
 
 /// Vehicle optional break time variant.
@@ -434,6 +726,18 @@ pub enum VehicleBreak {
         /// Break duration.
         duration: f64,
     },
+    /// A break which is scheduled based on accumulated driving time: vehicle has to rest
+    /// at least given duration after driving the given amount of time.
+    #[serde(rename_all = "camelCase")]
+    Driving {
+        /// Max driving time allowed without a break.
+        max_driving_time: f64,
+        /// Break duration.
+        duration: f64,
+        /// Specifies two parts the break can be split into. The first part does not reset
+        /// driving time, the second one does if it follows the first part.
+        split: Option<Vec<f64>>,
+    },
 }
 
 /// Specifies a vehicle type.
@@ -465,6 +769,59 @@ pub struct VehicleType {
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Vehicle compartments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compartments: Option<Vec<VehicleCompartment>>,
+
+    /// Vehicle trailer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailer: Option<VehicleTrailer>,
+}
+
+/// Specifies a vehicle compartment which can hold only one product type at a time.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleCompartment {
+    /// Compartment id.
+    pub id: String,
+
+    /// Compartment capacity.
+    pub capacity: i32,
+
+    /// A time needed to clean the compartment when it changes product type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cleaning_duration: Option<f64>,
+}
+
+/// Specifies a vehicle trailer which adds its capacity to the vehicle capacity. The trailer can be
+/// decoupled at a trailer park, so that the truck alone can serve truck only jobs, and coupled back
+/// at the same park later.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleTrailer {
+    /// Trailer capacity.
+    pub capacity: Vec<i32>,
+
+    /// Trailer parks where trailer can be decoupled and coupled back.
+    pub parks: Vec<VehicleTrailerPark>,
+}
+
+/// Specifies a trailer park.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleTrailerPark {
+    /// Park location.
+    pub location: Location,
+
+    /// A duration to decouple the trailer.
+    pub decouple_duration: f64,
+
+    /// A duration to couple the trailer back.
+    pub couple_duration: f64,
+
+    /// A tag which will be propagated back within corresponding activities in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
 }
 
 /// Specifies a vehicle profile.
@@ -491,6 +848,55 @@ pub struct MatrixProfile {
     pub speed: Option<f64>,
 }
 
+/// Specifies a driver type: a group of drivers with the same working hours, skills and costs.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriverType {
+    /// Driver type id.
+    pub type_id: String,
+
+    /// Concrete driver ids.
+    pub driver_ids: Vec<String>,
+
+    /// Driver costs.
+    pub costs: DriverCosts,
+
+    /// Driver shifts: time windows when driver is available to work.
+    pub shifts: Vec<DriverShift>,
+
+    /// Driver skills.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skills: Option<Vec<String>>,
+
+    /// Vehicle type ids which can be driven by the driver. All vehicle types, if not specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_type_ids: Option<Vec<String>>,
+}
+
+/// Specifies driver costs.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct DriverCosts {
+    /// Fixed is cost of driver usage per tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed: Option<f64>,
+
+    /// Cost per distance unit.
+    pub distance: f64,
+
+    /// Cost per time unit.
+    pub time: f64,
+}
+
+/// Specifies driver shift.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct DriverShift {
+    /// Shift start time in RFC3339 format.
+    pub start: String,
+
+    /// Shift end time in RFC3339 format.
+    pub end: String,
+}
+
 /// Specifies vehicle resource type.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(tag = "type")]
@@ -503,6 +909,23 @@ pub enum VehicleResource {
         /// A total resource capacity.
         capacity: Vec<i32>,
     },
+    /// A dock with limited amount of vehicles served simultaneously.
+    #[serde(rename(deserialize = "dock", serialize = "dock"))]
+    Dock {
+        /// Resource id.
+        id: String,
+        /// Amount of parallel slots.
+        slots: usize,
+    },
+    /// A satellite depot which is supplied by deliveries of first-level vehicles and from which
+    /// second-level vehicles are loaded at the shift start or reloads.
+    #[serde(rename(deserialize = "satellite", serialize = "satellite"))]
+    Satellite {
+        /// Resource id.
+        id: String,
+        /// A satellite location.
+        location: Location,
+    },
 }
 
 /// Specifies fleet.
@@ -517,6 +940,10 @@ pub struct Fleet {
     /// Specifies vehicle resources.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resources: Option<Vec<VehicleResource>>,
+
+    /// Driver types. If omitted, each vehicle has its own implicit driver without limitations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drivers: Option<Vec<DriverType>>,
 }
 
 // endregion
@@ -560,6 +987,9 @@ pub enum Objective {
     /// An objective to minimize sum of arrival times from all routes.
     MinimizeArrivalTime,
 
+    /// An objective to minimize makespan: the longest tour duration.
+    MinimizeMakespan,
+
     /// An objective to balance max load across all tours.
     BalanceMaxLoad {
         /// A relative load in single tour before balancing takes place.
@@ -606,6 +1036,21 @@ pub enum Objective {
         /// to consider them different. Relative distance metric is used.
         tolerance: Option<f64>,
     },
+
+    /// An objective to minimize changes of the plan comparing to the reference one.
+    MinimizePlanChanges {
+        /// A reference plan: tours with job ids in the order they are served.
+        reference: Vec<ReferenceTour>,
+        /// Options to specify weights of plan changes.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        options: Option<PlanChangeOptions>,
+    },
+
+    /// An objective which is a weighted sum of other objectives.
+    Weighted {
+        /// Objectives with their weights.
+        objectives: Vec<WeightedObjective>,
+    },
 }
 
 /// Specifies balance objective options. At the moment, it uses coefficient of variation as
@@ -632,6 +1077,39 @@ pub struct CompactOptions {
     pub distance: f64,
 }
 
+/// Specifies a tour of the reference plan.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceTour {
+    /// Vehicle id.
+    pub vehicle_id: String,
+
+    /// Ids of jobs in the order they are served in the tour.
+    pub jobs: Vec<String>,
+}
+
+/// Specifies weights of plan changes.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct PlanChangeOptions {
+    /// A weight of job activity served by another vehicle. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reassignment: Option<f64>,
+
+    /// A weight of neighbour job activities served in changed order. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<f64>,
+}
+
+/// Specifies an objective with its weight in weighted sum.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct WeightedObjective {
+    /// A weight of the objective.
+    pub weight: f64,
+
+    /// An objective.
+    pub objective: Objective,
+}
+
 // endregion
 
 // region Common
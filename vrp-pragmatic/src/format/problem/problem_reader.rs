@@ -1,5 +1,5 @@
 use super::*;
-use crate::construction::enablers::{OnlyVehicleActivityCost, VehicleTie};
+use crate::construction::enablers::VehicleTie;
 use crate::format::problem::clustering_reader::create_cluster_config;
 use crate::format::problem::fleet_reader::*;
 use crate::format::problem::goal_reader::create_goal_context;
@@ -9,6 +9,7 @@ use crate::validation::ValidationContext;
 use crate::{parse_time, CoordIndex};
 use vrp_core::construction::enablers::*;
 use vrp_core::models::common::{TimeOffset, TimeSpan, TimeWindow};
+use vrp_core::models::problem::SimpleActivityCost;
 use vrp_core::models::Extras;
 use vrp_core::solver::processing::{ReservedTimeDimension, VicinityDimension};
 
@@ -72,7 +73,7 @@ fn read_reserved_times_index(api_problem: &ApiProblem, fleet: &CoreFleet) -> Res
                     VehicleBreak::Required { time, duration } => {
                         Some((vehicle.type_id.clone(), shift_idx, time.clone(), *duration))
                     }
-                    VehicleBreak::Optional { .. } => None,
+                    VehicleBreak::Optional { .. } | VehicleBreak::Driving { .. } => None,
                 })
             })
         })
@@ -143,6 +144,7 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
                 .iter()
                 .chain(job.deliveries.iter())
                 .flat_map(|tasks| tasks.iter())
+                .chain(get_job_variant_tasks(job))
                 .any(|task| task.demand.as_ref().map_or(false, |d| d.len() > 1))
         });
     let has_skills = api_problem.plan.jobs.iter().any(|job| job.skills.is_some());
@@ -152,6 +154,9 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
     };
 
     let has_breaks = shift_has_fn(|s| s.breaks.as_ref().map_or(false, |b| !b.is_empty()));
+    let has_driving_breaks = shift_has_fn(|s| {
+        s.breaks.iter().flat_map(|breaks| breaks.iter()).any(|b| matches!(b, VehicleBreak::Driving { .. }))
+    });
     let has_reloads = shift_has_fn(|s| s.reloads.as_ref().map_or(false, |r| !r.is_empty()));
     let has_recharges = shift_has_fn(|s| s.recharges.as_ref().is_some());
 
@@ -164,7 +169,12 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .any(|order| order > 0);
 
     let has_group = api_problem.plan.jobs.iter().any(|job| job.group.is_some());
-    let has_value = api_problem.plan.jobs.iter().filter_map(|job| job.value).any(|value| value != 0.);
+    let has_value = api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| job.value.into_iter().chain(job.alternatives.iter().flatten().filter_map(|v| v.value)))
+        .any(|value| value != 0.);
     let has_compatibility = api_problem.plan.jobs.iter().any(|job| job.compatibility.is_some());
     let has_tour_size_limits =
         api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.tour_size.is_some()));
@@ -175,9 +185,46 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .iter()
         .any(|v| v.limits.as_ref().map_or(false, |l| l.max_duration.or(l.max_distance).is_some()));
 
+    let has_trip_limits =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.trip.is_some()));
+
+    let has_ride_limits =
+        api_problem.plan.jobs.iter().any(|job| job.max_ride_duration.or(job.max_ride_factor).is_some());
+
+    let has_time_in_vehicle_limits =
+        api_problem.plan.jobs.iter().flat_map(get_job_tasks).any(|task| task.max_time_in_vehicle.is_some());
+
+    let has_compartments =
+        api_problem.fleet.vehicles.iter().any(|v| v.compartments.as_ref().map_or(false, |c| !c.is_empty()));
+
+    let has_trailers = api_problem.fleet.vehicles.iter().any(|v| v.trailer.is_some());
+
+    let has_schedule_relations = api_problem.plan.relations.as_ref().map_or(false, |relations| {
+        relations.iter().any(|relation| matches!(relation.type_field, RelationType::Sync | RelationType::Precedence))
+    });
+
+    let has_periodic_jobs = api_problem.plan.jobs.iter().any(|job| job.periodic.is_some());
+    let has_split_jobs =
+        api_problem.plan.jobs.iter().flat_map(get_job_tasks).any(|task| task.splittable.unwrap_or(false));
+    let has_alternatives = api_problem.plan.jobs.iter().any(|job| job.alternatives.is_some());
+    let has_transfers =
+        api_problem.plan.jobs.iter().any(|job| job.transfers.as_ref().map_or(false, |transfers| !transfers.is_empty()));
+
+    let has_docks =
+        api_problem.fleet.resources.as_ref().map_or(false, |resources| {
+            resources.iter().any(|resource| matches!(resource, VehicleResource::Dock { .. }))
+        });
+
+    let has_satellites = api_problem.plan.jobs.iter().any(|job| job.satellite_id.is_some());
+
+    let zones = api_problem.plan.zones.iter().flat_map(|zones| zones.iter());
+    let has_zones = zones.clone().next().is_some();
+    let has_zone_fees = zones.filter_map(|zone| zone.fee).any(|fee| fee > 0.);
+
     ProblemProperties {
         has_multi_dimen_capacity,
         has_breaks,
+        has_driving_breaks,
         has_skills,
         has_unreachable_locations,
         has_reloads,
@@ -188,6 +235,20 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_compatibility,
         has_tour_size_limits,
         has_tour_travel_limits,
+        has_trip_limits,
+        has_ride_limits,
+        has_time_in_vehicle_limits,
+        has_compartments,
+        has_trailers,
+        has_schedule_relations,
+        has_periodic_jobs,
+        has_split_jobs,
+        has_alternatives,
+        has_transfers,
+        has_docks,
+        has_satellites,
+        has_zones,
+        has_zone_fees,
     }
 }
 
@@ -198,7 +259,13 @@ fn get_problem_blocks(
     problem_props: &ProblemProperties,
 ) -> Result<ProblemBlocks, MultiFormatError> {
     let coord_index = Arc::new(coord_index);
-    let fleet = read_fleet(api_problem, problem_props, &coord_index);
+    let fleet = read_fleet(api_problem, problem_props, &coord_index).map_err(|err| {
+        vec![FormatError::new(
+            "E0000".to_string(),
+            "cannot create fleet".to_string(),
+            format!("check fleet definition: '{err}'"),
+        )]
+    })?;
     let reserved_times_index = read_reserved_times_index(api_problem, &fleet);
 
     let transport = create_transport_costs(api_problem, &matrices, coord_index.clone()).map_err(|err| {
@@ -208,7 +275,7 @@ fn get_problem_blocks(
             format!("check matrix routing data: '{err}'"),
         )]
     })?;
-    let activity: Arc<dyn ActivityCost + Send + Sync> = Arc::new(OnlyVehicleActivityCost::default());
+    let activity: Arc<dyn ActivityCost + Send + Sync> = Arc::new(SimpleActivityCost::default());
 
     let (transport, activity) = if reserved_times_index.is_empty() {
         (transport, activity)
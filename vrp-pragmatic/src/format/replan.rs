@@ -0,0 +1,693 @@
+//! This module provides logic to re-plan already executing solution: it takes the original problem,
+//! its solution and the state of execution at some moment of time, and creates a new problem which
+//! keeps the past as is and allows to re-optimize the remaining part of the plan.
+
+#[cfg(test)]
+#[path = "../../tests/unit/format/replan_test.rs"]
+mod replan_test;
+
+use crate::format::problem::*;
+use crate::format::solution::{Solution, Stop, Tour};
+use crate::format::Location;
+use crate::{format_time, parse_time, parse_time_safe};
+use hashbrown::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use std::io::{BufReader, Read};
+use vrp_core::models::common::Timestamp;
+use vrp_core::prelude::GenericError;
+
+/// Specifies a state of solution execution at some moment of time.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionState {
+    /// Current time in RFC3339 format. All stops with arrival time before it are considered
+    /// as completed or in progress.
+    pub time: String,
+
+    /// Actual vehicle positions. If vehicle position is not specified, it is derived from
+    /// the last started stop of its tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicles: Option<Vec<VehiclePosition>>,
+
+    /// Ids of jobs cancelled since solution was created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelled: Option<Vec<String>>,
+}
+
+/// Specifies an actual vehicle position.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehiclePosition {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle location.
+    pub location: Location,
+}
+
+/// Deserializes execution state in json format from `BufReader`.
+pub fn deserialize_execution_state<R: Read>(reader: BufReader<R>) -> Result<ExecutionState, GenericError> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize execution state: '{err}'").into())
+}
+
+/// Creates a problem to re-plan the solution which is already being executed:
+/// * cancelled jobs are removed from the plan;
+/// * served jobs are kept as already done: their tasks are fixed at served places and times and they
+///   are locked in a strict relation which starts at departure of the vehicle which served them;
+/// * remaining tasks of partially served jobs follow the current vehicle position in the planned order,
+///   if it is not possible (e.g. job is served by different vehicles), only remaining tasks are kept
+///   and locked to the vehicle which serves them;
+/// * taken optional breaks and reloads are fixed and locked the same way;
+/// * departed vehicle continues its tour from current location and time which are kept by a locked
+///   position job which follows the served part of the tour;
+/// * delivery jobs which are already on board of the vehicle are locked to it;
+/// * served job with alternatives is replaced by its served variant;
+/// * finished tours are kept as is, expired vehicle shifts are removed, other shifts cannot start in the past.
+///
+/// Periodic and splittable jobs are kept as is. New jobs can be added to the problem plan before calling the function.
+pub fn create_replan_problem(
+    problem: &Problem,
+    solution: &Solution,
+    state: &ExecutionState,
+) -> Result<Problem, GenericError> {
+    let now = parse_time_safe(&state.time)?;
+    let positions = state
+        .vehicles
+        .iter()
+        .flat_map(|vehicles| vehicles.iter())
+        .map(|position| (position.vehicle_id.as_str(), &position.location))
+        .collect::<HashMap<_, _>>();
+    let cancelled = state.cancelled.iter().flat_map(|ids| ids.iter()).map(String::as_str).collect::<HashSet<_>>();
+
+    let progresses = solution
+        .tours
+        .iter()
+        .filter_map(|tour| get_tour_progress(tour, now, positions.get(tour.vehicle_id.as_str()).copied()))
+        .collect::<Vec<_>>();
+
+    let mut problem = problem.clone();
+
+    let done = update_plan(&mut problem, progresses.as_slice(), &cancelled);
+    update_fleet(&mut problem, progresses.as_slice(), &done, now);
+
+    Ok(problem)
+}
+
+/// Keeps information about tour execution progress.
+struct TourProgress {
+    vehicle_id: String,
+    shift_index: usize,
+    /// Tour departure time.
+    departure: Timestamp,
+    /// True if all tour stops are visited.
+    is_finished: bool,
+    /// Location and time where and when the vehicle can continue its tour.
+    start: (Location, Timestamp),
+    /// Job, break and reload activities of started stops in the order of visiting.
+    visited: Vec<TourActivity>,
+    /// Job activities of remaining stops in the planned order.
+    planned: Vec<TourActivity>,
+    /// Ids of delivery jobs which are expected to be on board.
+    on_board: HashSet<String>,
+}
+
+/// Keeps information about an activity of the tour.
+struct TourActivity {
+    job_id: String,
+    activity_type: String,
+    location: Location,
+    tag: Option<String>,
+    /// Service start time.
+    time: Timestamp,
+}
+
+fn get_tour_progress(tour: &Tour, now: Timestamp, position: Option<&Location>) -> Option<TourProgress> {
+    let departure = tour.stops.first().map(|stop| parse_time(&stop.schedule().departure))?;
+    if departure > now {
+        return None;
+    }
+
+    let started = tour.stops.iter().take_while(|stop| parse_time(&stop.schedule().arrival) <= now).count();
+    let (visited, remaining) = tour.stops.split_at(started);
+
+    let last_point = visited.iter().rev().find_map(|stop| stop.as_point())?;
+    // NOTE vehicle cannot continue its tour before the last started stop is completed
+    let start = (position.unwrap_or(&last_point.location).clone(), parse_time(&last_point.time.departure).max(now));
+
+    let visited = get_tour_activities(visited, |activity_type| {
+        is_job_activity(activity_type) || matches!(activity_type, "break" | "reload")
+    });
+    let planned = get_tour_activities(remaining, is_job_activity);
+
+    let on_board = remaining
+        .iter()
+        .take_while(|stop| !has_activity_type(stop, "reload"))
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.clone())
+        .collect();
+
+    Some(TourProgress {
+        vehicle_id: tour.vehicle_id.clone(),
+        shift_index: tour.shift_index,
+        departure,
+        is_finished: remaining.is_empty(),
+        start,
+        visited,
+        planned,
+        on_board,
+    })
+}
+
+fn get_tour_activities(stops: &[Stop], is_accepted: impl Fn(&str) -> bool) -> Vec<TourActivity> {
+    stops
+        .iter()
+        .flat_map(|stop| {
+            stop.activities().iter().filter(|activity| is_accepted(activity.activity_type.as_str())).filter_map(
+                move |activity| {
+                    let location = activity.location.as_ref().or_else(|| stop.location())?;
+                    let time = activity.time.as_ref().map_or(&stop.schedule().arrival, |time| &time.start);
+
+                    Some(TourActivity {
+                        job_id: activity.job_id.clone(),
+                        activity_type: activity.activity_type.clone(),
+                        location: location.clone(),
+                        tag: activity.job_tag.clone(),
+                        time: parse_time(time),
+                    })
+                },
+            )
+        })
+        .collect()
+}
+
+/// Updates plan jobs and relations. Returns ids of jobs which are kept in tours of departed vehicles.
+fn update_plan(problem: &mut Problem, progresses: &[TourProgress], cancelled: &HashSet<&str>) -> HashSet<String> {
+    let served = progresses.iter().fold(HashMap::<&str, Vec<_>>::new(), |mut acc, progress| {
+        progress
+            .visited
+            .iter()
+            .filter(|activity| is_job_activity(activity.activity_type.as_str()))
+            .for_each(|activity| acc.entry(activity.job_id.as_str()).or_default().push((progress, activity)));
+        acc
+    });
+
+    let mut done = HashSet::<String>::new();
+    let mut locks = Vec::<(String, usize, &TourProgress)>::new();
+
+    problem.plan.jobs.retain_mut(|job| {
+        if cancelled.contains(job.id.as_str()) {
+            return false;
+        }
+
+        // NOTE served part of periodic and splittable jobs cannot be expressed via job tasks
+        if job.periodic.is_some() || get_tasks(job).any(|task| task.splittable.unwrap_or(false)) {
+            return true;
+        }
+
+        if let Some(activities) = served.get(job.id.as_str()) {
+            // NOTE job with alternatives is replaced by its served variant
+            let served_activities = activities
+                .iter()
+                .map(|(_, activity)| (activity.activity_type.as_str(), &activity.location, activity.tag.as_ref()))
+                .collect::<Vec<_>>();
+            if let Some(variant) = get_served_job_variant(job, served_activities.as_slice()) {
+                *job = variant;
+            }
+
+            let progress = activities.first().unwrap().0;
+            let visited = activities.iter().map(|(_, activity)| *activity).collect::<Vec<_>>();
+            let planned = progress.planned.iter().filter(|activity| activity.job_id == job.id).collect::<Vec<_>>();
+            // NOTE job can be kept only when all its tasks are served or planned within one tour
+            let done_job = if activities.iter().all(|(other, _)| std::ptr::eq(*other, progress)) {
+                create_done_job(job, visited.as_slice(), planned.as_slice())
+            } else {
+                None
+            };
+
+            if let Some(done_job) = done_job {
+                *job = done_job;
+                done.insert(job.id.clone());
+
+                return true;
+            }
+
+            activities.iter().for_each(|(_, activity)| {
+                remove_served_task(job, activity.activity_type.as_str(), &activity.location, activity.tag.as_ref());
+            });
+
+            let remaining = get_tasks(job).count();
+            if remaining == 0 {
+                return false;
+            }
+
+            locks.push((job.id.clone(), remaining, progress));
+        } else if job.pickups.is_none() {
+            if let Some(progress) = progresses.iter().find(|progress| progress.on_board.contains(&job.id)) {
+                locks.push((job.id.clone(), get_tasks(job).count(), progress));
+            }
+        }
+
+        true
+    });
+
+    let job_ids = problem
+        .plan
+        .jobs
+        .iter()
+        .map(|job| job.id.as_str())
+        .filter(|job_id| !done.contains(*job_id))
+        .collect::<HashSet<_>>();
+    let mut relations = problem.plan.relations.take().unwrap_or_default();
+
+    relations.iter_mut().for_each(|relation| {
+        relation.jobs.retain(|job_id| is_reserved_job_id(job_id) || job_ids.contains(job_id.as_str()))
+    });
+    relations.retain(|relation| relation.jobs.iter().any(|job_id| !is_reserved_job_id(job_id)));
+
+    let locked = relations
+        .iter()
+        .filter(|relation| !matches!(relation.type_field, RelationType::Sync | RelationType::Precedence))
+        .flat_map(|relation| relation.jobs.iter().cloned())
+        .collect::<HashSet<_>>();
+
+    // NOTE job id is repeated as many times as the job has remaining tasks
+    relations.extend(locks.into_iter().filter(|(job_id, ..)| !locked.contains(job_id)).map(
+        |(job_id, remaining, progress)| Relation {
+            type_field: RelationType::Any,
+            jobs: vec![job_id; remaining],
+            vehicle_id: Some(progress.vehicle_id.clone()),
+            shift_index: Some(progress.shift_index),
+            offset: None,
+        },
+    ));
+
+    problem.plan.relations = if relations.is_empty() { None } else { Some(relations) };
+
+    done
+}
+
+fn update_fleet(problem: &mut Problem, progresses: &[TourProgress], done: &HashSet<String>, now: Timestamp) {
+    let progresses = progresses
+        .iter()
+        .map(|progress| ((progress.vehicle_id.as_str(), progress.shift_index), progress))
+        .collect::<HashMap<_, _>>();
+
+    // maps (vehicle id, old shift index) to a new shift index, removed shifts are not present
+    let mut shift_indices = HashMap::<(String, usize), usize>::new();
+    let mut split_types = Vec::<(String, String)>::new();
+    let mut tour_relations = Vec::<Relation>::new();
+    let mut position_jobs = Vec::<Job>::new();
+
+    let vehicles = std::mem::take(&mut problem.fleet.vehicles);
+    problem.fleet.vehicles = vehicles
+        .into_iter()
+        .flat_map(|vehicle| {
+            let (individual, common): (Vec<_>, Vec<_>) = vehicle.vehicle_ids.iter().cloned().partition(|vehicle_id| {
+                (0..vehicle.shifts.len()).any(|idx| progresses.contains_key(&(vehicle_id.as_str(), idx)))
+            });
+            let is_split_required = vehicle.vehicle_ids.len() > 1;
+
+            let individual = individual.into_iter().map(|vehicle_id| {
+                let type_id = if is_split_required {
+                    format!("{}_{}", vehicle.type_id, vehicle_id)
+                } else {
+                    vehicle.type_id.clone()
+                };
+                if is_split_required {
+                    split_types.push((vehicle.type_id.clone(), type_id.clone()));
+                }
+
+                (type_id, vec![vehicle_id])
+            });
+            let common = Some(common).filter(|ids| !ids.is_empty()).map(|ids| (vehicle.type_id.clone(), ids));
+
+            common
+                .into_iter()
+                .chain(individual)
+                .filter_map(|(type_id, vehicle_ids)| {
+                    let vehicle_id = vehicle_ids.first().unwrap().clone();
+                    let shifts = vehicle
+                        .shifts
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(idx, shift)| match progresses.get(&(vehicle_id.as_str(), idx)) {
+                            Some(progress) => {
+                                let (shift, relation, position_job) = create_departed_tour(shift, progress, done);
+                                tour_relations.push(relation);
+                                position_jobs.extend(position_job);
+
+                                Some((idx, shift))
+                            }
+                            None if is_shift_expired(shift, now) => None,
+                            None => Some((idx, create_future_shift(shift, now))),
+                        })
+                        .enumerate()
+                        .map(|(new_idx, (old_idx, shift))| {
+                            vehicle_ids.iter().for_each(|vehicle_id| {
+                                shift_indices.insert((vehicle_id.clone(), old_idx), new_idx);
+                            });
+                            shift
+                        })
+                        .collect::<Vec<_>>();
+
+                    if shifts.is_empty() {
+                        None
+                    } else {
+                        Some(VehicleType { type_id, vehicle_ids, shifts, ..vehicle.clone() })
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    problem.plan.jobs.extend(position_jobs);
+
+    // NOTE served part of departed tour is locked at its departure, so other relations cannot start there
+    let mut relations = problem.plan.relations.take().unwrap_or_default();
+    relations
+        .iter_mut()
+        .filter(|relation| {
+            relation.vehicle_id.as_ref().map_or(false, |vehicle_id| {
+                progresses.contains_key(&(vehicle_id.as_str(), relation.shift_index.unwrap_or_default()))
+            })
+        })
+        .for_each(|relation| relation.jobs.retain(|job_id| job_id != "departure"));
+    tour_relations.extend(relations);
+
+    if let Some(relations) = Some(tour_relations).filter(|relations| !relations.is_empty()) {
+        problem.plan.relations = Some(relations);
+    }
+
+    if let Some(relations) = problem.plan.relations.as_mut() {
+        relations.retain_mut(|relation| match relation.vehicle_id.as_ref() {
+            Some(vehicle_id) => {
+                let key = (vehicle_id.clone(), relation.shift_index.unwrap_or_default());
+                shift_indices.get(&key).map_or(false, |&shift_index| {
+                    relation.shift_index = Some(shift_index);
+                    true
+                })
+            }
+            None => true,
+        });
+    }
+
+    problem.plan.zones.iter_mut().flat_map(|zones| zones.iter_mut()).for_each(|zone| {
+        if let Some(type_ids) = zone.vehicle_type_ids.as_mut() {
+            let new_type_ids = split_types
+                .iter()
+                .filter(|(original, _)| type_ids.contains(original))
+                .map(|(_, type_id)| type_id.clone())
+                .collect::<Vec<_>>();
+            type_ids.extend(new_type_ids);
+        }
+    });
+}
+
+/// Creates a shift of departed vehicle which starts at the original departure, a strict relation which
+/// locks already visited activities of the tour, and a job which keeps the current vehicle position
+/// when the tour is not finished. Remaining activities of partially served jobs follow the position.
+fn create_departed_tour(
+    shift: &VehicleShift,
+    progress: &TourProgress,
+    done: &HashSet<String>,
+) -> (VehicleShift, Relation, Option<Job>) {
+    let departure = format_time(progress.departure);
+
+    let mut shift = shift.clone();
+    shift.start.earliest = departure.clone();
+    shift.start.latest = Some(departure);
+
+    // NOTE taken breaks are matched to non-driving breaks in the order of definition
+    let mut taken_breaks = progress.visited.iter().filter(|activity| activity.activity_type == "break");
+    let mut locked_breaks = Vec::<bool>::new();
+    shift.breaks = shift
+        .breaks
+        .take()
+        .map(|breaks| {
+            breaks
+                .into_iter()
+                .filter_map(|vehicle_break| match vehicle_break {
+                    VehicleBreak::Driving { .. } => Some(vehicle_break),
+                    // NOTE required break is not a job, so it cannot be locked and is removed once taken
+                    VehicleBreak::Required { .. } => match taken_breaks.next() {
+                        Some(_) => {
+                            locked_breaks.push(false);
+                            None
+                        }
+                        None => Some(vehicle_break),
+                    },
+                    VehicleBreak::Optional { time, places, policy } => match taken_breaks.next() {
+                        Some(activity) => {
+                            locked_breaks.push(true);
+                            let places = places
+                                .iter()
+                                .find(|place| place.tag.is_some() && place.tag == activity.tag)
+                                .or_else(|| places.first())
+                                .cloned()
+                                .into_iter()
+                                .collect();
+
+                            Some(VehicleBreak::Optional { time: get_fixed_time(activity.time), places, policy })
+                        }
+                        None if progress.is_finished => None,
+                        None => Some(VehicleBreak::Optional { time, places, policy }),
+                    },
+                })
+                .collect::<Vec<_>>()
+        })
+        .filter(|breaks| !breaks.is_empty());
+
+    // NOTE taken reloads go first as relation refers to reloads by their order
+    let mut locked_reloads = Vec::<bool>::new();
+    shift.reloads = shift
+        .reloads
+        .take()
+        .map(|mut reloads| {
+            let taken = progress
+                .visited
+                .iter()
+                .filter(|activity| activity.activity_type == "reload")
+                .filter_map(|activity| {
+                    let idx = reloads.iter().position(|reload| {
+                        reload.location == activity.location
+                            && activity.tag.as_ref().map_or(true, |tag| reload.tag.as_ref() == Some(tag))
+                    });
+                    locked_reloads.push(idx.is_some());
+
+                    let time = format_time(activity.time);
+                    idx.map(|idx| VehicleReload {
+                        times: Some(vec![vec![time.clone(), time]]),
+                        dock: None,
+                        ..reloads.remove(idx)
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let remaining = if progress.is_finished { vec![] } else { reloads };
+
+            taken.into_iter().chain(remaining).collect::<Vec<_>>()
+        })
+        .filter(|reloads| !reloads.is_empty());
+
+    let position_job = if progress.is_finished { None } else { Some(create_position_job(&shift, progress)) };
+
+    let mut locked_breaks = locked_breaks.into_iter();
+    let mut locked_reloads = locked_reloads.into_iter();
+    let visited = progress.visited.iter().filter_map(|activity| match activity.activity_type.as_str() {
+        "break" => locked_breaks.next().unwrap_or(false).then(|| "break".to_string()),
+        "reload" => locked_reloads.next().unwrap_or(false).then(|| "reload".to_string()),
+        _ => done.contains(&activity.job_id).then(|| activity.job_id.clone()),
+    });
+    let last = position_job.as_ref().map_or_else(|| "arrival".to_string(), |job| job.id.clone());
+    let planned = progress.planned.iter().filter(|activity| done.contains(&activity.job_id));
+
+    let relation = Relation {
+        type_field: RelationType::Strict,
+        jobs: std::iter::once("departure".to_string())
+            .chain(visited)
+            .chain(std::iter::once(last))
+            .chain(planned.map(|activity| activity.job_id.clone()))
+            .collect(),
+        vehicle_id: Some(progress.vehicle_id.clone()),
+        shift_index: Some(progress.shift_index),
+        offset: None,
+    };
+
+    (shift, relation, position_job)
+}
+
+/// Creates a job which keeps location and time where and when the vehicle can continue its tour.
+fn create_position_job(shift: &VehicleShift, progress: &TourProgress) -> Job {
+    let (location, time) = &progress.start;
+    // NOTE time window of locked job has to be closed, so the one of open shift is limited by one day
+    let end = shift.end.as_ref().map_or(time + 86400., |end| parse_time(&end.latest)).max(*time);
+
+    Job {
+        id: format!("{}_position_{}", progress.vehicle_id, progress.shift_index),
+        pickups: None,
+        deliveries: None,
+        replacements: None,
+        services: Some(vec![JobTask {
+            places: vec![JobPlace {
+                location: location.clone(),
+                duration: 0.,
+                setup: None,
+                duration_rules: None,
+                times: Some(vec![vec![format_time(*time), format_time(end)]]),
+                tag: None,
+                tolerance: None,
+                dock: None,
+            }],
+            demand: None,
+            order: None,
+            splittable: None,
+            min_split: None,
+            max_time_in_vehicle: None,
+        }]),
+        skills: None,
+        value: None,
+        group: None,
+        compatibility: None,
+        max_ride_duration: None,
+        max_ride_factor: None,
+        product: None,
+        periodic: None,
+        alternatives: None,
+        truck_only: None,
+        transfers: None,
+        satellite_id: None,
+    }
+}
+
+/// Creates a job which tasks are fixed at places and times of given served activities, remaining tasks
+/// are kept only at places of planned activities. Returns `None` when activities do not cover all job
+/// tasks or they cannot be locked in the order of visiting.
+fn create_done_job(job: &Job, served: &[&TourActivity], planned: &[&TourActivity]) -> Option<Job> {
+    let activities =
+        served.iter().map(|activity| (*activity, true)).chain(planned.iter().map(|activity| (*activity, false)));
+
+    // NOTE locked multi job activities follow the order of its tasks: pickups, deliveries, replacements, services
+    let ranks = activities
+        .clone()
+        .map(|(activity, _)| {
+            ["pickup", "delivery", "replacement", "service"].iter().position(|t| *t == activity.activity_type)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    if ranks.len() != get_tasks(job).count() || ranks.windows(2).any(|pair| pair[0] > pair[1]) {
+        return None;
+    }
+
+    let fix_tasks = |tasks: &Option<Vec<JobTask>>, activity_type: &str| -> Option<Option<Vec<JobTask>>> {
+        let mut tasks = tasks.clone().unwrap_or_default();
+        let fixed = activities
+            .clone()
+            .filter(|(activity, _)| activity.activity_type == activity_type)
+            .map(|(activity, is_served)| {
+                let is_visited = |place: &JobPlace| is_same_place(place, &activity.location, activity.tag.as_ref());
+                let idx = tasks.iter().position(|task| task.places.iter().any(is_visited))?;
+                let task = tasks.remove(idx);
+                let place = task.places.iter().find(|place| is_visited(place)).cloned()?;
+
+                let place = if is_served {
+                    let time = format_time(activity.time);
+                    JobPlace { times: Some(vec![vec![time.clone(), time]]), tolerance: None, dock: None, ..place }
+                } else {
+                    // NOTE locked task can have only one time window, so the one used by the plan is kept
+                    let times = place.times.clone().map(|times| {
+                        let idx = times.iter().position(|time| {
+                            time.first().zip(time.last()).map_or(false, |(start, end)| {
+                                parse_time(start) <= activity.time && activity.time <= parse_time(end)
+                            })
+                        });
+                        times.into_iter().nth(idx.unwrap_or_default()).into_iter().collect()
+                    });
+                    JobPlace { times, ..place }
+                };
+
+                Some(JobTask { places: vec![place], ..task })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Some(fixed).filter(|fixed| !fixed.is_empty()))
+    };
+
+    Some(Job {
+        pickups: fix_tasks(&job.pickups, "pickup")?,
+        deliveries: fix_tasks(&job.deliveries, "delivery")?,
+        replacements: fix_tasks(&job.replacements, "replacement")?,
+        services: fix_tasks(&job.services, "service")?,
+        transfers: None,
+        ..job.clone()
+    })
+}
+
+fn get_fixed_time(time: Timestamp) -> VehicleOptionalBreakTime {
+    let time = format_time(time);
+    VehicleOptionalBreakTime::TimeWindow(vec![time.clone(), time])
+}
+
+fn create_future_shift(shift: &VehicleShift, now: Timestamp) -> VehicleShift {
+    let mut shift = shift.clone();
+
+    if parse_time(&shift.start.earliest) < now {
+        shift.start.earliest = format_time(now);
+    }
+
+    shift
+}
+
+fn is_shift_expired(shift: &VehicleShift, now: Timestamp) -> bool {
+    shift.start.latest.as_ref().map_or(false, |latest| parse_time(latest) < now)
+        || shift.end.as_ref().map_or(false, |end| parse_time(&end.latest) < now)
+}
+
+fn remove_served_task(job: &mut Job, activity_type: &str, location: &Location, tag: Option<&String>) {
+    let tasks = match activity_type {
+        "pickup" => job.pickups.as_mut(),
+        "delivery" => job.deliveries.as_mut(),
+        "replacement" => job.replacements.as_mut(),
+        "service" => job.services.as_mut(),
+        _ => None,
+    };
+
+    if let Some(tasks) = tasks {
+        if let Some(idx) =
+            tasks.iter().position(|task| task.places.iter().any(|place| is_same_place(place, location, tag)))
+        {
+            tasks.remove(idx);
+        }
+    }
+
+    let filter_empty = |tasks: &mut Option<Vec<JobTask>>| {
+        if tasks.as_ref().map_or(false, |tasks| tasks.is_empty()) {
+            *tasks = None;
+        }
+    };
+
+    filter_empty(&mut job.pickups);
+    filter_empty(&mut job.deliveries);
+    filter_empty(&mut job.replacements);
+    filter_empty(&mut job.services);
+}
+
+fn is_same_place(place: &JobPlace, location: &Location, tag: Option<&String>) -> bool {
+    place.location == *location && tag.map_or(true, |tag| place.tag.as_ref() == Some(tag))
+}
+
+fn get_tasks(job: &Job) -> impl Iterator<Item = &JobTask> {
+    [&job.pickups, &job.deliveries, &job.replacements, &job.services]
+        .into_iter()
+        .flat_map(|tasks| tasks.iter().flat_map(|tasks| tasks.iter()))
+}
+
+fn has_activity_type(stop: &Stop, activity_type: &str) -> bool {
+    stop.activities().iter().any(|activity| activity.activity_type == activity_type)
+}
+
+fn is_job_activity(activity_type: &str) -> bool {
+    matches!(activity_type, "pickup" | "delivery" | "replacement" | "service")
+}
+
+fn is_reserved_job_id(job_id: &str) -> bool {
+    matches!(job_id, "departure" | "arrival" | "break" | "reload")
+}
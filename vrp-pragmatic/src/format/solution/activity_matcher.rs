@@ -3,14 +3,14 @@ use crate::format::problem::VehicleBreak;
 use crate::format::problem::{Problem as FormatProblem, VehicleRequiredBreakTime};
 use crate::format::solution::{Activity as FormatActivity, Schedule as FormatSchedule, Tour as FormatTour};
 use crate::format::solution::{PointStop, TransitStop};
-use crate::format::{CoordIndex, JobIndex};
+use crate::format::{get_transfer_job_id, get_variant_job_id, CoordIndex, JobIndex};
 use crate::parse_time;
 use hashbrown::HashSet;
 use std::cmp::Ordering;
 use std::iter::once;
 use std::sync::Arc;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Job, Single};
+use vrp_core::models::problem::{Job, JobAlternativeDimension, Single};
 use vrp_core::models::solution::{Activity, Place};
 use vrp_core::prelude::*;
 
@@ -42,32 +42,56 @@ pub(crate) fn try_match_point_job(
         "pickup" | "delivery" | "replacement" | "service" => {
             let job =
                 job_index.get(&activity.job_id).ok_or_else(|| format!("unknown job id: '{}'", activity.job_id))?;
-            let singles: Box<dyn Iterator<Item = &Arc<_>>> = match job {
-                Job::Single(single) => Box::new(once(single)),
-                Job::Multi(multi) => {
-                    let tags = multi
-                        .jobs
-                        .iter()
-                        .filter_map(|single| single.dimens.get_place_tags())
-                        .flat_map(|tags| tags.iter().map(|(_, tag)| tag))
-                        .collect::<HashSet<_>>();
-                    if tags.len() < multi.jobs.len() {
-                        return Err(format!(
-                            "cannot check multi job without unique tags, check '{}' job",
-                            activity.job_id
-                        )
-                        .into());
-                    }
 
-                    Box::new(multi.jobs.iter())
+            // NOTE job with alternatives is matched to the first variant which has the activity place,
+            // variants which can serve all job activities in the tour are preferred
+            let jobs = match job.dimens().get_job_alternative() {
+                Some(alternative) => {
+                    let mut jobs = (0..alternative.total)
+                        .flat_map(|index| {
+                            once(get_variant_job_id(&activity.job_id, index))
+                                .chain((0..2).map(move |leg| get_transfer_job_id(&activity.job_id, index, leg)))
+                        })
+                        .filter_map(|job_id| job_index.get(&job_id))
+                        .collect::<Vec<_>>();
+                    jobs.sort_by_key(|job| !can_serve_tour_activities(job, tour, &activity.job_id, coord_index));
+
+                    jobs
                 }
+                None => vec![job],
             };
-            let (single, place) = singles
-                .filter_map(|single| match_place(single, true, &ctx).map(|place| (single, place)))
-                .next()
-                .ok_or_else(|| format!("cannot match job '{}'", activity.job_id))?;
 
-            Ok(Some(JobInfo(job.clone(), single.clone(), place, ctx.time)))
+            jobs.into_iter()
+                .map(|job| {
+                    let singles: Box<dyn Iterator<Item = &Arc<_>>> = match job {
+                        Job::Single(single) => Box::new(once(single)),
+                        Job::Multi(multi) => {
+                            let tags = multi
+                                .jobs
+                                .iter()
+                                .filter_map(|single| single.dimens.get_place_tags())
+                                .flat_map(|tags| tags.iter().map(|(_, tag)| tag))
+                                .collect::<HashSet<_>>();
+                            if tags.len() < multi.jobs.len() {
+                                return Err(GenericError::from(format!(
+                                    "cannot check multi job without unique tags, check '{}' job",
+                                    activity.job_id
+                                )));
+                            }
+
+                            Box::new(multi.jobs.iter())
+                        }
+                    };
+
+                    Ok(singles
+                        .filter_map(|single| match_place(single, true, &ctx).map(|place| (single, place)))
+                        .next()
+                        .map(|(single, place)| JobInfo(job.clone(), single.clone(), place, ctx.time.clone())))
+                })
+                .find_map(|result| result.transpose())
+                .transpose()?
+                .ok_or_else(|| format!("cannot match job '{}'", activity.job_id).into())
+                .map(Some)
         }
         "break" | "reload" | "recharge" => Ok(Some(
             (1..)
@@ -81,6 +105,25 @@ pub(crate) fn try_match_point_job(
                 .next()
                 .ok_or_else(|| format!("cannot match '{}' for '{}'", ctx.act_type, tour.vehicle_id))?,
         )),
+        "decouple" | "couple" => Ok(Some(
+            (1..)
+                .map(|idx| format!("{}_trailer_{}_{}", tour.vehicle_id, tour.shift_index, idx))
+                .map(|job_id| job_index.get(&job_id))
+                .take_while(|job| job.is_some())
+                .filter_map(|job| job.and_then(|job| job.as_multi().map(|multi| (job.clone(), multi.clone()))))
+                .filter_map(|(job, multi)| {
+                    multi
+                        .jobs
+                        .iter()
+                        .find(|single| single.dimens.get_job_type() == Some(&activity.activity_type))
+                        .and_then(|single| {
+                            match_place(single, false, &ctx)
+                                .map(|place| JobInfo(job, single.clone(), place, ctx.time.clone()))
+                        })
+                })
+                .next()
+                .ok_or_else(|| format!("cannot match '{}' for '{}'", ctx.act_type, tour.vehicle_id))?,
+        )),
         _ => Err(format!("unknown activity type: {}", activity.activity_type).into()),
     }
 }
@@ -119,7 +162,7 @@ pub(crate) fn try_match_break_activity(
             VehicleBreak::Required { time: VehicleRequiredBreakTime::OffsetTime { earliest, latest }, duration } => {
                 Some(TimeWindow::new(route_start_time + *earliest, route_start_time + *latest + *duration))
             }
-            VehicleBreak::Optional { .. } => None,
+            VehicleBreak::Optional { .. } | VehicleBreak::Driving { .. } => None,
         })
         .find(|time| activity_time.intersects(time))
         .ok_or_else(|| "cannot match activity to required break".into())
@@ -148,18 +191,21 @@ fn match_place(single: &Arc<Single>, is_job_activity: bool, activity_ctx: &Activ
 
     match (is_same_tags, is_same_ids, is_job_activity) {
         (true, false, true) => None,
-        (true, true, _) | (true, false, false) => single
-            .places
-            .iter()
-            .enumerate()
-            .find(|(_, place)| {
+        (true, true, _) | (true, false, false) => {
+            let mut places = single.places.iter().enumerate().filter(|(_, place)| {
                 let is_same_location = place.location.map_or(true, |l| l == activity_ctx.location);
                 let is_proper_time =
                     place.times.iter().any(|time| time.intersects(activity_ctx.route_start_time, &activity_ctx.time));
 
                 is_same_location && is_proper_time
-            })
-            .map(|(idx, place)| {
+            });
+
+            // NOTE prefer place with the same duration as activity has, e.g. a part of split break
+            let duration = activity_ctx.time.duration();
+            let same_duration_place =
+                places.clone().find(|(_, place)| compare_floats(place.duration, duration) == Ordering::Equal);
+
+            same_duration_place.or_else(|| places.next()).map(|(idx, place)| {
                 // NOTE search for the latest occurrence assuming that times are sorted
                 let time = place
                     .times
@@ -175,7 +221,8 @@ fn match_place(single: &Arc<Single>, is_job_activity: bool, activity_ctx: &Activ
                 };
 
                 Place { idx, location: activity_ctx.location, duration: place.duration, time }
-            }),
+            })
+        }
         _ => None,
     }
 }
@@ -223,6 +270,28 @@ pub(super) fn get_job_tag(single: &Single, place: (Location, (TimeWindow, Timest
     })
 }
 
+/// Checks whether all activities of the job with given id in the tour can be served by the job.
+fn can_serve_tour_activities(job: &Job, tour: &FormatTour, job_id: &str, coord_index: &CoordIndex) -> bool {
+    let singles = match job {
+        Job::Single(single) => vec![single],
+        Job::Multi(multi) => multi.jobs.iter().collect(),
+    };
+
+    tour.stops
+        .iter()
+        .filter_map(|stop| stop.as_point())
+        .flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| activity.job_id == job_id)
+        .all(|(stop, activity)| {
+            let location = coord_index.get_by_loc(activity.location.as_ref().unwrap_or(&stop.location));
+
+            singles.iter().any(|single| {
+                single.dimens.get_job_type() == Some(&activity.activity_type)
+                    && single.places.iter().any(|place| place.location.is_some() && place.location == location)
+            })
+        })
+}
+
 fn get_job_id(single: &Arc<Single>) -> String {
     Activity {
         place: Place { idx: 0, location: 0, duration: 0.0, time: TimeWindow::new(0., 0.) },
@@ -137,6 +137,12 @@ fn insert_break(
             time: Some(Interval { start: format_time(activity_time.start), end: format_time(activity_time.end) }),
             job_tag: None,
             commute: None,
+            earliness: None,
+            lateness: None,
+            ride_duration: None,
+            time_in_vehicle: None,
+            quantity: None,
+            queueing: None,
         },
     );
 
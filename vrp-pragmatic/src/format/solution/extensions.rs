@@ -1,4 +1,4 @@
-use crate::format::solution::{Statistic, Timing};
+use crate::format::solution::{Statistic, TierCost, Timing};
 use std::ops::Add;
 
 impl Add for Statistic {
@@ -17,6 +17,20 @@ impl Add for Statistic {
                 commuting: self.times.commuting + rhs.times.commuting,
                 parking: self.times.parking + rhs.times.parking,
             },
+            fees: match (self.fees, rhs.fees) {
+                (Some(left), Some(right)) => Some(left + right),
+                (fees, None) | (None, fees) => fees,
+            },
+            load_cost: match (self.load_cost, rhs.load_cost) {
+                (Some(left), Some(right)) => Some(left + right),
+                (load_cost, None) | (None, load_cost) => load_cost,
+            },
+            tier_cost: match (self.tier_cost, rhs.tier_cost) {
+                (Some(left), Some(right)) => {
+                    Some(TierCost { time: left.time + right.time, distance: left.distance + right.distance })
+                }
+                (tier_cost, None) | (None, tier_cost) => tier_cost,
+            },
         }
     }
 }
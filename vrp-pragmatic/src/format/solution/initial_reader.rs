@@ -2,25 +2,25 @@
 #[path = "../../../tests/unit/format/solution/initial_reader_test.rs"]
 mod initial_reader_test;
 
-use crate::construction::enablers::{JobTie, VehicleTie};
+use crate::construction::enablers::{DriverTie, JobTie, VehicleTie};
 use crate::format::solution::activity_matcher::{try_match_point_job, JobInfo};
 use crate::format::solution::Activity as FormatActivity;
 use crate::format::solution::Stop as FormatStop;
 use crate::format::solution::Tour as FormatTour;
 use crate::format::solution::{deserialize_solution, map_reason_code};
-use crate::format::{get_coord_index, get_job_index, CoordIndex, JobIndex};
+use crate::format::{get_coord_index, get_job_index, get_jobs_by_id, CoordIndex, JobIndex};
 use crate::parse_time;
 use hashbrown::{HashMap, HashSet};
 use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Actor, Job};
+use vrp_core::models::problem::{Actor, Job, PartialJobDimension};
 use vrp_core::models::solution::Tour as CoreTour;
 use vrp_core::models::solution::{Activity, Registry, Route};
 use vrp_core::prelude::*;
 
-type ActorKey = (String, String, usize);
+type ActorKey = (String, String, usize, Option<String>);
 
 /// Reads initial solution from buffer.
 /// NOTE: Solution feasibility is not checked.
@@ -40,7 +40,7 @@ pub fn read_init_solution<R: Read>(
 
     let routes =
         solution.tours.iter().try_fold::<_, _, Result<_, GenericError>>(Vec::<_>::default(), |mut routes, tour| {
-            let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index);
+            let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index, tour.driver_id.clone());
             let actor =
                 actor_index.get(&actor_key).ok_or_else(|| format!("cannot find vehicle for {actor_key:?}"))?.clone();
             registry.use_actor(&actor);
@@ -63,10 +63,10 @@ pub fn read_init_solution<R: Read>(
         .unwrap_or_default()
         .iter()
         .try_fold::<Vec<_>, _, Result<_, GenericError>>(Default::default(), |mut acc, unassigned_job| {
-            let job = job_index
-                .get(&unassigned_job.job_id)
-                .cloned()
-                .ok_or_else(|| format!("cannot get job id for: {unassigned_job:?}"))?;
+            let jobs = get_jobs_by_id(job_index, &unassigned_job.job_id);
+            if jobs.is_empty() {
+                return Err(format!("cannot get job id for: {unassigned_job:?}").into());
+            }
             // NOTE we take the first reason only and map it to simple variant
             let code = unassigned_job
                 .reasons
@@ -74,8 +74,9 @@ pub fn read_init_solution<R: Read>(
                 .map(|reason| UnassignmentInfo::Simple(map_reason_code(&reason.code)))
                 .ok_or_else(|| format!("cannot get reason for: {unassigned_job:?}"))?;
 
-            added_jobs.insert(job.clone());
-            acc.push((job, code));
+            jobs.into_iter().filter(|job| added_jobs.insert(job.clone())).for_each(|job| {
+                acc.push((job, code.clone()));
+            });
 
             Ok(acc)
         })?;
@@ -107,6 +108,13 @@ fn try_insert_activity(
 
     if let Some(JobInfo(job, single, place, time)) = try_match_point_job(tour, stop, activity, job_index, coord_index)?
     {
+        // NOTE each visit of the splittable or periodic job is matched to the next unused part
+        let (job, single) = get_partial_jobs(&job, job_index)
+            .into_iter()
+            .find(|job| !added_jobs.contains(job))
+            .and_then(|job| job.as_single().cloned().map(|single| (job, single)))
+            .unwrap_or((job, single));
+
         let is_inserted = added_jobs.insert(job.clone());
         if !is_inserted && matches!(job, Job::Single(_)) {
             return Err(format!(
@@ -132,14 +140,27 @@ fn try_insert_activity(
     Ok(())
 }
 
+/// Returns all parts of the splittable job, all visits of the periodic job or the job itself.
+fn get_partial_jobs(job: &Job, job_index: &JobIndex) -> Vec<Job> {
+    let dimens = job.dimens();
+
+    match dimens.get_job_id() {
+        Some(job_id) if dimens.get_partial_job().is_some() || dimens.get_periodic_visit().is_some() => {
+            get_jobs_by_id(job_index, job_id)
+        }
+        _ => vec![job.clone()],
+    }
+}
+
 fn get_actor_key(actor: &Actor) -> ActorKey {
     let dimens = &actor.vehicle.dimens;
 
     let vehicle_id = dimens.get_vehicle_id().cloned().expect("cannot get vehicle id!");
     let type_id = dimens.get_vehicle_type().cloned().expect("cannot get type id!");
     let shift_index = dimens.get_shift_index().expect("cannot get shift index!");
+    let driver_id = actor.driver.dimens.get_driver_id().cloned();
 
-    (vehicle_id, type_id, shift_index)
+    (vehicle_id, type_id, shift_index, driver_id)
 }
 
 fn create_core_route(actor: Arc<Actor>, format_tour: &FormatTour) -> Result<Route, GenericError> {
@@ -101,6 +101,31 @@ fn map_code_reason(code: i32) -> (&'static str, &'static str) {
             ("RELOAD_RESOURCE_CONSTRAINT", "cannot be assigned due to reload resource constraint")
         }
         RECHARGE_CONSTRAINT_CODE => ("RECHARGE_CONSTRAINT_CODE", "cannot be assigned due to recharge constraint"),
+        RIDE_DURATION_CONSTRAINT_CODE => {
+            ("RIDE_DURATION_CONSTRAINT", "cannot be assigned due to max ride duration constraint of job")
+        }
+        COMPARTMENT_CONSTRAINT_CODE => {
+            ("COMPARTMENT_CONSTRAINT", "cannot be assigned due to vehicle compartments constraint")
+        }
+        SCHEDULE_RELATION_CONSTRAINT_CODE => {
+            ("SCHEDULE_RELATION_CONSTRAINT", "cannot be assigned due to sync or precedence relation constraint")
+        }
+        PERIODIC_CONSTRAINT_CODE => ("PERIODIC_CONSTRAINT", "cannot be assigned due to periodic visits constraint"),
+        DOCK_SLOTS_CONSTRAINT_CODE => ("DOCK_SLOTS_CONSTRAINT", "cannot be assigned due to dock slots constraint"),
+        ZONE_CONSTRAINT_CODE => ("ZONE_CONSTRAINT", "cannot be assigned due to zone access restriction"),
+        DRIVING_BREAK_CONSTRAINT_CODE => ("DRIVING_TIME_CONSTRAINT", "cannot be assigned due to driving time limit"),
+        JOB_ALTERNATIVES_CONSTRAINT_CODE => {
+            ("JOB_ALTERNATIVES_CONSTRAINT", "cannot be assigned as another job variant is already served")
+        }
+        TRIP_LIMIT_CONSTRAINT_CODE => ("TRIP_LIMIT_CONSTRAINT", "cannot be assigned due to trip limits of vehicle"),
+        TIME_IN_VEHICLE_CONSTRAINT_CODE => {
+            ("TIME_IN_VEHICLE_CONSTRAINT", "cannot be assigned due to max time in vehicle constraint")
+        }
+        TRAILER_CONSTRAINT_CODE => ("TRAILER_CONSTRAINT", "cannot be assigned due to trailer constraint"),
+        SATELLITE_CONSTRAINT_CODE => ("SATELLITE_CONSTRAINT", "cannot be assigned due to satellite constraint"),
+        SPLIT_JOB_CONSTRAINT_CODE => {
+            ("SPLIT_JOB_CONSTRAINT", "cannot be assigned as demand of splittable job cannot be served completely")
+        }
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -122,6 +147,19 @@ fn map_reason_code(reason: &str) -> i32 {
         "COMPATIBILITY_CONSTRAINT" => COMPATIBILITY_CONSTRAINT_CODE,
         "RELOAD_RESOURCE_CONSTRAINT" => RELOAD_RESOURCE_CONSTRAINT_CODE,
         "RECHARGE_CONSTRAINT_CODE" => RECHARGE_CONSTRAINT_CODE,
+        "RIDE_DURATION_CONSTRAINT" => RIDE_DURATION_CONSTRAINT_CODE,
+        "COMPARTMENT_CONSTRAINT" => COMPARTMENT_CONSTRAINT_CODE,
+        "SCHEDULE_RELATION_CONSTRAINT" => SCHEDULE_RELATION_CONSTRAINT_CODE,
+        "PERIODIC_CONSTRAINT" => PERIODIC_CONSTRAINT_CODE,
+        "DOCK_SLOTS_CONSTRAINT" => DOCK_SLOTS_CONSTRAINT_CODE,
+        "ZONE_CONSTRAINT" => ZONE_CONSTRAINT_CODE,
+        "DRIVING_TIME_CONSTRAINT" => DRIVING_BREAK_CONSTRAINT_CODE,
+        "JOB_ALTERNATIVES_CONSTRAINT" => JOB_ALTERNATIVES_CONSTRAINT_CODE,
+        "TRIP_LIMIT_CONSTRAINT" => TRIP_LIMIT_CONSTRAINT_CODE,
+        "TIME_IN_VEHICLE_CONSTRAINT" => TIME_IN_VEHICLE_CONSTRAINT_CODE,
+        "TRAILER_CONSTRAINT" => TRAILER_CONSTRAINT_CODE,
+        "SATELLITE_CONSTRAINT" => SATELLITE_CONSTRAINT_CODE,
+        "SPLIT_JOB_CONSTRAINT" => SPLIT_JOB_CONSTRAINT_CODE,
         _ => -1,
     }
 }
@@ -29,6 +29,7 @@ pub struct Timing {
 
 /// Represents statistic.
 #[derive(Clone, Deserialize, Default, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Statistic {
     /// Total cost.
     pub cost: f64,
@@ -38,6 +39,24 @@ pub struct Statistic {
     pub duration: i64,
     /// Timing statistic.
     pub times: Timing,
+    /// Total zone entry fees. They are included into total cost.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fees: Option<f64>,
+    /// Total extra distance cost caused by vehicle load. It is included into total cost.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub load_cost: Option<f64>,
+    /// Total extra costs caused by vehicle cost tiers. They are included into total cost.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier_cost: Option<TierCost>,
+}
+
+/// Represents extra costs caused by vehicle cost tiers.
+#[derive(Clone, Deserialize, Default, Serialize, PartialEq, Debug)]
+pub struct TierCost {
+    /// Extra time cost.
+    pub time: f64,
+    /// Extra distance cost.
+    pub distance: f64,
 }
 
 /// Represents a schedule.
@@ -101,6 +120,25 @@ pub struct Activity {
     /// Commute information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commute: Option<Commute>,
+    /// How early (in seconds) activity has started before job place's time window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earliness: Option<i64>,
+    /// How late (in seconds) activity has started after job place's time window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness: Option<i64>,
+    /// Actual ride duration (in seconds) of the job with max ride limit. Set only on its last activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ride_duration: Option<i64>,
+    /// Actual time in vehicle (in seconds) of the delivery with max time in vehicle limit: a duration
+    /// between departure from the trip start (shift start or reload) and service start.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_vehicle: Option<i64>,
+    /// A quantity served by the activity of the splittable job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<Vec<i32>>,
+    /// How long (in seconds) vehicle has waited in queue for a free dock slot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queueing: Option<i64>,
 }
 
 /// A stop is a place where vehicle is supposed to do some work.
@@ -200,6 +238,7 @@ pub struct TransitStop {
 
 /// A point stop is a stop where vehicle is supposed to be parked and do some work.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct PointStop {
     /// Stop location. When omitted vehicle can stop anywhere.
     pub location: Location,
@@ -209,13 +248,36 @@ pub struct PointStop {
     pub distance: i64,
     /// Vehicle load after departure from this stop.
     pub load: Vec<i32>,
+    /// Vehicle compartments usage after departure from this stop.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compartments: Option<Vec<CompartmentLoad>>,
+    /// Vehicle battery state of charge after departure from this stop.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_of_charge: Option<f64>,
     /// Parking time.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parking: Option<Interval>,
+    /// Setup time: incurred once on arrival to the stop before serving its first job activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup: Option<Interval>,
     /// Activities performed at the stop.
     pub activities: Vec<Activity>,
 }
 
+/// Specifies usage of a vehicle compartment.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq, Debug)]
+pub struct CompartmentLoad {
+    /// Compartment id.
+    pub id: String,
+    /// A product type assigned to the compartment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<String>,
+    /// Compartment load.
+    pub load: i32,
+}
+
 /// A tour is list of stops with their activities performed by specific vehicle.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -227,6 +289,10 @@ pub struct Tour {
     /// Shift index.
     #[serde(default)]
     pub shift_index: usize,
+    /// Driver id. Omitted when fleet has no drivers defined.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver_id: Option<String>,
     /// List of stops.
     pub stops: Vec<Stop>,
     /// Tour statistic.
@@ -265,6 +331,54 @@ pub struct UnassignedJob {
     pub reasons: Vec<UnassignedJobReason>,
 }
 
+/// Specifies how demand of the splittable job is served.
+#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitJob {
+    /// Job id.
+    pub job_id: String,
+    /// Total served quantity.
+    pub served: Vec<i32>,
+    /// Total quantity which is not served.
+    pub unserved: Vec<i32>,
+    /// Visits which serve the job.
+    pub visits: Vec<SplitJobVisit>,
+}
+
+/// Specifies a visit which serves a part of the splittable job.
+#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitJobVisit {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// Served quantity.
+    pub quantity: Vec<i32>,
+}
+
+/// Specifies tours which are served on the same day of the planning horizon.
+#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionDay {
+    /// A zero-based day index.
+    pub day: usize,
+    /// Tours served on the day.
+    pub tours: Vec<DayTour>,
+}
+
+/// Specifies a tour served on the given day.
+#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DayTour {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// Ids of jobs served by the tour.
+    pub job_ids: Vec<String>,
+}
+
 /// Specifies a type of violation.
 #[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -355,6 +469,14 @@ pub struct Solution {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub unassigned: Option<Vec<UnassignedJob>>,
 
+    /// List of splittable jobs which demand is served by multiple visits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_jobs: Option<Vec<SplitJob>>,
+
+    /// List of tours grouped by day of the planning horizon. Set only for problems with periodic jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days: Option<Vec<SolutionDay>>,
+
     /// List of constraint violations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub violations: Option<Vec<Violation>>,
@@ -2,16 +2,22 @@
 #[path = "../../../tests/unit/format/solution/writer_test.rs"]
 mod writer_test;
 
-use crate::construction::enablers::{JobTie, VehicleTie};
+use crate::construction::enablers::{get_compartment_accessor, DriverTie, JobTie, VehicleTie};
+use crate::construction::features::{get_activity_zones, get_charged_soc};
 use crate::format::coord_index::CoordIndex;
 use crate::format::solution::activity_matcher::get_job_tag;
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
 use crate::format::*;
+use hashbrown::{HashMap, HashSet};
 use vrp_core::construction::enablers::{get_route_intervals, ReservedTimesIndex};
+use vrp_core::construction::features::{get_compartment_usage, get_ride_duration, get_time_in_vehicle};
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Multi, TravelTime};
+use vrp_core::models::problem::{
+    get_original_time_window, get_service_duration, get_setup_duration, Job, Multi, PartialJobDimension, Single,
+    TimeWindowToleranceDimension, TravelTime, Vehicle,
+};
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::rosomaxa::evolution::TelemetryMetrics;
 use vrp_core::solver::processing::{ReservedTimeDimension, VicinityDimension};
@@ -20,16 +26,22 @@ use vrp_core::utils::CollectGroupBy;
 struct Leg {
     pub last_detail: Option<(DomainLocation, Timestamp)>,
     pub load: Option<MultiDimLoad>,
+    pub soc: Option<f64>,
     pub statistic: Statistic,
 }
 
 impl Leg {
-    fn new(last_detail: Option<(DomainLocation, Timestamp)>, load: Option<MultiDimLoad>, statistic: Statistic) -> Self {
-        Self { last_detail, load, statistic }
+    fn new(
+        last_detail: Option<(DomainLocation, Timestamp)>,
+        load: Option<MultiDimLoad>,
+        soc: Option<f64>,
+        statistic: Statistic,
+    ) -> Self {
+        Self { last_detail, load, soc, statistic }
     }
 
     fn empty() -> Self {
-        Self { last_detail: None, load: None, statistic: Statistic::default() }
+        Self { last_detail: None, load: None, soc: None, statistic: Statistic::default() }
     }
 }
 
@@ -52,9 +64,11 @@ pub(crate) fn create_solution(
     let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
 
     let unassigned = create_unassigned(solution);
+    let split_jobs = create_split_jobs(problem, solution);
+    let days = create_days(problem, &tours);
     let violations = create_violations(solution);
 
-    let api_solution = ApiSolution { statistic, tours, unassigned, violations, extras: None };
+    let api_solution = ApiSolution { statistic, tours, unassigned, split_jobs, days, violations, extras: None };
 
     let extras = create_extras(problem, &api_solution, solution.telemetry.as_ref(), output_type);
 
@@ -80,13 +94,19 @@ fn create_tour(
         vehicle_id: vehicle.dimens.get_vehicle_id().unwrap().clone(),
         type_id: vehicle.dimens.get_vehicle_type().unwrap().clone(),
         shift_index: vehicle.dimens.get_shift_index().unwrap(),
+        driver_id: actor.driver.dimens.get_driver_id().cloned(),
         stops: vec![],
         statistic: Statistic::default(),
     };
 
     let intervals = get_route_intervals(route, |a| get_activity_type(a).map_or(false, |t| t == "reload"));
+    let compartment_loads = get_compartment_loads(route);
+    let get_compartments =
+        |activity_idx: usize| compartment_loads.as_ref().and_then(|loads| loads.get(activity_idx).cloned());
+    let battery = vehicle.dimens.get_vehicle_battery();
 
-    let mut leg = intervals.into_iter().fold(Leg::empty(), |leg, (start_idx, end_idx)| {
+    let init = Leg { soc: battery.map(|battery| battery.initial), ..Leg::empty() };
+    let mut leg = intervals.into_iter().fold(init, |leg, (start_idx, end_idx)| {
         let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
             (leg.load.unwrap_or_default(), MultiDimLoad::default()),
             |acc, activity| {
@@ -108,6 +128,8 @@ fn create_tour(
                 location: coord_index.get_by_idx(start.place.location).unwrap(),
                 time: format_schedule(&start.schedule),
                 load: start_delivery.as_vec(),
+                compartments: get_compartments(0),
+                state_of_charge: leg.soc,
                 distance: 0,
                 activities: vec![ApiActivity {
                     job_id: "departure".to_string(),
@@ -123,18 +145,31 @@ fn create_tour(
                     },
                     job_tag: None,
                     commute: None,
+                    earliness: None,
+                    lateness: None,
+                    ride_duration: None,
+                    time_in_vehicle: None,
+                    quantity: None,
+                    queueing: None,
                 }],
                 parking: None,
+                setup: None,
             }));
             (start_idx + 1, start)
         } else {
             (start_idx, route.tour.get(start_idx - 1).unwrap())
         };
 
-        let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().fold(
-            Leg::new(Some((start.place.location, start.schedule.departure)), Some(start_delivery), leg.statistic),
-            |leg, act| {
-                let activity_type = get_activity_type(act).cloned();
+        let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().enumerate().fold(
+            Leg::new(
+                Some((start.place.location, start.schedule.departure)),
+                Some(start_delivery),
+                leg.soc,
+                leg.statistic,
+            ),
+            |leg, (idx, act)| {
+                let activity_idx = start_idx + idx;
+                let activity_type = get_activity_type(act).map(|activity_type| activity_type.to_string());
                 let (prev_location, prev_departure) = leg.last_detail.unwrap();
                 let prev_load = if activity_type.is_some() {
                     leg.load.unwrap()
@@ -171,7 +206,7 @@ fn create_tour(
                     (duration, transport_cost)
                 } else {
                     // NOTE: no need to drive in case of non-zero commute, this goes to commuting time
-                    (0., commuting * vehicle.costs.per_service_time)
+                    (0., commuting * (vehicle.costs.per_service_time + actor.driver.costs.per_service_time))
                 };
 
                 // NOTE two clusters at the same stop location
@@ -181,21 +216,42 @@ fn create_tour(
                         _ => 0.,
                     };
 
-                let activity_arrival = parking + act.schedule.arrival + commute.forward.duration;
+                let setup = get_setup_duration(act, prev_location);
+                let activity_arrival = parking + act.schedule.arrival + commute.forward.duration + setup;
                 let service_start = activity_arrival.max(act.place.time.start);
                 let waiting = service_start - activity_arrival;
-                let serving = act.place.duration - parking;
+                let serving = get_service_duration(route, act) - parking;
                 let service_end = service_start + serving;
                 let activity_departure = service_end;
 
+                let location_distance =
+                    transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure));
+                let load_ratio = get_load_ratio(vehicle, &leg.load.unwrap(), is_multi_dimen);
+                let load_cost = get_load_cost(vehicle, load_ratio, location_distance);
+                let soc = battery.zip(leg.soc).map(|(battery, soc)| {
+                    let arrival = soc - battery.get_consumption(location_distance, load_ratio);
+                    if activity_type == "recharge" {
+                        let power = act.job.as_ref().and_then(|single| single.dimens.get_recharge_power());
+                        get_charged_soc(act, battery, power, arrival)
+                    } else {
+                        arrival
+                    }
+                });
+                let location_distance = location_distance as i64;
+
                 // TODO: add better support of time based activity costs
-                let serving_cost = problem.activity.cost(route, act, service_start);
-                let total_cost = serving_cost + transport_cost + waiting * vehicle.costs.per_waiting_time;
+                let serving_cost = problem.activity.cost(route, act, service_start)
+                    + setup * (vehicle.costs.per_service_time + actor.driver.costs.per_service_time);
+                let waiting_cost = waiting * (vehicle.costs.per_waiting_time + actor.driver.costs.per_waiting_time);
+                let total_cost = serving_cost + transport_cost + waiting_cost + load_cost;
 
-                let location_distance =
-                    transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure))
-                        as i64;
                 let distance = leg.statistic.distance + location_distance - commute.forward.distance as i64;
+                let duration = leg.statistic.duration + act.schedule.departure as i64 - prev_departure as i64;
+
+                // NOTE tier costs depend on tour totals, so only their increment is attributed to activity
+                let tier_cost = get_tier_cost(vehicle, duration, distance);
+                let total_cost = total_cost + get_tier_cost_value(tier_cost.as_ref())
+                    - get_tier_cost_value(leg.statistic.tier_cost.as_ref());
 
                 let is_new_stop = match (act.commute.as_ref(), prev_location == act.place.location) {
                     (Some(commute), false) if commute.is_zero_distance() => true,
@@ -208,6 +264,8 @@ fn create_tour(
                         location: coord_index.get_by_idx(act.place.location).unwrap(),
                         time: format_schedule(&act.schedule),
                         load: prev_load.as_vec(),
+                        compartments: get_compartments(activity_idx - 1),
+                        state_of_charge: None,
                         distance,
                         parking: if parking > 0. {
                             Some(Interval {
@@ -217,12 +275,66 @@ fn create_tour(
                         } else {
                             None
                         },
+                        setup: if setup > 0. {
+                            Some(Interval {
+                                start: format_time(act.schedule.arrival + parking),
+                                end: format_time(act.schedule.arrival + parking + setup),
+                            })
+                        } else {
+                            None
+                        },
                         activities: vec![],
                     }));
                 }
 
                 let load = calculate_load(prev_load, act, is_multi_dimen);
 
+                let (earliness, lateness) = act
+                    .job
+                    .as_ref()
+                    .and_then(|single| single.dimens.get_time_window_tolerance(act.place.idx))
+                    .map(|tolerance| tolerance.get_deviation(&get_original_time_window(route, act), service_start))
+                    .map_or((None, None), |(earliness, lateness)| {
+                        let to_output = |value: f64| Some(value.round() as i64).filter(|value| *value > 0);
+                        (to_output(earliness), to_output(lateness))
+                    });
+
+                let ride_duration = act
+                    .job
+                    .as_ref()
+                    .and_then(|single| Multi::roots(single))
+                    .filter(|multi| multi.dimens.get_ride_duration_limit().is_some())
+                    .filter(|multi| {
+                        let last =
+                            route.tour.index_last(&Job::Multi(multi.clone())).and_then(|idx| route.tour.get(idx));
+                        last.map_or(false, |last| std::ptr::eq(last, act))
+                    })
+                    .and_then(|multi| get_ride_duration(route, &multi))
+                    .map(|duration| duration.round() as i64);
+
+                // NOTE time in vehicle is measured from departure of the trip start: shift start or reload
+                let time_in_vehicle =
+                    act.job
+                        .as_ref()
+                        .filter(|single| single.dimens.get_max_time_in_vehicle().is_some())
+                        .and_then(|_| {
+                            route.tour.all_activities().take(activity_idx).rev().find(|activity| {
+                                activity.job.is_none() || get_activity_type(activity) == Some("reload")
+                            })
+                        })
+                        .map(|trip_start| get_time_in_vehicle(route, trip_start, act).round() as i64);
+
+                let quantity = act.job.as_ref().and_then(|single| get_partial_quantity(single, is_multi_dimen));
+
+                let queueing = act
+                    .job
+                    .as_ref()
+                    .and_then(|single| single.dimens.get_place_docks())
+                    .filter(|docks| docks.iter().any(|(idx, _)| *idx == act.place.idx))
+                    .map(|_| service_start - activity_arrival.max(get_original_time_window(route, act).start))
+                    .map(|queueing| queueing.round() as i64)
+                    .filter(|queueing| *queueing > 0);
+
                 let last = tour.stops.len() - 1;
                 let last = match tour.stops.get_mut(last).unwrap() {
                     Stop::Point(point) => point,
@@ -231,6 +343,8 @@ fn create_tour(
 
                 last.time.departure = format_time(act.schedule.departure);
                 last.load = load.as_vec();
+                last.compartments = get_compartments(activity_idx);
+                last.state_of_charge = soc;
                 last.activities.push(ApiActivity {
                     job_id,
                     activity_type: activity_type.clone(),
@@ -244,6 +358,12 @@ fn create_tour(
                         .commute
                         .as_ref()
                         .map(|commute| Commute::new(commute, act.schedule.arrival, activity_departure, coord_index)),
+                    earliness,
+                    lateness,
+                    ride_duration,
+                    time_in_vehicle,
+                    quantity,
+                    queueing,
                 });
 
                 // NOTE detect when vehicle returns after activity to stop point
@@ -262,17 +382,25 @@ fn create_tour(
                     statistic: Statistic {
                         cost: leg.statistic.cost + total_cost,
                         distance,
-                        duration: leg.statistic.duration + act.schedule.departure as i64 - prev_departure as i64,
+                        duration,
                         times: Timing {
                             driving: leg.statistic.times.driving + driving as i64,
-                            serving: leg.statistic.times.serving + (if is_break { 0 } else { serving as i64 }),
+                            serving: leg.statistic.times.serving
+                                + (if is_break { 0 } else { (serving + setup) as i64 }),
                             waiting: leg.statistic.times.waiting + waiting as i64,
                             break_time: leg.statistic.times.break_time + (if is_break { serving as i64 } else { 0 }),
                             commuting: leg.statistic.times.commuting + commuting as i64,
                             parking: leg.statistic.times.parking + parking as i64,
                         },
+                        fees: None,
+                        load_cost: vehicle
+                            .costs
+                            .per_distance_loaded
+                            .map(|_| leg.statistic.load_cost.unwrap_or_default() + load_cost),
+                        tier_cost,
                     },
                     load: Some(load),
+                    soc,
                 }
             },
         );
@@ -282,7 +410,13 @@ fn create_tour(
         leg
     });
 
-    leg.statistic.cost += vehicle.costs.fixed;
+    leg.statistic.cost += vehicle.costs.fixed + actor.driver.costs.fixed;
+
+    let fees = get_zone_fees(route);
+    if fees > 0. {
+        leg.statistic.cost += fees;
+        leg.statistic.fees = Some(fees);
+    }
     tour.statistic = leg.statistic;
 
     insert_reserved_times_as_breaks(route, &mut tour, reserved_times_index);
@@ -319,6 +453,35 @@ fn format_schedule(schedule: &DomainSchedule) -> ApiSchedule {
     ApiSchedule { arrival: format_time(schedule.arrival), departure: format_time(schedule.departure) }
 }
 
+fn get_compartment_loads(route: &Route) -> Option<Vec<Vec<CompartmentLoad>>> {
+    let ids = route.actor.vehicle.dimens.get_vehicle_compartment_ids()?;
+    let usage = get_compartment_usage(route, &get_compartment_accessor())?;
+
+    let products = route
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.job.as_ref())
+        .filter_map(|single| single.dimens.get_product_demand().zip(single.dimens.get_job_product()))
+        .map(|(demand, product)| (demand.product, product.clone()))
+        .collect::<HashMap<_, _>>();
+
+    Some(
+        usage
+            .into_iter()
+            .map(|usage| {
+                ids.iter()
+                    .zip(usage)
+                    .map(|(id, (product, load))| CompartmentLoad {
+                        id: id.clone(),
+                        product: product.and_then(|product| products.get(&product).cloned()),
+                        load,
+                    })
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
 fn calculate_load(current: MultiDimLoad, act: &Activity, is_multi_dimen: bool) -> MultiDimLoad {
     let job = act.job.as_ref();
     let demand = job.and_then(|job| get_capacity(&job.dimens, is_multi_dimen)).unwrap_or_default();
@@ -331,10 +494,32 @@ fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
         vec![UnassignedJobReason { code: code.to_string(), description: reason.to_string(), details: None }]
     };
 
+    let mut job_ids = HashSet::new();
+
+    let periodic_visits = solution
+        .routes
+        .iter()
+        .flat_map(|route| route.tour.jobs())
+        .filter(|job| job.dimens().get_periodic_visit().is_some())
+        .filter_map(|job| job.dimens().get_job_id().cloned())
+        .fold(HashMap::<String, usize>::new(), |mut acc, job_id| {
+            *acc.entry(job_id).or_insert(0) += 1;
+            acc
+        });
+
     let unassigned = solution
         .unassigned
         .iter()
         .filter(|(job, _)| job.dimens().get_vehicle_id().is_none())
+        // NOTE optional visits of the periodic job are not reported
+        .filter(|(job, _)| {
+            let dimens = job.dimens();
+            dimens.get_periodic_visit().zip(dimens.get_job_id()).map_or(true, |(visit, job_id)| {
+                periodic_visits.get(job_id).copied().unwrap_or(0) < visit.policy.min_visits
+            })
+        })
+        // NOTE unassigned parts of the splittable job are reported once
+        .filter(|(job, _)| job.dimens().get_job_id().map_or(true, |job_id| job_ids.insert(job_id.clone())))
         .map(|(job, code)| {
             let job_id = job.dimens().get_job_id().expect("job id expected").clone();
 
@@ -384,6 +569,114 @@ fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
     }
 }
 
+fn create_split_jobs(problem: &DomainProblem, solution: &DomainSolution) -> Option<Vec<SplitJob>> {
+    let is_multi_dimen = has_multi_dim_demand(problem);
+    let get_part = |single: &Single| {
+        let job_id = single.dimens.get_job_id().cloned()?;
+        let quantity = get_partial_quantity(single, is_multi_dimen)?;
+
+        Some((job_id, MultiDimLoad::new(quantity)))
+    };
+
+    let mut split_jobs = solution
+        .routes
+        .iter()
+        .flat_map(|route| {
+            let dimens = &route.actor.vehicle.dimens;
+            let vehicle_id = dimens.get_vehicle_id().cloned().expect("vehicle id");
+            let shift_index = dimens.get_shift_index().expect("shift index");
+
+            route.tour.all_activities().filter_map(move |activity| {
+                let (job_id, quantity) = activity.job.as_ref().and_then(|single| get_part(single))?;
+                Some((job_id, Some((vehicle_id.clone(), shift_index)), quantity))
+            })
+        })
+        .chain(solution.unassigned.iter().filter_map(|(job, _)| {
+            let (job_id, quantity) = job.as_single().and_then(|single| get_part(single))?;
+            Some((job_id, None, quantity))
+        }))
+        .fold(HashMap::<String, SplitJob>::new(), |mut acc, (job_id, visit, quantity)| {
+            // NOTE use demand size of the part to have the same dimensions in served and unserved
+            let empty = vec![0; quantity.size.max(1)];
+            let split_job = acc.entry(job_id.clone()).or_insert_with(|| SplitJob {
+                job_id,
+                served: empty.clone(),
+                unserved: empty,
+                visits: vec![],
+            });
+
+            match visit {
+                Some((vehicle_id, shift_index)) => {
+                    split_job.served = (MultiDimLoad::new(split_job.served.clone()) + quantity).as_vec();
+                    split_job.visits.push(SplitJobVisit { vehicle_id, shift_index, quantity: quantity.as_vec() });
+                }
+                None => split_job.unserved = (MultiDimLoad::new(split_job.unserved.clone()) + quantity).as_vec(),
+            }
+
+            acc
+        })
+        .into_values()
+        .collect::<Vec<_>>();
+
+    // NOTE sort to have consistent order
+    split_jobs.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    if split_jobs.is_empty() {
+        None
+    } else {
+        Some(split_jobs)
+    }
+}
+
+fn create_days(problem: &DomainProblem, tours: &[Tour]) -> Option<Vec<SolutionDay>> {
+    let shift_days = problem
+        .fleet
+        .actors
+        .iter()
+        .filter_map(|actor| {
+            let dimens = &actor.vehicle.dimens;
+            Some(((dimens.get_vehicle_id()?.clone(), dimens.get_shift_index()?), dimens.get_shift_day()?))
+        })
+        .collect::<HashMap<_, _>>();
+
+    if shift_days.is_empty() {
+        return None;
+    }
+
+    let mut days = tours
+        .iter()
+        .filter_map(|tour| shift_days.get(&(tour.vehicle_id.clone(), tour.shift_index)).map(|&day| (day, tour)))
+        .fold(HashMap::<usize, Vec<DayTour>>::new(), |mut acc, (day, tour)| {
+            let mut used_ids = HashSet::new();
+            let job_ids = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .filter(|activity| {
+                    matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "service" | "replacement")
+                })
+                .filter(|activity| used_ids.insert(activity.job_id.clone()))
+                .map(|activity| activity.job_id.clone())
+                .collect();
+
+            acc.entry(day).or_default().push(DayTour {
+                vehicle_id: tour.vehicle_id.clone(),
+                shift_index: tour.shift_index,
+                job_ids,
+            });
+
+            acc
+        })
+        .into_iter()
+        .map(|(day, tours)| SolutionDay { day, tours })
+        .collect::<Vec<_>>();
+
+    // NOTE sort to have consistent order
+    days.sort_by_key(|day| day.day);
+
+    Some(days)
+}
+
 fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
     // NOTE at the moment only break violation is mapped
     let violations = solution
@@ -403,8 +696,12 @@ fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
     }
 }
 
-fn get_activity_type(activity: &Activity) -> Option<&String> {
-    activity.job.as_ref().and_then(|single| single.dimens.get_job_type())
+fn get_activity_type(activity: &Activity) -> Option<&str> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_type()).map(|job_type| match job_type.as_str() {
+        // NOTE driving break is reported as a regular break
+        "driving_break" => "break",
+        job_type => job_type,
+    })
 }
 
 fn get_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<Demand<MultiDimLoad>> {
@@ -425,6 +722,56 @@ fn get_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<Demand<Mult
     }
 }
 
+/// Returns extra distance cost caused by vehicle load on the leg with given distance.
+fn get_load_cost(vehicle: &Vehicle, load_ratio: f64, distance: Distance) -> Cost {
+    vehicle.costs.per_distance_loaded.map_or(Cost::default(), |per_distance_loaded| {
+        distance * (per_distance_loaded - vehicle.costs.per_distance) * load_ratio
+    })
+}
+
+/// Returns vehicle load ratio.
+fn get_load_ratio(vehicle: &Vehicle, load: &MultiDimLoad, is_multi_dimen: bool) -> f64 {
+    let capacity = if is_multi_dimen {
+        vehicle.dimens.get_capacity().cloned()
+    } else {
+        vehicle.dimens.get_capacity().map(|capacity: &SingleDimLoad| MultiDimLoad::new(vec![capacity.value]))
+    };
+
+    capacity
+        .map(|capacity| load.ratio(&capacity))
+        .filter(|ratio| !ratio.is_nan())
+        .map_or(0., |ratio| ratio.clamp(0., 1.))
+}
+
+/// Returns extra costs caused by vehicle cost tiers for given tour duration and distance.
+fn get_tier_cost(vehicle: &Vehicle, duration: i64, distance: i64) -> Option<TierCost> {
+    vehicle.costs.has_tiers().then(|| {
+        let (time, distance) = vehicle.costs.get_tier_costs(duration as f64, distance as f64);
+        TierCost { time, distance }
+    })
+}
+
+fn get_tier_cost_value(tier_cost: Option<&TierCost>) -> Cost {
+    tier_cost.map_or(Cost::default(), |tier_cost| tier_cost.time + tier_cost.distance)
+}
+
+/// Returns a quantity served by the partial job.
+fn get_partial_quantity(single: &Single, is_multi_dimen: bool) -> Option<Vec<i32>> {
+    single.dimens.get_partial_job()?;
+
+    get_capacity(&single.dimens, is_multi_dimen).map(|demand| {
+        let pickup = (demand.pickup.0 + demand.pickup.1).as_vec();
+        let delivery = (demand.delivery.0 + demand.delivery.1).as_vec();
+
+        // NOTE replacement has the same pickup and delivery demand
+        if pickup.iter().any(|value| *value != 0) {
+            pickup
+        } else {
+            delivery
+        }
+    })
+}
+
 fn get_parking_time(extras: &DomainExtras) -> f64 {
     extras.get_cluster_config().map_or(0., |config| config.serving.get_parking())
 }
@@ -476,3 +823,15 @@ fn get_api_metrics(metrics: Option<&TelemetryMetrics>) -> Option<ApiMetrics> {
             .collect(),
     })
 }
+
+/// Returns total entry fees of zones visited by the route: each zone is paid once per tour.
+fn get_zone_fees(route: &Route) -> Cost {
+    route
+        .tour
+        .all_activities()
+        .flat_map(|activity| get_activity_zones(activity).iter())
+        .map(|zone| (zone.index, zone.fee))
+        .collect::<HashMap<_, _>>()
+        .values()
+        .sum()
+}
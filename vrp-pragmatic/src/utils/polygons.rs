@@ -0,0 +1,38 @@
+#[cfg(test)]
+#[path = "../../tests/unit/utils/polygons_test.rs"]
+mod polygons_test;
+
+use crate::format::Location;
+
+/// Checks whether location is inside polygon specified by its vertices. Only geocoordinates are
+/// supported: false is returned for any other location type.
+pub fn is_inside_polygon(location: &Location, polygon: &[Location]) -> bool {
+    let (lat, lng) = match location {
+        Location::Coordinate { lat, lng } => (*lat, *lng),
+        _ => return false,
+    };
+
+    let vertices = polygon
+        .iter()
+        .filter_map(|vertex| match vertex {
+            Location::Coordinate { lat, lng } => Some((*lat, *lng)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if vertices.len() < 3 || vertices.len() != polygon.len() {
+        return false;
+    }
+
+    // NOTE use ray casting algorithm treating coordinates as planar which is fine for city-sized zones
+    vertices.iter().zip(vertices.iter().cycle().skip(vertices.len().saturating_sub(1))).fold(
+        false,
+        |is_inside, (curr, prev)| {
+            let ((lat_i, lng_i), (lat_j, lng_j)) = (*curr, *prev);
+            let is_crossing =
+                (lng_i > lng) != (lng_j > lng) && lat < (lat_j - lat_i) * (lng - lng_i) / (lng_j - lng_i) + lat_i;
+
+            is_inside ^ is_crossing
+        },
+    )
+}
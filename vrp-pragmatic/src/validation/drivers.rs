@@ -0,0 +1,172 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/drivers_test.rs"]
+mod drivers_test;
+
+use super::*;
+use crate::parse_time_safe;
+use crate::utils::combine_error_results;
+use hashbrown::HashSet;
+use vrp_core::models::common::TimeWindow;
+
+/// Checks that fleet has no drivers with duplicate ids.
+fn check_e1400_no_drivers_with_duplicate_ids(ctx: &ValidationContext) -> Result<(), FormatError> {
+    get_duplicates(ctx.drivers().flat_map(|driver| driver.driver_ids.iter())).map_or(Ok(()), |ids| {
+        Err(FormatError::new(
+            "E1400".to_string(),
+            "duplicated driver ids".to_string(),
+            format!("remove duplicated driver ids: {}", ids.join(", ")),
+        ))
+    })
+}
+
+/// Checks that driver shift time is correct.
+fn check_e1401_driver_shift_time(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .drivers()
+        .filter(|driver| {
+            let tws =
+                driver.shifts.iter().map(|shift| vec![shift.start.clone(), shift.end.clone()]).collect::<Vec<_>>();
+            tws.is_empty() || !check_raw_time_windows(&tws, false)
+        })
+        .map(|driver| driver.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1401".to_string(),
+            "invalid driver shift time".to_string(),
+            format!(
+                "ensure that driver has non-overlapping shifts with correct start and end times, driver type ids: {}",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that driver vehicle types are known.
+fn check_e1402_driver_vehicle_types(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let vehicle_type_ids = ctx.vehicles().map(|vehicle| &vehicle.type_id).collect::<HashSet<_>>();
+    let type_ids = ctx
+        .drivers()
+        .filter(|driver| {
+            driver
+                .vehicle_type_ids
+                .as_ref()
+                .map_or(false, |ids| ids.is_empty() || ids.iter().any(|id| !vehicle_type_ids.contains(id)))
+        })
+        .map(|driver| driver.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1402".to_string(),
+            "invalid driver vehicle types".to_string(),
+            format!(
+                "ensure that driver vehicle type ids are not empty and refer to existing vehicle types, \
+                 driver type ids: {}",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that drivers list is not empty when specified.
+fn check_e1403_empty_drivers_list(ctx: &ValidationContext) -> Result<(), FormatError> {
+    if ctx.problem.fleet.drivers.as_ref().map_or(false, |drivers| drivers.is_empty()) {
+        Err(FormatError::new(
+            "E1403".to_string(),
+            "empty drivers list".to_string(),
+            "either specify at least one driver type or omit drivers property".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that each driver can drive at least one vehicle type.
+fn check_e1404_driver_without_vehicles(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .drivers()
+        .filter(|driver| !ctx.vehicles().any(|vehicle| can_drive(driver, vehicle)))
+        .map(|driver| driver.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1404".to_string(),
+            "driver without vehicles".to_string(),
+            format!(
+                "ensure that driver has at least one allowed vehicle type with a shift overlapping driver shifts, \
+                 driver type ids: {}",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that each vehicle type can be driven by at least one driver.
+fn check_e1405_vehicle_without_drivers(ctx: &ValidationContext) -> Result<(), FormatError> {
+    if ctx.problem.fleet.drivers.is_none() {
+        return Ok(());
+    }
+
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| !ctx.drivers().any(|driver| can_drive(driver, vehicle)))
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1405".to_string(),
+            "vehicle without drivers".to_string(),
+            format!(
+                "ensure that vehicle type is allowed for at least one driver with a shift overlapping vehicle shifts, \
+                 vehicle type ids: {}",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks whether the driver is allowed to drive the vehicle and has a shift overlapping one of vehicle shifts.
+fn can_drive(driver: &DriverType, vehicle: &VehicleType) -> bool {
+    let is_allowed_type = driver.vehicle_type_ids.as_ref().map_or(true, |ids| ids.contains(&vehicle.type_id));
+
+    is_allowed_type
+        && driver.shifts.iter().filter_map(|shift| get_time_window(&shift.start, Some(&shift.end))).any(|driver_time| {
+            vehicle
+                .shifts
+                .iter()
+                .filter_map(|shift| get_time_window(&shift.start.earliest, shift.end.as_ref().map(|end| &end.latest)))
+                .any(|vehicle_time| vehicle_time.overlapping(&driver_time).map_or(false, |time| time.end > time.start))
+        })
+}
+
+fn get_time_window(start: &str, end: Option<&String>) -> Option<TimeWindow> {
+    let start = parse_time_safe(start).ok()?;
+    let end = end.map_or(Some(f64::MAX), |end| parse_time_safe(end).ok())?;
+
+    Some(TimeWindow::new(start, end))
+}
+
+/// Validates drivers from the fleet.
+pub fn validate_drivers(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
+    combine_error_results(&[
+        check_e1400_no_drivers_with_duplicate_ids(ctx),
+        check_e1401_driver_shift_time(ctx),
+        check_e1402_driver_vehicle_types(ctx),
+        check_e1403_empty_drivers_list(ctx),
+        check_e1404_driver_without_vehicles(ctx),
+        check_e1405_vehicle_without_drivers(ctx),
+    ])
+    .map_err(|errors| errors.into())
+}
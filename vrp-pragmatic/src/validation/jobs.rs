@@ -4,6 +4,8 @@ mod jobs_test;
 
 use super::*;
 use crate::utils::combine_error_results;
+use hashbrown::{HashMap, HashSet};
+use std::iter::once;
 use vrp_core::models::common::MultiDimLoad;
 
 /// Checks that plan has no jobs with duplicate ids.
@@ -132,14 +134,14 @@ fn check_e1105_empty_jobs(ctx: &ValidationContext) -> Result<(), FormatError> {
     }
 }
 
-/// Checks that job has no negative duration aka service time.
+/// Checks that job has no negative duration aka service time or setup duration.
 fn check_e1106_negative_duration(ctx: &ValidationContext) -> Result<(), FormatError> {
     let ids = ctx
         .jobs()
         .filter(|job| {
             ctx.tasks(job)
                 .iter()
-                .flat_map(|task| task.places.iter().map(|place| place.duration))
+                .flat_map(|task| task.places.iter().flat_map(|place| once(place.duration).chain(place.setup)))
                 .any(|duration| duration.is_sign_negative())
         })
         .map(|job| job.id.clone())
@@ -179,6 +181,414 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that job place time window tolerance is specified correctly.
+fn check_e1108_invalid_time_window_tolerance(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let is_invalid = |tolerance: &Option<TimeTolerance>| {
+        tolerance.as_ref().map_or(false, |tolerance| tolerance.max.is_sign_negative() || tolerance.penalty < 0.)
+    };
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job).iter().flat_map(|task| task.places.iter()).any(|place| {
+                place.tolerance.as_ref().map_or(false, |tolerance| {
+                    place.times.is_none() || is_invalid(&tolerance.earliness) || is_invalid(&tolerance.lateness)
+                })
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "invalid time window tolerance".to_string(),
+            format!(
+                "specify time windows and use non-negative tolerance values in jobs with ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that max ride duration limit is used only within pickup and delivery jobs and has valid values.
+fn check_e1109_invalid_ride_duration_limit(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_tasks = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map_or(false, |tasks| !tasks.is_empty());
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.max_ride_duration.is_some() || job.max_ride_factor.is_some())
+        .filter(|job| {
+            let is_pickup_delivery = has_tasks(&job.pickups) && has_tasks(&job.deliveries);
+            let is_invalid_duration = job.max_ride_duration.map_or(false, |duration| duration.is_sign_negative());
+            let is_invalid_factor = job.max_ride_factor.map_or(false, |factor| factor < 1.);
+
+            !is_pickup_delivery || is_invalid_duration || is_invalid_factor
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "invalid max ride duration limit".to_string(),
+            format!(
+                "use ride limit only with pickup and delivery jobs, non-negative duration and factor not less than 1, \
+                 job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that splittable task is used only in jobs with single task with positive demand and valid min split.
+fn check_e1110_invalid_splittable_job(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let relation_job_ids = get_relation_job_ids(ctx);
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| ctx.tasks(job).iter().any(|task| task.splittable.unwrap_or(false)))
+        .filter(|job| {
+            let tasks = ctx.tasks(job);
+            let is_invalid_task = match (tasks.as_slice(), job.services.is_some()) {
+                (&[task], false) => {
+                    let demand = task.demand.as_ref();
+                    let has_demand = demand.map_or(false, |demand| demand.iter().any(|value| *value > 0));
+                    let is_invalid_min_split = task.min_split.as_ref().map_or(false, |min_split| {
+                        demand.map_or(true, |demand| demand.len() != min_split.len())
+                            || min_split.iter().any(|value| *value < 0)
+                    });
+
+                    !has_demand || is_invalid_min_split
+                }
+                _ => true,
+            };
+
+            is_invalid_task || relation_job_ids.contains(&job.id)
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1110".to_string(),
+            "invalid splittable job".to_string(),
+            format!(
+                "use splittable task only in jobs with single pickup, delivery or replacement task with positive \
+                 demand, valid min split and without relations, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that periodic job has valid frequency, spacing and day patterns, and is not splittable or used in relations.
+fn check_e1111_invalid_periodic_job(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let relation_job_ids = get_relation_job_ids(ctx);
+
+    let ids = ctx
+        .jobs()
+        .filter_map(|job| job.periodic.as_ref().map(|periodic| (job, periodic)))
+        .filter(|(job, periodic)| {
+            let is_invalid_frequency =
+                periodic.frequency == 0 || periodic.max_frequency.map_or(false, |max| max < periodic.frequency);
+            let is_invalid_spacing = periodic
+                .max_spacing
+                .map_or(false, |max| max == 0 || periodic.min_spacing.map_or(false, |min| min > max));
+            let max_visits = periodic.max_frequency.unwrap_or(periodic.frequency).max(periodic.frequency);
+            let is_invalid_patterns = periodic.day_patterns.as_ref().map_or(false, |patterns| {
+                patterns.is_empty()
+                    || patterns.iter().any(|pattern| {
+                        let days = pattern.iter().collect::<HashSet<_>>().len();
+                        days < periodic.frequency || days > max_visits
+                    })
+            });
+            let is_splittable = ctx.tasks(job).iter().any(|task| task.splittable.unwrap_or(false));
+
+            is_invalid_frequency
+                || is_invalid_spacing
+                || is_invalid_patterns
+                || is_splittable
+                || relation_job_ids.contains(&job.id)
+        })
+        .map(|(job, _)| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "invalid periodic job".to_string(),
+            format!(
+                "check that frequency is positive and not bigger than max frequency, spacing and day patterns are \
+                 valid, job is not splittable and has no relations, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job alternatives are defined properly.
+fn check_e1112_invalid_job_alternatives(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_tasks = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map_or(false, |tasks| !tasks.is_empty());
+    let relation_job_ids = get_relation_job_ids(ctx);
+
+    let ids = ctx
+        .jobs()
+        .filter_map(|job| job.alternatives.as_ref().map(|variants| (job, variants)))
+        .filter(|(job, variants)| {
+            let has_own_tasks = has_tasks(&job.pickups)
+                || has_tasks(&job.deliveries)
+                || has_tasks(&job.replacements)
+                || has_tasks(&job.services);
+            let has_empty_variant = variants.iter().any(|variant| {
+                !(has_tasks(&variant.pickups)
+                    || has_tasks(&variant.deliveries)
+                    || has_tasks(&variant.replacements)
+                    || has_tasks(&variant.services))
+            });
+            let is_splittable = ctx.tasks(job).iter().any(|task| task.splittable.unwrap_or(false));
+
+            variants.is_empty()
+                || has_own_tasks
+                || has_empty_variant
+                || is_splittable
+                || job.periodic.is_some()
+                || relation_job_ids.contains(&job.id)
+        })
+        .map(|(job, _)| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "invalid job alternatives".to_string(),
+            format!(
+                "check that job with alternatives has no own tasks, each variant has at least one task, job is not \
+                 periodic or splittable and has no relations, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job place duration rules are defined properly.
+fn check_e1113_invalid_duration_rules(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx.vehicles().map(|vehicle| &vehicle.type_id).collect::<HashSet<_>>();
+    let is_invalid_rule = |rule: &JobPlaceDurationRule| {
+        let vehicle_types = rule.vehicle_types.as_ref();
+        let has_condition = vehicle_types.map_or(false, |types| !types.is_empty())
+            || rule.skills.as_ref().map_or(false, |skills| !skills.is_empty());
+        let has_unknown_type = vehicle_types.map_or(false, |types| types.iter().any(|id| !type_ids.contains(id)));
+        let is_valid_value = match (rule.multiplier, rule.value) {
+            (Some(multiplier), None) => multiplier > 0.,
+            (None, Some(value)) => !value.is_sign_negative(),
+            _ => false,
+        };
+
+        !has_condition || has_unknown_type || !is_valid_value
+    };
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job)
+                .iter()
+                .flat_map(|task| task.places.iter())
+                .filter_map(|place| place.duration_rules.as_ref())
+                .any(|rules| rules.is_empty() || rules.iter().any(is_invalid_rule))
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1113".to_string(),
+            "invalid job place duration rules".to_string(),
+            format!(
+                "check that each duration rule has known vehicle types or skills and exactly one of positive \
+                 multiplier or non-negative value, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that max time in vehicle is used only with non-negative values on deliveries of delivery jobs.
+fn check_e1114_invalid_max_time_in_vehicle(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_limit =
+        |tasks: &Option<Vec<JobTask>>| tasks.iter().flatten().any(|task| task.max_time_in_vehicle.is_some());
+    let is_invalid = |pickups: &Option<Vec<JobTask>>,
+                      deliveries: &Option<Vec<JobTask>>,
+                      replacements: &Option<Vec<JobTask>>,
+                      services: &Option<Vec<JobTask>>| {
+        let has_pickups = pickups.as_ref().map_or(false, |tasks| !tasks.is_empty());
+        let is_negative = deliveries
+            .iter()
+            .flatten()
+            .filter_map(|task| task.max_time_in_vehicle)
+            .any(|limit| limit.is_sign_negative());
+
+        has_limit(pickups)
+            || has_limit(replacements)
+            || has_limit(services)
+            || (has_pickups && has_limit(deliveries))
+            || is_negative
+    };
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            is_invalid(&job.pickups, &job.deliveries, &job.replacements, &job.services)
+                || job.alternatives.iter().flatten().any(|variant| {
+                    is_invalid(&variant.pickups, &variant.deliveries, &variant.replacements, &variant.services)
+                })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1114".to_string(),
+            "invalid max time in vehicle".to_string(),
+            format!(
+                "use max time in vehicle only with non-negative values on delivery tasks of jobs without pickups, \
+                 job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job transfers are used only with pickup and delivery jobs and have valid transfer places.
+fn check_e1115_invalid_job_transfers(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let task_count = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map_or(0, |tasks| tasks.len());
+    let relation_job_ids = get_relation_job_ids(ctx);
+
+    let ids = ctx
+        .jobs()
+        .filter_map(|job| job.transfers.as_ref().map(|transfers| (job, transfers)))
+        .filter(|(job, transfers)| {
+            let is_pickup_delivery = task_count(&job.pickups) == 1
+                && task_count(&job.deliveries) == 1
+                && task_count(&job.replacements) == 0
+                && task_count(&job.services) == 0;
+            let has_invalid_place = transfers.iter().any(|place| {
+                place.duration.is_sign_negative()
+                    || place.times.as_ref().map_or(false, |tws| !check_raw_time_windows(tws, false))
+            });
+            let is_splittable = ctx.tasks(job).iter().any(|task| task.splittable.unwrap_or(false));
+
+            transfers.is_empty()
+                || !is_pickup_delivery
+                || has_invalid_place
+                || is_splittable
+                || job.alternatives.is_some()
+                || job.periodic.is_some()
+                || relation_job_ids.contains(&job.id)
+        })
+        .map(|(job, _)| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1115".to_string(),
+            "invalid job transfers".to_string(),
+            format!(
+                "check that job with transfers has exactly one pickup and one delivery, is not periodic or \
+                 splittable, has no alternatives and relations, transfer places have non-negative duration and \
+                 valid time windows, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that satellite supply job is a delivery job at existing satellite location without relations.
+fn check_e1116_invalid_satellite_supply(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let relation_job_ids = get_relation_job_ids(ctx);
+    let task_count = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map_or(0, |tasks| tasks.len());
+    let satellites = ctx
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Satellite { id, location } => Some((id, location)),
+            VehicleResource::Reload { .. } | VehicleResource::Dock { .. } => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    let ids = ctx
+        .jobs()
+        .filter_map(|job| job.satellite_id.as_ref().map(|satellite_id| (job, satellite_id)))
+        .filter(|(job, satellite_id)| {
+            let is_delivery = task_count(&job.deliveries) > 0
+                && task_count(&job.pickups) == 0
+                && task_count(&job.replacements) == 0
+                && task_count(&job.services) == 0;
+            let is_at_satellite = satellites.get(satellite_id).map_or(false, |location| {
+                job.deliveries
+                    .iter()
+                    .flatten()
+                    .flat_map(|task| task.places.iter())
+                    .all(|place| &place.location == *location)
+            });
+            let is_splittable = ctx.tasks(job).iter().any(|task| task.splittable.unwrap_or(false));
+
+            !is_delivery
+                || !is_at_satellite
+                || is_splittable
+                || job.alternatives.is_some()
+                || job.periodic.is_some()
+                || job.transfers.is_some()
+                || relation_job_ids.contains(&job.id)
+        })
+        .map(|(job, _)| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1116".to_string(),
+            "invalid satellite supply job".to_string(),
+            format!(
+                "check that job with satellite id refers to existing satellite, has only deliveries at the satellite \
+                 location, is not periodic or splittable, has no alternatives, transfers and relations, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Returns ids of jobs used in relations.
+fn get_relation_job_ids<'a>(ctx: &ValidationContext<'a>) -> HashSet<&'a String> {
+    ctx.problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter())
+        .flat_map(|relation| relation.jobs.iter())
+        .collect()
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     combine_error_results(&[
@@ -190,6 +600,15 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_invalid_time_window_tolerance(ctx),
+        check_e1109_invalid_ride_duration_limit(ctx),
+        check_e1110_invalid_splittable_job(ctx),
+        check_e1111_invalid_periodic_job(ctx),
+        check_e1112_invalid_job_alternatives(ctx),
+        check_e1113_invalid_duration_rules(ctx),
+        check_e1114_invalid_max_time_in_vehicle(ctx),
+        check_e1115_invalid_job_transfers(ctx),
+        check_e1116_invalid_satellite_supply(ctx),
     ])
     .map_err(|errors| errors.into())
 }
@@ -26,11 +26,17 @@ use self::objectives::validate_objectives;
 mod vehicles;
 use self::vehicles::validate_vehicles;
 
+mod drivers;
+use self::drivers::validate_drivers;
+
 mod relations;
 use self::relations::validate_relations;
 
 mod routing;
 use self::routing::validate_routing;
+
+mod zones;
+use self::zones::validate_zones;
 use hashbrown::HashMap;
 
 impl<'a> ValidationContext<'a> {
@@ -50,9 +56,11 @@ impl<'a> ValidationContext<'a> {
             .err()
             .into_iter()
             .chain(validate_vehicles(self).err())
+            .chain(validate_drivers(self).err())
             .chain(validate_objectives(self).err())
             .chain(validate_routing(self).err())
             .chain(validate_relations(self).err())
+            .chain(validate_zones(self).err())
             .flatten()
             .collect::<Vec<_>>()
             .into();
@@ -75,6 +83,15 @@ impl<'a> ValidationContext<'a> {
     }
 
     /// Gets a flat list of job tasks from the job.
+    fn drivers(&self) -> impl Iterator<Item = &DriverType> {
+        self.problem.fleet.drivers.iter().flat_map(|drivers| drivers.iter())
+    }
+
+    /// Gets list of geofenced zones from the plan.
+    fn zones(&self) -> impl Iterator<Item = &Zone> {
+        self.problem.plan.zones.iter().flat_map(|zones| zones.iter())
+    }
+
     fn tasks(&self, job: &'a Job) -> Vec<&'a JobTask> {
         job.pickups
             .as_ref()
@@ -83,6 +100,7 @@ impl<'a> ValidationContext<'a> {
             .chain(job.deliveries.as_ref().iter().flat_map(|tasks| tasks.iter()))
             .chain(job.replacements.as_ref().iter().flat_map(|tasks| tasks.iter()))
             .chain(job.services.as_ref().iter().flat_map(|tasks| tasks.iter()))
+            .chain(get_job_variant_tasks(job))
             .collect()
     }
 }
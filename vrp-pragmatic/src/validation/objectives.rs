@@ -5,6 +5,7 @@ mod objectives_test;
 use super::*;
 use crate::format::problem::Objective::*;
 use crate::utils::combine_error_results;
+use hashbrown::HashSet;
 
 /// Checks that objective is not empty when specified.
 fn check_e1600_empty_objective(objectives: &[&Objective]) -> Result<(), FormatError> {
@@ -33,6 +34,7 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
                 MaximizeValue { .. } => acc.entry("maximize-value"),
                 MinimizeUnassigned { .. } => acc.entry("minimize-unassigned"),
                 MinimizeArrivalTime => acc.entry("minimize-arrival-time"),
+                MinimizeMakespan => acc.entry("minimize-makespan"),
                 BalanceMaxLoad { .. } => acc.entry("balance-max-load"),
                 BalanceActivities { .. } => acc.entry("balance-activities"),
                 BalanceDistance { .. } => acc.entry("balance-distance"),
@@ -40,6 +42,8 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
                 CompactTour { .. } => acc.entry("compact-tour"),
                 TourOrder => acc.entry("tour-order"),
                 FastService { .. } => acc.entry("fast-service"),
+                MinimizePlanChanges { .. } => acc.entry("minimize-plan-changes"),
+                Weighted { .. } => acc.entry("weighted"),
             }
             .and_modify(|count| *count += 1)
             .or_insert(1_usize);
@@ -186,8 +190,77 @@ fn check_e1607_jobs_with_value_but_no_objective(
     }
 }
 
+/// Checks that reference plan of plan changes objective is valid.
+fn check_e1608_invalid_plan_reference(ctx: &ValidationContext, objectives: &[&Objective]) -> Result<(), FormatError> {
+    let vehicle_ids = ctx.vehicles().flat_map(|vehicle| vehicle.vehicle_ids.iter()).collect::<HashSet<_>>();
+
+    let has_invalid_reference = objectives
+        .iter()
+        .filter_map(|objective| match objective {
+            MinimizePlanChanges { reference, options } => Some((reference, options)),
+            _ => None,
+        })
+        .any(|(reference, options)| {
+            let mut job_ids = HashSet::new();
+            let has_unknown_vehicle = reference.iter().any(|tour| !vehicle_ids.contains(&tour.vehicle_id));
+            let has_duplicate_job = !reference.iter().flat_map(|tour| tour.jobs.iter()).all(|id| job_ids.insert(id));
+            let has_negative_weight = options.as_ref().map_or(false, |options| {
+                options.reassignment.iter().chain(options.sequence.iter()).any(|weight| *weight < 0.)
+            });
+
+            has_unknown_vehicle || has_duplicate_job || has_negative_weight
+        });
+
+    if has_invalid_reference {
+        Err(FormatError::new(
+            "E1608".to_string(),
+            "invalid reference plan in plan changes objective".to_string(),
+            "use only known vehicle ids, specify each job only once and use non-negative weights".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that weighted objective is valid.
+fn check_e1609_invalid_weighted_objective(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let has_invalid_weighted = objectives.iter().any(|objective| match objective {
+        Weighted { objectives } => {
+            objectives.is_empty()
+                || objectives
+                    .iter()
+                    .any(|weighted| weighted.weight <= 0. || matches!(weighted.objective, Weighted { .. }))
+        }
+        _ => false,
+    });
+
+    if has_invalid_weighted {
+        Err(FormatError::new(
+            "E1609".to_string(),
+            "invalid weighted objective".to_string(),
+            "specify at least one objective with positive weight and do not nest weighted objectives".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
-    ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().flatten().collect())
+    // NOTE components of weighted objective are checked as they were specified separately
+    ctx.problem.objectives.as_ref().map(|objectives| {
+        objectives
+            .iter()
+            .flatten()
+            .flat_map(|objective| {
+                let components = match objective {
+                    Weighted { objectives } => objectives.iter().map(|weighted| &weighted.objective).collect(),
+                    _ => vec![],
+                };
+
+                std::iter::once(objective).chain(components)
+            })
+            .collect()
+    })
 }
 
 pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
@@ -201,6 +274,8 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), MultiFormatErr
             check_e1605_check_positive_value_and_order(ctx),
             check_e1606_check_multiple_cost_objectives(&objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
+            check_e1608_invalid_plan_reference(ctx, &objectives),
+            check_e1609_invalid_weighted_objective(&objectives),
         ])
         .map_err(|errors| errors.into())
     } else {
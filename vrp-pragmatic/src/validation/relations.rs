@@ -39,7 +39,7 @@ fn check_e1201_vehicle_existence(
 ) -> Result<(), FormatError> {
     let vehicle_ids = relations
         .iter()
-        .map(|relation| relation.vehicle_id.clone())
+        .filter_map(|relation| relation.vehicle_id.clone())
         .filter(|vehicle_id| !vehicle_map.contains_key(vehicle_id))
         .collect::<Vec<_>>();
 
@@ -112,15 +112,15 @@ fn check_e1204_job_assigned_to_multiple_vehicles(relations: &[Relation]) -> Resu
     let mut job_vehicle_map = HashMap::<String, String>::new();
     let job_ids: Vec<String> = relations
         .iter()
-        .flat_map(|relation| {
+        .filter_map(|relation| relation.vehicle_id.as_ref().map(|vehicle_id| (vehicle_id, relation)))
+        .flat_map(|(vehicle_id, relation)| {
             relation
                 .jobs
                 .clone()
                 .into_iter()
                 .filter(|job_id| !is_reserved_job_id(job_id))
                 .filter(|job_id| {
-                    *job_vehicle_map.entry(job_id.clone()).or_insert_with(|| relation.vehicle_id.clone())
-                        != relation.vehicle_id
+                    job_vehicle_map.entry(job_id.clone()).or_insert_with(|| vehicle_id.clone()) != vehicle_id
                 })
                 .collect::<Vec<String>>()
                 .into_iter()
@@ -144,9 +144,15 @@ fn check_e1205_relation_has_correct_shift_index(
 ) -> Result<(), FormatError> {
     let vehicle_ids: Vec<String> = relations
         .iter()
-        .filter_map(|relation| vehicle_map.get(&relation.vehicle_id).map(|vehicle| (vehicle, relation)))
+        .filter_map(|relation| {
+            relation
+                .vehicle_id
+                .as_ref()
+                .and_then(|vehicle_id| vehicle_map.get(vehicle_id))
+                .map(|vehicle| (vehicle, relation))
+        })
         .filter(|(vehicle, relation)| vehicle.shifts.get(relation.shift_index.unwrap_or(0)).is_none())
-        .map(|(_, relation)| relation.vehicle_id.clone())
+        .filter_map(|(_, relation)| relation.vehicle_id.clone())
         .collect::<Vec<_>>();
 
     if vehicle_ids.is_empty() {
@@ -171,8 +177,10 @@ fn check_e1206_relation_has_no_missing_shift_properties(
     let vehicle_ids: Vec<String> = relations
         .iter()
         .filter_map(|relation| {
-            vehicle_map
-                .get(&relation.vehicle_id)
+            relation
+                .vehicle_id
+                .as_ref()
+                .and_then(|vehicle_id| vehicle_map.get(vehicle_id))
                 .and_then(|vehicle| vehicle.shifts.get(relation.shift_index.unwrap_or(0)))
                 .map(|vehicle_shift| (vehicle_shift, relation))
         })
@@ -184,7 +192,7 @@ fn check_e1206_relation_has_no_missing_shift_properties(
                 _ => false,
             })
         })
-        .map(|(_, relation)| relation.vehicle_id.clone())
+        .filter_map(|(_, relation)| relation.vehicle_id.clone())
         .collect::<Vec<_>>();
 
     if vehicle_ids.is_empty() {
@@ -252,6 +260,82 @@ fn check_e1207_no_incomplete_relation(ctx: &ValidationContext, relations: &[Rela
     }
 }
 
+/// Checks that relation which locks jobs to the vehicle has vehicle id specified.
+fn check_e1208_lock_relation_has_vehicle_id(relations: &[Relation]) -> Result<(), FormatError> {
+    let job_ids = relations
+        .iter()
+        .filter(|relation| is_lock_relation(relation) && relation.vehicle_id.is_none())
+        .flat_map(|relation| relation.jobs.iter().filter(|job_id| !is_reserved_job_id(job_id)).cloned())
+        .collect::<Vec<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1208".to_string(),
+            "relation of any, sequence or strict type has no vehicle id".to_string(),
+            format!("specify vehicle id for relations with job ids: '{}'", job_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that sync and precedence relations are properly defined.
+fn check_e1209_valid_schedule_relation(ctx: &ValidationContext, relations: &[Relation]) -> Result<(), FormatError> {
+    let schedule_relations = relations.iter().filter(|relation| !is_lock_relation(relation)).collect::<Vec<_>>();
+    let job_frequencies = schedule_relations.iter().flat_map(|relation| relation.jobs.iter()).fold(
+        HashMap::<&String, usize>::new(),
+        |mut acc, job_id| {
+            *acc.entry(job_id).or_default() += 1;
+            acc
+        },
+    );
+
+    let mut job_ids = schedule_relations
+        .iter()
+        .filter(|relation| {
+            let has_invalid_jobs = relation.jobs.iter().any(|job_id| {
+                is_reserved_job_id(job_id)
+                    || ctx.job_index.get(job_id).map_or(false, |job| {
+                        let tasks = ctx.tasks(job);
+                        tasks.len() != 1
+                            || tasks.iter().flat_map(|task| task.places.iter()).any(|place| place.tolerance.is_some())
+                    })
+            });
+
+            relation.vehicle_id.is_some()
+                || relation.shift_index.is_some()
+                || relation.jobs.len() < 2
+                || relation.offset.map_or(false, |offset| offset < 0.)
+                || has_invalid_jobs
+        })
+        .flat_map(|relation| relation.jobs.iter().cloned())
+        .chain(job_frequencies.iter().filter(|(_, &count)| count > 1).map(|(job_id, _)| (*job_id).clone()))
+        .filter(|job_id| !is_reserved_job_id(job_id))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    job_ids.sort();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1209".to_string(),
+            "invalid sync or precedence relation".to_string(),
+            format!(
+                "check that relation has at least two unique job ids of jobs with a single task without time window \
+                 tolerance, non-negative offset and no vehicle id or shift index, job ids: '{}'",
+                job_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn is_lock_relation(relation: &Relation) -> bool {
+    matches!(relation.type_field, RelationType::Any | RelationType::Sequence | RelationType::Strict)
+}
+
 /// Validates relations in the plan.
 pub fn validate_relations(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     let vehicle_map = ctx
@@ -260,15 +344,20 @@ pub fn validate_relations(ctx: &ValidationContext) -> Result<(), MultiFormatErro
         .collect::<HashMap<_, _>>();
 
     if let Some(relations) = ctx.problem.plan.relations.as_ref() {
+        let lock_relations =
+            relations.iter().filter(|relation| is_lock_relation(relation)).cloned().collect::<Vec<_>>();
+
         combine_error_results(&[
             check_e1200_job_existence(ctx, relations),
-            check_e1201_vehicle_existence(relations, &vehicle_map),
+            check_e1201_vehicle_existence(&lock_relations, &vehicle_map),
             check_e1202_empty_job_list(relations),
             check_e1203_no_multiple_places_times(ctx, relations),
-            check_e1204_job_assigned_to_multiple_vehicles(relations),
-            check_e1205_relation_has_correct_shift_index(relations, &vehicle_map),
-            check_e1206_relation_has_no_missing_shift_properties(relations, &vehicle_map),
-            check_e1207_no_incomplete_relation(ctx, relations),
+            check_e1204_job_assigned_to_multiple_vehicles(&lock_relations),
+            check_e1205_relation_has_correct_shift_index(&lock_relations, &vehicle_map),
+            check_e1206_relation_has_no_missing_shift_properties(&lock_relations, &vehicle_map),
+            check_e1207_no_incomplete_relation(ctx, &lock_relations),
+            check_e1208_lock_relation_has_vehicle_id(relations),
+            check_e1209_valid_schedule_relation(ctx, relations),
         ])
         .map_err(|errors| errors.into())
     } else {
@@ -222,8 +222,9 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
         .resources
         .iter()
         .flat_map(|resources| resources.iter())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, .. } => id.to_string(),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, .. } => Some(id.to_string()),
+            VehicleResource::Dock { .. } | VehicleResource::Satellite { .. } => None,
         })
         .collect::<Vec<_>>();
 
@@ -264,6 +265,101 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+fn check_e1309_vehicle_compartments(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.compartments.as_ref().map_or(false, |compartments| {
+                let unique_ids = compartments.iter().map(|compartment| &compartment.id).collect::<HashSet<_>>();
+
+                vehicle.capacity.len() != 1
+                    || unique_ids.len() != compartments.len()
+                    || compartments.iter().any(|compartment| {
+                        compartment.capacity <= 0
+                            || compartment.cleaning_duration.map_or(false, |duration| duration < 0.)
+                    })
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid vehicle compartments".to_string(),
+            format!(
+                "ensure that compartment ids are unique, capacities are positive, cleaning durations are not \
+                 negative and vehicle capacity has single dimension, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1310_vehicle_dock_resources(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let docks = ctx
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Dock { id, slots } => Some((id.to_string(), *slots)),
+            VehicleResource::Reload { .. } | VehicleResource::Satellite { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    let dock_ids = docks.iter().map(|(id, _)| id.clone()).collect::<HashSet<_>>();
+
+    if dock_ids.len() != docks.len() || docks.iter().any(|(_, slots)| *slots == 0) {
+        return Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid dock resource".to_string(),
+            "make sure that fleet dock resource ids are unique and each dock has at least one slot".to_string(),
+        ));
+    }
+
+    let job_ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job)
+                .iter()
+                .flat_map(|task| task.places.iter())
+                .filter_map(|place| place.dock.as_ref())
+                .any(|dock| !dock_ids.contains(dock))
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(move |_, shift, _| {
+            shift
+                .reloads
+                .iter()
+                .flat_map(|reloads| reloads.iter())
+                .filter_map(|reload| reload.dock.as_ref())
+                .all(|dock| dock_ids.contains(dock))
+        }),
+    );
+
+    if job_ids.is_empty() && type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid dock resource".to_string(),
+            format!(
+                "make sure that fleet has all dock resources defined, check job ids: '{}', vehicle type ids: '{}'",
+                job_ids.join(", "),
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 type CheckShiftFn = Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>;
 
 fn get_invalid_type_ids(ctx: &ValidationContext, check_shift_fn: CheckShiftFn) -> Vec<String> {
@@ -281,6 +377,113 @@ fn get_invalid_type_ids(ctx: &ValidationContext, check_shift_fn: CheckShiftFn) -
         .collect::<Vec<_>>()
 }
 
+fn check_e1311_vehicle_load_dependent_costs(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.costs.distance_loaded.map_or(false, |distance_loaded| distance_loaded < 0.))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid load dependent distance cost".to_string(),
+            format!(
+                "ensure that distance cost for loaded vehicle is not negative, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1312_vehicle_recharge_battery(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            shift.recharges.as_ref().map_or(true, |recharges| {
+                let is_battery_valid = recharges.battery.as_ref().map_or(true, |battery| {
+                    let initial = battery.initial.unwrap_or(battery.capacity);
+                    let reserve = battery.reserve.unwrap_or(0.);
+
+                    battery.capacity > 0.
+                        && battery.consumption > 0.
+                        && battery.consumption_loaded.map_or(true, |loaded| loaded >= battery.consumption)
+                        && reserve >= 0.
+                        && reserve <= initial
+                        && initial <= battery.capacity
+                });
+                let is_power_valid =
+                    recharges.stations.iter().all(|station| station.power.map_or(true, |power| power > 0.));
+                let has_limit = recharges.battery.is_some() || recharges.max_distance.is_some();
+
+                has_limit && is_battery_valid && is_power_valid
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid vehicle battery or recharge stations".to_string(),
+            format!(
+                "ensure that recharges have either max distance or battery, battery capacity and consumption are \
+                 positive, reserve is not greater than initial charge, initial charge is not greater than capacity \
+                 and stations power is positive, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that driving breaks are correct.
+fn check_e1313_vehicle_driving_breaks(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            shift.breaks.as_ref().map_or(true, |breaks| {
+                let driving_breaks = breaks
+                    .iter()
+                    .filter_map(|vehicle_break| match vehicle_break {
+                        VehicleBreak::Driving { max_driving_time, duration, split } => {
+                            Some((*max_driving_time, *duration, split))
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                let is_valid_break = driving_breaks.iter().all(|(max_driving_time, duration, split)| {
+                    *max_driving_time > 0.
+                        && *duration > 0.
+                        && split.as_ref().map_or(true, |split| split.len() == 2 && split.iter().all(|part| *part > 0.))
+                });
+
+                // NOTE driving break cannot be combined with other breaks within the same shift
+                let is_single_break = driving_breaks.is_empty() || breaks.len() == 1;
+
+                is_valid_break && is_single_break
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1313".to_string(),
+            "invalid driving break in vehicle shift".to_string(),
+            format!(
+                "ensure that max driving time and break duration are positive, split has exactly two positive \
+                 parts and driving break is the only break in the shift, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 fn check_shift_time_windows(
     shift_time: Option<TimeWindow>,
     tws: Vec<Option<TimeWindow>>,
@@ -300,6 +503,122 @@ fn get_shift_time_window(shift: &VehicleShift) -> Option<TimeWindow> {
     )
 }
 
+fn check_e1314_vehicle_cost_tiers(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_invalid_tiers = |tiers: Option<&Vec<VehicleCostTier>>| {
+        tiers.map_or(false, |tiers| {
+            let thresholds = tiers.iter().map(|tier| tier.threshold.to_bits()).collect::<HashSet<_>>();
+            thresholds.len() != tiers.len() || tiers.iter().any(|tier| tier.threshold < 0. || tier.cost < 0.)
+        })
+    };
+
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            has_invalid_tiers(vehicle.costs.time_tiers.as_ref())
+                || has_invalid_tiers(vehicle.costs.distance_tiers.as_ref())
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1314".to_string(),
+            "invalid vehicle cost tiers".to_string(),
+            format!(
+                "ensure that cost tiers have unique non-negative thresholds and non-negative costs, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1315_vehicle_trip_limits(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter_map(|vehicle| {
+            vehicle.limits.as_ref().and_then(|limits| limits.trip.as_ref()).map(|trip| (vehicle, trip))
+        })
+        .filter(|(_, trip)| {
+            let is_empty = trip.max_distance.is_none() && trip.max_duration.is_none() && trip.size.is_none();
+            let has_non_positive = trip.max_distance.into_iter().chain(trip.max_duration).any(|value| value <= 0.)
+                || trip.size.map_or(false, |size| size == 0);
+
+            is_empty || has_non_positive
+        })
+        .map(|(vehicle, _)| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1315".to_string(),
+            "invalid vehicle trip limits".to_string(),
+            format!(
+                "ensure that trip limits have at least one positive value and no non-positive values, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1316_vehicle_trailer(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter_map(|vehicle| vehicle.trailer.as_ref().map(|trailer| (vehicle, trailer)))
+        .filter(|(vehicle, trailer)| {
+            trailer.capacity.len() != vehicle.capacity.len()
+                || trailer.capacity.iter().any(|value| *value < 0)
+                || trailer.parks.is_empty()
+                || trailer.parks.iter().any(|park| park.decouple_duration < 0. || park.couple_duration < 0.)
+        })
+        .map(|(vehicle, _)| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1316".to_string(),
+            "invalid vehicle trailer".to_string(),
+            format!(
+                "ensure that trailer capacity has the same dimensions as vehicle capacity and no negative values, \
+                 at least one park is specified and park durations are not negative, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1317_vehicle_satellite_resources(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let satellites = ctx
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Satellite { id, location } => Some((id, location)),
+            VehicleResource::Reload { .. } | VehicleResource::Dock { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    let ids = satellites.iter().map(|(id, _)| *id).collect::<HashSet<_>>();
+    let locations = satellites.iter().map(|(_, location)| *location).collect::<HashSet<_>>();
+
+    if ids.len() != satellites.len() || locations.len() != satellites.len() {
+        Err(FormatError::new(
+            "E1317".to_string(),
+            "invalid satellite resource".to_string(),
+            "make sure that fleet satellite resource ids and locations are unique".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 /// Validates vehicles from the fleet.
 pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     combine_error_results(&[
@@ -311,6 +630,15 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError
         check_e1306_vehicle_has_no_zero_costs(ctx),
         check_e1307_vehicle_offset_break_rescheduling(ctx),
         check_e1308_vehicle_reload_resources(ctx),
+        check_e1309_vehicle_compartments(ctx),
+        check_e1310_vehicle_dock_resources(ctx),
+        check_e1311_vehicle_load_dependent_costs(ctx),
+        check_e1312_vehicle_recharge_battery(ctx),
+        check_e1313_vehicle_driving_breaks(ctx),
+        check_e1314_vehicle_cost_tiers(ctx),
+        check_e1315_vehicle_trip_limits(ctx),
+        check_e1316_vehicle_trailer(ctx),
+        check_e1317_vehicle_satellite_resources(ctx),
     ])
     .map_err(|errors| errors.into())
 }
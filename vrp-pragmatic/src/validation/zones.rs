@@ -0,0 +1,84 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/zones_test.rs"]
+mod zones_test;
+
+use super::*;
+use crate::format::Location;
+use crate::utils::combine_error_results;
+use hashbrown::HashSet;
+
+/// Checks that plan has no zones with duplicate ids.
+fn check_e1700_no_zones_with_duplicate_ids(ctx: &ValidationContext) -> Result<(), FormatError> {
+    get_duplicates(ctx.zones().map(|zone| &zone.id)).map_or(Ok(()), |ids| {
+        Err(FormatError::new(
+            "E1700".to_string(),
+            "duplicated zone ids".to_string(),
+            format!("remove duplicated zone ids: {}", ids.join(", ")),
+        ))
+    })
+}
+
+/// Checks that zone polygon is valid.
+fn check_e1701_zone_polygon(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let zone_ids = ctx
+        .zones()
+        .filter(|zone| {
+            zone.polygon.len() < 3
+                || zone.polygon.iter().any(|location| match location {
+                    Location::Coordinate { lat, lng } => !(-90. ..=90.).contains(lat) || !(-180. ..=180.).contains(lng),
+                    _ => true,
+                })
+        })
+        .map(|zone| zone.id.clone())
+        .collect::<Vec<_>>();
+
+    if zone_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1701".to_string(),
+            "invalid zone polygon".to_string(),
+            format!(
+                "ensure that zone polygon has at least three vertices defined by valid coordinates, zone ids: {}",
+                zone_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that zone has valid vehicle types and fee.
+fn check_e1702_zone_access_and_fee(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx.vehicles().map(|vehicle| &vehicle.type_id).collect::<HashSet<_>>();
+
+    let zone_ids = ctx
+        .zones()
+        .filter(|zone| {
+            zone.fee.map_or(false, |fee| fee < 0.)
+                || zone.vehicle_type_ids.iter().flatten().any(|type_id| !type_ids.contains(type_id))
+        })
+        .map(|zone| zone.id.clone())
+        .collect::<Vec<_>>();
+
+    if zone_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1702".to_string(),
+            "invalid zone vehicle types or fee".to_string(),
+            format!(
+                "ensure that zone refers to existing vehicle types and its fee is not negative, zone ids: {}",
+                zone_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Validates geofenced zones from the plan.
+pub fn validate_zones(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
+    combine_error_results(&[
+        check_e1700_no_zones_with_duplicate_ids(ctx),
+        check_e1701_zone_polygon(ctx),
+        check_e1702_zone_access_and_fee(ctx),
+    ])
+    .map_err(|errors| errors.into())
+}
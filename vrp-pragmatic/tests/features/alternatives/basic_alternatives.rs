@@ -0,0 +1,104 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format::Location;
+use crate::helpers::*;
+
+fn create_variant(job: Job) -> JobVariant {
+    JobVariant {
+        pickups: job.pickups,
+        deliveries: job.deliveries,
+        replacements: job.replacements,
+        services: job.services,
+        value: job.value,
+    }
+}
+
+fn create_job_with_alternatives(id: &str, variants: Vec<Job>) -> Job {
+    Job { alternatives: Some(variants.into_iter().map(create_variant).collect()), ..create_job(id) }
+}
+
+fn get_job_locations(solution: &Solution, job_id: &str) -> Vec<Location> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .filter_map(|stop| stop.as_point())
+        .filter(|stop| stop.activities.iter().any(|activity| activity.job_id == job_id))
+        .map(|stop| stop.location.clone())
+        .collect()
+}
+
+#[test]
+fn can_serve_only_one_feasible_variant() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_alternatives(
+                    "job1",
+                    vec![
+                        create_delivery_job_with_times("job1", (10., 0.), vec![(0, 5)], 1.),
+                        create_service_job("job1", (3., 0.)),
+                    ],
+                ),
+                create_delivery_job("job2", (5., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_job_locations(&solution, "job1"), vec![(3., 0.).to_loc()]);
+    assert_eq!(get_job_locations(&solution, "job2"), vec![(5., 0.).to_loc()]);
+}
+
+#[test]
+fn can_serve_closest_variant() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_job_with_alternatives(
+                "job1",
+                vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job1", (2., 0.))],
+            )],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_job_locations(&solution, "job1"), vec![(2., 0.).to_loc()]);
+}
+
+#[test]
+fn can_report_job_with_unassignable_variants_once() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_job_with_alternatives(
+                "job1",
+                vec![
+                    create_delivery_job_with_times("job1", (10., 0.), vec![(0, 5)], 1.),
+                    create_delivery_job_with_times("job1", (20., 0.), vec![(0, 5)], 1.),
+                ],
+            )],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.expect("expected unassigned job");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job1");
+    assert!(solution.tours.is_empty());
+}
@@ -0,0 +1,87 @@
+use crate::format::problem::*;
+use crate::format::solution::Solution;
+use crate::helpers::*;
+
+fn create_problem(split: Option<Vec<f64>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (20., 0.)),
+                create_delivery_job("job3", (30., 0.)),
+                create_delivery_job("job4", (40., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Driving { max_driving_time: 50., duration: 10., split }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_break_count(solution: &Solution) -> usize {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "break")
+        .count()
+}
+
+fn get_driving_times(solution: &Solution) -> Vec<f64> {
+    let stops = solution.tours[0].stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>();
+
+    stops
+        .windows(2)
+        .map(|stops| crate::parse_time(&stops[1].time.arrival) - crate::parse_time(&stops[0].time.departure))
+        .collect()
+}
+
+#[test]
+fn can_schedule_break_after_driving_time_limit() {
+    let problem = create_problem(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![vec!["departure"], vec!["job4"], vec!["job3", "break"], vec!["job2"], vec!["job1"], vec!["arrival"]]
+    );
+    assert_eq!(get_driving_times(&solution), vec![40., 10., 10., 10., 10.]);
+    let schedule = solution.tours[0].stops[2].schedule();
+    assert_eq!(crate::parse_time(&schedule.departure) - crate::parse_time(&schedule.arrival), 11.);
+}
+
+#[test]
+fn can_schedule_split_break_after_driving_time_limit() {
+    let problem = create_problem(Some(vec![3., 7.]));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(get_break_count(&solution) > 0);
+}
+
+#[test]
+fn can_skip_break_when_driving_time_is_within_limit() {
+    let mut problem = create_problem(None);
+    problem.fleet.vehicles[0].shifts[0].breaks =
+        Some(vec![VehicleBreak::Driving { max_driving_time: 100., duration: 10., split: None }]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_break_count(&solution), 0);
+}
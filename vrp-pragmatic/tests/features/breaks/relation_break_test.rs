@@ -29,8 +29,9 @@ fn get_solution(
             relations: Some(vec![Relation {
                 type_field: relation_type,
                 jobs,
-                vehicle_id: "my_vehicle_1".to_string(),
+                vehicle_id: Some("my_vehicle_1".to_string()),
                 shift_index: None,
+                offset: None,
             }]),
             ..create_empty_plan()
         },
@@ -0,0 +1,63 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_product_job(id: &str, location: (f64, f64), product: &str) -> Job {
+    Job { product: Some(product.to_string()), ..create_delivery_job(id, location) }
+}
+
+fn create_problem_with_compartments(reloads: Option<Vec<VehicleReload>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_product_job("job1", (1., 0.), "fuel"),
+                create_product_job("job2", (2., 0.), "diesel"),
+                create_product_job("job3", (3., 0.), "gas"),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { reloads, ..create_default_vehicle_shift() }],
+                capacity: vec![10],
+                compartments: Some(vec![
+                    VehicleCompartment { id: "c1".to_string(), capacity: 5, cleaning_duration: Some(3.) },
+                    VehicleCompartment { id: "c2".to_string(), capacity: 5, cleaning_duration: Some(3.) },
+                ]),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_skip_job_with_product_which_does_not_fit_into_compartments() {
+    let problem = create_problem_with_compartments(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.as_ref().map_or(0, |unassigned| unassigned.len()), 1);
+    let reason = solution.unassigned.iter().flatten().flat_map(|job| job.reasons.iter()).next().unwrap();
+    assert_eq!(reason.code, "COMPARTMENT_CONSTRAINT");
+}
+
+#[test]
+fn can_use_reload_to_change_compartment_product() {
+    let problem = create_problem_with_compartments(Some(vec![VehicleReload {
+        location: (0., 0.).to_loc(),
+        duration: 2.0,
+        ..create_default_reload()
+    }]));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let stops = solution.tours[0].stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>();
+    assert!(stops.iter().all(|stop| stop.compartments.as_ref().map_or(false, |usage| usage.len() == 2)));
+    assert!(stops.iter().flat_map(|stop| stop.activities.iter()).any(|activity| activity.activity_type == "reload"));
+}
@@ -0,0 +1,71 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem(distance_loaded: Option<f64>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("light", (0., 10.), vec![1]),
+                create_delivery_job_with_demand("heavy", (10., 0.), vec![9]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity: vec![10],
+                costs: VehicleCosts {
+                    fixed: None,
+                    distance: 1.,
+                    time: 0.,
+                    distance_loaded,
+                    time_tiers: None,
+                    distance_tiers: None,
+                },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_job_ids(solution: &Solution) -> Vec<String> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.clone())
+        .collect()
+}
+
+#[test]
+fn can_deliver_heavy_job_first_with_load_dependent_costs() {
+    let problem = create_problem(Some(10.));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_job_ids(&solution), vec!["heavy".to_string(), "light".to_string()]);
+
+    let statistic = &solution.tours[0].statistic;
+    let distance = statistic.distance as f64;
+    let load_cost = statistic.load_cost.expect("load cost should be reported");
+    assert!(load_cost > 0.);
+    assert!((statistic.cost - distance - load_cost).abs() < 1E-6);
+    assert_eq!(solution.statistic.load_cost, Some(load_cost));
+}
+
+#[test]
+fn can_omit_load_cost_without_load_dependent_costs() {
+    let problem = create_problem(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.statistic.load_cost, None);
+}
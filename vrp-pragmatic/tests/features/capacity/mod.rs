@@ -1 +1,4 @@
+mod compartments_test;
+mod load_cost_test;
 mod simple_capacity_test;
+mod split_delivery_test;
@@ -0,0 +1,119 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_split_delivery_job(id: &str, location: (f64, f64), demand: i32, min_split: Option<i32>) -> Job {
+    let job = create_delivery_job_with_demand(id, location, vec![demand]);
+
+    Job {
+        deliveries: job.deliveries.map(|tasks| {
+            tasks
+                .into_iter()
+                .map(|task| JobTask { splittable: Some(true), min_split: min_split.map(|value| vec![value]), ..task })
+                .collect()
+        }),
+        ..job
+    }
+}
+
+fn create_test_problem(job: Job, vehicle_ids: Vec<&str>) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vehicle_ids.into_iter().map(|id| id.to_string()).collect(),
+                capacity: vec![10],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_quantities(solution: &Solution) -> Vec<Vec<i32>> {
+    let mut quantities = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().flat_map(|stop| stop.activities().iter()))
+        .filter_map(|activity| activity.quantity.clone())
+        .collect::<Vec<_>>();
+    quantities.sort();
+
+    quantities
+}
+
+#[test]
+fn can_split_delivery_between_vehicles() {
+    let problem = create_test_problem(create_split_delivery_job("job1", (1., 0.), 15, None), vec!["v1", "v2"]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert_eq!(get_quantities(&solution), vec![vec![7], vec![8]]);
+    let split_jobs = solution.split_jobs.expect("split jobs");
+    assert_eq!(split_jobs.len(), 1);
+    assert_eq!(split_jobs[0].job_id, "job1");
+    assert_eq!(split_jobs[0].served, vec![15]);
+    assert_eq!(split_jobs[0].unserved, vec![0]);
+    assert_eq!(split_jobs[0].visits.len(), 2);
+}
+
+#[test]
+fn can_unassign_split_delivery_when_not_all_parts_can_be_served() {
+    let problem = create_test_problem(create_split_delivery_job("job1", (1., 0.), 15, None), vec!["v1"]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert!(get_quantities(&solution).is_empty());
+    let unassigned = solution.unassigned.expect("unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job1");
+    let split_jobs = solution.split_jobs.expect("split jobs");
+    assert_eq!(split_jobs[0].served, vec![0]);
+    assert_eq!(split_jobs[0].unserved, vec![15]);
+}
+
+#[test]
+fn can_respect_min_split_quantity() {
+    let problem = create_test_problem(create_split_delivery_job("job1", (1., 0.), 15, Some(10)), vec!["v1", "v2"]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert!(solution.split_jobs.is_none());
+    assert_eq!(
+        solution.unassigned.map(|unassigned| unassigned[0].reasons[0].code.clone()),
+        Some("CAPACITY_CONSTRAINT".to_string())
+    );
+}
+
+#[test]
+fn can_split_multi_dimensional_delivery_with_small_dimension() {
+    let job = create_delivery_job_with_demand("job1", (1., 0.), vec![10, 1]);
+    let job = Job {
+        deliveries: job.deliveries.map(|tasks| {
+            tasks
+                .into_iter()
+                .map(|task| JobTask { splittable: Some(true), min_split: Some(vec![2, 1]), ..task })
+                .collect()
+        }),
+        ..job
+    };
+    let mut problem = create_test_problem(job, vec!["v1", "v2"]);
+    problem.fleet.vehicles[0].capacity = vec![5, 5];
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_quantities(&solution), vec![vec![5, 0], vec![5, 1]]);
+    let split_jobs = solution.split_jobs.expect("split jobs");
+    assert_eq!(split_jobs[0].served, vec![10, 1]);
+    assert_eq!(split_jobs[0].unserved, vec![0, 0]);
+}
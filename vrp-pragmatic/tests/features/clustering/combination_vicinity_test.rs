@@ -30,8 +30,9 @@ fn can_handle_job_in_relation_with_vicinity_cluster_impl(type_field: RelationTyp
             relations: Some(vec![Relation {
                 type_field,
                 jobs: vec!["departure".to_string(), "job1".to_string()],
-                vehicle_id: "my_vehicle_1".to_string(),
+                vehicle_id: Some("my_vehicle_1".to_string()),
                 shift_index: None,
+                offset: None,
             }]),
             ..create_empty_plan()
         },
@@ -45,6 +45,12 @@ impl From<ActivityData> for Activity {
                 forward: convert_expected_commute_info(fwd),
                 backward: convert_expected_commute_info(bak),
             }),
+            earliness: None,
+            lateness: None,
+            ride_duration: None,
+            time_in_vehicle: None,
+            quantity: None,
+            queueing: None,
         }
     }
 }
@@ -82,7 +88,10 @@ impl From<StopData> for Stop {
             } else {
                 None
             },
+            setup: None,
             load: vec![stop.load],
+            compartments: None,
+            state_of_charge: None,
             activities: stop.activities.into_iter().map(ActivityData::into).collect(),
         })
     }
@@ -100,6 +109,9 @@ fn create_statistic(data: (f64, i64, i64, (i64, i64, i64, i64))) -> Statistic {
             parking: data.3 .3,
             ..Timing::default()
         },
+        fees: None,
+        load_cost: None,
+        tier_cost: None,
     }
 }
 
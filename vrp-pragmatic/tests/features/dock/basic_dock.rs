@@ -0,0 +1,85 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_dock_job(id: &str, times: Vec<(i32, i32)>) -> Job {
+    let mut job = create_delivery_job_with_times(id, (5., 0.), times, 10.);
+    job.deliveries.as_mut().unwrap()[0].places[0].dock = Some("dock1".to_string());
+
+    job
+}
+
+fn create_problem(slots: usize, times: Vec<(i32, i32)>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_dock_job("job1", times.clone()), create_dock_job("job2", times)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![1],
+                // NOTE fix departure time to avoid waiting being compensated by later departure
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            resources: Some(vec![VehicleResource::Dock { id: "dock1".to_string(), slots }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_queueing(solution: &Solution) -> Vec<i64> {
+    let mut queueing = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.job_id.starts_with("job"))
+        .map(|activity| activity.queueing.unwrap_or(0))
+        .collect::<Vec<_>>();
+    queueing.sort_unstable();
+
+    queueing
+}
+
+parameterized_test! {can_queue_vehicles_at_dock, (slots, expected_queueing), {
+    can_queue_vehicles_at_dock_impl(slots, expected_queueing);
+}}
+
+can_queue_vehicles_at_dock! {
+    case01_single_slot: (1, vec![0, 10]),
+    case02_two_slots: (2, vec![0, 0]),
+}
+
+fn can_queue_vehicles_at_dock_impl(slots: usize, expected_queueing: Vec<i64>) {
+    let problem = create_problem(slots, vec![]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert_eq!(get_queueing(&solution), expected_queueing);
+}
+
+#[test]
+fn can_keep_job_unassigned_when_dock_is_busy() {
+    let problem = create_problem(1, vec![(0, 10)]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.iter().flatten().count(), 1);
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_queueing(&solution), vec![0]);
+}
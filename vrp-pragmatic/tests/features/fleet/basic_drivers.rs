@@ -0,0 +1,116 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_driver_type(driver_ids: Vec<&str>, skills: Option<Vec<String>>) -> DriverType {
+    DriverType {
+        type_id: "driver".to_string(),
+        driver_ids: driver_ids.into_iter().map(|id| id.to_string()).collect(),
+        costs: DriverCosts { fixed: None, distance: 0., time: 1. },
+        shifts: vec![DriverShift { start: format_time(0.), end: format_time(1000.) }],
+        skills,
+        vehicle_type_ids: None,
+    }
+}
+
+#[test]
+fn can_use_only_one_vehicle_with_single_driver() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (-10., 0.)),
+                create_delivery_job("job3", (0., 10.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            drivers: Some(vec![create_driver_type(vec!["driver_1"], None)]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].driver_id, Some("driver_1".to_string()));
+}
+
+#[test]
+fn can_use_driver_skills_to_serve_job() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_skills("job1", (10., 0.), all_of_skills(vec!["hazmat".to_string()]))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            drivers: Some(vec![
+                create_driver_type(vec!["driver_1"], None),
+                DriverType {
+                    type_id: "certified".to_string(),
+                    ..create_driver_type(vec!["driver_2"], Some(vec!["hazmat".to_string()]))
+                },
+            ]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].driver_id, Some("driver_2".to_string()));
+}
+
+#[test]
+fn can_use_only_vehicle_types_allowed_for_driver() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_skills("job1", (10., 0.), all_of_skills(vec!["hazmat".to_string()]))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    costs: VehicleCosts { fixed: Some(1.), ..create_default_vehicle_costs() },
+                    ..create_default_vehicle("cheap")
+                },
+                VehicleType {
+                    costs: VehicleCosts { fixed: Some(100.), ..create_default_vehicle_costs() },
+                    ..create_default_vehicle("truck")
+                },
+            ],
+            drivers: Some(vec![
+                DriverType {
+                    type_id: "truck_driver".to_string(),
+                    vehicle_type_ids: Some(vec!["truck".to_string()]),
+                    ..create_driver_type(vec!["driver_1"], Some(vec!["hazmat".to_string()]))
+                },
+                DriverType {
+                    type_id: "cheap_driver".to_string(),
+                    vehicle_type_ids: Some(vec!["cheap".to_string()]),
+                    ..create_driver_type(vec!["driver_2"], None)
+                },
+            ]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, "truck_1");
+    assert_eq!(solution.tours[0].driver_id, Some("driver_1".to_string()));
+}
@@ -0,0 +1,52 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem(time_tiers: Option<Vec<VehicleCostTier>>, distance_tiers: Option<Vec<VehicleCostTier>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (-10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                costs: VehicleCosts { time_tiers, distance_tiers, ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_report_time_tier_costs() {
+    let problem = create_problem(Some(vec![VehicleCostTier { threshold: 30., cost: 2. }]), None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let statistic = &solution.tours[0].statistic;
+    assert_eq!(statistic.duration, 42);
+    assert_eq!(statistic.tier_cost, Some(TierCost { time: 12., distance: 0. }));
+    assert_eq!(statistic.cost, 10. + 40. + 42. + 12.);
+    assert_eq!(solution.statistic.tier_cost, statistic.tier_cost);
+}
+
+#[test]
+fn can_split_tours_with_expensive_distance_tier() {
+    let problem = Problem {
+        objectives: Some(vec![vec![Objective::MinimizeUnassigned { breaks: None }], vec![Objective::MinimizeCost]]),
+        ..create_problem(None, Some(vec![VehicleCostTier { threshold: 30., cost: 10. }]))
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert!(solution.tours.iter().all(|tour| tour.statistic.tier_cost == Some(TierCost { time: 0., distance: 0. })));
+}
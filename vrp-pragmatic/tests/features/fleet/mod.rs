@@ -1,5 +1,7 @@
+mod basic_drivers;
 mod basic_multi_shift;
 mod basic_open_end;
+mod cost_tiers;
 mod multi_dimens;
 mod profile_variation;
 mod unreachable_jobs;
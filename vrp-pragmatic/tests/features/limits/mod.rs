@@ -1,3 +1,5 @@
 mod max_distance;
 mod max_duration;
+mod time_in_vehicle;
 mod tour_size;
+mod trip_limits;
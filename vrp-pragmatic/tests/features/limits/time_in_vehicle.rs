@@ -0,0 +1,88 @@
+use crate::format::problem::*;
+use crate::format::solution::Activity;
+use crate::helpers::*;
+
+fn create_delivery_job_with_limit(id: &str, location: (f64, f64), max_time_in_vehicle: f64) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            max_time_in_vehicle: Some(max_time_in_vehicle),
+            ..create_task(location, None)
+        }]),
+        ..create_job(id)
+    }
+}
+
+fn create_test_problem(jobs: Vec<Job>, capacity: i32, reloads: Option<Vec<VehicleReload>>) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { reloads, ..create_default_vehicle_shift() }],
+                capacity: vec![capacity],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_deliveries(solution: &crate::format::solution::Solution) -> Vec<&Activity> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .collect()
+}
+
+#[test]
+fn can_skip_job_because_of_max_time_in_vehicle() {
+    let problem = create_test_problem(
+        vec![
+            create_delivery_job_with_limit("job1", (1., 0.), 6.),
+            create_delivery_job_with_limit("job2", (5., 0.), 6.),
+            create_delivery_job_with_limit("job3", (10., 0.), 6.),
+        ],
+        10,
+        None,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.as_ref().expect("should have unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job3");
+    assert_eq!(unassigned[0].reasons[0].code, "TIME_IN_VEHICLE_CONSTRAINT");
+    assert_eq!(
+        get_deliveries(&solution)
+            .into_iter()
+            .map(|activity| (activity.job_id.as_str(), activity.time_in_vehicle))
+            .collect::<Vec<_>>(),
+        vec![("job1", Some(1)), ("job2", Some(6))]
+    );
+}
+
+#[test]
+fn can_measure_max_time_in_vehicle_from_reload() {
+    let problem = create_test_problem(
+        vec![
+            create_delivery_job_with_limit("job1", (5., 0.), 5.),
+            create_delivery_job_with_limit("job2", (5., 0.), 5.),
+        ],
+        1,
+        Some(vec![VehicleReload { location: (0., 0.).to_loc(), duration: 2.0, ..create_default_reload() }]),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_deliveries(&solution).into_iter().map(|activity| activity.time_in_vehicle).collect::<Vec<_>>(),
+        vec![Some(5), Some(5)]
+    );
+}
@@ -0,0 +1,73 @@
+use crate::format::problem::*;
+use crate::format::solution::Stop;
+use crate::helpers::*;
+
+fn create_test_problem(trip: VehicleTripLimits, reloads: Option<Vec<VehicleReload>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+                create_delivery_job("job4", (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { reloads, ..create_default_vehicle_shift() }],
+                capacity: vec![10],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    trip: Some(trip),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_trip_sizes(stops: &[Stop]) -> Vec<usize> {
+    stops.iter().flat_map(|stop| stop.activities().iter()).fold(vec![0], |mut acc, activity| {
+        match activity.activity_type.as_str() {
+            "reload" => acc.push(0),
+            "departure" | "arrival" => {}
+            _ => *acc.last_mut().unwrap() += 1,
+        }
+        acc
+    })
+}
+
+#[test]
+fn can_split_tour_into_trips_by_trip_size() {
+    let problem = create_test_problem(
+        VehicleTripLimits { max_distance: None, max_duration: None, size: Some(2) },
+        Some(vec![VehicleReload { location: (0., 0.).to_loc(), duration: 2.0, ..create_default_reload() }]),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_trip_sizes(&solution.tours[0].stops), vec![2, 2]);
+}
+
+#[test]
+fn can_skip_jobs_because_of_trip_distance_without_reloads() {
+    let problem =
+        create_test_problem(VehicleTripLimits { max_distance: Some(5.), max_duration: None, size: None }, None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.expect("should have unassigned jobs");
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_trip_sizes(&solution.tours[0].stops), vec![2]);
+    assert_eq!(unassigned.len(), 2);
+    assert!(unassigned.iter().all(|job| job.reasons.iter().any(|reason| reason.code == "TRIP_LIMIT_CONSTRAINT")));
+}
@@ -1,22 +1,29 @@
 //! This module contains feature tests: minimalistic tests which check features in isolation
 //! and their combination.
 
+mod alternatives;
 mod breaks;
 mod capacity;
 mod clustering;
 mod compatibility;
+mod dock;
 mod fleet;
 mod format;
 mod group;
 mod limits;
 mod multjob;
+mod periodic;
 mod pickdev;
 mod priorities;
 mod recharge;
 mod relations;
 mod reload;
+mod satellite;
 mod skills;
 mod timing;
 mod tour_shape;
+mod trailer;
+mod transfer;
 mod unassigned;
 mod work_balance;
+mod zones;
@@ -34,6 +34,9 @@ fn can_handle_limited_capacity() {
             distance: 36,
             duration: 42,
             times: Timing { driving: 36, serving: 6, ..Timing::default() },
+            fees: None,
+            load_cost: None,
+            tier_cost: None,
         }
     );
     assert!(solution.unassigned.is_none());
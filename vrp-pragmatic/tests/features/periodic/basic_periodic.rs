@@ -0,0 +1,144 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+const DAY: f64 = 86400.;
+
+fn create_periodic_job(id: &str, location: (f64, f64), periodic: JobPeriodic) -> Job {
+    Job { periodic: Some(periodic), ..create_delivery_job(id, location) }
+}
+
+fn create_periodic(frequency: usize, max_frequency: Option<usize>, min_spacing: Option<usize>) -> JobPeriodic {
+    JobPeriodic { frequency, max_frequency, day_patterns: None, min_spacing, max_spacing: None }
+}
+
+fn create_daily_shifts_fleet(days: usize) -> Fleet {
+    Fleet {
+        vehicles: vec![VehicleType {
+            shifts: (0..days)
+                .map(|day| {
+                    let start = day as f64 * DAY;
+                    VehicleShift {
+                        start: ShiftStart { earliest: format_time(start), latest: None, location: (0., 0.).to_loc() },
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: format_time(start + 1000.),
+                            location: (0., 0.).to_loc(),
+                        }),
+                        ..create_default_vehicle_shift()
+                    }
+                })
+                .collect(),
+            ..create_default_vehicle_type()
+        }],
+        ..create_default_fleet()
+    }
+}
+
+fn get_visit_days(solution: &Solution, job_id: &str) -> Vec<usize> {
+    let mut days = solution
+        .days
+        .iter()
+        .flat_map(|days| days.iter())
+        .filter(|day| day.tours.iter().any(|tour| tour.job_ids.iter().any(|id| id == job_id)))
+        .map(|day| day.day)
+        .collect::<Vec<_>>();
+    days.sort_unstable();
+
+    days
+}
+
+#[test]
+fn can_visit_periodic_jobs_on_different_days() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_periodic_job("job1", (1., 0.), create_periodic(2, None, Some(2))),
+                create_periodic_job(
+                    "job2",
+                    (2., 0.),
+                    JobPeriodic { day_patterns: Some(vec![vec![0, 2, 4]]), ..create_periodic(3, None, None) },
+                ),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_daily_shifts_fleet(5),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let job1_days = get_visit_days(&solution, "job1");
+    assert_eq!(job1_days.len(), 2);
+    assert!(job1_days[1] - job1_days[0] >= 2);
+    assert_eq!(get_visit_days(&solution, "job2"), vec![0, 2, 4]);
+    assert_eq!(get_visit_days(&solution, "job3").len(), 1);
+}
+
+#[test]
+fn can_skip_optional_visits_of_periodic_job() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_periodic_job("job1", (1., 0.), create_periodic(2, Some(5), Some(2)))],
+            ..create_empty_plan()
+        },
+        fleet: create_daily_shifts_fleet(5),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_visit_days(&solution, "job1"), vec![0, 2, 4]);
+}
+
+#[test]
+fn can_report_periodic_job_as_unassigned_when_frequency_is_not_met() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_periodic_job("job1", (1., 0.), create_periodic(3, None, Some(3)))],
+            ..create_empty_plan()
+        },
+        fleet: create_daily_shifts_fleet(5),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.as_ref().expect("unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job1");
+    assert_eq!(unassigned[0].reasons[0].code, "PERIODIC_CONSTRAINT");
+    assert!(get_visit_days(&solution, "job1").is_empty());
+}
+
+#[test]
+fn can_visit_periodic_job_on_all_days_of_single_pattern() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_periodic_job(
+                "job1",
+                (1., 0.),
+                JobPeriodic {
+                    day_patterns: Some(vec![vec![0, 2, 4], vec![1, 3]]),
+                    ..create_periodic(2, Some(3), None)
+                },
+            )],
+            ..create_empty_plan()
+        },
+        fleet: create_daily_shifts_fleet(4),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_visit_days(&solution, "job1"), vec![1, 3]);
+}
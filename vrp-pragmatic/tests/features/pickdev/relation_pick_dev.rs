@@ -12,8 +12,9 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
             relations: Some(vec![Relation {
                 type_field: RelationType::Sequence,
                 jobs: to_strings(vec!["job1", "job2", "job1", "job2"]),
-                vehicle_id: "my_vehicle_1".to_string(),
+                vehicle_id: Some("my_vehicle_1".to_string()),
                 shift_index: None,
+                offset: None,
             }]),
             ..create_empty_plan()
         },
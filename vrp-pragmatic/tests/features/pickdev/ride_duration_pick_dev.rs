@@ -0,0 +1,72 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn get_ride_durations(solution: &Solution) -> Vec<(String, i64)> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter_map(|activity| activity.ride_duration.map(|duration| (activity.job_id.clone(), duration)))
+        .collect()
+}
+
+parameterized_test! {can_limit_ride_duration_of_pickup_delivery_job, (max_ride_duration, max_ride_factor), {
+    can_limit_ride_duration_of_pickup_delivery_job_impl(max_ride_duration, max_ride_factor);
+}}
+
+can_limit_ride_duration_of_pickup_delivery_job! {
+    case01_max_duration: (Some(10.), None),
+    case02_max_factor: (None, Some(1.2)),
+}
+
+fn can_limit_ride_duration_of_pickup_delivery_job_impl(max_ride_duration: Option<f64>, max_ride_factor: Option<f64>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { max_ride_duration, max_ride_factor, ..create_pickup_delivery_job("job1", (1., 0.), (10., 0.)) },
+                create_pickup_delivery_job("job2", (2., 0.), (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let ride_durations = get_ride_durations(&solution);
+    assert_eq!(ride_durations.len(), 1);
+    assert_eq!(ride_durations[0].0, "job1");
+    assert!(ride_durations[0].1 <= 10);
+}
+
+#[test]
+fn can_keep_job_unassigned_when_ride_duration_is_too_strict() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { max_ride_duration: Some(5.), ..create_pickup_delivery_job("job1", (1., 0.), (10., 0.)) }],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution
+            .unassigned
+            .iter()
+            .flatten()
+            .flat_map(|job| job.reasons.iter())
+            .map(|r| r.code.clone())
+            .collect::<Vec<_>>(),
+        vec!["RIDE_DURATION_CONSTRAINT".to_string()]
+    );
+}
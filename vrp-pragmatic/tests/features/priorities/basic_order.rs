@@ -15,7 +15,7 @@ fn create_test_plan_with_three_jobs() -> Plan {
 }
 
 fn create_test_limit() -> Option<VehicleLimits> {
-    Some(VehicleLimits { max_distance: Some(15.), max_duration: None, tour_size: None })
+    Some(VehicleLimits { max_distance: Some(15.), max_duration: None, tour_size: None, trip: None })
 }
 
 fn create_order_objective(is_constrained: bool) -> Vec<Vec<Objective>> {
@@ -147,9 +147,21 @@ fn can_follow_order_when_prioritized_property_set() {
 fn can_handle_order_between_special_activities() {
     let create_test_job = |id: &str, location: (f64, f64), order: i32| Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: location.to_loc(), duration: 100., tag: None }],
+            places: vec![JobPlace {
+                times: None,
+                location: location.to_loc(),
+                duration: 100.,
+                setup: None,
+                duration_rules: None,
+                tag: None,
+                tolerance: None,
+                dock: None,
+            }],
             demand: Some(vec![1]),
             order: Some(order),
+            splittable: None,
+            min_split: None,
+            max_time_in_vehicle: None,
         }]),
         ..create_job(id)
     };
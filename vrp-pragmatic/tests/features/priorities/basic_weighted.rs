@@ -0,0 +1,77 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn weighted(objectives: Vec<(f64, Objective)>) -> Objective {
+    Weighted {
+        objectives: objectives.into_iter().map(|(weight, objective)| WeightedObjective { weight, objective }).collect(),
+    }
+}
+
+#[test]
+fn can_use_weighted_objective() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![vec![weighted(vec![
+            (1000., MinimizeUnassigned { breaks: None }),
+            (50., MinimizeTours),
+            (1., MinimizeCost),
+        ])]]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.distance, 4);
+}
+
+#[test]
+fn can_use_tour_order_as_soft_constraint_within_weighted_objective() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_order("job1", (2., 0.), 2),
+                create_delivery_job_with_order("job2", (5., 0.), 1),
+                create_delivery_job("job3", (7., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: Some(15.),
+                    max_duration: None,
+                    tour_size: None,
+                    trip: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![vec![weighted(vec![
+            (1000., MinimizeUnassigned { breaks: None }),
+            (10., TourOrder),
+            (1., MinimizeCost),
+        ])]]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.statistic.distance, 14);
+}
@@ -0,0 +1,60 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+#[test]
+fn can_charge_battery_partially_with_power() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (30., 0.)), create_delivery_job("job2", (70., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    recharges: Some(VehicleRecharges {
+                        max_distance: None,
+                        battery: Some(VehicleBattery {
+                            capacity: 100.,
+                            initial: Some(60.),
+                            reserve: Some(5.),
+                            consumption: 1.,
+                            consumption_loaded: None,
+                        }),
+                        stations: vec![VehicleRechargeStation {
+                            location: (50., 0.).to_loc(),
+                            duration: 0.0,
+                            times: None,
+                            tag: None,
+                            power: Some(1.),
+                            dock: None,
+                        }],
+                    }),
+                    ..create_default_vehicle_shift_with_locations((0., 0.), (100., 0.))
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![vec!["departure"], vec!["job1"], vec!["recharge"], vec!["job2"], vec!["arrival"]]
+    );
+    assert_eq!(
+        solution.tours[0]
+            .stops
+            .iter()
+            .filter_map(|stop| stop.as_point())
+            .map(|stop| stop.state_of_charge)
+            .collect::<Vec<_>>(),
+        vec![Some(60.), Some(30.), Some(55.), Some(35.), Some(5.)]
+    );
+    let schedule = solution.tours[0].stops[2].schedule();
+    assert_eq!(parse_time(&schedule.departure) - parse_time(&schedule.arrival), 45.);
+}
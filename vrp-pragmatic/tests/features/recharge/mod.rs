@@ -1 +1,2 @@
 mod basic_recharge;
+mod battery_recharge;
@@ -10,8 +10,9 @@ fn can_skip_constraints_check() {
             relations: Some(vec![Relation {
                 type_field: RelationType::Any,
                 jobs: to_strings(vec!["departure", "job1", "job2"]),
-                vehicle_id: "my_vehicle_1".to_string(),
+                vehicle_id: Some("my_vehicle_1".to_string()),
                 shift_index: None,
+                offset: None,
             }]),
             ..create_empty_plan()
         },
@@ -13,8 +13,9 @@ fn create_and_solve_problem_with_three_jobs(any_relation_jobs: Vec<String>) -> S
             relations: Some(vec![Relation {
                 type_field: RelationType::Any,
                 jobs: any_relation_jobs,
-                vehicle_id: "my_vehicle_1".to_string(),
+                vehicle_id: Some("my_vehicle_1".to_string()),
                 shift_index: None,
+                offset: None,
             }]),
             ..create_empty_plan()
         },
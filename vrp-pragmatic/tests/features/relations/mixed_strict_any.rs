@@ -18,14 +18,16 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                 Relation {
                     type_field: RelationType::Strict,
                     jobs: to_strings(vec!["departure", "job4", "job2", "job6"]),
-                    vehicle_id: "my_vehicle_1".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string()),
                     shift_index: None,
+                    offset: None,
                 },
                 Relation {
                     type_field: RelationType::Any,
                     jobs: to_strings(vec!["job1", "job3"]),
-                    vehicle_id: "my_vehicle_1".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string()),
                     shift_index: None,
+                    offset: None,
                 },
             ]),
             ..create_empty_plan()
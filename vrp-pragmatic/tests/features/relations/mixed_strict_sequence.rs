@@ -18,14 +18,16 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                 Relation {
                     type_field: RelationType::Strict,
                     jobs: to_strings(vec!["departure", "job4", "job2", "job6"]),
-                    vehicle_id: "my_vehicle_1".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string()),
                     shift_index: None,
+                    offset: None,
                 },
                 Relation {
                     type_field: RelationType::Sequence,
                     jobs: to_strings(vec!["job1", "job3"]),
-                    vehicle_id: "my_vehicle_1".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string()),
                     shift_index: None,
+                    offset: None,
                 },
             ]),
             ..create_empty_plan()
@@ -122,26 +124,30 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                 Relation {
                     type_field: RelationType::Strict,
                     jobs: to_strings(vec!["departure", "job1", "job6"]),
-                    vehicle_id: "my_vehicle_1".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string()),
                     shift_index: None,
+                    offset: None,
                 },
                 Relation {
                     type_field: RelationType::Sequence,
                     jobs: to_strings(vec!["job3", "job7"]),
-                    vehicle_id: "my_vehicle_1".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string()),
                     shift_index: None,
+                    offset: None,
                 },
                 Relation {
                     type_field: RelationType::Strict,
                     jobs: to_strings(vec!["departure", "job2", "job8"]),
-                    vehicle_id: "my_vehicle_2".to_string(),
+                    vehicle_id: Some("my_vehicle_2".to_string()),
                     shift_index: None,
+                    offset: None,
                 },
                 Relation {
                     type_field: RelationType::Sequence,
                     jobs: to_strings(vec!["job4", "job5"]),
-                    vehicle_id: "my_vehicle_2".to_string(),
+                    vehicle_id: Some("my_vehicle_2".to_string()),
                     shift_index: None,
+                    offset: None,
                 },
             ]),
             ..create_empty_plan()
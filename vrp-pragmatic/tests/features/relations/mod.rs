@@ -5,3 +5,4 @@ mod mixed_strict_sequence;
 mod sequence_with_new_jobs;
 mod strict_with_new_jobs;
 mod strict_with_old_jobs;
+mod sync_precedence;
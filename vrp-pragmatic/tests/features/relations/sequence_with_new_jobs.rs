@@ -15,8 +15,9 @@ fn can_use_sequence_relation_with_strict_time_windows() {
             relations: Some(vec![Relation {
                 type_field: RelationType::Sequence,
                 jobs: to_strings(vec!["job5", "job4"]),
-                vehicle_id: "my_vehicle_1".to_string(),
+                vehicle_id: Some("my_vehicle_1".to_string()),
                 shift_index: None,
+                offset: None,
             }]),
             ..create_empty_plan()
         },
@@ -0,0 +1,113 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn create_schedule_relation(type_field: RelationType, jobs: Vec<&str>, offset: f64) -> Relation {
+    Relation { type_field, jobs: to_strings(jobs), vehicle_id: None, shift_index: None, offset: Some(offset) }
+}
+
+fn create_two_vehicles_fleet() -> Fleet {
+    Fleet {
+        vehicles: vec![VehicleType {
+            vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+            ..create_default_vehicle_type()
+        }],
+        ..create_default_fleet()
+    }
+}
+
+/// Returns vehicle id, service start and end of the job's activity.
+fn get_service_schedule(solution: &Solution, job_id: &str, duration: f64) -> (String, f64, f64) {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().map(move |stop| (tour, stop)))
+        .flat_map(|(tour, stop)| stop.activities().iter().map(move |activity| (tour, stop, activity)))
+        .find(|(_, _, activity)| activity.job_id == job_id)
+        .map(|(tour, stop, activity)| {
+            let departure = activity.time.as_ref().map_or(&stop.schedule().departure, |time| &time.end);
+            let departure = parse_time(departure);
+
+            (tour.vehicle_id.clone(), departure - duration, departure)
+        })
+        .unwrap_or_else(|| panic!("cannot find job '{job_id}' in solution"))
+}
+
+#[test]
+fn can_synchronize_jobs_served_by_different_vehicles() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (5., 0.), vec![], 10.),
+                create_delivery_job_with_times("job2", (5., 0.), vec![], 10.),
+                create_delivery_job_with_times("job3", (3., 0.), vec![], 1.),
+            ],
+            relations: Some(vec![create_schedule_relation(RelationType::Sync, vec!["job1", "job2"], 0.)]),
+            ..create_empty_plan()
+        },
+        fleet: create_two_vehicles_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let (first_vehicle, first_start, _) = get_service_schedule(&solution, "job1", 10.);
+    let (second_vehicle, second_start, _) = get_service_schedule(&solution, "job2", 10.);
+    assert_ne!(first_vehicle, second_vehicle);
+    assert_eq!(first_start, second_start);
+}
+
+#[test]
+fn can_serve_jobs_in_precedence_order_with_offset() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (5., 0.), vec![], 10.),
+                create_delivery_job_with_times("job2", (1., 0.), vec![], 0.),
+            ],
+            relations: Some(vec![create_schedule_relation(RelationType::Precedence, vec!["job1", "job2"], 10.)]),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let (_, _, first_end) = get_service_schedule(&solution, "job1", 10.);
+    let (_, second_start, _) = get_service_schedule(&solution, "job2", 0.);
+    assert!(second_start >= first_end + 10.);
+}
+
+#[test]
+fn can_keep_job_unassigned_when_sync_is_not_possible() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (5., 0.), vec![(0, 10)], 1.),
+                create_delivery_job_with_times("job2", (5., 0.), vec![(100, 200)], 1.),
+            ],
+            relations: Some(vec![create_schedule_relation(RelationType::Sync, vec!["job1", "job2"], 0.)]),
+            ..create_empty_plan()
+        },
+        fleet: create_two_vehicles_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let reasons = solution
+        .unassigned
+        .iter()
+        .flatten()
+        .flat_map(|job| job.reasons.iter())
+        .map(|reason| reason.code.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(reasons, vec!["SCHEDULE_RELATION_CONSTRAINT".to_string()]);
+}
@@ -66,7 +66,14 @@ fn can_properly_handle_load_without_capacity_violation() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: Some(20.0), distance: 0.002, time: 0.003 },
+                costs: VehicleCosts {
+                    fixed: Some(20.0),
+                    distance: 0.002,
+                    time: 0.003,
+                    distance_loaded: None,
+                    time_tiers: None,
+                    distance_tiers: None,
+                },
                 shifts: vec![VehicleShift {
                     reloads: Some(vec![
                         VehicleReload {
@@ -0,0 +1,72 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_supply_job(id: &str, demand: i32, times: Vec<(i32, i32)>) -> Job {
+    let mut job = create_delivery_job_with_times(id, (10., 0.), times, 1.);
+    job.deliveries.iter_mut().flatten().for_each(|task| task.demand = Some(vec![demand]));
+
+    Job { satellite_id: Some("sat1".to_string()), ..job }
+}
+
+fn create_test_problem(supply: Job, truck_capacity: i32) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![supply, create_delivery_job("job1", (12., 0.)), create_delivery_job("job2", (14., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_capacity("truck", vec![truck_capacity]),
+                VehicleType {
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    ..create_vehicle_with_capacity("bike", vec![10])
+                },
+            ],
+            resources: Some(vec![VehicleResource::Satellite { id: "sat1".to_string(), location: (10., 0.).to_loc() }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_tour_jobs(solution: &Solution, vehicle_id: &str) -> Vec<String> {
+    solution
+        .tours
+        .iter()
+        .filter(|tour| tour.vehicle_id == vehicle_id)
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.clone())
+        .collect()
+}
+
+#[test]
+fn can_load_second_level_vehicle_after_satellite_is_supplied() {
+    let problem = create_test_problem(create_supply_job("supply1", 2, vec![(50, 100)]), 2);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_tour_jobs(&solution, "truck_1"), vec!["supply1".to_string()]);
+    let mut bike_jobs = get_tour_jobs(&solution, "bike_1");
+    bike_jobs.sort();
+    assert_eq!(bike_jobs, vec!["job1".to_string(), "job2".to_string()]);
+    let bike_tour = solution.tours.iter().find(|tour| tour.vehicle_id == "bike_1").unwrap();
+    assert_eq!(bike_tour.stops.first().unwrap().schedule().departure, format_time(51.));
+}
+
+#[test]
+fn can_unassign_job_when_satellite_supply_is_not_enough() {
+    let problem = create_test_problem(create_supply_job("supply1", 1, vec![(0, 100)]), 1);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.iter().flatten().count(), 1);
+    assert_eq!(get_tour_jobs(&solution, "truck_1"), vec!["supply1".to_string()]);
+    assert_eq!(get_tour_jobs(&solution, "bike_1").len(), 1);
+}
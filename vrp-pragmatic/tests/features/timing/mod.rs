@@ -1,4 +1,7 @@
 mod basic_multiple_times;
 mod basic_waiting_time;
+mod setup_duration;
+mod soft_time_windows;
 mod strict_leads_to_unassigned;
 mod strict_split_into_two_tours;
+mod vehicle_service_duration;
@@ -0,0 +1,65 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_delivery_job_with_setup(id: &str, location: (f64, f64), times: Vec<(i32, i32)>, setup: f64) -> Job {
+    let mut job = create_delivery_job_with_times(id, location, times, 1.);
+    job.deliveries.as_mut().unwrap()[0].places[0].setup = Some(setup);
+
+    job
+}
+
+#[test]
+fn can_incur_setup_once_for_jobs_at_the_same_location() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_setup("job1", (5., 0.), vec![(0, 100)], 10.),
+                create_delivery_job_with_setup("job2", (5., 0.), vec![(0, 100)], 10.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let stops = solution.tours.first().unwrap().stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>();
+    let job_stops = stops.iter().filter(|stop| stop.activities.iter().any(|a| a.job_id.starts_with("job")));
+    assert_eq!(job_stops.count(), 1);
+    let stop = stops.iter().find(|stop| stop.setup.is_some()).expect("no stop with setup");
+    assert_eq!(stop.activities.len(), 2);
+    assert_eq!(stop.setup, Some(Interval { start: format_time(5.), end: format_time(15.) }));
+    assert_eq!(stop.activities[0].time.as_ref().map(|time| time.start.clone()), Some(format_time(15.)));
+    assert_eq!(stop.activities[1].time.as_ref().map(|time| time.start.clone()), Some(format_time(16.)));
+    assert_eq!(solution.statistic.times.serving, 12);
+}
+
+parameterized_test! {can_consider_setup_within_time_window, (setup, expected_unassigned), {
+    can_consider_setup_within_time_window_impl(setup, expected_unassigned);
+}}
+
+can_consider_setup_within_time_window! {
+    case01_fits: (4., None),
+    case02_exceeds: (10., Some(1)),
+}
+
+fn can_consider_setup_within_time_window_impl(setup: f64, expected_unassigned: Option<usize>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_setup("job1", (5., 0.), vec![(0, 10)], setup)],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.map(|jobs| jobs.len()), expected_unassigned);
+}
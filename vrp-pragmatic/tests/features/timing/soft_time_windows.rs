@@ -0,0 +1,100 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_delivery_job_with_tolerance(
+    id: &str,
+    location: (f64, f64),
+    times: Vec<(i32, i32)>,
+    tolerance: JobPlaceTolerance,
+) -> Job {
+    let mut job = create_delivery_job_with_times(id, location, times, 1.);
+    job.deliveries.as_mut().unwrap()[0].places[0].tolerance = Some(tolerance);
+
+    job
+}
+
+fn get_activity<'a>(solution: &'a Solution, job_id: &str) -> &'a Activity {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .find(|activity| activity.job_id == job_id)
+        .expect("cannot find job activity")
+}
+
+#[test]
+fn can_serve_job_late_within_lateness_tolerance() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (10., 0.), vec![(0, 100)], 1.),
+                create_delivery_job_with_tolerance(
+                    "job2",
+                    (5., 0.),
+                    vec![(0, 2)],
+                    JobPlaceTolerance { earliness: None, lateness: Some(TimeTolerance { max: 10., penalty: 1. }) },
+                ),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let activity = get_activity(&solution, "job2");
+    assert_eq!(activity.lateness, Some(3));
+    assert_eq!(activity.earliness, None);
+}
+
+#[test]
+fn can_serve_job_early_within_earliness_tolerance() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_tolerance(
+                "job1",
+                (10., 0.),
+                vec![(15, 20)],
+                JobPlaceTolerance { earliness: Some(TimeTolerance { max: 5., penalty: 1. }), lateness: None },
+            )],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let activity = get_activity(&solution, "job1");
+    assert_eq!(activity.earliness, Some(5));
+    assert_eq!(activity.lateness, None);
+}
+
+#[test]
+fn can_have_unassigned_job_outside_of_tolerance() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_tolerance(
+                "job1",
+                (10., 0.),
+                vec![(0, 2)],
+                JobPlaceTolerance { earliness: None, lateness: Some(TimeTolerance { max: 5., penalty: 1. }) },
+            )],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.map(|jobs| jobs.len()), Some(1));
+}
@@ -0,0 +1,46 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_vehicle_dependent_service_duration, (type_id, skills, rule, expected), {
+    can_use_vehicle_dependent_service_duration_impl(type_id, skills, rule, expected);
+}}
+
+can_use_vehicle_dependent_service_duration! {
+    case01_type_multiplier: ("truck", None, (Some("truck"), None, Some(2.), None), 20),
+    case02_skill_value: ("van", Some("expert"), (None, Some("expert"), None, Some(3.)), 3),
+    case03_skill_multiplier: ("van", Some("expert"), (None, Some("expert"), Some(0.5), None), 5),
+    case04_missing_skill: ("van", None, (None, Some("expert"), None, Some(3.)), 10),
+}
+
+fn can_use_vehicle_dependent_service_duration_impl(
+    type_id: &str,
+    skills: Option<&str>,
+    rule: (Option<&str>, Option<&str>, Option<f64>, Option<f64>),
+    expected: i64,
+) {
+    let (rule_type, rule_skill, multiplier, value) = rule;
+    let mut job = create_delivery_job_with_duration("job1", (5., 0.), 10.);
+    job.deliveries.as_mut().unwrap()[0].places[0].duration_rules = Some(vec![JobPlaceDurationRule {
+        vehicle_types: rule_type.map(|id| vec![id.to_string()]),
+        skills: rule_skill.map(|skill| vec![skill.to_string()]),
+        multiplier,
+        value,
+    }]);
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                skills: skills.map(|skill| vec![skill.to_string()]),
+                ..create_default_vehicle(type_id)
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.statistic.times.serving, expected);
+}
@@ -0,0 +1,51 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_reference(tours: Vec<(&str, Vec<&str>)>) -> Vec<ReferenceTour> {
+    tours
+        .into_iter()
+        .map(|(vehicle_id, jobs)| ReferenceTour {
+            vehicle_id: vehicle_id.to_string(),
+            jobs: jobs.into_iter().map(|job| job.to_string()).collect(),
+        })
+        .collect()
+}
+
+#[test]
+fn can_keep_reference_plan() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![
+            vec![Objective::MinimizeUnassigned { breaks: None }],
+            vec![Objective::MinimizePlanChanges {
+                reference: create_reference(vec![("v1", vec!["job3", "job1"]), ("v2", vec!["job2"])]),
+                options: None,
+            }],
+            vec![Objective::MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let get_tour_ids = |vehicle_id: &str| {
+        solution.tours.iter().find(|tour| tour.vehicle_id == vehicle_id).map(get_ids_from_tour).unwrap_or_default()
+    };
+    assert_eq!(get_tour_ids("v1"), vec![vec!["departure"], vec!["job3"], vec!["job1"], vec!["arrival"]]);
+    assert_eq!(get_tour_ids("v2"), vec![vec!["departure"], vec!["job2"], vec!["arrival"]]);
+}
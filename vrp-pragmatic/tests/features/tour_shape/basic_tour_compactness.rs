@@ -23,8 +23,9 @@ fn can_compact_tour() {
             relations: Some(vec![Relation {
                 type_field: RelationType::Any,
                 jobs: vec!["job-4".to_string(), "job4".to_string()],
-                vehicle_id: "my_vehicle_1".to_string(),
+                vehicle_id: Some("my_vehicle_1".to_string()),
                 shift_index: None,
+                offset: None,
             }]),
             ..create_empty_plan()
         },
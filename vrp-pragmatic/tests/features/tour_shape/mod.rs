@@ -1 +1,2 @@
+mod basic_plan_stability;
 mod basic_tour_compactness;
@@ -0,0 +1,92 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_truck_only_job(id: &str, location: (f64, f64)) -> Job {
+    Job { truck_only: Some(true), ..create_delivery_job(id, location) }
+}
+
+fn create_test_problem(jobs: Vec<Job>, truck_capacity: i32, trailer_capacity: i32) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity: vec![truck_capacity],
+                trailer: Some(VehicleTrailer {
+                    capacity: vec![trailer_capacity],
+                    parks: vec![VehicleTrailerPark {
+                        location: (5., 0.).to_loc(),
+                        decouple_duration: 2.,
+                        couple_duration: 3.,
+                        tag: None,
+                    }],
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_activities(solution: &Solution) -> Vec<(String, String)> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| (activity.activity_type.clone(), activity.job_id.clone()))
+        .collect()
+}
+
+#[test]
+fn can_serve_truck_only_job_with_decoupled_trailer() {
+    let problem =
+        create_test_problem(vec![create_delivery_job("job1", (3., 0.)), create_truck_only_job("job2", (8., 0.))], 1, 9);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let activities = get_activities(&solution);
+    let decouple_idx = activities.iter().position(|(activity_type, _)| activity_type == "decouple").unwrap();
+    assert_eq!(
+        activities[decouple_idx..decouple_idx + 3].to_vec(),
+        vec![
+            ("decouple".to_string(), "decouple".to_string()),
+            ("delivery".to_string(), "job2".to_string()),
+            ("couple".to_string(), "couple".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn can_skip_truck_only_job_when_truck_capacity_is_exceeded() {
+    let problem = create_test_problem(
+        vec![create_truck_only_job("job1", (8., 0.)), create_truck_only_job("job2", (9., 0.))],
+        1,
+        9,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.as_ref().expect("should have unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].reasons[0].code, "TRAILER_CONSTRAINT");
+    assert_eq!(get_activities(&solution).iter().filter(|(activity_type, _)| activity_type == "decouple").count(), 1);
+}
+
+#[test]
+fn can_keep_trailer_when_there_are_no_truck_only_jobs() {
+    let problem =
+        create_test_problem(vec![create_delivery_job("job1", (3., 0.)), create_delivery_job("job2", (8., 0.))], 1, 9);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(get_activities(&solution)
+        .iter()
+        .all(|(activity_type, _)| activity_type != "decouple" && activity_type != "couple"));
+}
@@ -0,0 +1,109 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_job_with_transfer(id: &str, transfer: (f64, f64)) -> Job {
+    Job {
+        transfers: Some(vec![create_job_place(transfer, Some("hub".to_string()))]),
+        ..create_pickup_delivery_job(id, (1., 0.), (20., 0.))
+    }
+}
+
+fn create_vehicle_with_max_distance(id: &str, location: (f64, f64), max_distance: f64) -> VehicleType {
+    VehicleType {
+        shifts: vec![create_default_vehicle_shift_with_locations(location, location)],
+        limits: Some(VehicleLimits {
+            max_distance: Some(max_distance),
+            max_duration: None,
+            tour_size: None,
+            trip: None,
+        }),
+        ..create_default_vehicle(id)
+    }
+}
+
+fn create_test_problem(job: Job, max_distance: f64) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_max_distance("v1", (0., 0.), max_distance),
+                create_vehicle_with_max_distance("v2", (20., 0.), max_distance),
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_tour_activities(solution: &Solution) -> Vec<(String, Vec<(String, String)>)> {
+    let mut activities = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let activities = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .filter(|activity| activity.job_id == "job1")
+                .map(|activity| (activity.activity_type.clone(), activity.job_tag.clone().unwrap_or_default()))
+                .collect();
+
+            (tour.vehicle_id.clone(), activities)
+        })
+        .collect::<Vec<_>>();
+    activities.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    activities
+}
+
+#[test]
+fn can_transfer_shipment_between_vehicles() {
+    let problem = create_test_problem(create_job_with_transfer("job1", (10., 0.)), 20.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_tour_activities(&solution),
+        vec![
+            (
+                "v1_1".to_string(),
+                vec![("pickup".to_string(), "p1".to_string()), ("delivery".to_string(), "hub".to_string())]
+            ),
+            (
+                "v2_1".to_string(),
+                vec![("pickup".to_string(), "hub".to_string()), ("delivery".to_string(), "d1".to_string())]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn can_serve_shipment_directly_when_feasible() {
+    let problem = create_test_problem(create_job_with_transfer("job1", (10., 0.)), 100.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_tour_activities(&solution)[0].1,
+        vec![("pickup".to_string(), "p1".to_string()), ("delivery".to_string(), "d1".to_string())]
+    );
+}
+
+#[test]
+fn can_unassign_shipment_when_transfer_is_not_feasible() {
+    let problem = create_test_problem(create_job_with_transfer("job1", (10., 0.)), 15.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.expect("expected unassigned job");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job1");
+    assert!(solution.tours.is_empty());
+}
@@ -1,3 +1,4 @@
 mod balance_activities;
 mod balance_max_load;
 mod balance_transport;
+mod minimize_makespan;
@@ -0,0 +1,120 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_zone(vehicle_type_ids: Option<Vec<&str>>, fee: Option<f64>) -> Zone {
+    Zone {
+        id: "zone1".to_string(),
+        polygon: vec![(4., -1.), (4., 1.), (6., 1.), (6., -1.)].into_iter().map(|coord| coord.to_loc()).collect(),
+        vehicle_type_ids: vehicle_type_ids.map(|ids| ids.into_iter().map(|id| id.to_string()).collect()),
+        fee,
+    }
+}
+
+fn create_problem(zone: Zone, vehicles: Vec<VehicleType>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (2., 0.))],
+            zones: Some(vec![zone]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles, ..create_default_fleet() },
+        ..create_empty_problem()
+    }
+}
+
+fn get_served_jobs(solution: &Solution, type_id: &str) -> Vec<String> {
+    let mut jobs = solution
+        .tours
+        .iter()
+        .filter(|tour| tour.type_id == type_id)
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.job_id.starts_with("job"))
+        .map(|activity| activity.job_id.clone())
+        .collect::<Vec<_>>();
+    jobs.sort();
+
+    jobs
+}
+
+#[test]
+fn can_serve_job_inside_zone_only_by_allowed_vehicle_type() {
+    let problem = create_problem(
+        create_zone(Some(vec!["small"]), None),
+        vec![VehicleType { capacity: vec![1], ..create_default_vehicle("small") }, create_default_vehicle("big")],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_served_jobs(&solution, "small"), vec!["job1".to_string()]);
+    assert_eq!(get_served_jobs(&solution, "big"), vec!["job2".to_string()]);
+}
+
+#[test]
+fn can_have_unassigned_due_to_zone_restriction() {
+    let problem = create_problem(create_zone(Some(vec![]), None), vec![create_default_vehicle_type()]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "ZONE_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to zone access restriction".to_string(),
+                details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }])
+            }]
+        }])
+    );
+    assert_eq!(get_served_jobs(&solution, "my_vehicle"), vec!["job2".to_string()]);
+}
+
+#[test]
+fn can_report_zone_entry_fee_once_per_tour() {
+    let mut problem = create_problem(create_zone(None, Some(100.)), vec![create_default_vehicle_type()]);
+    problem.plan.jobs.push(create_delivery_job("job3", (5., 0.5)));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].statistic.fees, Some(100.));
+    assert_eq!(solution.statistic.fees, Some(100.));
+    assert!(solution.statistic.cost > 100.);
+}
+
+#[test]
+fn can_ignore_zone_entry_fee_when_tour_passes_through_zone() {
+    let mut problem = create_problem(create_zone(None, Some(100.)), vec![create_default_vehicle_type()]);
+    problem.plan.jobs = vec![create_delivery_job("job1", (10., 0.))];
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].statistic.fees, None);
+    assert_eq!(solution.statistic.fees, None);
+}
+
+#[test]
+fn can_report_zone_entry_fee_without_minimize_cost_objective() {
+    let problem = Problem {
+        objectives: Some(vec![vec![Objective::MinimizeUnassigned { breaks: None }], vec![Objective::MinimizeDistance]]),
+        ..create_problem(create_zone(None, Some(100.)), vec![create_default_vehicle_type()])
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].statistic.fees, Some(100.));
+    assert_eq!(solution.statistic.fees, Some(100.));
+}
@@ -75,7 +75,7 @@ prop_compose! {
                         tag: Some("p1".to_owned()),
                         ..pickup
                     }
-                ], demand: demand.clone(), order }
+                ], demand: demand.clone(), order, splittable: None, min_split: None, max_time_in_vehicle: None }
             ]),
             deliveries: Some(vec![
              JobTask { places: vec![
@@ -83,14 +83,22 @@ prop_compose! {
                         tag: Some("d1".to_owned()),
                         ..delivery
                     }
-                ], demand, order: None }
+                ], demand, order: None, splittable: None, min_split: None, max_time_in_vehicle: None }
             ]),
             replacements: None,
             services: None,
             skills,
             value,
             group,
-            compatibility
+            compatibility,
+            max_ride_duration: None,
+            max_ride_factor: None,
+            product: None,
+            periodic: None,
+            alternatives: None,
+            truck_only: None,
+            transfers: None,
+            satellite_id: None,
         }
     }
 }
@@ -137,6 +145,14 @@ prop_compose! {
             value,
             group,
             compatibility,
+            max_ride_duration: None,
+            max_ride_factor: None,
+            product: None,
+            periodic: None,
+            alternatives: None,
+            truck_only: None,
+            transfers: None,
+            satellite_id: None,
         }
     }
 }
@@ -152,7 +168,7 @@ prop_compose! {
      demand in demand_proto,
      order in order_proto,
     ) -> JobTask {
-       JobTask { places: vec![place], demand, order }
+       JobTask { places: vec![place], demand, order, splittable: None, min_split: None, max_time_in_vehicle: None }
     }
 }
 
@@ -169,7 +185,7 @@ prop_compose! {
      times in time_windows,
      tag in tags
     ) -> JobPlace {
-      JobPlace { times, location, duration, tag }
+      JobPlace { times, location, duration, setup: None, duration_rules: None, tag, tolerance: None, dock: None }
     }
 }
 
@@ -48,7 +48,7 @@ fn generate_relation(
             let len = job_count.min(job_ids.read().unwrap().len());
             let jobs = if job_count > 0 { job_ids.write().unwrap().drain(0..len).collect::<Vec<_>>() } else { vec![] };
 
-            Relation { type_field: relation_type, jobs, vehicle_id, shift_index: None }
+            Relation { type_field: relation_type, jobs, vehicle_id: Some(vehicle_id), shift_index: None, offset: None }
         })
         // NOTE prop_filter behaves in strange way
         .prop_filter_map(
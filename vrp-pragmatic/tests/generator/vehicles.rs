@@ -33,6 +33,8 @@ prop_compose! {
             capacity,
             skills,
             limits,
+            compartments: None,
+            trailer: None,
         }
     }
 }
@@ -56,6 +58,7 @@ prop_compose! {
           duration,
           tag,
           resource_id: None,
+          dock: None,
         }
     }
 }
@@ -107,7 +110,7 @@ prop_compose! {
      vehicles in vehicles_proto,
      profiles in profiles_proto
     ) -> Fleet {
-        Fleet { vehicles, profiles, resources: None }
+        Fleet { vehicles, profiles, resources: None, drivers: None }
     }
 }
 
@@ -8,8 +8,16 @@ use vrp_core::models::solution::*;
 use vrp_core::models::*;
 use vrp_core::utils::DefaultRandom;
 
-const DEFAULT_VEHICLE_COSTS: Costs =
-    Costs { fixed: 100.0, per_distance: 1.0, per_driving_time: 1.0, per_waiting_time: 1.0, per_service_time: 1.0 };
+const DEFAULT_VEHICLE_COSTS: Costs = Costs {
+    fixed: 100.0,
+    per_distance: 1.0,
+    per_driving_time: 1.0,
+    per_waiting_time: 1.0,
+    per_service_time: 1.0,
+    per_distance_loaded: None,
+    time_tiers: vec![],
+    distance_tiers: vec![],
+};
 pub const DEFAULT_JOB_LOCATION: Location = 0;
 pub const DEFAULT_JOB_DURATION: Duration = 0.0;
 pub const DEFAULT_JOB_TIME_SPAN: TimeSpan = TimeSpan::Window(TimeWindow { start: 0., end: 1000. });
@@ -9,11 +9,27 @@ use vrp_core::models::problem::{TransportCost, TravelTime};
 use vrp_core::models::solution::Route;
 
 pub fn create_job_place(location: (f64, f64), tag: Option<String>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1., tag }
+    JobPlace {
+        times: None,
+        location: location.to_loc(),
+        duration: 1.,
+        setup: None,
+        duration_rules: None,
+        tag,
+        tolerance: None,
+        dock: None,
+    }
 }
 
 pub fn create_task(location: (f64, f64), tag: Option<String>) -> JobTask {
-    JobTask { places: vec![create_job_place(location, tag)], demand: Some(vec![1]), order: None }
+    JobTask {
+        places: vec![create_job_place(location, tag)],
+        demand: Some(vec![1]),
+        order: None,
+        splittable: None,
+        min_split: None,
+        max_time_in_vehicle: None,
+    }
 }
 
 pub fn create_job(id: &str) -> Job {
@@ -27,6 +43,14 @@ pub fn create_job(id: &str) -> Job {
         value: None,
         group: None,
         compatibility: None,
+        max_ride_duration: None,
+        max_ride_factor: None,
+        product: None,
+        periodic: None,
+        alternatives: None,
+        truck_only: None,
+        transfers: None,
+        satellite_id: None,
     }
 }
 
@@ -40,6 +64,9 @@ pub fn create_delivery_job_with_order(id: &str, location: (f64, f64), order: i32
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: Some(order),
+            splittable: None,
+            min_split: None,
+            max_time_in_vehicle: None,
         }]),
         ..create_job(id)
     }
@@ -51,6 +78,9 @@ pub fn create_delivery_job_with_group(id: &str, location: (f64, f64), group: &st
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: None,
+            splittable: None,
+            min_split: None,
+            max_time_in_vehicle: None,
         }]),
         group: Some(group.to_string()),
         ..create_job(id)
@@ -63,6 +93,9 @@ pub fn create_delivery_job_with_compatibility(id: &str, location: (f64, f64), co
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: None,
+            splittable: None,
+            min_split: None,
+            max_time_in_vehicle: None,
         }]),
         compatibility: Some(compatibility.to_string()),
         ..create_job(id)
@@ -83,6 +116,9 @@ pub fn create_delivery_job_with_duration(id: &str, location: (f64, f64), duratio
             places: vec![JobPlace { duration, ..create_job_place(location, None) }],
             demand: Some(vec![1]),
             order: None,
+            splittable: None,
+            min_split: None,
+            max_time_in_vehicle: None,
         }]),
         ..create_job(id)
     }
@@ -94,6 +130,9 @@ pub fn create_delivery_job_with_times(id: &str, location: (f64, f64), times: Vec
             places: vec![JobPlace { duration, times: convert_times(&times), ..create_job_place(location, None) }],
             demand: Some(vec![1]),
             order: None,
+            splittable: None,
+            min_split: None,
+            max_time_in_vehicle: None,
         }]),
         ..create_job(id)
     }
@@ -142,6 +181,9 @@ pub fn create_pickup_delivery_job_with_params(
             }],
             demand: Some(demand.clone()),
             order: None,
+            splittable: None,
+            min_split: None,
+            max_time_in_vehicle: None,
         }]),
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace {
@@ -151,6 +193,9 @@ pub fn create_pickup_delivery_job_with_params(
             }],
             demand: Some(demand),
             order: None,
+            splittable: None,
+            min_split: None,
+            max_time_in_vehicle: None,
         }]),
 
         ..create_job(id)
@@ -160,9 +205,21 @@ pub fn create_pickup_delivery_job_with_params(
 pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1., tag: None }],
+            places: vec![JobPlace {
+                times: None,
+                location: Location::Reference { index },
+                duration: 1.,
+                setup: None,
+                duration_rules: None,
+                tag: None,
+                tolerance: None,
+                dock: None,
+            }],
             demand: Some(vec![1]),
             order: None,
+            splittable: None,
+            min_split: None,
+            max_time_in_vehicle: None,
         }]),
         ..create_job(id)
     }
@@ -184,6 +241,9 @@ pub fn create_multi_job(
                 }],
                 demand: Some(demand),
                 order: None,
+                splittable: None,
+                min_split: None,
+                max_time_in_vehicle: None,
             })
             .collect::<Vec<_>>();
 
@@ -198,7 +258,7 @@ pub fn create_multi_job(
 }
 
 pub fn create_default_reload() -> VehicleReload {
-    VehicleReload { times: None, location: (0., 0.).to_loc(), duration: 2.0, tag: None, resource_id: None }
+    VehicleReload { times: None, location: (0., 0.).to_loc(), duration: 2.0, tag: None, resource_id: None, dock: None }
 }
 
 pub fn create_default_vehicle_shift() -> VehicleShift {
@@ -226,7 +286,14 @@ pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64,
 }
 
 pub fn create_default_vehicle_costs() -> VehicleCosts {
-    VehicleCosts { fixed: Some(10.), distance: 1., time: 1. }
+    VehicleCosts {
+        fixed: Some(10.),
+        distance: 1.,
+        time: 1.,
+        distance_loaded: None,
+        time_tiers: None,
+        distance_tiers: None,
+    }
 }
 
 pub fn create_default_vehicle_profile() -> VehicleProfile {
@@ -255,11 +322,18 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         capacity,
         skills: None,
         limits: None,
+        compartments: None,
+        trailer: None,
     }
 }
 
 pub fn create_default_fleet() -> Fleet {
-    Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles(), resources: None }
+    Fleet {
+        vehicles: vec![create_default_vehicle_type()],
+        profiles: create_default_matrix_profiles(),
+        resources: None,
+        drivers: None,
+    }
 }
 
 pub fn create_default_matrix_profiles() -> Vec<MatrixProfile> {
@@ -271,13 +345,13 @@ pub fn create_min_jobs_cost_objective() -> Option<Vec<Vec<Objective>>> {
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, clustering: None, zones: None }
 }
 
 pub fn create_empty_problem() -> Problem {
     Problem {
         plan: create_empty_plan(),
-        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None },
+        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None, drivers: None },
         objectives: None,
     }
 }
@@ -68,6 +68,31 @@ impl StopBuilder {
         self
     }
 
+    pub fn compartments(mut self, compartments: Vec<(&str, Option<&str>, i32)>) -> Self {
+        let mut stop = self.stop.to_point();
+        stop.compartments = Some(
+            compartments
+                .into_iter()
+                .map(|(id, product, load)| CompartmentLoad {
+                    id: id.to_string(),
+                    product: product.map(|product| product.to_string()),
+                    load,
+                })
+                .collect(),
+        );
+        self.stop = Stop::Point(stop);
+
+        self
+    }
+
+    pub fn state_of_charge(mut self, state_of_charge: f64) -> Self {
+        let mut stop = self.stop.to_point();
+        stop.state_of_charge = Some(state_of_charge);
+        self.stop = Stop::Point(stop);
+
+        self
+    }
+
     pub fn schedule_stamp(mut self, arrival: Timestamp, departure: Timestamp) -> Self {
         *self.stop.schedule_mut() = Schedule { arrival: format_time(arrival), departure: format_time(departure) };
 
@@ -169,7 +194,10 @@ impl Default for StopBuilder {
                 time: Schedule { arrival: format_time(0.), departure: format_time(0.) },
                 distance: 0,
                 load: vec![],
+                compartments: None,
+                state_of_charge: None,
                 parking: None,
+                setup: None,
                 activities: vec![],
             }),
         }
@@ -262,6 +290,12 @@ impl Default for ActivityBuilder {
                 time: None,
                 job_tag: None,
                 commute: None,
+                earliness: None,
+                lateness: None,
+                ride_duration: None,
+                time_in_vehicle: None,
+                quantity: None,
+                queueing: None,
             },
         }
     }
@@ -371,6 +405,7 @@ impl Default for TourBuilder {
                 shift_index: 0,
                 stops: vec![],
                 statistic: Default::default(),
+                driver_id: None,
             },
         }
     }
@@ -414,6 +449,8 @@ impl Default for SolutionBuilder {
                 statistic: Default::default(),
                 tours: vec![],
                 unassigned: None,
+                split_jobs: None,
+                days: None,
                 violations: None,
                 extras: None,
             },
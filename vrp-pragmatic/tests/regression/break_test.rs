@@ -17,14 +17,21 @@ fn can_handle_properly_invalid_break_removal() {
                             places: vec![JobPlace {
                                 location: Location::Coordinate { lat: 52.437842517427846, lng: 13.3829646081322 },
                                 duration: 1.0,
+                                setup: None,
+                                duration_rules: None,
                                 times: Some(vec![vec![
                                     "2020-07-04T09:00:00Z".to_string(),
                                     "2020-07-04T13:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                tolerance: None,
+                                dock: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            splittable: None,
+                            min_split: None,
+                            max_time_in_vehicle: None,
                         }]),
                         ..create_job("job1")
                     },
@@ -33,14 +40,21 @@ fn can_handle_properly_invalid_break_removal() {
                             places: vec![JobPlace {
                                 location: Location::Coordinate { lat: 52.504574435265766, lng: 13.512204487216097 },
                                 duration: 2.0,
+                                setup: None,
+                                duration_rules: None,
                                 times: Some(vec![vec![
                                     "2020-07-04T09:00:00Z".to_string(),
                                     "2020-07-04T11:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                tolerance: None,
+                                dock: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            splittable: None,
+                            min_split: None,
+                            max_time_in_vehicle: None,
                         }]),
                         ..create_job("job2")
                     },
@@ -49,14 +63,21 @@ fn can_handle_properly_invalid_break_removal() {
                             places: vec![JobPlace {
                                 location: Location::Coordinate { lat: 52.51627010959871, lng: 13.515165894434492 },
                                 duration: 3.0,
+                                setup: None,
+                                duration_rules: None,
                                 times: Some(vec![
                                     vec!["2020-07-04T09:00:00Z".to_string(), "2020-07-04T13:00:00Z".to_string()],
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                tolerance: None,
+                                dock: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            splittable: None,
+                            min_split: None,
+                            max_time_in_vehicle: None,
                         }]),
                         ..create_job("job3")
                     },
@@ -65,14 +86,21 @@ fn can_handle_properly_invalid_break_removal() {
                             places: vec![JobPlace {
                                 location: Location::Coordinate { lat: 52.49739587223939, lng: 13.499267072502096 },
                                 duration: 4.0,
+                                setup: None,
+                                duration_rules: None,
                                 times: Some(vec![vec![
                                     "2020-07-04T14:00:00Z".to_string(),
                                     "2020-07-04T16:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                tolerance: None,
+                                dock: None,
                             }],
                             demand: Some(vec![2]),
                             order: None,
+                            splittable: None,
+                            min_split: None,
+                            max_time_in_vehicle: None,
                         }]),
                         ..create_job("job4")
                     },
@@ -81,14 +109,21 @@ fn can_handle_properly_invalid_break_removal() {
                             places: vec![JobPlace {
                                 location: Location::Coordinate { lat: 52.47816437518683, lng: 13.480325156196248 },
                                 duration: 5.0,
+                                setup: None,
+                                duration_rules: None,
                                 times: Some(vec![
                                     vec!["2020-07-04T09:00:00Z".to_string(), "2020-07-04T11:00:00Z".to_string()],
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                tolerance: None,
+                                dock: None,
                             }],
                             demand: Some(vec![3]),
                             order: None,
+                            splittable: None,
+                            min_split: None,
+                            max_time_in_vehicle: None,
                         }]),
                         ..create_job("job5")
                     },
@@ -97,14 +132,21 @@ fn can_handle_properly_invalid_break_removal() {
                             places: vec![JobPlace {
                                 location: Location::Coordinate { lat: 52.44030727908021, lng: 13.433537947080476 },
                                 duration: 6.0,
+                                setup: None,
+                                duration_rules: None,
                                 times: Some(vec![vec![
                                     "2020-07-04T14:00:00Z".to_string(),
                                     "2020-07-04T18:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                tolerance: None,
+                                dock: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            splittable: None,
+                            min_split: None,
+                            max_time_in_vehicle: None,
                         }]),
                         ..create_job("job6")
                     },
@@ -116,7 +158,14 @@ fn can_handle_properly_invalid_break_removal() {
                     type_id: "vehicle1".to_string(),
                     vehicle_ids: vec!["vehicle1_1".to_string()],
                     profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-                    costs: VehicleCosts { fixed: Some(20.), distance: 0.002, time: 0.003 },
+                    costs: VehicleCosts {
+                        fixed: Some(20.),
+                        distance: 0.002,
+                        time: 0.003,
+                        distance_loaded: None,
+                        time_tiers: None,
+                        distance_tiers: None,
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: "2020-07-04T09:00:00Z".to_string(),
@@ -142,6 +191,8 @@ fn can_handle_properly_invalid_break_removal() {
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    compartments: None,
+                    trailer: None,
                 }],
                 ..create_default_fleet()
             },
@@ -11,11 +11,18 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
         places: vec![JobPlace {
             location: Location::Reference { index },
             duration: 0.,
+            setup: None,
+            duration_rules: None,
             times: None,
             tag: Some(tag.to_string()),
+            tolerance: None,
+            dock: None,
         }],
         demand: Some(vec![1]),
         order: None,
+        splittable: None,
+        min_split: None,
+        max_time_in_vehicle: None,
     };
 
     let problem = Problem {
@@ -36,7 +43,14 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance: 0.0, time: 1.0 },
+                costs: VehicleCosts {
+                    fixed: None,
+                    distance: 0.0,
+                    time: 1.0,
+                    distance_loaded: None,
+                    time_tiers: None,
+                    distance_tiers: None,
+                },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: format_time(0.),
@@ -33,6 +33,7 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
                 shift_index,
                 stops: vec![],
                 statistic: Statistic::default(),
+                driver_id: None,
             })
             .collect(),
         ..SolutionBuilder::default().build()
@@ -129,11 +130,18 @@ fn check_jobs_impl(
                 places: vec![JobPlace {
                     location: Location::Coordinate { lat: 0.0, lng: 0.0 },
                     duration: 0.0,
+                    setup: None,
+                    duration_rules: None,
                     times: None,
                     tag: Some(format!("{tgt}{idx}")),
+                    tolerance: None,
+                    dock: None,
                 }],
                 demand: if tgt != "service" { Some(vec![1]) } else { None },
                 order: None,
+                splittable: None,
+                min_split: None,
+                max_time_in_vehicle: None,
             })
             .collect()
     };
@@ -166,6 +174,7 @@ fn check_jobs_impl(
                 shift_index,
                 stops: stops.into_iter().map(create_stop).collect(),
                 statistic: Statistic::default(),
+                driver_id: None,
             })
             .collect(),
         unassigned: Some(
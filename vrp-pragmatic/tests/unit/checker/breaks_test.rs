@@ -102,6 +102,12 @@ fn can_check_breaks_impl(
         time: Some(Interval { start: "1970-01-01T00:00:03Z".to_string(), end: "1970-01-01T00:00:04Z".to_string() }),
         job_tag: None,
         commute: None,
+        earliness: None,
+        lateness: None,
+        ride_duration: None,
+        time_in_vehicle: None,
+        quantity: None,
+        queueing: None,
     }];
     if has_break {
         activities.push(Activity {
@@ -111,6 +117,12 @@ fn can_check_breaks_impl(
             time: Some(Interval { start: "1970-01-01T00:00:04Z".to_string(), end: "1970-01-01T00:00:06Z".to_string() }),
             job_tag: None,
             commute: None,
+            earliness: None,
+            lateness: None,
+            ride_duration: None,
+            time_in_vehicle: None,
+            quantity: None,
+            queueing: None,
         });
     }
 
@@ -150,3 +162,99 @@ fn can_check_breaks_impl(
 
     assert_eq!(result, expected_result);
 }
+
+fn create_activity(job_id: &str, activity_type: &str, start: f64, end: f64) -> Activity {
+    Activity {
+        job_id: job_id.to_string(),
+        activity_type: activity_type.to_string(),
+        location: None,
+        time: Some(Interval { start: format_time(start), end: format_time(end) }),
+        job_tag: None,
+        commute: None,
+        earliness: None,
+        lateness: None,
+        ride_duration: None,
+        time_in_vehicle: None,
+        quantity: None,
+        queueing: None,
+    }
+}
+
+parameterized_test! {can_check_driving_time, (break_durations, expected_result), {
+    can_check_driving_time_impl(break_durations, expected_result);
+}}
+
+can_check_driving_time! {
+    case01_full_break: (vec![5.], Ok(())),
+    case02_split_break: (vec![2., 3.], Ok(())),
+    case03_first_part_only: (vec![2.], Err("driving time limit is exceeded at stop 3 for vehicle 'my_vehicle_1': 39 > 20".into())),
+    case04_too_short_break: (vec![1.], Err("driving time limit is exceeded at stop 3 for vehicle 'my_vehicle_1': 39 > 20".into())),
+    case05_no_break: (vec![], Err("driving time limit is exceeded at stop 3 for vehicle 'my_vehicle_1': 39 > 20".into())),
+}
+
+fn can_check_driving_time_impl(break_durations: Vec<f64>, expected_result: Result<(), GenericError>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (20., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Driving {
+                        max_driving_time: 20.,
+                        duration: 5.,
+                        split: Some(vec![2., 3.]),
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let departure = 21. + break_durations.iter().sum::<f64>();
+    let activities = once(create_activity("job2", "delivery", 20., 21.))
+        .chain(break_durations.iter().scan(21., |start, duration| {
+            let activity = create_activity("break", "break", *start, *start + *duration);
+            *start += *duration;
+            Some(activity)
+        }))
+        .collect();
+
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((10., 0.))
+                        .schedule_stamp(10., 11.)
+                        .load(vec![1])
+                        .distance(10)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((20., 0.))
+                        .schedule_stamp(20., departure)
+                        .load(vec![0])
+                        .distance(20)
+                        .activities(activities)
+                        .build(),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(departure + 20., departure + 20.)
+                        .load(vec![0])
+                        .distance(40)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_driving_time(&ctx);
+
+    assert_eq!(result, expected_result);
+}
@@ -0,0 +1,113 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::models::examples::create_example_problem;
+
+type StopCompartments = Option<Vec<(&'static str, Option<&'static str>, i32)>>;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { product: Some("fuel".to_string()), ..create_delivery_job("job1", (1., 0.)) },
+                Job { product: Some("diesel".to_string()), ..create_delivery_job("job2", (2., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity: vec![10],
+                compartments: Some(vec![
+                    VehicleCompartment { id: "c1".to_string(), capacity: 5, cleaning_duration: Some(3.) },
+                    VehicleCompartment { id: "c2".to_string(), capacity: 5, cleaning_duration: None },
+                ]),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_solution(compartments: Vec<StopCompartments>, reload_stop_idx: Option<usize>) -> Solution {
+    let stops = compartments
+        .into_iter()
+        .enumerate()
+        .map(|(idx, compartments)| {
+            let stop = StopBuilder::default().coordinate((idx as f64, 0.)).schedule_stamp(idx as f64, idx as f64);
+            let stop = if let Some(compartments) = compartments { stop.compartments(compartments) } else { stop };
+
+            match idx {
+                0 => stop.build_departure(),
+                idx if Some(idx) == reload_stop_idx => stop.build_single("reload", "reload"),
+                idx => stop.build_single(format!("job{idx}").as_str(), "delivery"),
+            }
+        })
+        .collect();
+
+    SolutionBuilder::default().tour(TourBuilder::default().stops(stops).build()).build()
+}
+
+parameterized_test! {can_check_compartments, (compartments, reload_stop_idx, expected_result), {
+    can_check_compartments_impl(compartments, reload_stop_idx, expected_result);
+}}
+
+can_check_compartments! {
+    case01_valid: (vec![
+        Some(vec![("c1", Some("fuel"), 1), ("c2", Some("diesel"), 1)]),
+        Some(vec![("c1", Some("fuel"), 0), ("c2", Some("diesel"), 1)]),
+        Some(vec![("c1", Some("fuel"), 0), ("c2", Some("diesel"), 0)]),
+    ], None, Ok(())),
+    case02_missing_usage: (vec![
+        Some(vec![("c1", Some("fuel"), 1), ("c2", Some("diesel"), 1)]),
+        None,
+        Some(vec![("c1", Some("fuel"), 0), ("c2", Some("diesel"), 0)]),
+    ], None, Err(vec!["compartments usage does not match vehicle compartments in tour 'my_vehicle_1'".into()])),
+    case03_exceeds_capacity: (vec![
+        Some(vec![("c1", Some("fuel"), 6), ("c2", Some("diesel"), 1)]),
+        Some(vec![("c1", Some("fuel"), 0), ("c2", Some("diesel"), 1)]),
+        Some(vec![("c1", Some("fuel"), 0), ("c2", Some("diesel"), 0)]),
+    ], None, Err(vec!["load exceeds capacity of compartment 'c1' in tour 'my_vehicle_1'".into()])),
+    case04_product_change_without_reload: (vec![
+        Some(vec![("c1", Some("fuel"), 1), ("c2", None, 0)]),
+        Some(vec![("c1", Some("diesel"), 0), ("c2", None, 0)]),
+    ], None, Err(vec!["compartment product type is changed without reload in tour 'my_vehicle_1'".into()])),
+    case05_product_change_at_reload: (vec![
+        Some(vec![("c1", Some("fuel"), 1), ("c2", None, 0)]),
+        Some(vec![("c1", Some("fuel"), 0), ("c2", None, 0)]),
+        Some(vec![("c1", Some("diesel"), 1), ("c2", None, 0)]),
+    ], Some(2), Ok(())),
+}
+
+fn can_check_compartments_impl(
+    compartments: Vec<StopCompartments>,
+    reload_stop_idx: Option<usize>,
+    expected_result: Result<(), Vec<GenericError>>,
+) {
+    let problem = create_test_problem();
+    let solution = create_test_solution(compartments, reload_stop_idx);
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_compartments(&ctx);
+
+    assert_eq!(result, expected_result);
+}
+
+#[test]
+fn can_get_cleaning_duration_at_reload() {
+    let problem = create_test_problem();
+    let solution = create_test_solution(
+        vec![
+            Some(vec![("c1", Some("fuel"), 1), ("c2", Some("fuel"), 1)]),
+            Some(vec![("c1", Some("fuel"), 0), ("c2", Some("fuel"), 0)]),
+            Some(vec![("c1", Some("diesel"), 1), ("c2", Some("diesel"), 1)]),
+        ],
+        Some(2),
+    );
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+    let tour = ctx.solution.tours.first().unwrap();
+    let stop = tour.stops.get(2).and_then(|stop| stop.as_point()).unwrap();
+
+    let result = get_cleaning_duration(&ctx, tour, stop);
+
+    assert_eq!(result, 3.);
+}
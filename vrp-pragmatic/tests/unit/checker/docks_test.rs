@@ -0,0 +1,72 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::models::examples::create_example_problem;
+
+fn create_test_problem(slots: usize) -> Problem {
+    let create_dock_job = |id: &str| Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace {
+                duration: 10.,
+                dock: Some("dock1".to_string()),
+                ..create_job_place((1., 0.), None)
+            }],
+            ..create_task((1., 0.), None)
+        }]),
+        ..create_job(id)
+    };
+
+    Problem {
+        plan: Plan { jobs: vec![create_dock_job("job1"), create_dock_job("job2")], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            resources: Some(vec![VehicleResource::Dock { id: "dock1".to_string(), slots }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_tour(vehicle_id: &str, job_id: &str, service_start: f64) -> Tour {
+    let departure = service_start + 10.;
+
+    TourBuilder::default()
+        .vehicle_id(vehicle_id)
+        .stops(vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).build_departure(),
+            StopBuilder::default().coordinate((1., 0.)).schedule_stamp(1., departure).build_single_time(
+                job_id,
+                "delivery",
+                (service_start, departure),
+            ),
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(departure + 1., departure + 1.).build_arrival(),
+        ])
+        .build()
+}
+
+parameterized_test! {can_check_dock_slots, (slots, service_starts, expected_result), {
+    can_check_dock_slots_impl(slots, service_starts, expected_result);
+}}
+
+can_check_dock_slots! {
+    case01_queued: (1, (1., 11.), Ok(())),
+    case02_overlap_single_slot: (1, (1., 5.), Err(vec![
+        "dock slots violation, expected: not more than 1 vehicles at dock 'dock1', got: 2".into()
+    ])),
+    case03_overlap_two_slots: (2, (1., 1.), Ok(())),
+}
+
+fn can_check_dock_slots_impl(slots: usize, service_starts: (f64, f64), expected_result: Result<(), Vec<GenericError>>) {
+    let problem = create_test_problem(slots);
+    let solution = SolutionBuilder::default()
+        .tour(create_test_tour("my_vehicle_1", "job1", service_starts.0))
+        .tour(create_test_tour("my_vehicle_2", "job2", service_starts.1))
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_docks(&ctx);
+
+    assert_eq!(result, expected_result);
+}
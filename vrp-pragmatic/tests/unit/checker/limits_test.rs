@@ -25,6 +25,7 @@ fn create_test_solution(statistic: Statistic, stops: Vec<Stop>) -> Solution {
             shift_index: 0,
             stops,
             statistic,
+            driver_id: None,
         })
         .build()
 }
@@ -59,7 +60,7 @@ pub fn can_check_shift_and_distance_limit_impl(
     actual: i64,
     expected: Result<(), GenericError>,
 ) {
-    let problem = create_test_problem(Some(VehicleLimits { max_distance, max_duration, tour_size: None }));
+    let problem = create_test_problem(Some(VehicleLimits { max_distance, max_duration, tour_size: None, trip: None }));
     let solution =
         create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
     let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
@@ -71,8 +72,12 @@ pub fn can_check_shift_and_distance_limit_impl(
 
 #[test]
 pub fn can_check_tour_size_limit() {
-    let problem =
-        create_test_problem(Some(VehicleLimits { max_distance: None, max_duration: None, tour_size: Some(2) }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        max_duration: None,
+        tour_size: Some(2),
+        trip: None,
+    }));
     let solution = create_test_solution(
         Statistic::default(),
         vec![
@@ -164,3 +169,174 @@ fn can_check_shift_time() {
 
     assert_eq!(result, Err("tour time is outside shift time, vehicle id 'my_vehicle_1', shift index: 0".into()));
 }
+
+parameterized_test! {can_check_ride_duration, (max_ride_duration, reported, expected), {
+    can_check_ride_duration_impl(max_ride_duration, reported, expected);
+}}
+
+can_check_ride_duration! {
+    case_01: (10., Some(9), Ok(())),
+    case_02: (5., Some(9), Err("max ride duration violation for job 'job1', expected: not more than 5, got: 9, vehicle id 'some_real_vehicle'")),
+    case_03: (10., None, Err("ride duration mismatch for job 'job1', expected: 9, got: None, vehicle id 'some_real_vehicle'")),
+}
+
+fn can_check_ride_duration_impl(max_ride_duration: f64, reported: Option<i64>, expected: Result<(), &str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                max_ride_duration: Some(max_ride_duration),
+                ..create_pickup_delivery_job("job1", (1., 0.), (10., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        ..create_test_problem(None)
+    };
+    let mut delivery = StopBuilder::default()
+        .coordinate((10., 0.))
+        .schedule_stamp(11., 12.)
+        .load(vec![0])
+        .build_single_tag("job1", "delivery", "d1");
+    if let Stop::Point(point) = &mut delivery {
+        point.activities[0].ride_duration = reported;
+    }
+    let solution = create_test_solution(
+        Statistic::default(),
+        vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![0]).build_departure(),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(1., 2.)
+                .load(vec![1])
+                .build_single_tag("job1", "pickup", "p1"),
+            delivery,
+        ],
+    );
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_ride_durations(&ctx);
+
+    assert_eq!(result, expected.map_err(|err| err.into()));
+}
+
+parameterized_test! {can_check_trip_limits, (trip, expected), {
+    can_check_trip_limits_impl(trip, expected);
+}}
+
+can_check_trip_limits! {
+    case_01: ((Some(6.), Some(6.), Some(1)), Ok(())),
+    case_02: ((Some(5.), None, None), Err("max trip distance limit violation, expected: not more than 5, got: 6, vehicle id 'some_real_vehicle', shift index: 0, trip index: 1")),
+    case_03: ((None, Some(5.), None), Err("max trip duration limit violation, expected: not more than 5, got: 6, vehicle id 'some_real_vehicle', shift index: 0, trip index: 1")),
+    case_04: ((None, None, Some(0)), Err("trip size limit violation, expected: not more than 0, got: 1, vehicle id 'some_real_vehicle', shift index: 0, trip index: 0")),
+}
+
+fn can_check_trip_limits_impl(trip: (Option<f64>, Option<f64>, Option<usize>), expected: Result<(), &str>) {
+    let (max_distance, max_duration, size) = trip;
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        max_duration: None,
+        tour_size: None,
+        trip: Some(VehicleTripLimits { max_distance, max_duration, size }),
+    }));
+    let solution = create_test_solution(
+        Statistic::default(),
+        vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(1., 1.)
+                .load(vec![0])
+                .distance(1)
+                .build_single("job1", "delivery"),
+            StopBuilder::default()
+                .coordinate((0., 0.))
+                .schedule_stamp(2., 4.)
+                .load(vec![1])
+                .distance(2)
+                .build_single("reload", "reload"),
+            StopBuilder::default()
+                .coordinate((3., 0.))
+                .schedule_stamp(7., 7.)
+                .load(vec![0])
+                .distance(5)
+                .build_single("job2", "delivery"),
+            StopBuilder::default()
+                .coordinate((0., 0.))
+                .schedule_stamp(10., 10.)
+                .load(vec![0])
+                .distance(8)
+                .build_arrival(),
+        ],
+    );
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_trip_limits(&ctx);
+
+    assert_eq!(result, expected.map_err(|err| err.into()));
+}
+
+parameterized_test! {can_check_time_in_vehicle, (max_time_in_vehicle, reported, expected), {
+    can_check_time_in_vehicle_impl(max_time_in_vehicle, reported, expected);
+}}
+
+can_check_time_in_vehicle! {
+    case_01: (5., (Some(1), Some(3)), Ok(())),
+    case_02: (2., (Some(1), Some(3)), Err("max time in vehicle violation for job 'job2', expected: not more than 2, got: 3, vehicle id 'some_real_vehicle'")),
+    case_03: (5., (None, Some(3)), Err("time in vehicle mismatch for job 'job1', expected: 1, got: None, vehicle id 'some_real_vehicle'")),
+}
+
+fn can_check_time_in_vehicle_impl(
+    max_time_in_vehicle: f64,
+    reported: (Option<i64>, Option<i64>),
+    expected: Result<(), &str>,
+) {
+    let create_job = |id: &str, location: (f64, f64)| {
+        let job = create_delivery_job(id, location);
+        let deliveries = job.deliveries.clone().map(|tasks| {
+            tasks.into_iter().map(|task| JobTask { max_time_in_vehicle: Some(max_time_in_vehicle), ..task }).collect()
+        });
+
+        Job { deliveries, ..job }
+    };
+    let with_time_in_vehicle = |mut stop: Stop, time_in_vehicle: Option<i64>| {
+        if let Stop::Point(point) = &mut stop {
+            point.activities[0].time_in_vehicle = time_in_vehicle;
+        }
+        stop
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_job("job1", (1., 0.)), create_job("job2", (3., 0.))], ..create_empty_plan() },
+        ..create_test_problem(None)
+    };
+    let solution = create_test_solution(
+        Statistic::default(),
+        vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+            with_time_in_vehicle(
+                StopBuilder::default()
+                    .coordinate((1., 0.))
+                    .schedule_stamp(1., 2.)
+                    .load(vec![0])
+                    .build_single("job1", "delivery"),
+                reported.0,
+            ),
+            StopBuilder::default()
+                .coordinate((0., 0.))
+                .schedule_stamp(3., 4.)
+                .load(vec![1])
+                .build_single("reload", "reload"),
+            with_time_in_vehicle(
+                StopBuilder::default()
+                    .coordinate((3., 0.))
+                    .schedule_stamp(7., 8.)
+                    .load(vec![0])
+                    .build_single("job2", "delivery"),
+                reported.1,
+            ),
+        ],
+    );
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_time_in_vehicle(&ctx);
+
+    assert_eq!(result, expected.map_err(|err| err.into()));
+}
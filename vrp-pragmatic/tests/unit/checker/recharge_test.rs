@@ -0,0 +1,108 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::models::examples::create_example_problem;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    recharges: Some(VehicleRecharges {
+                        max_distance: None,
+                        battery: Some(VehicleBattery {
+                            capacity: 20.,
+                            initial: Some(10.),
+                            reserve: Some(2.),
+                            consumption: 1.,
+                            consumption_loaded: None,
+                        }),
+                        stations: vec![VehicleRechargeStation {
+                            location: (5., 0.).to_loc(),
+                            duration: 0.,
+                            times: None,
+                            tag: None,
+                            power: Some(1.),
+                            dock: None,
+                        }],
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_tour(recharge: (f64, f64), job_soc: f64, arrival_soc: f64) -> Tour {
+    let (charged, charging) = recharge;
+
+    TourBuilder::default()
+        .stops(vec![
+            StopBuilder::default()
+                .coordinate((0., 0.))
+                .state_of_charge(10.)
+                .schedule_stamp(0., 0.)
+                .load(vec![1])
+                .build_departure(),
+            StopBuilder::default()
+                .coordinate((5., 0.))
+                .distance(5)
+                .state_of_charge(charged)
+                .schedule_stamp(5., 5. + charging)
+                .load(vec![1])
+                .build_single("recharge", "recharge"),
+            StopBuilder::default()
+                .coordinate((10., 0.))
+                .distance(10)
+                .state_of_charge(job_soc)
+                .schedule_stamp(10. + charging, 10. + charging)
+                .load(vec![0])
+                .build_single("job1", "delivery"),
+            StopBuilder::default()
+                .coordinate((0., 0.))
+                .distance(20)
+                .state_of_charge(arrival_soc)
+                .schedule_stamp(20. + charging, 20. + charging)
+                .load(vec![0])
+                .build_arrival(),
+        ])
+        .build()
+}
+
+parameterized_test! {can_check_state_of_charge, (recharge, job_soc, arrival_soc, expected_result), {
+    can_check_state_of_charge_impl(recharge, job_soc, arrival_soc, expected_result);
+}}
+
+can_check_state_of_charge! {
+    case01_valid: ((17., 12.), 12., 2., Ok(())),
+    case02_below_reserve: ((15., 10.), 10., 0., Err(vec![
+        "state of charge is below reserve at stop 3 for vehicle 'my_vehicle_1': 0 < 2".into()
+    ])),
+    case03_short_charging: ((17., 5.), 12., 2., Err(vec![
+        "charging time is too short at stop 1 for vehicle 'my_vehicle_1': 5 < 12".into()
+    ])),
+    case04_unexpected_soc: ((17., 12.), 11., 1., Err(vec![
+        "unexpected state of charge at stop 2 for vehicle 'my_vehicle_1', expected: 12, got: 11".into()
+    ])),
+    case05_over_capacity: ((25., 20.), 20., 10., Err(vec![
+        "invalid state of charge after recharge at stop 1 for vehicle 'my_vehicle_1': 25".into()
+    ])),
+}
+
+fn can_check_state_of_charge_impl(
+    recharge: (f64, f64),
+    job_soc: f64,
+    arrival_soc: f64,
+    expected_result: Result<(), Vec<GenericError>>,
+) {
+    let problem = create_test_problem();
+    let solution = SolutionBuilder::default().tour(create_test_tour(recharge, job_soc, arrival_soc)).build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_recharges(&ctx);
+
+    assert_eq!(result, expected_result);
+}
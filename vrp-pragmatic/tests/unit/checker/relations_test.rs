@@ -5,14 +5,25 @@ use crate::helpers::*;
 mod single {
     use super::*;
     use vrp_core::models::examples::create_example_problem;
-    use RelationType::{Any, Sequence, Strict};
+    use RelationType::{Any, Precedence, Sequence, Strict, Sync};
 
     fn create_relation(job_ids: Vec<&str>, relation_type: RelationType) -> Relation {
         Relation {
             type_field: relation_type,
             jobs: job_ids.iter().map(|id| id.to_string()).collect(),
-            vehicle_id: "my_vehicle_1".to_string(),
+            vehicle_id: Some("my_vehicle_1".to_string()),
             shift_index: None,
+            offset: None,
+        }
+    }
+
+    fn create_schedule_relation(job_ids: Vec<&str>, relation_type: RelationType, offset: f64) -> Relation {
+        Relation {
+            type_field: relation_type,
+            jobs: job_ids.iter().map(|id| id.to_string()).collect(),
+            vehicle_id: None,
+            shift_index: None,
+            offset: Some(offset),
         }
     }
 
@@ -20,8 +31,9 @@ mod single {
         Relation {
             type_field: Sequence,
             jobs: vec!["job1".to_string()],
-            vehicle_id: vehicle_id.to_string(),
+            vehicle_id: Some(vehicle_id.to_string()),
             shift_index: None,
+            offset: None,
         }
     }
 
@@ -29,8 +41,9 @@ mod single {
         Relation {
             type_field: Sequence,
             jobs: vec!["job1".to_string()],
-            vehicle_id: "my_vehicle_1".to_string(),
+            vehicle_id: Some("my_vehicle_1".to_string()),
             shift_index: Some(1),
+            offset: None,
         }
     }
 
@@ -64,6 +77,13 @@ mod single {
         case_wrong_vehicle_01: (Some(vec![create_relation_with_wrong_id("my_vehicle_2")]), Err(())),
         case_wrong_vehicle_02: (Some(vec![create_relation_with_wrong_id("my_vehicle_x")]), Err(())),
         case_wrong_vehicle_03: (Some(vec![create_relation_with_wrong_shift()]), Err(())),
+
+        case_sync_01:     (Some(vec![create_schedule_relation(vec!["job1", "job3"], Sync, 6.)]), Ok(())),
+        case_sync_02:     (Some(vec![create_schedule_relation(vec!["job1", "job3"], Sync, 5.)]), Err(())),
+
+        case_precedence_01: (Some(vec![create_schedule_relation(vec!["job1", "job3"], Precedence, 5.)]), Ok(())),
+        case_precedence_02: (Some(vec![create_schedule_relation(vec!["job1", "job3"], Precedence, 6.)]), Err(())),
+        case_precedence_03: (Some(vec![create_schedule_relation(vec!["job3", "job1"], Precedence, 0.)]), Err(())),
     }
 
     fn can_check_relations_impl(relations: Option<Vec<Relation>>, expected_result: Result<(), ()>) {
@@ -103,6 +123,8 @@ mod single {
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    compartments: None,
+                    trailer: None,
                 }],
                 ..create_default_fleet()
             },
@@ -0,0 +1,102 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::models::examples::create_example_problem;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { satellite_id: Some("sat1".to_string()), ..create_delivery_job("supply1", (10., 0.)) },
+                create_delivery_job("job1", (12., 0.)),
+                create_delivery_job("job2", (14., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_capacity("truck", vec![2]),
+                VehicleType {
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    ..create_vehicle_with_capacity("bike", vec![2])
+                },
+            ],
+            resources: Some(vec![VehicleResource::Satellite { id: "sat1".to_string(), location: (10., 0.).to_loc() }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_truck_tour() -> Tour {
+    TourBuilder::default()
+        .type_id("truck")
+        .vehicle_id("truck_1")
+        .stops(vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+            StopBuilder::default()
+                .coordinate((10., 0.))
+                .schedule_stamp(10., 11.)
+                .load(vec![0])
+                .build_single("supply1", "delivery"),
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(21., 21.).load(vec![0]).build_arrival(),
+        ])
+        .build()
+}
+
+fn create_bike_tour(departure: f64, job_ids: Vec<&str>) -> Tour {
+    let load = job_ids.len() as i32;
+    let stops = job_ids.into_iter().enumerate().map(|(idx, job_id)| {
+        let time = departure + (idx + 1) as f64 * 2.;
+        StopBuilder::default()
+            .coordinate((10. + (idx + 1) as f64 * 2., 0.))
+            .schedule_stamp(time, time + 1.)
+            .load(vec![load - idx as i32 - 1])
+            .build_single(job_id, "delivery")
+    });
+
+    TourBuilder::default()
+        .type_id("bike")
+        .vehicle_id("bike_1")
+        .stops(
+            std::iter::once(
+                StopBuilder::default()
+                    .coordinate((10., 0.))
+                    .schedule_stamp(0., departure)
+                    .load(vec![load])
+                    .build_departure(),
+            )
+            .chain(stops)
+            .chain(std::iter::once(
+                StopBuilder::default().coordinate((10., 0.)).schedule_stamp(100., 100.).build_arrival(),
+            ))
+            .collect(),
+        )
+        .build()
+}
+
+parameterized_test! {can_check_satellites, (departure, job_ids, expected), {
+    can_check_satellites_impl(departure, job_ids, expected);
+}}
+
+can_check_satellites! {
+    case01_valid_tours: (11., vec!["job1"], Ok(())),
+    case02_departure_before_supply: (
+        5., vec!["job1"],
+        Err(vec!["tour 'bike_1' departs from satellite 'sat1' before it is supplied".into()])
+    ),
+    case03_not_enough_supply: (
+        11., vec!["job1", "job2"],
+        Err(vec!["satellite 'sat1' loads more than supplied: [2, 0, 0, 0, 0, 0, 0, 0] vs [1, 0, 0, 0, 0, 0, 0, 0]".into()])
+    ),
+}
+
+fn can_check_satellites_impl(departure: f64, job_ids: Vec<&str>, expected: Result<(), Vec<GenericError>>) {
+    let problem = create_test_problem();
+    let solution =
+        SolutionBuilder::default().tour(create_truck_tour()).tour(create_bike_tour(departure, job_ids)).build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_satellites(&ctx);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,86 @@
+use super::*;
+use crate::helpers::*;
+use std::iter::once;
+use vrp_core::models::examples::create_example_problem;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { truck_only: Some(true), ..create_delivery_job("job1", (8., 0.)) },
+                create_delivery_job("job2", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity: vec![1],
+                trailer: Some(VehicleTrailer {
+                    capacity: vec![9],
+                    parks: vec![VehicleTrailerPark {
+                        location: (5., 0.).to_loc(),
+                        decouple_duration: 1.,
+                        couple_duration: 1.,
+                        tag: None,
+                    }],
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_tour(activities: Vec<(f64, &str, &str, i32)>) -> Tour {
+    let stops = activities.into_iter().enumerate().map(|(idx, (location, job_id, activity_type, load))| {
+        let time = (idx + 1) as f64 * 10.;
+        StopBuilder::default()
+            .coordinate((location, 0.))
+            .schedule_stamp(time, time + 1.)
+            .load(vec![load])
+            .build_single(job_id, activity_type)
+    });
+
+    TourBuilder::default()
+        .stops(
+            once(StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure())
+                .chain(stops)
+                .chain(once(StopBuilder::default().coordinate((0., 0.)).schedule_stamp(100., 100.).build_arrival()))
+                .collect(),
+        )
+        .build()
+}
+
+parameterized_test! {can_check_trailers, (activities, expected), {
+    can_check_trailers_impl(activities, expected);
+}}
+
+can_check_trailers! {
+    case01_valid_tour: (
+        vec![(3., "job2", "delivery", 1), (5., "decouple", "decouple", 1), (8., "job1", "delivery", 0), (5., "couple", "couple", 0)],
+        Ok(())
+    ),
+    case02_truck_only_job_with_trailer: (
+        vec![(3., "job2", "delivery", 1), (8., "job1", "delivery", 0)],
+        Err(vec!["truck only job 'job1' is served with trailer in tour 'my_vehicle_1'".into()])
+    ),
+    case03_not_coupled_back: (
+        vec![(3., "job2", "delivery", 1), (5., "decouple", "decouple", 1), (8., "job1", "delivery", 0)],
+        Err(vec!["trailer is not coupled back in tour 'my_vehicle_1'".into()])
+    ),
+    case04_truck_is_overloaded: (
+        vec![(5., "decouple", "decouple", 2), (8., "job1", "delivery", 1), (3., "job2", "delivery", 0), (5., "couple", "couple", 0)],
+        Err(vec!["truck load exceeds its capacity while trailer is decoupled in tour 'my_vehicle_1'".into()])
+    ),
+}
+
+fn can_check_trailers_impl(activities: Vec<(f64, &str, &str, i32)>, expected: Result<(), Vec<GenericError>>) {
+    let problem = create_test_problem();
+    let solution = SolutionBuilder::default().tour(create_test_tour(activities)).build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_trailers(&ctx);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,64 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::models::examples::create_example_problem;
+
+fn create_test_problem(vehicle_type_ids: Option<Vec<&str>>, fee: Option<f64>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.))],
+            zones: Some(vec![Zone {
+                id: "zone1".to_string(),
+                polygon: vec![(0.5, -0.5), (0.5, 0.5), (1.5, 0.5), (1.5, -0.5)]
+                    .into_iter()
+                    .map(|(lat, lng)| Location::new_coordinate(lat, lng))
+                    .collect(),
+                vehicle_type_ids: vehicle_type_ids.map(|ids| ids.into_iter().map(|id| id.to_string()).collect()),
+                fee,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_tour(fees: Option<f64>) -> Tour {
+    TourBuilder::default()
+        .stops(vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).build_departure(),
+            StopBuilder::default().coordinate((1., 0.)).schedule_stamp(1., 2.).build_single("job1", "delivery"),
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(3., 3.).build_arrival(),
+        ])
+        .statistic(Statistic { fees, ..Statistic::default() })
+        .build()
+}
+
+parameterized_test! {can_check_zones, (vehicle_type_ids, fee, reported_fees, expected_result), {
+    can_check_zones_impl(vehicle_type_ids, fee, reported_fees, expected_result);
+}}
+
+can_check_zones! {
+    case01_no_restrictions: (None, None, None, Ok(())),
+    case02_allowed_with_fee: (Some(vec!["my_vehicle"]), Some(10.), Some(10.), Ok(())),
+    case03_disallowed: (Some(vec!["other_vehicle"]), None, None, Err(vec![
+        "zone access violation: job 'job1' inside zone 'zone1' is served by vehicle type 'my_vehicle'".into()
+    ])),
+    case04_fee_mismatch: (None, Some(10.), None, Err(vec![
+        "zone fees mismatch for vehicle 'my_vehicle_1', expected: 10, got: 0".into()
+    ])),
+}
+
+fn can_check_zones_impl(
+    vehicle_type_ids: Option<Vec<&str>>,
+    fee: Option<f64>,
+    reported_fees: Option<f64>,
+    expected_result: Result<(), Vec<GenericError>>,
+) {
+    let problem = create_test_problem(vehicle_type_ids, fee);
+    let solution = SolutionBuilder::default().tour(create_test_tour(reported_fees)).build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_zones(&ctx);
+
+    assert_eq!(result, expected_result);
+}
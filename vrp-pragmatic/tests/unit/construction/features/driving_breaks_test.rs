@@ -0,0 +1,148 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::models::solution::Activity;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn driving_break(location: Location, place_idx: usize) -> Activity {
+    let mut single_shared = create_single_with_type("driving_break", "driving_break");
+    let single_mut = Arc::get_mut(&mut single_shared).unwrap();
+    single_mut.dimens.set_shift_index(0).set_vehicle_id("v1".to_string());
+
+    let mut activity = Activity { job: Some(single_shared), ..create_activity_at_location(location) };
+    activity.place.idx = place_idx;
+
+    activity
+}
+
+fn create_route_ctx(activities: &[Location], breaks: Vec<(usize, Location, usize)>) -> RouteContext {
+    let fleet = test_fleet_with_vehicles(vec![Arc::new(test_vehicle_with_no_end("v1"))]);
+    let mut route_ctx = RouteContext::new_with_state(
+        create_route_with_activities(
+            &fleet,
+            "v1",
+            activities
+                .iter()
+                .enumerate()
+                .map(|(idx, &location)| Activity {
+                    schedule: Schedule::new(location as f64, location as f64),
+                    job: Some(create_single(&format!("job{}", idx + 1))),
+                    ..create_activity_at_location(location)
+                })
+                .collect(),
+        ),
+        RouteState::default(),
+    );
+
+    breaks.into_iter().for_each(|(break_idx, location, place_idx)| {
+        route_ctx.route_mut().tour.insert_at(driving_break(location, place_idx), break_idx);
+    });
+
+    route_ctx
+}
+
+fn create_feature(limit: Duration) -> Feature {
+    create_driving_break_feature(
+        "driving_break",
+        VIOLATION_CODE,
+        Arc::new(move |_: &Actor| Some(limit)),
+        TestTransportCost::new_shared(),
+    )
+    .expect("cannot create feature")
+}
+
+parameterized_test! {can_accumulate_driving_time, (breaks, activities, expected), {
+    can_accumulate_driving_time_impl(breaks, activities, expected);
+}}
+
+can_accumulate_driving_time! {
+    case01_no_breaks: (vec![], vec![5, 10, 15], vec![0., 5., 10., 15.]),
+    case02_full_break: (vec![(2, 5, 0)], vec![5, 10, 15], vec![0., 5., 0., 5., 10.]),
+    case03_first_part_only: (vec![(2, 5, 1)], vec![5, 10, 15], vec![0., 5., 5., 10., 15.]),
+    case04_split_break: (vec![(2, 5, 1), (4, 10, 2)], vec![5, 10, 15], vec![0., 5., 5., 10., 0., 5.]),
+    case05_second_part_only: (vec![(2, 5, 2)], vec![5, 10, 15], vec![0., 5., 5., 10., 15.]),
+}
+
+fn can_accumulate_driving_time_impl(
+    breaks: Vec<(usize, Location, usize)>,
+    activities: Vec<Location>,
+    expected: Vec<Duration>,
+) {
+    let mut route_ctx = create_route_ctx(&activities, breaks);
+    let state = create_feature(100.).state.unwrap();
+
+    state.accept_route_state(&mut route_ctx);
+
+    let driving = (0..route_ctx.route().tour.total())
+        .map(|idx| route_ctx.state().get_activity_state::<Duration>(DRIVING_TIME_KEY, idx).copied().unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(driving, expected);
+}
+
+parameterized_test! {can_evaluate_insertion, (limit, target, insertion_data, expected), {
+    can_evaluate_insertion_impl(limit, target, insertion_data, expected);
+}}
+
+can_evaluate_insertion! {
+    case01_accept_break_at_balanced_position: (15., Some(0), (2, 3), None),
+    case02_reject_break_at_other_position: (15., Some(0), (1, 2), ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_reject_break_at_the_end: (15., Some(0), (4, 5), ConstraintViolation::skip(VIOLATION_CODE)),
+    case04_accept_break_which_helps_partially: (8., Some(0), (2, 3), None),
+    case05_accept_first_part_when_limit_is_exceeded: (15., Some(1), (2, 3), None),
+    case06_reject_job_over_limit: (22., None, (4, 5), ConstraintViolation::skip(VIOLATION_CODE)),
+    case07_accept_job_within_limit: (25., None, (4, 5), None),
+}
+
+fn can_evaluate_insertion_impl(
+    limit: Duration,
+    target: Option<usize>,
+    insertion_data: (usize, usize),
+    expected: Option<ConstraintViolation>,
+) {
+    let (prev, next) = insertion_data;
+    let mut route_ctx = create_route_ctx(&[5, 10, 15, 20], vec![]);
+    let feature = create_feature(limit);
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    state.accept_route_state(&mut route_ctx);
+    let prev_activity = route_ctx.route().tour.get(prev).unwrap();
+    let target = match target {
+        Some(place_idx) => driving_break(prev_activity.place.location, place_idx),
+        None => create_activity_at_location(25),
+    };
+
+    let result = constraint.evaluate(&MoveContext::Activity {
+        route_ctx: &route_ctx,
+        activity_ctx: &ActivityContext {
+            index: prev,
+            prev: prev_activity,
+            target: &target,
+            next: route_ctx.route().tour.get(next),
+        },
+    });
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_handle_obsolete_breaks, (limit, breaks, expected), {
+    can_handle_obsolete_breaks_impl(limit, breaks, expected);
+}}
+
+can_handle_obsolete_breaks! {
+    case01_remove_obsolete: (20., vec![(2, 5, 0)], vec![0, 5, 10]),
+    case02_keep_needed: (8., vec![(2, 5, 0)], vec![0, 5, 5, 10]),
+}
+
+fn can_handle_obsolete_breaks_impl(limit: Duration, breaks: Vec<(usize, Location, usize)>, expected: Vec<Location>) {
+    let mut solution = SolutionContext {
+        routes: vec![create_route_ctx(&[5, 10], breaks)],
+        ..create_solution_context_for_fleet(&test_fleet())
+    };
+    let state = create_feature(limit).state.unwrap();
+
+    state.accept_solution_state(&mut solution);
+
+    assert_eq!(
+        expected,
+        solution.routes[0].route().tour.all_activities().map(|a| a.place.location).collect::<Vec<_>>()
+    );
+}
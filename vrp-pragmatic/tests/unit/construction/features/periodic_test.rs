@@ -0,0 +1,223 @@
+use super::*;
+use crate::construction::enablers::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::models::problem::{Fleet, Single};
+
+const VIOLATION_CODE: ViolationCode = 1;
+const STATE_KEY: StateKey = StateKey(2);
+const TOTAL_DAYS: usize = 5;
+
+fn create_test_fleet() -> Fleet {
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        (0..TOTAL_DAYS)
+            .map(|day| {
+                let mut vehicle = test_vehicle(format!("v{day}").as_str());
+                vehicle.dimens.set_shift_day(day);
+                Arc::new(vehicle)
+            })
+            .collect(),
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_test_visit(index: usize, policy: &Arc<PeriodicPolicy>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_job_id("job1".to_string()).set_periodic_visit(Some(PeriodicVisit {
+        index,
+        total: TOTAL_DAYS,
+        policy: policy.clone(),
+    }));
+
+    Arc::new(single)
+}
+
+fn create_test_policy(
+    min_spacing: usize,
+    max_spacing: Option<usize>,
+    patterns: Option<Vec<Vec<usize>>>,
+) -> PeriodicPolicy {
+    PeriodicPolicy {
+        min_visits: 1,
+        day_patterns: patterns
+            .map(|patterns| patterns.into_iter().map(|pattern| pattern.into_iter().collect()).collect()),
+        min_spacing,
+        max_spacing,
+    }
+}
+
+parameterized_test! {can_evaluate_periodic_visit, (policy, visit_days, target_day, expected), {
+    can_evaluate_periodic_visit_impl(policy, visit_days, target_day, expected);
+}}
+
+can_evaluate_periodic_visit! {
+    case01_no_visits: (create_test_policy(1, None, None), vec![], 0, None),
+    case02_same_day: (create_test_policy(1, None, None), vec![0], 0, Some(())),
+    case03_min_spacing_violated: (create_test_policy(2, None, None), vec![0], 1, Some(())),
+    case04_min_spacing_satisfied: (create_test_policy(2, None, None), vec![0], 2, None),
+    case05_max_spacing_violated: (create_test_policy(1, Some(3), None), vec![0], 4, Some(())),
+    case06_max_spacing_satisfied: (create_test_policy(1, Some(3), None), vec![0, 4], 2, None),
+    case07_pattern_satisfied: (create_test_policy(1, None, Some(vec![vec![0, 2, 4], vec![1, 3]])), vec![0], 2, None),
+    case08_pattern_violated: (create_test_policy(1, None, Some(vec![vec![0, 2, 4], vec![1, 3]])), vec![0], 3, Some(())),
+    case09_pattern_unavailable: (create_test_policy(1, None, Some(vec![vec![0, 2, 5], vec![1, 3]])), vec![], 0, Some(())),
+}
+
+fn can_evaluate_periodic_visit_impl(
+    policy: PeriodicPolicy,
+    visit_days: Vec<usize>,
+    target_day: usize,
+    expected: Option<()>,
+) {
+    let policy = Arc::new(policy);
+    let fleet = create_test_fleet();
+    let feature = create_periodic_feature("periodic", VIOLATION_CODE, STATE_KEY, &fleet).unwrap();
+    let mut solution_ctx = SolutionContext {
+        routes: (0..TOTAL_DAYS)
+            .map(|day| {
+                let activities = visit_days
+                    .iter()
+                    .position(|visit_day| *visit_day == day)
+                    .map(|index| create_activity_with_job_at_location(create_test_visit(index, &policy), 1))
+                    .into_iter()
+                    .collect();
+
+                RouteContext::new_with_state(
+                    create_route_with_activities(&fleet, format!("v{day}").as_str(), activities),
+                    RouteState::default(),
+                )
+            })
+            .collect(),
+        ..create_solution_context_for_fleet(&fleet)
+    };
+    let job = Job::Single(create_test_visit(visit_days.len(), &policy));
+    solution_ctx.required.push(job.clone());
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    let result = feature.constraint.as_ref().unwrap().evaluate(&MoveContext::route(
+        &solution_ctx,
+        &solution_ctx.routes[target_day],
+        &job,
+    ));
+
+    assert_eq!(result, expected.and_then(|_| ConstraintViolation::fail(VIOLATION_CODE)));
+}
+
+#[test]
+fn can_merge_only_non_periodic_jobs() {
+    let policy = Arc::new(create_test_policy(1, None, None));
+    let fleet = create_test_fleet();
+    let feature = create_periodic_feature("periodic", VIOLATION_CODE, STATE_KEY, &fleet).unwrap();
+    let constraint = feature.constraint.as_ref().unwrap();
+    let periodic = Job::Single(create_test_visit(0, &policy));
+    let regular = Job::Single(Arc::new(create_single_with_location(Some(DEFAULT_JOB_LOCATION))));
+
+    assert!(constraint.merge(regular.clone(), regular.clone()).is_ok());
+    assert_eq!(constraint.merge(regular.clone(), periodic.clone()).err(), Some(VIOLATION_CODE));
+    assert_eq!(constraint.merge(periodic, regular).err(), Some(VIOLATION_CODE));
+}
+
+#[test]
+fn can_remove_invalid_visits_on_solution_state() {
+    let policy = Arc::new(create_test_policy(2, None, None));
+    let fleet = create_test_fleet();
+    let feature = create_periodic_feature("periodic", VIOLATION_CODE, STATE_KEY, &fleet).unwrap();
+    let mut solution_ctx = SolutionContext {
+        routes: (0..2)
+            .map(|day| {
+                RouteContext::new_with_state(
+                    create_route_with_activities(
+                        &fleet,
+                        format!("v{day}").as_str(),
+                        vec![create_activity_with_job_at_location(create_test_visit(day, &policy), 1)],
+                    ),
+                    RouteState::default(),
+                )
+            })
+            .collect(),
+        ..create_solution_context_for_fleet(&fleet)
+    };
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[0].route().tour.job_count(), 1);
+    assert_eq!(solution_ctx.routes[1].route().tour.job_count(), 0);
+    assert_eq!(solution_ctx.unassigned.len(), 1);
+    assert!(matches!(solution_ctx.unassigned.values().next(), Some(UnassignmentInfo::Simple(VIOLATION_CODE))));
+}
+
+fn create_test_solution_ctx(fleet: &Fleet, visits: Vec<(usize, Arc<Single>)>) -> SolutionContext {
+    let mut solution_ctx = create_solution_context_for_fleet(fleet);
+    solution_ctx.routes = (0..TOTAL_DAYS)
+        .map(|day| {
+            let activities = visits
+                .iter()
+                .filter(|(visit_day, _)| *visit_day == day)
+                .map(|(_, visit)| create_activity_with_job_at_location(visit.clone(), 1))
+                .collect();
+
+            RouteContext::new_with_state(
+                create_route_with_activities(fleet, format!("v{day}").as_str(), activities),
+                RouteState::default(),
+            )
+        })
+        .collect();
+    solution_ctx.routes.iter().for_each(|route_ctx| {
+        solution_ctx.registry.get_route(&route_ctx.route().actor).expect("route should be available");
+    });
+
+    solution_ctx
+}
+
+parameterized_test! {can_remove_incomplete_visits_on_solution_state, (min_visits, patterns, visit_days, pending, expected), {
+    can_remove_incomplete_visits_on_solution_state_impl(min_visits, patterns, visit_days, pending, expected);
+}}
+
+can_remove_incomplete_visits_on_solution_state! {
+    case01_min_visits_violated: (2, None, vec![0], false, 0),
+    case02_min_visits_satisfied: (2, None, vec![0, 2], false, 2),
+    case03_min_visits_violated_with_pending: (2, None, vec![0], true, 1),
+    case04_pattern_incomplete: (2, Some(vec![vec![0, 2, 4], vec![1, 3]]), vec![0, 2], false, 0),
+    case05_pattern_complete: (2, Some(vec![vec![0, 2, 4], vec![1, 3]]), vec![1, 3], false, 2),
+    case06_longer_pattern_complete: (2, Some(vec![vec![0, 2, 4], vec![1, 3]]), vec![0, 2, 4], false, 3),
+    case07_pattern_incomplete_with_pending: (2, Some(vec![vec![0, 2, 4], vec![1, 3]]), vec![0, 2], true, 2),
+}
+
+fn can_remove_incomplete_visits_on_solution_state_impl(
+    min_visits: usize,
+    patterns: Option<Vec<Vec<usize>>>,
+    visit_days: Vec<usize>,
+    pending: bool,
+    expected: usize,
+) {
+    let policy = Arc::new(PeriodicPolicy { min_visits, ..create_test_policy(1, None, patterns) });
+    let fleet = create_test_fleet();
+    let feature = create_periodic_feature("periodic", VIOLATION_CODE, STATE_KEY, &fleet).unwrap();
+    let visits = visit_days.iter().enumerate().map(|(index, day)| (*day, create_test_visit(index, &policy))).collect();
+    let mut solution_ctx = create_test_solution_ctx(&fleet, visits);
+    if pending {
+        solution_ctx.required.push(Job::Single(create_test_visit(visit_days.len(), &policy)));
+    }
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    let assigned = solution_ctx.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>();
+    assert_eq!(assigned, expected);
+    assert_eq!(solution_ctx.unassigned.len(), visit_days.len() - expected);
+    assert!(solution_ctx.unassigned.values().all(|info| matches!(info, UnassignmentInfo::Simple(VIOLATION_CODE))));
+    assert!(expected > 0 || solution_ctx.routes.is_empty());
+}
+
+#[test]
+fn can_keep_incomplete_visits_when_one_is_locked() {
+    let policy = Arc::new(PeriodicPolicy { min_visits: 2, ..create_test_policy(1, None, None) });
+    let fleet = create_test_fleet();
+    let feature = create_periodic_feature("periodic", VIOLATION_CODE, STATE_KEY, &fleet).unwrap();
+    let visit = create_test_visit(0, &policy);
+    let mut solution_ctx = create_test_solution_ctx(&fleet, vec![(0, visit.clone())]);
+    solution_ctx.locked.insert(Job::Single(visit));
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(), 1);
+    assert!(solution_ctx.unassigned.is_empty());
+}
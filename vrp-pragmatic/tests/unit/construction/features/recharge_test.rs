@@ -19,9 +19,28 @@ fn create_route_ctx(activities: &[Location], recharges: Vec<(usize, Location)>,
         test_fleet()
     };
 
+    create_route_ctx_with_fleet(&fleet, activities, recharges)
+}
+
+fn create_battery_route_ctx(
+    battery: RechargeBattery,
+    activities: &[Location],
+    recharges: Vec<(usize, Location)>,
+) -> RouteContext {
+    let mut vehicle = test_vehicle_with_no_end("v1");
+    vehicle.dimens.set_vehicle_battery(battery);
+
+    create_route_ctx_with_fleet(&test_fleet_with_vehicles(vec![Arc::new(vehicle)]), activities, recharges)
+}
+
+fn create_route_ctx_with_fleet(
+    fleet: &Fleet,
+    activities: &[Location],
+    recharges: Vec<(usize, Location)>,
+) -> RouteContext {
     let mut route_ctx = RouteContext::new_with_state(
         create_route_with_activities(
-            &fleet,
+            fleet,
             "v1",
             activities
                 .iter()
@@ -43,16 +62,34 @@ fn create_route_ctx(activities: &[Location], recharges: Vec<(usize, Location)>,
     route_ctx
 }
 
-fn create_feature(limit: Distance) -> Feature {
+fn create_battery(consumption_loaded: Option<f64>) -> RechargeBattery {
+    RechargeBattery { capacity: 20., initial: 10., reserve: 2., consumption: 1., consumption_loaded }
+}
+
+fn create_accessor(limit: Option<Distance>, power: Option<f64>) -> RechargeAccessor {
+    RechargeAccessor {
+        distance_limit_fn: Arc::new(move |_: &Actor| limit),
+        battery_fn: Arc::new(|actor: &Actor| actor.vehicle.dimens.get_vehicle_battery()),
+        power_fn: Arc::new(move |_: &Single| power),
+        demand_ratio_fn: Arc::new(|_: &Actor, _: &Single| 0.),
+    }
+}
+
+fn create_feature_with_accessor(accessor: RechargeAccessor) -> Feature {
     create_recharge_feature(
         "recharge",
         VIOLATION_CODE,
-        Arc::new(move |_: &Actor| Some(limit)),
+        accessor,
         TestTransportCost::new_shared(),
+        Arc::new(SimpleActivityCost::default()),
     )
     .expect("cannot create feature")
 }
 
+fn create_feature(limit: Distance) -> Feature {
+    create_feature_with_accessor(create_accessor(Some(limit), None))
+}
+
 parameterized_test! {can_accumulate_distance, (limit, recharges, activities, expected_counters), {
     can_accumulate_distance_impl(limit, recharges, activities, expected_counters);
 }}
@@ -188,3 +225,70 @@ fn can_accept_recharge_in_long_empty_route() {
 
     assert_eq!(result, None);
 }
+
+parameterized_test! {can_track_state_of_charge, (power, consumption_loaded, expected_soc, expected_charging), {
+    can_track_state_of_charge_impl(power, consumption_loaded, expected_soc, expected_charging);
+}}
+
+can_track_state_of_charge! {
+    case01_partial_charge: (Some(0.5), None, vec![10., 5., 10., 7., 2.], 14.),
+    case02_full_charge: (None, None, vec![10., 5., 20., 17., 12.], 0.),
+    case03_load_dependent: (Some(0.5), Some(3.), vec![10., 3., 15., 12., 2.], 28.),
+}
+
+fn can_track_state_of_charge_impl(
+    power: Option<f64>,
+    consumption_loaded: Option<f64>,
+    expected_soc: Vec<f64>,
+    expected_charging: Duration,
+) {
+    let mut route_ctx = create_battery_route_ctx(create_battery(consumption_loaded), &[5, 10, 15], vec![(2, 7)]);
+    route_ctx.state_mut().put_activity_state(LOAD_RATIO_KEY, 0, 0.2);
+    route_ctx.state_mut().put_activity_state(LOAD_RATIO_KEY, 3, 0.5);
+    let state = create_feature_with_accessor(create_accessor(None, power)).state.unwrap();
+
+    state.accept_route_state(&mut route_ctx);
+
+    let soc = (0..route_ctx.route().tour.total())
+        .map(|idx| route_ctx.state().get_activity_state::<f64>(RECHARGE_SOC_KEY, idx).copied().unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(soc, expected_soc);
+    let station = route_ctx.route().tour.get(2).unwrap();
+    assert_eq!(station.place.duration, DEFAULT_JOB_DURATION + expected_charging);
+    assert_eq!(station.schedule.departure - station.schedule.arrival, station.place.duration);
+}
+
+parameterized_test! {can_evaluate_insertion_with_battery, (power, insertion_data, expected), {
+    can_evaluate_insertion_with_battery_impl(power, insertion_data, expected);
+}}
+
+can_evaluate_insertion_with_battery! {
+    case01_accept_within_initial_charge: (None, (1, 6, (1, 2)), None),
+    case02_reject_above_initial_charge: (None, (1, 12, (1, 2)), ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_accept_within_capacity: (None, (3, 20, (3, 4)), None),
+    case04_reject_above_capacity: (None, (3, 30, (3, 4)), ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_evaluate_insertion_with_battery_impl(
+    power: Option<f64>,
+    insertion_data: (usize, Location, (usize, usize)),
+    expected: Option<ConstraintViolation>,
+) {
+    let (index, new_location, (prev, next)) = insertion_data;
+    let mut route_ctx = create_battery_route_ctx(create_battery(None), &[5, 10, 15], vec![(2, 7)]);
+    let feature = create_feature_with_accessor(create_accessor(None, power));
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    state.accept_route_state(&mut route_ctx);
+
+    let result = constraint.evaluate(&MoveContext::Activity {
+        route_ctx: &route_ctx,
+        activity_ctx: &ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(prev).unwrap(),
+            target: &create_activity_at_location(new_location),
+            next: route_ctx.route().tour.get(next),
+        },
+    });
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,208 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::enablers::update_route_schedule;
+use vrp_core::models::problem::{SimpleActivityCost, VehiclePlace};
+use vrp_core::models::solution::{Activity, Place};
+
+const VIOLATION_CODE: ViolationCode = 1;
+const SATELLITE_LOCATION: Location = 10;
+
+fn create_delivery(id: &str, satellite_id: Option<&str>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single
+        .dimens
+        .set_job_id(id.to_string())
+        .set_job_type("delivery".to_string())
+        .set_job_satellite(satellite_id.map(|id| id.to_string()))
+        .set_demand(Demand {
+            pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+            delivery: (SingleDimLoad::new(1), SingleDimLoad::default()),
+        });
+
+    Arc::new(single)
+}
+
+fn create_activity(single: Arc<Single>, location: Location, time: (f64, f64), schedule: (f64, f64)) -> Activity {
+    Activity {
+        place: Place { idx: 0, location, duration: 0., time: TimeWindow::new(time.0, time.1) },
+        schedule: Schedule::new(schedule.0, schedule.1),
+        job: Some(single),
+        commute: None,
+    }
+}
+
+fn create_feature() -> Feature {
+    let satellites = Satellites {
+        locations: vec![(SATELLITE_LOCATION, 0)].into_iter().collect(),
+        ids: vec![("sat1".to_string(), 0)].into_iter().collect(),
+    };
+
+    // NOTE satellite inventory is not tracked as total jobs differs from jobs in the solution
+    create_satellite_feature::<SingleDimLoad>(
+        "satellites",
+        usize::MAX,
+        VIOLATION_CODE,
+        satellites,
+        TestTransportCost::new_shared(),
+        Arc::new(SimpleActivityCost::default()),
+    )
+    .unwrap()
+}
+
+fn create_fleet() -> Fleet {
+    let mut bike = test_vehicle("bike");
+    bike.details = vec![VehicleDetail {
+        start: Some(VehiclePlace { location: SATELLITE_LOCATION, time: Default::default() }),
+        end: Some(VehiclePlace { location: SATELLITE_LOCATION, time: Default::default() }),
+    }];
+
+    test_fleet_with_vehicles(vec![Arc::new(test_vehicle("truck")), Arc::new(bike)])
+}
+
+/// Creates a solution with truck route which supplies the satellite and bike route which
+/// serves a job with given time window end.
+fn create_solution_ctx(fleet: &Fleet, supply_departure: f64, job_end: f64) -> SolutionContext {
+    let truck = create_route_with_activities(
+        fleet,
+        "truck",
+        vec![create_activity(
+            create_delivery("supply1", Some("sat1")),
+            SATELLITE_LOCATION,
+            (0., 1000.),
+            (supply_departure, supply_departure),
+        )],
+    );
+    let bike = create_route_with_activities(
+        fleet,
+        "bike",
+        vec![create_activity(create_delivery("job1", None), 12, (0., job_end), (2., 2.))],
+    );
+
+    let mut solution_ctx = create_solution_context_for_fleet(fleet);
+    solution_ctx.routes = vec![truck, bike]
+        .into_iter()
+        .map(|route| {
+            let mut route_ctx = RouteContext::new_with_state(route, RouteState::default());
+            update_route_schedule(
+                &mut route_ctx,
+                &SimpleActivityCost::default(),
+                TestTransportCost::new_shared().as_ref(),
+                &ScheduleStateKeys::default(),
+            );
+            route_ctx
+        })
+        .collect();
+
+    solution_ctx
+}
+
+#[test]
+fn can_delay_departure_till_satellite_is_supplied() {
+    let fleet = create_fleet();
+    let feature = create_feature();
+    let mut solution_ctx = create_solution_ctx(&fleet, 0., 1000.);
+    // NOTE truck departs later, so supply is delivered at 15
+    update_route_departure(
+        &mut solution_ctx.routes[0],
+        &SimpleActivityCost::default(),
+        TestTransportCost::new_shared().as_ref(),
+        5.,
+        &ScheduleStateKeys::default(),
+    );
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    let bike_route = solution_ctx.routes[1].route();
+    assert_eq!(bike_route.tour.start().unwrap().schedule.departure, 15.);
+    assert_eq!(bike_route.tour.get(1).unwrap().schedule.arrival, 17.);
+    assert!(solution_ctx.unassigned.is_empty());
+}
+
+parameterized_test! {can_bound_supply_time_window_by_latest_departure, (job_end, expected), {
+    can_bound_supply_time_window_by_latest_departure_impl(job_end, TimeWindow::new(expected.0, expected.1));
+}}
+
+can_bound_supply_time_window_by_latest_departure! {
+    case01_tight_job: (20., (0., 18.)),
+    case02_relaxed_job: (1000., (0., 998.)),
+}
+
+fn can_bound_supply_time_window_by_latest_departure_impl(job_end: f64, expected: TimeWindow) {
+    let fleet = create_fleet();
+    let feature = create_feature();
+    let mut solution_ctx = create_solution_ctx(&fleet, 0., job_end);
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[0].route().tour.get(1).unwrap().place.time, expected);
+    assert!(solution_ctx.unassigned.is_empty());
+}
+
+parameterized_test! {can_evaluate_supply_activity, (job_end, expected), {
+    can_evaluate_supply_activity_impl(job_end, expected);
+}}
+
+can_evaluate_supply_activity! {
+    case01_supply_in_time: (20., None),
+    case02_supply_is_late: (11., ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_evaluate_supply_activity_impl(job_end: f64, expected: Option<ConstraintViolation>) {
+    let fleet = create_fleet();
+    let feature = create_feature();
+    let mut solution_ctx = create_solution_ctx(&fleet, 0., job_end);
+    // NOTE remove existing supply to evaluate insertion of a new one
+    let supply = solution_ctx.routes[0].route().tour.jobs().next().cloned().unwrap();
+    solution_ctx.routes[0].route_mut().tour.remove(&supply);
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = &solution_ctx.routes[0];
+    let target = create_activity(create_delivery("supply2", Some("sat1")), SATELLITE_LOCATION, (0., 1000.), (0., 0.));
+
+    let actual = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+        route_ctx,
+        &ActivityContext {
+            index: 0,
+            prev: route_ctx.route().tour.get(0).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(1),
+        },
+    ));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn can_evaluate_supply_job_in_route_starting_at_satellite() {
+    let fleet = create_fleet();
+    let feature = create_feature();
+    let solution_ctx = create_solution_ctx(&fleet, 0., 1000.);
+    let constraint = feature.constraint.as_ref().unwrap();
+    let supply = Job::Single(create_delivery("supply2", Some("sat1")));
+
+    let truck = constraint.evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &supply));
+    let bike = constraint.evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[1], &supply));
+
+    assert_eq!(truck, None);
+    assert_eq!(bike, ConstraintViolation::fail(VIOLATION_CODE));
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    can_merge_jobs_impl(source, candidate, expected);
+}}
+
+can_merge_jobs! {
+    case01_no_supply: (None, None, Ok(())),
+    case02_source_supply: (Some("sat1"), None, Err(VIOLATION_CODE)),
+    case03_candidate_supply: (None, Some("sat1"), Err(VIOLATION_CODE)),
+    case04_both_supply: (Some("sat1"), Some("sat1"), Err(VIOLATION_CODE)),
+}
+
+fn can_merge_jobs_impl(source: Option<&str>, candidate: Option<&str>, expected: Result<(), ViolationCode>) {
+    let feature = create_feature();
+    let source = Job::Single(create_delivery("job1", source));
+    let candidate = Job::Single(create_delivery("job2", candidate));
+
+    let result = feature.constraint.unwrap().merge(source, candidate).map(|_| ());
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,156 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::enablers::NoRouteIntervals;
+use vrp_core::models::problem::Single;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_delivery(id: &str, demand: i32, truck_only: bool) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single
+        .dimens
+        .set_job_id(id.to_string())
+        .set_job_type("delivery".to_string())
+        .set_job_truck_only(truck_only)
+        .set_demand(Demand {
+            pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+            delivery: (SingleDimLoad::new(demand), SingleDimLoad::default()),
+        });
+
+    Arc::new(single)
+}
+
+fn create_park_job(vehicle_id: &str) -> Job {
+    let singles = ["decouple", "couple"]
+        .into_iter()
+        .map(|job_type| {
+            let mut single = create_single_with_location(Some(5));
+            single.dimens.set_job_type(job_type.to_string()).set_shift_index(0).set_vehicle_id(vehicle_id.to_string());
+            Arc::new(single)
+        })
+        .collect();
+
+    let mut dimens = Dimensions::default();
+    dimens
+        .set_job_id(format!("{vehicle_id}_trailer_0_1"))
+        .set_job_type("trailer".to_string())
+        .set_shift_index(0)
+        .set_vehicle_id(vehicle_id.to_string());
+
+    Job::Multi(Multi::new_shared(singles, dimens))
+}
+
+fn create_feature() -> Feature {
+    create_trailer_feature::<SingleDimLoad>("trailer", VIOLATION_CODE, Arc::new(NoRouteIntervals::default())).unwrap()
+}
+
+fn create_fleet(truck: i32) -> Fleet {
+    let mut vehicle = test_vehicle("v1");
+    vehicle
+        .dimens
+        .set_capacity(SingleDimLoad::new(truck + 8))
+        .set_vehicle_trailer(TrailerCapacity { truck: SingleDimLoad::new(truck), trailer: SingleDimLoad::new(8) });
+
+    test_fleet_with_vehicles(vec![Arc::new(vehicle)])
+}
+
+/// Creates a route with the following activities: job1, decouple, job2 (truck only), couple.
+fn create_route_ctx(fleet: &Fleet, feature: &Feature) -> RouteContext {
+    let park = create_park_job("v1");
+    let park = park.as_multi().unwrap();
+    let mut route_ctx = RouteContext::new_with_state(
+        create_route_with_activities(
+            fleet,
+            "v1",
+            vec![
+                create_activity_with_job_at_location(create_delivery("job1", 1, false), 1),
+                create_activity_with_job_at_location(park.jobs[0].clone(), 5),
+                create_activity_with_job_at_location(create_delivery("job2", 1, true), 8),
+                create_activity_with_job_at_location(park.jobs[1].clone(), 5),
+            ],
+        ),
+        RouteState::default(),
+    );
+
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+#[test]
+fn can_track_decoupled_ranges() {
+    let fleet = create_fleet(2);
+    let feature = create_feature();
+
+    let route_ctx = create_route_ctx(&fleet, &feature);
+
+    let ranges = route_ctx.state().get_route_state::<DecoupledRanges>(TRAILER_RANGES_KEY).unwrap();
+    assert_eq!(ranges, &vec![(2, Some(4))]);
+}
+
+parameterized_test! {can_evaluate_activity, (truck, index, target, expected), {
+    can_evaluate_activity_impl(truck, index, target, expected);
+}}
+
+can_evaluate_activity! {
+    case01_regular_job_with_trailer: (2, 1, (1, false), None),
+    case02_truck_only_job_with_trailer: (2, 0, (0, true), ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_truck_only_job_without_trailer: (2, 2, (1, true), None),
+    case04_truck_is_overloaded: (1, 2, (1, true), ConstraintViolation::skip(VIOLATION_CODE)),
+    case05_truck_is_not_loaded: (1, 3, (0, false), None),
+    case06_regular_job_after_couple: (1, 4, (1, false), None),
+}
+
+fn can_evaluate_activity_impl(truck: i32, index: usize, target: (i32, bool), expected: Option<ConstraintViolation>) {
+    let fleet = create_fleet(truck);
+    let feature = create_feature();
+    let route_ctx = create_route_ctx(&fleet, &feature);
+    let target = create_activity_with_job_at_location(create_delivery("job3", target.0, target.1), 3);
+
+    let actual = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+        &route_ctx,
+        &ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(index).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(index + 1),
+        },
+    ));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn can_evaluate_park_jobs() {
+    let fleet = create_fleet(2);
+    let feature = create_feature();
+    let constraint = feature.constraint.as_ref().unwrap();
+    let route_ctx =
+        RouteContext::new_with_state(create_route_with_activities(&fleet, "v1", vec![]), RouteState::default());
+    let solution_ctx = create_solution_context_for_fleet(&fleet);
+
+    let own_park = constraint.evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &create_park_job("v1")));
+    let other_park = constraint.evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &create_park_job("v2")));
+
+    assert_eq!(own_park, None);
+    assert_eq!(other_park, ConstraintViolation::fail(VIOLATION_CODE));
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    can_merge_jobs_impl(source, candidate, expected);
+}}
+
+can_merge_jobs! {
+    case01_regular_jobs: (Job::Single(create_delivery("job1", 1, false)), Job::Single(create_delivery("job2", 1, false)), Ok(())),
+    case02_truck_only_jobs: (Job::Single(create_delivery("job1", 1, true)), Job::Single(create_delivery("job2", 1, true)), Ok(())),
+    case03_mixed_jobs: (Job::Single(create_delivery("job1", 1, false)), Job::Single(create_delivery("job2", 1, true)), Err(1)),
+    case04_park_job: (create_park_job("v1"), Job::Single(create_delivery("job2", 1, false)), Err(1)),
+}
+
+fn can_merge_jobs_impl(source: Job, candidate: Job, expected: Result<(), i32>) {
+    let constraint = create_feature().constraint.unwrap();
+
+    let actual = constraint.merge(source, candidate).map(|_| ());
+
+    assert_eq!(actual, expected);
+}
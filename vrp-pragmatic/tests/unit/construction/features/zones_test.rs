@@ -0,0 +1,88 @@
+use super::*;
+use crate::helpers::*;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_zone(index: usize, vehicle_types: Option<Vec<&str>>, fee: Cost) -> Arc<GeoZone> {
+    Arc::new(GeoZone {
+        index,
+        vehicle_types: vehicle_types.map(|types| types.iter().map(|t| t.to_string()).collect()),
+        fee,
+    })
+}
+
+fn create_job_with_zones(zones: Option<Vec<Arc<GeoZone>>>) -> Job {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_place_zones(zones.map(|zones| vec![(0, zones)]));
+
+    Job::Single(Arc::new(single))
+}
+
+parameterized_test! {can_check_zone_access, (vehicle_types, expected), {
+    can_check_zone_access_impl(vehicle_types, expected);
+}}
+
+can_check_zone_access! {
+    case01_no_zones: (None, None),
+    case02_any_vehicle_type: (Some(None), None),
+    case03_allowed_vehicle_type: (Some(Some(vec!["v1"])), None),
+    case04_allowed_among_many: (Some(Some(vec!["v2", "v1"])), None),
+    case05_disallowed_vehicle_type: (Some(Some(vec!["v2"])), ConstraintViolation::fail(VIOLATION_CODE)),
+}
+
+fn can_check_zone_access_impl(vehicle_types: Option<Option<Vec<&str>>>, expected: Option<ConstraintViolation>) {
+    let fleet = test_fleet();
+    let route_ctx =
+        RouteContext::new_with_state(create_route_with_activities(&fleet, "v1", vec![]), RouteState::default());
+    let job = create_job_with_zones(vehicle_types.map(|vehicle_types| vec![create_zone(0, vehicle_types, 0.)]));
+    let constraint = create_zone_feature("zones", VIOLATION_CODE, ZONE_KEY).unwrap().constraint.unwrap();
+
+    let actual = constraint.evaluate(&MoveContext::route(&create_solution_context_for_fleet(&fleet), &route_ctx, &job));
+
+    assert_eq!(actual, expected)
+}
+
+#[test]
+fn can_track_route_zones_with_fees() {
+    let fleet = test_fleet();
+    let create_activity = |zones| {
+        let job = create_job_with_zones(Some(zones)).to_single().clone();
+        create_activity_with_job_at_location(job, DEFAULT_JOB_LOCATION)
+    };
+    let mut route_ctx = RouteContext::new_with_state(
+        create_route_with_activities(
+            &fleet,
+            "v1",
+            vec![
+                create_activity(vec![create_zone(0, None, 10.)]),
+                create_activity(vec![create_zone(0, None, 10.), create_zone(1, None, 5.)]),
+            ],
+        ),
+        RouteState::default(),
+    );
+    let state = create_zone_feature("zones", VIOLATION_CODE, ZONE_KEY).unwrap().state.unwrap();
+
+    state.accept_route_state(&mut route_ctx);
+
+    let zones = route_ctx.state().get_route_state::<HashMap<usize, Cost>>(ZONE_KEY).unwrap();
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones.values().sum::<Cost>(), 15.);
+}
+
+parameterized_test! {can_merge_zones, (source, candidate, expected), {
+    can_merge_zones_impl(source, candidate, expected);
+}}
+
+can_merge_zones! {
+    case_01: (create_job_with_zones(None), create_job_with_zones(None), Ok(())),
+    case_02: (create_job_with_zones(Some(vec![create_zone(0, None, 0.)])), create_job_with_zones(None), Err(1)),
+    case_03: (create_job_with_zones(None), create_job_with_zones(Some(vec![create_zone(0, None, 0.)])), Err(1)),
+}
+
+fn can_merge_zones_impl(source: Job, candidate: Job, expected: Result<(), i32>) {
+    let constraint = create_zone_feature("zones", VIOLATION_CODE, ZONE_KEY).unwrap().constraint.unwrap();
+
+    let result = constraint.merge(source, candidate).map(|_| ());
+
+    assert_eq!(result, expected);
+}
@@ -15,11 +15,18 @@ fn can_use_index_with_coordinate_an_unknown_location_types() {
                         places: vec![JobPlace {
                             location: unknown_location.clone(),
                             duration: 0.,
+                            setup: None,
+                            duration_rules: None,
                             times: None,
                             tag: None,
+                            tolerance: None,
+                            dock: None,
                         }],
                         demand: None,
                         order: None,
+                        splittable: None,
+                        min_split: None,
+                        max_time_in_vehicle: None,
                     }]),
                     ..create_job("job3")
                 },
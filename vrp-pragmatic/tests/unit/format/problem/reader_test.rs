@@ -76,10 +76,17 @@ fn can_read_complex_problem() {
                             ]),
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 100.0,
+                            setup: None,
+                            duration_rules: None,
                             tag: Some("my_delivery".to_string()),
+                            tolerance: None,
+                            dock: None,
                         }],
                         demand: Some(vec![0, 1]),
                         order: None,
+                        splittable: None,
+                        min_split: None,
+                        max_time_in_vehicle: None,
                     }]),
                     skills: Some(all_of_skills(vec!["unique".to_string()])),
                     ..create_job("delivery_job")
@@ -93,10 +100,17 @@ fn can_read_complex_problem() {
                             ]]),
                             location: (52.48300, 13.4420).to_loc(),
                             duration: 110.0,
+                            setup: None,
+                            duration_rules: None,
                             tag: None,
+                            tolerance: None,
+                            dock: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
+                        splittable: None,
+                        min_split: None,
+                        max_time_in_vehicle: None,
                     }]),
                     deliveries: Some(vec![JobTask {
                         places: vec![JobPlace {
@@ -106,10 +120,17 @@ fn can_read_complex_problem() {
                             ]]),
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 120.0,
+                            setup: None,
+                            duration_rules: None,
                             tag: None,
+                            tolerance: None,
+                            dock: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
+                        splittable: None,
+                        min_split: None,
+                        max_time_in_vehicle: None,
                     }]),
                     ..create_job("pickup_delivery_job")
                 },
@@ -122,10 +143,17 @@ fn can_read_complex_problem() {
                             ]]),
                             location: (52.48321, 13.4438).to_loc(),
                             duration: 90.0,
+                            setup: None,
+                            duration_rules: None,
                             tag: None,
+                            tolerance: None,
+                            dock: None,
                         }],
                         demand: Some(vec![3]),
                         order: None,
+                        splittable: None,
+                        min_split: None,
+                        max_time_in_vehicle: None,
                     }]),
                     skills: Some(all_of_skills(vec!["unique2".to_string()])),
                     ..create_job("pickup_job")
@@ -138,7 +166,14 @@ fn can_read_complex_problem() {
                 type_id: "my_vehicle".to_string(),
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 profile: create_default_vehicle_profile(),
-                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2. },
+                costs: VehicleCosts {
+                    fixed: Some(100.),
+                    distance: 1.,
+                    time: 2.,
+                    distance_loaded: None,
+                    time_tiers: None,
+                    distance_tiers: None,
+                },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
@@ -167,7 +202,14 @@ fn can_read_complex_problem() {
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
-                limits: Some(VehicleLimits { max_distance: Some(123.1), max_duration: Some(100.), tour_size: Some(3) }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(123.1),
+                    max_duration: Some(100.),
+                    tour_size: Some(3),
+                    trip: None,
+                }),
+                compartments: None,
+                trailer: None,
             }],
             ..create_default_fleet()
         },
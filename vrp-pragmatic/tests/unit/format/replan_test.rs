@@ -0,0 +1,413 @@
+use super::*;
+use crate::format::solution::Solution;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem(vehicle_ids: Vec<&str>, shifts: Vec<VehicleShift>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_multi_job("job2", vec![((2., 0.), 1., vec![1])], vec![((3., 0.), 1., vec![1])]),
+                create_delivery_job("job3", (4., 0.)),
+                create_delivery_job("job4", (5., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vehicle_ids.into_iter().map(|id| id.to_string()).collect(),
+                shifts,
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_solution(vehicle_id: &str) -> Solution {
+    SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .vehicle_id(vehicle_id)
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![1])
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((2., 0.))
+                        .schedule_stamp(3., 4.)
+                        .load(vec![2])
+                        .build_single_tag("job2", "pickup", "p1"),
+                    StopBuilder::default()
+                        .coordinate((3., 0.))
+                        .schedule_stamp(5., 6.)
+                        .load(vec![1])
+                        .build_single_tag("job2", "delivery", "d1"),
+                    StopBuilder::default()
+                        .coordinate((4., 0.))
+                        .schedule_stamp(7., 8.)
+                        .load(vec![0])
+                        .build_single("job3", "delivery"),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(12., 12.).load(vec![0]).build_arrival(),
+                ])
+                .build(),
+        )
+        .build()
+}
+
+fn create_state(time: f64, vehicles: Option<Vec<VehiclePosition>>, cancelled: Option<Vec<&str>>) -> ExecutionState {
+    ExecutionState {
+        time: format_time(time),
+        vehicles,
+        cancelled: cancelled.map(|ids| ids.into_iter().map(|id| id.to_string()).collect()),
+    }
+}
+
+fn get_job_ids(problem: &Problem) -> Vec<String> {
+    problem.plan.jobs.iter().map(|job| job.id.clone()).collect()
+}
+
+fn get_locked_jobs(problem: &Problem) -> Vec<(String, String, usize)> {
+    problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter())
+        .flat_map(|relation| {
+            relation.jobs.iter().map(|job_id| {
+                (job_id.clone(), relation.vehicle_id.clone().unwrap(), relation.shift_index.unwrap_or_default())
+            })
+        })
+        .collect()
+}
+
+type ShiftStartData = (String, Option<String>, Location);
+
+fn get_shift_starts(problem: &Problem) -> Vec<(String, Vec<String>, Vec<ShiftStartData>)> {
+    problem
+        .fleet
+        .vehicles
+        .iter()
+        .map(|vehicle| {
+            (
+                vehicle.type_id.clone(),
+                vehicle.vehicle_ids.clone(),
+                vehicle
+                    .shifts
+                    .iter()
+                    .map(|shift| {
+                        (shift.start.earliest.clone(), shift.start.latest.clone(), shift.start.location.clone())
+                    })
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+fn get_job_times(problem: &Problem, job_id: &str) -> Vec<Vec<String>> {
+    problem
+        .plan
+        .jobs
+        .iter()
+        .filter(|job| job.id == job_id)
+        .flat_map(|job| get_tasks(job).flat_map(|task| task.places.iter()))
+        .flat_map(|place| place.times.iter().flatten().cloned())
+        .collect()
+}
+
+fn to_strings(values: Vec<&str>) -> Vec<String> {
+    values.into_iter().map(|value| value.to_string()).collect()
+}
+
+#[test]
+fn can_keep_served_part_of_departed_vehicle() {
+    let mut problem = create_test_problem(vec!["v1", "v2"], vec![create_default_vehicle_shift()]);
+    problem.plan.jobs[1].deliveries.as_mut().unwrap()[0].places[0].times =
+        Some(vec![vec![format_time(0.), format_time(2.)], vec![format_time(4.), format_time(10.)]]);
+    let solution = create_test_solution("v1");
+
+    let result = create_replan_problem(&problem, &solution, &create_state(4., None, None)).unwrap();
+
+    assert_eq!(get_job_ids(&result), vec!["job1", "job2", "job3", "job4", "v1_position_0"]);
+    assert_eq!(get_job_times(&result, "job1"), vec![vec![format_time(1.), format_time(1.)]]);
+    assert_eq!(
+        get_job_times(&result, "job2"),
+        vec![vec![format_time(3.), format_time(3.)], vec![format_time(4.), format_time(10.)]]
+    );
+    let position = result.plan.jobs.last().unwrap();
+    assert_eq!(position.services.as_ref().unwrap()[0].places[0].location, (2., 0.).to_loc());
+    assert_eq!(get_job_times(&result, "v1_position_0"), vec![vec![format_time(4.), format_time(1000.)]]);
+    assert!(matches!(result.plan.relations.as_ref().unwrap()[0].type_field, RelationType::Strict));
+    assert_eq!(
+        get_locked_jobs(&result),
+        vec![
+            ("departure".to_string(), "v1".to_string(), 0),
+            ("job1".to_string(), "v1".to_string(), 0),
+            ("job2".to_string(), "v1".to_string(), 0),
+            ("v1_position_0".to_string(), "v1".to_string(), 0),
+            ("job2".to_string(), "v1".to_string(), 0),
+            ("job3".to_string(), "v1".to_string(), 0),
+        ]
+    );
+    assert_eq!(
+        get_shift_starts(&result),
+        vec![
+            ("my_vehicle".to_string(), vec!["v2".to_string()], vec![(format_time(4.), None, (0., 0.).to_loc())]),
+            (
+                "my_vehicle_v1".to_string(),
+                vec!["v1".to_string()],
+                vec![(format_time(0.), Some(format_time(0.)), (0., 0.).to_loc())]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn can_use_vehicle_position_and_cancelled_jobs() {
+    let problem = create_test_problem(vec!["v1"], vec![create_default_vehicle_shift()]);
+    let solution = create_test_solution("v1");
+    let state = create_state(
+        4.,
+        Some(vec![VehiclePosition { vehicle_id: "v1".to_string(), location: (10., 0.).to_loc() }]),
+        Some(vec!["job3", "job4"]),
+    );
+
+    let result = create_replan_problem(&problem, &solution, &state).unwrap();
+
+    assert_eq!(get_job_ids(&result), vec!["job1", "job2", "v1_position_0"]);
+    let position = result.plan.jobs.last().unwrap();
+    assert_eq!(position.services.as_ref().unwrap()[0].places[0].location, (10., 0.).to_loc());
+    assert_eq!(
+        get_locked_jobs(&result),
+        vec![
+            ("departure".to_string(), "v1".to_string(), 0),
+            ("job1".to_string(), "v1".to_string(), 0),
+            ("job2".to_string(), "v1".to_string(), 0),
+            ("v1_position_0".to_string(), "v1".to_string(), 0),
+            ("job2".to_string(), "v1".to_string(), 0),
+        ]
+    );
+    assert_eq!(
+        get_shift_starts(&result),
+        vec![(
+            "my_vehicle".to_string(),
+            vec!["v1".to_string()],
+            vec![(format_time(0.), Some(format_time(0.)), (0., 0.).to_loc())]
+        )]
+    );
+}
+
+#[test]
+fn can_keep_finished_tour_and_remove_expired_shifts() {
+    let expired_shift = VehicleShift {
+        end: Some(ShiftEnd { earliest: None, latest: format_time(10.), location: (0., 0.).to_loc() }),
+        ..create_default_vehicle_shift()
+    };
+    let future_shift = VehicleShift {
+        start: ShiftStart { earliest: format_time(100.), latest: None, location: (0., 0.).to_loc() },
+        ..create_default_vehicle_shift()
+    };
+    let mut problem = create_test_problem(vec!["v1", "v2"], vec![expired_shift, future_shift]);
+    problem.plan.relations = Some(vec![Relation {
+        type_field: RelationType::Sequence,
+        jobs: vec!["departure".to_string(), "job1".to_string(), "job4".to_string()],
+        vehicle_id: Some("v2".to_string()),
+        shift_index: Some(1),
+        offset: None,
+    }]);
+    let solution = create_test_solution("v1");
+
+    let result = create_replan_problem(&problem, &solution, &create_state(20., None, None)).unwrap();
+
+    assert_eq!(get_job_ids(&result), vec!["job1", "job2", "job3", "job4"]);
+    assert_eq!(
+        get_job_times(&result, "job2"),
+        vec![vec![format_time(3.), format_time(3.)], vec![format_time(5.), format_time(5.)]]
+    );
+    assert_eq!(
+        get_locked_jobs(&result),
+        vec![
+            ("departure".to_string(), "v1".to_string(), 0),
+            ("job1".to_string(), "v1".to_string(), 0),
+            ("job2".to_string(), "v1".to_string(), 0),
+            ("job2".to_string(), "v1".to_string(), 0),
+            ("job3".to_string(), "v1".to_string(), 0),
+            ("arrival".to_string(), "v1".to_string(), 0),
+            ("departure".to_string(), "v2".to_string(), 0),
+            ("job4".to_string(), "v2".to_string(), 0),
+        ]
+    );
+    assert_eq!(
+        get_shift_starts(&result),
+        vec![
+            ("my_vehicle".to_string(), vec!["v2".to_string()], vec![(format_time(100.), None, (0., 0.).to_loc())]),
+            (
+                "my_vehicle_v1".to_string(),
+                vec!["v1".to_string()],
+                vec![
+                    (format_time(0.), Some(format_time(0.)), (0., 0.).to_loc()),
+                    (format_time(100.), None, (0., 0.).to_loc())
+                ]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn can_unlock_departure_of_departed_vehicle_relation() {
+    let mut problem = create_test_problem(vec!["v1"], vec![create_default_vehicle_shift()]);
+    problem.plan.relations = Some(vec![Relation {
+        type_field: RelationType::Sequence,
+        jobs: to_strings(vec!["departure", "job1", "job4"]),
+        vehicle_id: Some("v1".to_string()),
+        shift_index: None,
+        offset: None,
+    }]);
+    let solution = create_test_solution("v1");
+
+    let result = create_replan_problem(&problem, &solution, &create_state(4., None, Some(vec!["job3"]))).unwrap();
+
+    let relations = result.plan.relations.unwrap();
+    assert_eq!(relations.len(), 2);
+    assert_eq!(relations[0].jobs, to_strings(vec!["departure", "job1", "job2", "v1_position_0", "job2"]));
+    assert_eq!(relations[1].jobs, to_strings(vec!["job4"]));
+}
+
+#[test]
+fn can_keep_not_departed_vehicle_plan() {
+    let shift = VehicleShift {
+        start: ShiftStart { earliest: format_time(10.), latest: None, location: (0., 0.).to_loc() },
+        ..create_default_vehicle_shift()
+    };
+    let problem = create_test_problem(vec!["v1"], vec![shift]);
+    let mut solution = create_test_solution("v1");
+    solution.tours[0].stops[0].schedule_mut().departure = format_time(10.);
+
+    let result = create_replan_problem(&problem, &solution, &create_state(5., None, None)).unwrap();
+
+    assert_eq!(get_job_ids(&result), vec!["job1", "job2", "job3", "job4"]);
+    assert!(result.plan.relations.is_none());
+    assert_eq!(
+        get_shift_starts(&result),
+        vec![("my_vehicle".to_string(), vec!["v1".to_string()], vec![(format_time(10.), None, (0., 0.).to_loc())])]
+    );
+}
+
+#[test]
+fn can_update_breaks_of_departed_vehicle() {
+    let shift = VehicleShift {
+        breaks: Some(vec![
+            VehicleBreak::Required {
+                time: VehicleRequiredBreakTime::OffsetTime { earliest: 1., latest: 2. },
+                duration: 1.,
+            },
+            VehicleBreak::Required {
+                time: VehicleRequiredBreakTime::OffsetTime { earliest: 20., latest: 30. },
+                duration: 1.,
+            },
+            VehicleBreak::Driving { max_driving_time: 100., duration: 10., split: None },
+        ]),
+        ..create_default_vehicle_shift()
+    };
+    let problem = create_test_problem(vec!["v1"], vec![shift]);
+    let mut solution = create_test_solution("v1");
+    solution.tours[0].stops[1].activities_mut().push(ActivityBuilder::break_type().build());
+
+    let result = create_replan_problem(&problem, &solution, &create_state(4., None, None)).unwrap();
+
+    let breaks = result.fleet.vehicles[0].shifts[0].breaks.as_ref().unwrap();
+    assert_eq!(breaks.len(), 2);
+    match &breaks[0] {
+        VehicleBreak::Required { time: VehicleRequiredBreakTime::OffsetTime { earliest, latest }, .. } => {
+            assert_eq!(*earliest, 20.);
+            assert_eq!(*latest, 30.);
+        }
+        _ => unreachable!("unexpected break type"),
+    }
+    assert!(matches!(breaks[1], VehicleBreak::Driving { .. }));
+    assert_eq!(
+        result.plan.relations.unwrap()[0].jobs,
+        to_strings(vec!["departure", "job1", "job2", "v1_position_0", "job2"])
+    );
+}
+
+#[test]
+fn can_lock_taken_optional_break_and_reload() {
+    let break_place =
+        |tag: &str| VehicleOptionalBreakPlace { duration: 1., location: None, tag: Some(tag.to_string()) };
+    let reload = |location: (f64, f64)| VehicleReload {
+        location: location.to_loc(),
+        duration: 1.,
+        times: None,
+        tag: None,
+        resource_id: None,
+        dock: None,
+    };
+    let shift = VehicleShift {
+        breaks: Some(vec![
+            VehicleBreak::Optional {
+                time: VehicleOptionalBreakTime::TimeOffset(vec![0., 10.]),
+                places: vec![break_place("b1"), break_place("b2")],
+                policy: None,
+            },
+            VehicleBreak::Optional {
+                time: VehicleOptionalBreakTime::TimeOffset(vec![10., 20.]),
+                places: vec![break_place("b3")],
+                policy: None,
+            },
+        ]),
+        reloads: Some(vec![reload((5., 0.)), reload((2., 0.))]),
+        ..create_default_vehicle_shift()
+    };
+    let problem = create_test_problem(vec!["v1"], vec![shift]);
+    let mut solution = create_test_solution("v1");
+    solution.tours[0].stops[1].activities_mut().push(ActivityBuilder::break_type().tag("b2").build());
+    solution.tours[0].stops[2]
+        .activities_mut()
+        .push(ActivityBuilder::default().job_id("reload").activity_type("reload").build());
+
+    let result = create_replan_problem(&problem, &solution, &create_state(4., None, None)).unwrap();
+
+    let shift = &result.fleet.vehicles[0].shifts[0];
+    match shift.breaks.as_ref().unwrap().as_slice() {
+        [VehicleBreak::Optional { time: VehicleOptionalBreakTime::TimeWindow(time), places, .. }, VehicleBreak::Optional { time: VehicleOptionalBreakTime::TimeOffset(_), .. }] =>
+        {
+            assert_eq!(time, &vec![format_time(1.), format_time(1.)]);
+            assert_eq!(places.len(), 1);
+            assert_eq!(places[0].tag, Some("b2".to_string()));
+        }
+        _ => unreachable!("unexpected breaks"),
+    }
+    let reloads = shift.reloads.as_ref().unwrap();
+    assert_eq!(reloads.len(), 2);
+    assert_eq!(reloads[0].location, (2., 0.).to_loc());
+    assert_eq!(reloads[0].times, Some(vec![vec![format_time(3.), format_time(3.)]]));
+    assert_eq!(reloads[1].location, (5., 0.).to_loc());
+    assert_eq!(
+        result.plan.relations.unwrap()[0].jobs,
+        to_strings(vec!["departure", "job1", "break", "job2", "reload", "v1_position_0", "job2"])
+    );
+}
+
+#[test]
+fn can_deserialize_execution_state() {
+    let state = r#"
+      {
+        "time": "1970-01-01T00:00:10Z",
+        "vehicles": [ { "vehicleId": "v1", "location": { "lat": 1.0, "lng": 2.0 } } ],
+        "cancelled": [ "job1" ]
+      }
+    "#;
+
+    let state = deserialize_execution_state(BufReader::new(state.as_bytes())).unwrap();
+
+    assert_eq!(state.time, format_time(10.));
+    assert_eq!(state.vehicles.as_ref().unwrap()[0].vehicle_id, "v1");
+    assert_eq!(state.vehicles.as_ref().unwrap()[0].location, Location::new_coordinate(1., 2.));
+    assert_eq!(state.cancelled, Some(vec!["job1".to_string()]));
+}
@@ -65,7 +65,10 @@ fn can_create_geo_json_for_cluster_geometry() {
         time: Schedule { arrival: format_time(0.), departure: format_time(10.) },
         distance: 0,
         load: vec![],
+        compartments: None,
+        state_of_charge: None,
         parking: None,
+        setup: None,
         activities: vec![
             Activity {
                 job_id: "job1".to_string(),
@@ -74,6 +77,12 @@ fn can_create_geo_json_for_cluster_geometry() {
                 time: Some(Interval { start: format_time(0.), end: format_time(1.) }),
                 job_tag: None,
                 commute: Some(Commute { forward: None, backward: None }),
+                earliness: None,
+                lateness: None,
+                ride_duration: None,
+                time_in_vehicle: None,
+                quantity: None,
+                queueing: None,
             },
             Activity {
                 job_id: "job2".to_string(),
@@ -93,6 +102,12 @@ fn can_create_geo_json_for_cluster_geometry() {
                         time: Interval { start: format_time(3.), end: format_time(4.) },
                     }),
                 }),
+                earliness: None,
+                lateness: None,
+                ride_duration: None,
+                time_in_vehicle: None,
+                quantity: None,
+                queueing: None,
             },
         ],
     };
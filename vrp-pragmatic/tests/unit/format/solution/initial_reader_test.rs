@@ -150,6 +150,7 @@ fn can_handle_empty_tour_error_in_init_solution() {
             shift_index: 0,
             stops: Default::default(),
             statistic: Default::default(),
+            driver_id: None,
         })
         .build();
 
@@ -0,0 +1,46 @@
+use super::*;
+
+fn create_square() -> Vec<Location> {
+    vec![
+        Location::new_coordinate(0., 0.),
+        Location::new_coordinate(0., 10.),
+        Location::new_coordinate(10., 10.),
+        Location::new_coordinate(10., 0.),
+    ]
+}
+
+parameterized_test! {can_check_location_inside_polygon, (location, expected), {
+    can_check_location_inside_polygon_impl(location, expected);
+}}
+
+can_check_location_inside_polygon! {
+    case01_inside: (Location::new_coordinate(5., 5.), true),
+    case02_outside: (Location::new_coordinate(15., 5.), false),
+    case03_outside_negative: (Location::new_coordinate(-1., -1.), false),
+    case04_reference: (Location::new_reference(0), false),
+}
+
+fn can_check_location_inside_polygon_impl(location: Location, expected: bool) {
+    assert_eq!(is_inside_polygon(&location, create_square().as_slice()), expected);
+}
+
+#[test]
+fn can_check_location_inside_concave_polygon() {
+    let polygon = vec![
+        Location::new_coordinate(0., 0.),
+        Location::new_coordinate(0., 10.),
+        Location::new_coordinate(5., 5.),
+        Location::new_coordinate(10., 10.),
+        Location::new_coordinate(10., 0.),
+    ];
+
+    assert!(is_inside_polygon(&Location::new_coordinate(2., 5.), polygon.as_slice()));
+    assert!(!is_inside_polygon(&Location::new_coordinate(5., 8.), polygon.as_slice()));
+}
+
+#[test]
+fn can_ignore_malformed_polygon() {
+    let polygon = vec![Location::new_coordinate(0., 0.), Location::new_coordinate(10., 10.)];
+
+    assert!(!is_inside_polygon(&Location::new_coordinate(5., 5.), polygon.as_slice()));
+}
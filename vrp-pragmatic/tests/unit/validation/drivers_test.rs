@@ -0,0 +1,132 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_driver_type(type_id: &str, driver_ids: Vec<&str>, shifts: Vec<(&str, &str)>) -> DriverType {
+    DriverType {
+        type_id: type_id.to_string(),
+        driver_ids: driver_ids.into_iter().map(|id| id.to_string()).collect(),
+        costs: DriverCosts { fixed: None, distance: 0., time: 1. },
+        shifts: shifts
+            .into_iter()
+            .map(|(start, end)| DriverShift { start: start.to_string(), end: end.to_string() })
+            .collect(),
+        skills: None,
+        vehicle_type_ids: None,
+    }
+}
+
+fn create_problem_with_drivers(drivers: Vec<DriverType>) -> Problem {
+    Problem { fleet: Fleet { drivers: Some(drivers), ..create_default_fleet() }, ..create_empty_problem() }
+}
+
+#[test]
+fn can_detect_duplicated_driver_ids() {
+    let shift = ("1970-01-01T00:00:00Z", "1970-01-01T00:01:40Z");
+    let problem = create_problem_with_drivers(vec![
+        create_driver_type("type1", vec!["driver1", "driver2"], vec![shift]),
+        create_driver_type("type2", vec!["driver2"], vec![shift]),
+    ]);
+
+    let result =
+        check_e1400_no_drivers_with_duplicate_ids(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), Some("E1400".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_driver_shift_time, (shifts, expected), {
+    can_detect_invalid_driver_shift_time_impl(shifts, expected);
+}}
+
+can_detect_invalid_driver_shift_time! {
+    case01: (vec![("1970-01-01T00:00:00Z", "1970-01-01T00:01:40Z")], None),
+    case02: (vec![("1970-01-01T00:01:40Z", "1970-01-01T00:00:00Z")], Some("E1401".to_string())),
+    case03: (vec![], Some("E1401".to_string())),
+    case04: (vec![("1970-01-01T00:00:00Z", "1970-01-01T00:01:40Z"), ("1970-01-01T00:01:00Z", "1970-01-01T00:02:00Z")],
+             Some("E1401".to_string())),
+}
+
+fn can_detect_invalid_driver_shift_time_impl(shifts: Vec<(&str, &str)>, expected: Option<String>) {
+    let problem = create_problem_with_drivers(vec![create_driver_type("type1", vec!["driver1"], shifts)]);
+
+    let result = check_e1401_driver_shift_time(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_unknown_driver_vehicle_types, (vehicle_type_ids, expected), {
+    can_detect_unknown_driver_vehicle_types_impl(vehicle_type_ids, expected);
+}}
+
+can_detect_unknown_driver_vehicle_types! {
+    case01_not_specified: (None, None),
+    case02_known: (Some(vec!["my_vehicle"]), None),
+    case03_unknown: (Some(vec!["my_vehicle", "unknown"]), Some("E1402".to_string())),
+    case04_empty: (Some(vec![]), Some("E1402".to_string())),
+}
+
+fn can_detect_unknown_driver_vehicle_types_impl(vehicle_type_ids: Option<Vec<&str>>, expected: Option<String>) {
+    let shift = ("1970-01-01T00:00:00Z", "1970-01-01T00:01:40Z");
+    let problem = create_problem_with_drivers(vec![DriverType {
+        vehicle_type_ids: vehicle_type_ids.map(|ids| ids.into_iter().map(|id| id.to_string()).collect()),
+        ..create_driver_type("type1", vec!["driver1"], vec![shift])
+    }]);
+
+    let result = check_e1402_driver_vehicle_types(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_empty_drivers_list, (drivers, expected), {
+    can_detect_empty_drivers_list_impl(drivers, expected);
+}}
+
+can_detect_empty_drivers_list! {
+    case01_not_specified: (None, None),
+    case02_empty: (Some(vec![]), Some("E1403".to_string())),
+    case03_not_empty: (Some(vec![create_driver_type("type1", vec!["driver1"], vec![("1970-01-01T00:00:00Z", "1970-01-01T00:01:40Z")])]), None),
+}
+
+fn can_detect_empty_drivers_list_impl(drivers: Option<Vec<DriverType>>, expected: Option<String>) {
+    let problem = Problem { fleet: Fleet { drivers, ..create_default_fleet() }, ..create_empty_problem() };
+
+    let result = check_e1403_empty_drivers_list(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_drivers_and_vehicles_without_pairs, (shift, vehicle_type_ids, expected), {
+    can_detect_drivers_and_vehicles_without_pairs_impl(shift, vehicle_type_ids, expected);
+}}
+
+can_detect_drivers_and_vehicles_without_pairs! {
+    case01_all_types: (("1970-01-01T00:00:00Z", "1970-01-01T00:01:40Z"), None, (None, None)),
+    case02_one_type: (("1970-01-01T00:00:00Z", "1970-01-01T00:01:40Z"), Some(vec!["vehicle1"]), (None, Some("E1405"))),
+    case03_no_overlap: (("1970-01-01T00:20:00Z", "1970-01-01T00:30:00Z"), None, (Some("E1404"), Some("E1405"))),
+    case04_touching: (("1970-01-01T00:16:40Z", "1970-01-01T00:30:00Z"), None, (Some("E1404"), Some("E1405"))),
+}
+
+fn can_detect_drivers_and_vehicles_without_pairs_impl(
+    shift: (&str, &str),
+    vehicle_type_ids: Option<Vec<&str>>,
+    expected: (Option<&str>, Option<&str>),
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("vehicle1"), create_default_vehicle("vehicle2")],
+            drivers: Some(vec![DriverType {
+                vehicle_type_ids: vehicle_type_ids.map(|ids| ids.into_iter().map(|id| id.to_string()).collect()),
+                ..create_driver_type("type1", vec!["driver1"], vec![shift])
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let driver_result = check_e1404_driver_without_vehicles(&ctx);
+    let vehicle_result = check_e1405_vehicle_without_drivers(&ctx);
+
+    assert_eq!(driver_result.err().map(|err| err.code), expected.0.map(|code| code.to_string()));
+    assert_eq!(vehicle_result.err().map(|err| err.code), expected.1.map(|code| code.to_string()));
+}
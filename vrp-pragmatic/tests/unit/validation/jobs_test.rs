@@ -59,6 +59,18 @@ fn can_detect_negative_duration() {
     assert_result("E1106", "job1", result);
 }
 
+#[test]
+fn can_detect_negative_setup_duration() {
+    let mut job = create_delivery_job("job1", (1., 0.));
+    job.deliveries.as_mut().unwrap()[0].places[0].setup = Some(-10.);
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1106_negative_duration(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_result("E1106", "job1", result);
+}
+
 #[test]
 fn can_detect_negative_demand() {
     let problem = Problem {
@@ -73,3 +85,395 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+parameterized_test! {can_detect_invalid_ride_duration_limit, (is_pickup_delivery, max_ride_duration, max_ride_factor, expected), {
+    can_detect_invalid_ride_duration_limit_impl(is_pickup_delivery, max_ride_duration, max_ride_factor, expected);
+}}
+
+can_detect_invalid_ride_duration_limit! {
+    case01: (true, Some(100.), None, None),
+    case02: (true, None, Some(1.5), None),
+    case03: (false, Some(100.), None, Some("E1109")),
+    case04: (true, Some(-1.), None, Some("E1109")),
+    case05: (true, None, Some(0.5), Some("E1109")),
+}
+
+fn can_detect_invalid_ride_duration_limit_impl(
+    is_pickup_delivery: bool,
+    max_ride_duration: Option<f64>,
+    max_ride_factor: Option<f64>,
+    expected: Option<&str>,
+) {
+    let job = if is_pickup_delivery {
+        create_pickup_delivery_job("job1", (1., 0.), (2., 0.))
+    } else {
+        create_delivery_job("job1", (1., 0.))
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { max_ride_duration, max_ride_factor, ..job }], ..create_empty_plan() },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1109_invalid_ride_duration_limit(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if let Some(code) = expected {
+        assert_result(code, "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_splittable_job, (job, min_split, has_relation, expected), {
+    can_detect_invalid_splittable_job_impl(job, min_split, has_relation, expected);
+}}
+
+can_detect_invalid_splittable_job! {
+    case01_delivery: (create_delivery_job("job1", (1., 0.)), None, false, None),
+    case02_min_split: (create_delivery_job("job1", (1., 0.)), Some(vec![1]), false, None),
+    case03_pickup_delivery: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), None, false, Some("E1110")),
+    case04_service: (create_service_job("job1", (1., 0.)), None, false, Some("E1110")),
+    case05_invalid_min_split: (create_delivery_job("job1", (1., 0.)), Some(vec![1, 1]), false, Some("E1110")),
+    case06_negative_min_split: (create_delivery_job("job1", (1., 0.)), Some(vec![-1]), false, Some("E1110")),
+    case07_relation: (create_delivery_job("job1", (1., 0.)), None, true, Some("E1110")),
+}
+
+fn can_detect_invalid_splittable_job_impl(
+    job: Job,
+    min_split: Option<Vec<i32>>,
+    has_relation: bool,
+    expected: Option<&str>,
+) {
+    let make_splittable = |tasks: Option<Vec<JobTask>>| {
+        tasks.map(|tasks| {
+            tasks
+                .into_iter()
+                .map(|task| JobTask { splittable: Some(true), min_split: min_split.clone(), ..task })
+                .collect::<Vec<_>>()
+        })
+    };
+    let job = Job {
+        pickups: make_splittable(job.pickups),
+        deliveries: make_splittable(job.deliveries),
+        services: make_splittable(job.services),
+        ..job
+    };
+    let relations = if has_relation {
+        Some(vec![Relation {
+            type_field: RelationType::Any,
+            jobs: vec!["job1".to_string()],
+            vehicle_id: Some("my_vehicle_1".to_string()),
+            shift_index: None,
+            offset: None,
+        }])
+    } else {
+        None
+    };
+    let problem =
+        Problem { plan: Plan { jobs: vec![job], relations, ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1110_invalid_splittable_job(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(code) = expected {
+        assert_result(code, "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+fn create_periodic(frequency: usize, max_frequency: Option<usize>) -> JobPeriodic {
+    JobPeriodic { frequency, max_frequency, day_patterns: None, min_spacing: None, max_spacing: None }
+}
+
+parameterized_test! {can_detect_invalid_periodic_job, (periodic, has_relation, expected), {
+    can_detect_invalid_periodic_job_impl(periodic, has_relation, expected);
+}}
+
+can_detect_invalid_periodic_job! {
+    case01_valid: (create_periodic(2, Some(3)), false, None),
+    case02_zero_frequency: (create_periodic(0, None), false, Some("E1111")),
+    case03_invalid_max_frequency: (create_periodic(2, Some(1)), false, Some("E1111")),
+    case04_zero_max_spacing: (JobPeriodic { max_spacing: Some(0), ..create_periodic(2, None) }, false, Some("E1111")),
+    case05_invalid_spacing: (
+        JobPeriodic { min_spacing: Some(3), max_spacing: Some(2), ..create_periodic(2, None) }, false, Some("E1111")
+    ),
+    case06_valid_patterns: (JobPeriodic { day_patterns: Some(vec![vec![0, 2]]), ..create_periodic(2, None) }, false, None),
+    case07_empty_patterns: (JobPeriodic { day_patterns: Some(vec![]), ..create_periodic(2, None) }, false, Some("E1111")),
+    case08_short_pattern: (
+        JobPeriodic { day_patterns: Some(vec![vec![0, 2], vec![1, 1]]), ..create_periodic(2, None) }, false, Some("E1111")
+    ),
+    case09_relation: (create_periodic(2, None), true, Some("E1111")),
+    case10_long_pattern: (
+        JobPeriodic { day_patterns: Some(vec![vec![0, 2, 4]]), ..create_periodic(2, None) }, false, Some("E1111")
+    ),
+    case11_long_pattern_with_max_frequency: (
+        JobPeriodic { day_patterns: Some(vec![vec![0, 2, 4]]), ..create_periodic(2, Some(3)) }, false, None
+    ),
+}
+
+fn can_detect_invalid_periodic_job_impl(periodic: JobPeriodic, has_relation: bool, expected: Option<&str>) {
+    let relations = if has_relation {
+        Some(vec![Relation {
+            type_field: RelationType::Any,
+            jobs: vec!["job1".to_string()],
+            vehicle_id: Some("my_vehicle_1".to_string()),
+            shift_index: None,
+            offset: None,
+        }])
+    } else {
+        None
+    };
+    let job = Job { periodic: Some(periodic), ..create_delivery_job("job1", (1., 0.)) };
+    let problem =
+        Problem { plan: Plan { jobs: vec![job], relations, ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1111_invalid_periodic_job(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(code) = expected {
+        assert_result(code, "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+fn create_variant(deliveries: Option<Vec<JobTask>>) -> JobVariant {
+    JobVariant { pickups: None, deliveries, replacements: None, services: None, value: None }
+}
+
+parameterized_test! {can_detect_invalid_job_alternatives, (job, expected), {
+    can_detect_invalid_job_alternatives_impl(job, expected);
+}}
+
+can_detect_invalid_job_alternatives! {
+    case01_valid: (Job {
+        alternatives: Some(vec![create_variant(Some(vec![create_task((1., 0.), None)])), create_variant(Some(vec![create_task((2., 0.), None)]))]),
+        ..create_job("job1")
+    }, None),
+    case02_empty_alternatives: (Job { alternatives: Some(vec![]), ..create_job("job1") }, Some("E1112")),
+    case03_own_tasks: (Job {
+        alternatives: Some(vec![create_variant(Some(vec![create_task((2., 0.), None)]))]),
+        ..create_delivery_job("job1", (1., 0.))
+    }, Some("E1112")),
+    case04_empty_variant: (Job {
+        alternatives: Some(vec![create_variant(Some(vec![create_task((2., 0.), None)])), create_variant(Some(vec![]))]),
+        ..create_job("job1")
+    }, Some("E1112")),
+    case05_periodic: (Job {
+        alternatives: Some(vec![create_variant(Some(vec![create_task((2., 0.), None)]))]),
+        periodic: Some(create_periodic(2, None)),
+        ..create_job("job1")
+    }, Some("E1112")),
+}
+
+fn can_detect_invalid_job_alternatives_impl(job: Job, expected: Option<&str>) {
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1112_invalid_job_alternatives(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(code) = expected {
+        assert_result(code, "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_duration_rules, (vehicle_types, skills, multiplier, value, expected), {
+    can_detect_invalid_duration_rules_impl(vehicle_types, skills, multiplier, value, expected);
+}}
+
+can_detect_invalid_duration_rules! {
+    case01_valid_type: (Some(vec!["my_vehicle"]), None, Some(2.), None, None),
+    case02_valid_skills: (None, Some(vec!["expert"]), None, Some(5.), None),
+    case03_no_condition: (None, None, Some(2.), None, Some("E1113")),
+    case04_unknown_type: (Some(vec!["unknown"]), None, Some(2.), None, Some("E1113")),
+    case05_both_values: (Some(vec!["my_vehicle"]), None, Some(2.), Some(5.), Some("E1113")),
+    case06_no_values: (Some(vec!["my_vehicle"]), None, None, None, Some("E1113")),
+    case07_zero_multiplier: (Some(vec!["my_vehicle"]), None, Some(0.), None, Some("E1113")),
+    case08_negative_value: (None, Some(vec!["expert"]), None, Some(-1.), Some("E1113")),
+}
+
+fn can_detect_invalid_duration_rules_impl(
+    vehicle_types: Option<Vec<&str>>,
+    skills: Option<Vec<&str>>,
+    multiplier: Option<f64>,
+    value: Option<f64>,
+    expected: Option<&str>,
+) {
+    let to_strings = |values: Option<Vec<&str>>| values.map(|values| values.iter().map(|v| v.to_string()).collect());
+    let mut job = create_delivery_job("job1", (1., 0.));
+    job.deliveries.as_mut().unwrap()[0].places[0].duration_rules = Some(vec![JobPlaceDurationRule {
+        vehicle_types: to_strings(vehicle_types),
+        skills: to_strings(skills),
+        multiplier,
+        value,
+    }]);
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1113_invalid_duration_rules(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(code) = expected {
+        assert_result(code, "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+fn with_max_time_in_vehicle(tasks: Option<Vec<JobTask>>, limit: f64) -> Option<Vec<JobTask>> {
+    tasks.map(|tasks| tasks.into_iter().map(|task| JobTask { max_time_in_vehicle: Some(limit), ..task }).collect())
+}
+
+parameterized_test! {can_detect_invalid_max_time_in_vehicle, (job, expected), {
+    can_detect_invalid_max_time_in_vehicle_impl(job, expected);
+}}
+
+can_detect_invalid_max_time_in_vehicle! {
+    case01_delivery: ({
+        let job = create_delivery_job("job1", (1., 0.));
+        Job { deliveries: with_max_time_in_vehicle(job.deliveries.clone(), 100.), ..job }
+    }, None),
+    case02_negative: ({
+        let job = create_delivery_job("job1", (1., 0.));
+        Job { deliveries: with_max_time_in_vehicle(job.deliveries.clone(), -1.), ..job }
+    }, Some("E1114")),
+    case03_pickup: ({
+        let job = create_pickup_job("job1", (1., 0.));
+        Job { pickups: with_max_time_in_vehicle(job.pickups.clone(), 100.), ..job }
+    }, Some("E1114")),
+    case04_service: ({
+        let job = create_service_job("job1", (1., 0.));
+        Job { services: with_max_time_in_vehicle(job.services.clone(), 100.), ..job }
+    }, Some("E1114")),
+    case05_pickup_delivery: ({
+        let job = create_pickup_delivery_job("job1", (1., 0.), (2., 0.));
+        Job { deliveries: with_max_time_in_vehicle(job.deliveries.clone(), 100.), ..job }
+    }, Some("E1114")),
+    case06_variant_delivery: (Job {
+        alternatives: Some(vec![create_variant(with_max_time_in_vehicle(Some(vec![create_task((2., 0.), None)]), 100.))]),
+        ..create_job("job1")
+    }, None),
+    case07_variant_pickup_delivery: (Job {
+        alternatives: Some(vec![JobVariant {
+            pickups: Some(vec![create_task((1., 0.), None)]),
+            ..create_variant(with_max_time_in_vehicle(Some(vec![create_task((2., 0.), None)]), 100.))
+        }]),
+        ..create_job("job1")
+    }, Some("E1114")),
+}
+
+fn can_detect_invalid_max_time_in_vehicle_impl(job: Job, expected: Option<&str>) {
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1114_invalid_max_time_in_vehicle(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if let Some(code) = expected {
+        assert_result(code, "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_job_transfers, (job, expected), {
+    can_detect_invalid_job_transfers_impl(job, expected);
+}}
+
+can_detect_invalid_job_transfers! {
+    case01_valid: (Job {
+        transfers: Some(vec![create_job_place((5., 0.), Some("hub".to_string()))]),
+        ..create_pickup_delivery_job("job1", (1., 0.), (10., 0.))
+    }, None),
+    case02_empty_transfers: (Job { transfers: Some(vec![]), ..create_pickup_delivery_job("job1", (1., 0.), (10., 0.)) }, Some("E1115")),
+    case03_delivery_job: (Job {
+        transfers: Some(vec![create_job_place((5., 0.), None)]),
+        ..create_delivery_job("job1", (1., 0.))
+    }, Some("E1115")),
+    case04_negative_duration: (Job {
+        transfers: Some(vec![JobPlace { duration: -1., ..create_job_place((5., 0.), None) }]),
+        ..create_pickup_delivery_job("job1", (1., 0.), (10., 0.))
+    }, Some("E1115")),
+    case05_periodic: (Job {
+        transfers: Some(vec![create_job_place((5., 0.), None)]),
+        periodic: Some(create_periodic(2, None)),
+        ..create_pickup_delivery_job("job1", (1., 0.), (10., 0.))
+    }, Some("E1115")),
+}
+
+fn can_detect_invalid_job_transfers_impl(job: Job, expected: Option<&str>) {
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1115_invalid_job_transfers(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(code) = expected {
+        assert_result(code, "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_satellite_supply, (job, has_relation, expected), {
+    can_detect_invalid_satellite_supply_impl(job, has_relation, expected);
+}}
+
+can_detect_invalid_satellite_supply! {
+    case01_valid_supply: (Job { satellite_id: Some("sat1".to_string()), ..create_delivery_job("job1", (1., 0.)) }, false, None),
+    case02_unknown_satellite: (Job {
+        satellite_id: Some("sat2".to_string()),
+        ..create_delivery_job("job1", (1., 0.))
+    }, false, Some("E1116")),
+    case03_other_location: (Job {
+        satellite_id: Some("sat1".to_string()),
+        ..create_delivery_job("job1", (2., 0.))
+    }, false, Some("E1116")),
+    case04_pickup_job: (Job {
+        satellite_id: Some("sat1".to_string()),
+        ..create_pickup_delivery_job("job1", (1., 0.), (1., 0.))
+    }, false, Some("E1116")),
+    case05_periodic: (Job {
+        satellite_id: Some("sat1".to_string()),
+        periodic: Some(create_periodic(2, None)),
+        ..create_delivery_job("job1", (1., 0.))
+    }, false, Some("E1116")),
+    case06_relation: (Job { satellite_id: Some("sat1".to_string()), ..create_delivery_job("job1", (1., 0.)) }, true, Some("E1116")),
+}
+
+fn can_detect_invalid_satellite_supply_impl(job: Job, has_relation: bool, expected: Option<&str>) {
+    let relations = if has_relation {
+        Some(vec![Relation {
+            type_field: RelationType::Any,
+            jobs: vec!["job1".to_string()],
+            vehicle_id: Some("my_vehicle_1".to_string()),
+            shift_index: None,
+            offset: None,
+        }])
+    } else {
+        None
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], relations, ..create_empty_plan() },
+        fleet: Fleet {
+            resources: Some(vec![VehicleResource::Satellite { id: "sat1".to_string(), location: (1., 0.).to_loc() }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1116_invalid_satellite_supply(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(code) = expected {
+        assert_result(code, "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
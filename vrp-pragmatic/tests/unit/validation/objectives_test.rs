@@ -218,3 +218,104 @@ fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Vec<Objective>
 
     assert_eq!(result.err().map(|e| e.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_plan_reference, (reference, options, expected), {
+    can_detect_invalid_plan_reference_impl(reference, options, expected);
+}}
+
+can_detect_invalid_plan_reference! {
+    case01_valid: (vec![("my_vehicle_1", vec!["job1", "job2"])], None, None),
+    case02_unknown_vehicle: (vec![("unknown", vec!["job1"])], None, Some("E1608".to_string())),
+    case03_duplicate_job: (vec![("my_vehicle_1", vec!["job1", "job1"])], None, Some("E1608".to_string())),
+    case04_duplicate_job_in_tours: (
+        vec![("my_vehicle_1", vec!["job1"]), ("my_vehicle_1", vec!["job1"])], None, Some("E1608".to_string())
+    ),
+    case05_negative_weight: (vec![("my_vehicle_1", vec!["job1"])], Some((Some(-1.), None)), Some("E1608".to_string())),
+    case06_positive_weights: (vec![("my_vehicle_1", vec!["job1"])], Some((Some(10.), Some(1.))), None),
+}
+
+fn can_detect_invalid_plan_reference_impl(
+    reference: Vec<(&str, Vec<&str>)>,
+    options: Option<(Option<f64>, Option<f64>)>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        objectives: Some(vec![
+            vec![MinimizePlanChanges {
+                reference: reference
+                    .into_iter()
+                    .map(|(vehicle_id, jobs)| ReferenceTour {
+                        vehicle_id: vehicle_id.to_string(),
+                        jobs: jobs.into_iter().map(|job| job.to_string()).collect(),
+                    })
+                    .collect(),
+                options: options.map(|(reassignment, sequence)| PlanChangeOptions { reassignment, sequence }),
+            }],
+            vec![MinimizeCost],
+        ]),
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1608_invalid_plan_reference(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+fn weighted(objectives: Vec<(f64, Objective)>) -> Objective {
+    Weighted {
+        objectives: objectives.into_iter().map(|(weight, objective)| WeightedObjective { weight, objective }).collect(),
+    }
+}
+
+parameterized_test! {can_detect_invalid_weighted_objective, (objectives, expected), {
+    can_detect_invalid_weighted_objective_impl(objectives, expected);
+}}
+
+can_detect_invalid_weighted_objective! {
+    case01_valid: (vec![vec![weighted(vec![(1., min_cost()), (50., MinimizeTours)])]], None),
+    case02_empty: (vec![vec![weighted(vec![])], vec![min_cost()]], Some("E1609")),
+    case03_zero_weight: (vec![vec![weighted(vec![(0., min_cost())])]], Some("E1609")),
+    case04_negative_weight: (vec![vec![weighted(vec![(1., min_cost()), (-1., MinimizeTours)])]], Some("E1609")),
+    case05_nested: (vec![vec![weighted(vec![(1., min_cost()), (1., weighted(vec![(1., MinimizeTours)]))])]], Some("E1609")),
+}
+
+fn can_detect_invalid_weighted_objective_impl(objectives: Vec<Vec<Objective>>, expected: Option<&str>) {
+    let problem = Problem { objectives: Some(objectives), ..create_empty_problem() };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1609_invalid_weighted_objective(&objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_validate_components_of_weighted_objective, (objectives, expected), {
+    can_validate_components_of_weighted_objective_impl(objectives, expected);
+}}
+
+can_validate_components_of_weighted_objective! {
+    case01_cost_in_weighted: (vec![vec![weighted(vec![(1., min_cost()), (50., MinimizeTours)])]], None),
+    case02_no_cost: (vec![vec![weighted(vec![(50., MinimizeTours)])]], Some("E1602")),
+    case03_multiple_costs: (
+        vec![vec![weighted(vec![(1., min_cost()), (1., MinimizeDistance)])]], Some("E1606")
+    ),
+    case04_duplicates: (vec![vec![weighted(vec![(1., min_cost())])], vec![min_cost()]], Some("E1601,E1606")),
+}
+
+fn can_validate_components_of_weighted_objective_impl(objectives: Vec<Vec<Objective>>, expected: Option<&str>) {
+    let problem = Problem { objectives: Some(objectives), ..create_empty_problem() };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = validate_objectives(&ctx);
+
+    assert_eq!(
+        result.err().map(|err| err.errors.into_iter().map(|err| err.code).collect::<Vec<_>>().join(",")),
+        expected.map(|codes| codes.to_string())
+    );
+}
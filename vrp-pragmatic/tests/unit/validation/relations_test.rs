@@ -46,8 +46,9 @@ fn can_detect_relation_errors_impl(
             relations: Some(vec![Relation {
                 type_field: RelationType::Strict,
                 jobs: job_ids,
-                vehicle_id,
+                vehicle_id: Some(vehicle_id),
                 shift_index,
+                offset: None,
             }]),
             ..create_empty_plan()
         },
@@ -92,8 +93,9 @@ fn can_detect_multi_place_time_window_jobs_impl(relation_type: RelationType, exp
             relations: Some(vec![Relation {
                 type_field: relation_type,
                 jobs: vec!["job1".to_string(), "job2".to_string(), "job3".to_string()],
-                vehicle_id: "my_vehicle_1".to_string(),
+                vehicle_id: Some("my_vehicle_1".to_string()),
                 shift_index: None,
+                offset: None,
             }]),
             ..create_empty_plan()
         },
@@ -134,8 +136,9 @@ fn can_detect_multi_vehicle_assignment_impl(relations: Vec<(&str, &str)>, expect
                     .map(|(job_id, vehicle_id)| Relation {
                         type_field: RelationType::Any,
                         jobs: vec![job_id.to_string()],
-                        vehicle_id: vehicle_id.to_string(),
+                        vehicle_id: Some(vehicle_id.to_string()),
                         shift_index: None,
+                        offset: None,
                     })
                     .collect(),
             ),
@@ -184,8 +187,9 @@ fn can_detect_incomplete_multi_job_in_relation_impl(
             relations: Some(vec![Relation {
                 type_field: relation_type,
                 jobs,
-                vehicle_id: "my_vehicle_1".to_string(),
+                vehicle_id: Some("my_vehicle_1".to_string()),
                 shift_index: None,
+                offset: None,
             }]),
             ..create_empty_plan()
         },
@@ -197,3 +201,73 @@ fn can_detect_incomplete_multi_job_in_relation_impl(
 
     assert_eq!(result.map(|err| err.code), expected);
 }
+
+#[test]
+fn can_detect_lock_relation_without_vehicle_id() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.))],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Strict,
+                jobs: vec!["job1".to_string()],
+                vehicle_id: None,
+                shift_index: None,
+                offset: None,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.map(|err| err.code), Some("E1208".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_schedule_relation, (relation_type, jobs, vehicle_id, offset, expected), {
+    can_detect_invalid_schedule_relation_impl(
+        relation_type,
+        jobs.iter().map(|job| job.to_string()).collect(),
+        vehicle_id.map(|id: &str| id.to_string()),
+        offset,
+        expected.map(|result: &str| result.to_string()),
+    );
+}}
+
+can_detect_invalid_schedule_relation! {
+    case01_valid_sync: (RelationType::Sync, &["job1", "job2"], None, Some(10.), None),
+    case02_valid_precedence: (RelationType::Precedence, &["job1", "job2"], None, None, None),
+    case03_single_job: (RelationType::Sync, &["job1"], None, None, Some("E1209")),
+    case04_duplicate_job: (RelationType::Sync, &["job1", "job1"], None, None, Some("E1209")),
+    case05_vehicle_id: (RelationType::Precedence, &["job1", "job2"], Some("my_vehicle_1"), None, Some("E1209")),
+    case06_negative_offset: (RelationType::Precedence, &["job1", "job2"], None, Some(-1.), Some("E1209")),
+    case07_multi_task_job: (RelationType::Sync, &["job1", "job3"], None, None, Some("E1209")),
+    case08_reserved_job: (RelationType::Sync, &["job1", "departure"], None, None, Some("E1209")),
+}
+
+fn can_detect_invalid_schedule_relation_impl(
+    relation_type: RelationType,
+    jobs: Vec<String>,
+    vehicle_id: Option<String>,
+    offset: Option<f64>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_pickup_delivery_job("job3", (1., 0.), (2., 0.)),
+            ],
+            relations: Some(vec![Relation { type_field: relation_type, jobs, vehicle_id, shift_index: None, offset }]),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.map(|err| err.code), expected);
+}
@@ -1,6 +1,7 @@
 use super::*;
 use crate::format_time;
 use crate::helpers::*;
+use std::iter::once;
 
 #[test]
 fn can_detect_invalid_break_time() {
@@ -44,7 +45,14 @@ fn can_detect_zero_costs_impl(costs: (f64, f64), expected: Option<String>) {
     let problem = Problem {
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance, time },
+                costs: VehicleCosts {
+                    fixed: None,
+                    distance,
+                    time,
+                    distance_loaded: None,
+                    time_tiers: None,
+                    distance_tiers: None,
+                },
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -58,6 +66,136 @@ fn can_detect_zero_costs_impl(costs: (f64, f64), expected: Option<String>) {
     assert_eq!(result.err().map(|err| err.code), expected);
 }
 
+parameterized_test! {can_detect_invalid_load_dependent_costs, (distance_loaded, expected), {
+    can_detect_invalid_load_dependent_costs_impl(distance_loaded, expected);
+}}
+
+can_detect_invalid_load_dependent_costs! {
+    case01: (None, None),
+    case02: (Some(0.), None),
+    case03: (Some(2.), None),
+    case04: (Some(-1.), Some("E1311".to_string())),
+}
+
+fn can_detect_invalid_load_dependent_costs_impl(distance_loaded: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { distance_loaded, ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1311_vehicle_load_dependent_costs(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_recharge_battery, (max_distance, battery, power, expected), {
+    can_detect_invalid_recharge_battery_impl(max_distance, battery, power, expected);
+}}
+
+can_detect_invalid_recharge_battery! {
+    case01_max_distance: (Some(100.), None, None, None),
+    case02_battery: (None, Some((20., Some(10.), Some(2.), 1.)), Some(1.), None),
+    case03_no_limits: (None, None, None, Some("E1312".to_string())),
+    case04_zero_capacity: (None, Some((0., None, None, 1.)), None, Some("E1312".to_string())),
+    case05_zero_consumption: (None, Some((20., None, None, 0.)), None, Some("E1312".to_string())),
+    case06_initial_above_capacity: (None, Some((20., Some(30.), None, 1.)), None, Some("E1312".to_string())),
+    case07_reserve_above_initial: (None, Some((20., Some(10.), Some(15.), 1.)), None, Some("E1312".to_string())),
+    case08_zero_power: (None, Some((20., None, None, 1.)), Some(0.), Some("E1312".to_string())),
+}
+
+fn can_detect_invalid_recharge_battery_impl(
+    max_distance: Option<f64>,
+    battery: Option<(f64, Option<f64>, Option<f64>, f64)>,
+    power: Option<f64>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    recharges: Some(VehicleRecharges {
+                        max_distance,
+                        battery: battery.map(|(capacity, initial, reserve, consumption)| VehicleBattery {
+                            capacity,
+                            initial,
+                            reserve,
+                            consumption,
+                            consumption_loaded: None,
+                        }),
+                        stations: vec![VehicleRechargeStation {
+                            location: (1., 0.).to_loc(),
+                            duration: 0.,
+                            times: None,
+                            tag: None,
+                            power,
+                            dock: None,
+                        }],
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1312_vehicle_recharge_battery(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_driving_break, (driving_break, has_other_break, expected), {
+    can_detect_invalid_driving_break_impl(driving_break, has_other_break, expected);
+}}
+
+can_detect_invalid_driving_break! {
+    case01_valid: ((270., 45., None), false, None),
+    case02_valid_split: ((270., 45., Some(vec![15., 30.])), false, None),
+    case03_zero_driving_time: ((0., 45., None), false, Some("E1313".to_string())),
+    case04_zero_duration: ((270., 0., None), false, Some("E1313".to_string())),
+    case05_invalid_split: ((270., 45., Some(vec![15., 15., 15.])), false, Some("E1313".to_string())),
+    case06_combined_with_other_break: ((270., 45., None), true, Some("E1313".to_string())),
+}
+
+fn can_detect_invalid_driving_break_impl(
+    driving_break: (f64, f64, Option<Vec<f64>>),
+    has_other_break: bool,
+    expected: Option<String>,
+) {
+    let (max_driving_time, duration, split) = driving_break;
+    let other_break = VehicleBreak::Required {
+        time: VehicleRequiredBreakTime::OffsetTime { earliest: 10., latest: 20. },
+        duration: 2.,
+    };
+    let breaks = once(VehicleBreak::Driving { max_driving_time, duration, split })
+        .chain(once(other_break).filter(|_| has_other_break))
+        .collect();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { breaks: Some(breaks), ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1313_vehicle_driving_breaks(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
 parameterized_test! {can_handle_rescheduling_with_required_break, (latest, expected), {
     can_handle_rescheduling_with_required_break_impl(latest, expected);
 }}
@@ -136,3 +274,259 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_handle_vehicle_compartments, (compartments, capacity, expected), {
+    can_handle_vehicle_compartments_impl(compartments, capacity, expected);
+}}
+
+can_handle_vehicle_compartments! {
+    case01_valid: (vec![("c1", 5, None), ("c2", 5, Some(10.))], vec![10], None),
+    case02_duplicate_ids: (vec![("c1", 5, None), ("c1", 5, None)], vec![10], Some("E1309".to_string())),
+    case03_zero_capacity: (vec![("c1", 0, None)], vec![10], Some("E1309".to_string())),
+    case04_negative_cleaning: (vec![("c1", 5, Some(-1.))], vec![10], Some("E1309".to_string())),
+    case05_multi_dim_capacity: (vec![("c1", 5, None)], vec![10, 10], Some("E1309".to_string())),
+}
+
+fn can_handle_vehicle_compartments_impl(
+    compartments: Vec<(&str, i32, Option<f64>)>,
+    capacity: Vec<i32>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity,
+                compartments: Some(
+                    compartments
+                        .into_iter()
+                        .map(|(id, capacity, cleaning_duration)| VehicleCompartment {
+                            id: id.to_string(),
+                            capacity,
+                            cleaning_duration,
+                        })
+                        .collect(),
+                ),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1309_vehicle_compartments(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_dock_resources, (docks, job_dock, reload_dock, expected), {
+    can_handle_dock_resources_impl(docks, job_dock, reload_dock, expected);
+}}
+
+can_handle_dock_resources! {
+    case01_valid: (vec![("d1", 1), ("d2", 2)], Some("d1"), Some("d2"), None),
+    case02_no_references: (vec![("d1", 1)], None, None, None),
+    case03_duplicate_ids: (vec![("d1", 1), ("d1", 2)], Some("d1"), None, Some("E1310".to_string())),
+    case04_zero_slots: (vec![("d1", 0)], Some("d1"), None, Some("E1310".to_string())),
+    case05_unknown_job_dock: (vec![("d1", 1)], Some("d2"), None, Some("E1310".to_string())),
+    case06_unknown_reload_dock: (vec![("d1", 1)], None, Some("d2"), Some("E1310".to_string())),
+}
+
+fn can_handle_dock_resources_impl(
+    docks: Vec<(&str, usize)>,
+    job_dock: Option<&str>,
+    reload_dock: Option<&str>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        dock: job_dock.map(|dock| dock.to_string()),
+                        ..create_job_place((1., 0.), None)
+                    }],
+                    ..create_task((1., 0.), None)
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload {
+                        dock: reload_dock.map(|dock| dock.to_string()),
+                        ..create_default_reload()
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            resources: Some(
+                docks.into_iter().map(|(id, slots)| VehicleResource::Dock { id: id.to_string(), slots }).collect(),
+            ),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1310_vehicle_dock_resources(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_cost_tiers, (time_tiers, distance_tiers, expected), {
+    can_detect_invalid_cost_tiers_impl(time_tiers, distance_tiers, expected);
+}}
+
+can_detect_invalid_cost_tiers! {
+    case01_no_tiers: (None, None, None),
+    case02_valid_tiers: (Some(vec![(10., 2.), (20., 3.)]), Some(vec![(0., 1.)]), None),
+    case03_negative_threshold: (Some(vec![(-1., 2.)]), None, Some("E1314".to_string())),
+    case04_negative_cost: (None, Some(vec![(10., -1.)]), Some("E1314".to_string())),
+    case05_same_threshold: (Some(vec![(10., 2.), (10., 3.)]), None, Some("E1314".to_string())),
+}
+
+fn can_detect_invalid_cost_tiers_impl(
+    time_tiers: Option<Vec<(f64, f64)>>,
+    distance_tiers: Option<Vec<(f64, f64)>>,
+    expected: Option<String>,
+) {
+    let create_tiers = |tiers: Option<Vec<(f64, f64)>>| {
+        tiers.map(|tiers| {
+            tiers.into_iter().map(|(threshold, cost)| VehicleCostTier { threshold, cost }).collect::<Vec<_>>()
+        })
+    };
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts {
+                    time_tiers: create_tiers(time_tiers),
+                    distance_tiers: create_tiers(distance_tiers),
+                    ..create_default_vehicle_costs()
+                },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1314_vehicle_cost_tiers(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_trip_limits, (max_distance, max_duration, size, expected), {
+    can_detect_invalid_trip_limits_impl(max_distance, max_duration, size, expected);
+}}
+
+can_detect_invalid_trip_limits! {
+    case01_valid_limits: (Some(100.), Some(3600.), Some(3), None),
+    case02_valid_size_only: (None, None, Some(3), None),
+    case03_empty_limits: (None, None, None, Some("E1315".to_string())),
+    case04_zero_distance: (Some(0.), None, None, Some("E1315".to_string())),
+    case05_negative_duration: (None, Some(-1.), Some(3), Some("E1315".to_string())),
+    case06_zero_size: (None, Some(3600.), Some(0), Some("E1315".to_string())),
+}
+
+fn can_detect_invalid_trip_limits_impl(
+    max_distance: Option<f64>,
+    max_duration: Option<f64>,
+    size: Option<usize>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    trip: Some(VehicleTripLimits { max_distance, max_duration, size }),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1315_vehicle_trip_limits(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_trailer, (trailer_capacity, parks, expected), {
+    can_detect_invalid_trailer_impl(trailer_capacity, parks, expected);
+}}
+
+can_detect_invalid_trailer! {
+    case01_valid_trailer: (vec![5], vec![(10., 20.)], None),
+    case02_different_dimensions: (vec![5, 5], vec![(10., 20.)], Some("E1316".to_string())),
+    case03_negative_capacity: (vec![-1], vec![(10., 20.)], Some("E1316".to_string())),
+    case04_no_parks: (vec![5], vec![], Some("E1316".to_string())),
+    case05_negative_duration: (vec![5], vec![(10., -1.)], Some("E1316".to_string())),
+}
+
+fn can_detect_invalid_trailer_impl(trailer_capacity: Vec<i32>, parks: Vec<(f64, f64)>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                trailer: Some(VehicleTrailer {
+                    capacity: trailer_capacity,
+                    parks: parks
+                        .into_iter()
+                        .map(|(decouple_duration, couple_duration)| VehicleTrailerPark {
+                            location: (1., 0.).to_loc(),
+                            decouple_duration,
+                            couple_duration,
+                            tag: None,
+                        })
+                        .collect(),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1316_vehicle_trailer(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_satellite_resources, (satellites, expected), {
+    can_detect_invalid_satellite_resources_impl(satellites, expected);
+}}
+
+can_detect_invalid_satellite_resources! {
+    case01_valid_satellites: (vec![("sat1", (1., 0.)), ("sat2", (2., 0.))], None),
+    case02_duplicate_ids: (vec![("sat1", (1., 0.)), ("sat1", (2., 0.))], Some("E1317".to_string())),
+    case03_duplicate_locations: (vec![("sat1", (1., 0.)), ("sat2", (1., 0.))], Some("E1317".to_string())),
+}
+
+fn can_detect_invalid_satellite_resources_impl(satellites: Vec<(&str, (f64, f64))>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            resources: Some(
+                satellites
+                    .into_iter()
+                    .map(|(id, location)| VehicleResource::Satellite {
+                        id: id.to_string(),
+                        location: location.to_loc(),
+                    })
+                    .collect(),
+            ),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1317_vehicle_satellite_resources(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
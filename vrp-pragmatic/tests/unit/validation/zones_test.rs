@@ -0,0 +1,89 @@
+use super::*;
+use crate::format::Location;
+use crate::helpers::*;
+
+fn create_zone(id: &str, polygon: Vec<(f64, f64)>, vehicle_type_ids: Option<Vec<&str>>, fee: Option<f64>) -> Zone {
+    Zone {
+        id: id.to_string(),
+        polygon: polygon.into_iter().map(|(lat, lng)| Location::new_coordinate(lat, lng)).collect(),
+        vehicle_type_ids: vehicle_type_ids.map(|ids| ids.into_iter().map(|id| id.to_string()).collect()),
+        fee,
+    }
+}
+
+fn create_square_zone(id: &str) -> Zone {
+    create_zone(id, vec![(0., 0.), (0., 1.), (1., 1.), (1., 0.)], None, None)
+}
+
+fn create_problem_with_zones(zones: Vec<Zone>) -> Problem {
+    Problem {
+        plan: Plan { zones: Some(zones), ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_detect_duplicated_zone_ids() {
+    let problem = create_problem_with_zones(vec![create_square_zone("zone1"), create_square_zone("zone1")]);
+
+    let result =
+        check_e1700_no_zones_with_duplicate_ids(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), Some("E1700".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_zone_polygon, (polygon, expected), {
+    can_detect_invalid_zone_polygon_impl(polygon, expected);
+}}
+
+can_detect_invalid_zone_polygon! {
+    case01_valid: (vec![(0., 0.), (0., 1.), (1., 1.)], None),
+    case02_too_few_vertices: (vec![(0., 0.), (0., 1.)], Some("E1701".to_string())),
+    case03_empty: (vec![], Some("E1701".to_string())),
+    case04_invalid_lat: (vec![(0., 0.), (91., 1.), (1., 1.)], Some("E1701".to_string())),
+    case05_invalid_lng: (vec![(0., 0.), (0., -181.), (1., 1.)], Some("E1701".to_string())),
+}
+
+fn can_detect_invalid_zone_polygon_impl(polygon: Vec<(f64, f64)>, expected: Option<String>) {
+    let problem = create_problem_with_zones(vec![create_zone("zone1", polygon, None, None)]);
+
+    let result = check_e1701_zone_polygon(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+#[test]
+fn can_detect_reference_location_in_zone_polygon() {
+    let mut zone = create_square_zone("zone1");
+    zone.polygon.push(Location::Reference { index: 0 });
+    let problem = create_problem_with_zones(vec![zone]);
+
+    let result = check_e1701_zone_polygon(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), Some("E1701".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_zone_access_and_fee, (vehicle_type_ids, fee, expected), {
+    can_detect_invalid_zone_access_and_fee_impl(vehicle_type_ids, fee, expected);
+}}
+
+can_detect_invalid_zone_access_and_fee! {
+    case01_no_restrictions: (None, None, None),
+    case02_known_type: (Some(vec!["my_vehicle"]), Some(10.), None),
+    case03_unknown_type: (Some(vec!["unknown"]), None, Some("E1702".to_string())),
+    case04_negative_fee: (None, Some(-1.), Some("E1702".to_string())),
+}
+
+fn can_detect_invalid_zone_access_and_fee_impl(
+    vehicle_type_ids: Option<Vec<&str>>,
+    fee: Option<f64>,
+    expected: Option<String>,
+) {
+    let polygon = vec![(0., 0.), (0., 1.), (1., 1.)];
+    let problem = create_problem_with_zones(vec![create_zone("zone1", polygon, vehicle_type_ids, fee)]);
+
+    let result = check_e1702_zone_access_and_fee(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
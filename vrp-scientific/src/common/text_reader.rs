@@ -53,6 +53,9 @@ pub(crate) fn create_fleet_with_distance_costs(
                 per_driving_time: 0.0,
                 per_waiting_time: 0.0,
                 per_service_time: 0.0,
+                per_distance_loaded: None,
+                time_tiers: vec![],
+                distance_tiers: vec![],
             },
             dimens: create_dimens_with_id("driver", &0.to_string()),
             details: Default::default(),
@@ -69,6 +72,9 @@ pub(crate) fn create_fleet_with_distance_costs(
                         per_driving_time: 0.0,
                         per_waiting_time: 0.0,
                         per_service_time: 0.0,
+                        per_distance_loaded: None,
+                        time_tiers: vec![],
+                        distance_tiers: vec![],
                     },
                     dimens,
                     details: vec![VehicleDetail {